@@ -1,25 +1,243 @@
-use crate::sim::jobs::Job;
+use crate::sim::carry_over::{self, ImportError};
+use crate::sim::game::{Game, GameState};
+use crate::sim::jobs::{Job, KNOWN_TAGS};
+use crate::sim::log::LogSearch;
+use crate::sim::mutators::Mutator;
+use crate::sim::processors::ProcessorState;
+use std::path::PathBuf;
+use std::time::Duration;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How long a rejection reason stays in the footer.
+const FLASH_DURATION: Duration = Duration::from_millis(1_500);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum FocusTarget {
+    #[default]
     Jobs,
     Processors,
 }
 
-impl Default for FocusTarget {
-    fn default() -> Self {
-        FocusTarget::Jobs
+/// Answer to the load screen shown before a saved game is opened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadChoice {
+    Continue,
+    /// Archive the save, as `--new-run` does, and start over.
+    NewGame,
+    /// Pack a care package out of this slot for another one to receive.
+    SendPackage,
+    /// Deliver the waiting care package into this slot.
+    ReceivePackage,
+    Quit,
+}
+
+impl LoadChoice {
+    /// The choices listed on the screen; quitting is a key.
+    pub const MENU: [Self; 4] = [
+        Self::Continue,
+        Self::NewGame,
+        Self::SendPackage,
+        Self::ReceivePackage,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Continue => "Continue",
+            Self::NewGame => "New Game",
+            Self::SendPackage => "Send Care Package",
+            Self::ReceivePackage => "Receive Care Package",
+            Self::Quit => "Quit",
+        }
+    }
+}
+
+/// Picker shown at launch when profiles exist or nothing has been saved yet.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProfilePicker {
+    /// Existing profiles, most recently played first.
+    pub profiles: Vec<String>,
+    /// 0 is the shared default save, then `profiles` in order.
+    pub selected: usize,
+    /// Name being typed for a new profile.
+    pub naming: Option<String>,
+    /// Why the last typed name was refused.
+    pub error: Option<String>,
+}
+
+impl ProfilePicker {
+    pub fn new(profiles: Vec<String>) -> Self {
+        Self {
+            profiles,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProfileChoice {
+    /// The save outside any profile.
+    Default,
+    Named(String),
+    Quit,
+}
+
+/// Transient popup listing board jobs for a processor chosen first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuickPick {
+    pub processor_index: usize,
+    pub selected: usize,
+}
+
+/// Popup editing one processor's daemon affinity per unlocked tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AffinityEditor {
+    pub processor_index: usize,
+    /// Highlighted row in the unlocked tags.
+    pub selected: usize,
+}
+
+/// Store popup choosing the model a processor is traded in for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TradeInPicker {
+    pub processor_index: usize,
+    /// Highlighted row in `Game::catalog_models`.
+    pub selected: usize,
+}
+
+/// Glossary popup state: filter text and highlighted match.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GlossaryState {
+    pub query: String,
+    pub searching: bool,
+    pub selected: usize,
+}
+
+/// Event-log focus: highlighted entry and the search over the log.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogFocus {
+    /// Sequence number of the highlighted entry.
+    pub selected: Option<u64>,
+    pub search: LogSearch,
+    pub searching: bool,
+}
+
+/// Purchase that was refused by the enforced credit reserve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HoldAction {
+    Store {
+        item: usize,
+        processor: Option<usize>,
+    },
+    Replace(usize),
+    ReplaceModel(usize),
+    Repair(usize),
+}
+
+/// Consecutive presses of a reserve-blocked purchase; holding the key
+/// (terminal auto-repeat) reaches the override threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReserveHold {
+    pub action: HoldAction,
+    pub presses: u8,
+}
+
+/// Previous run offered on the new-game screen, and the unit picked from it.
+#[derive(Debug, Clone)]
+pub struct CarryOver {
+    pub source: GameState,
+    pub processors: Result<Vec<ProcessorState>, ImportError>,
+    /// None carries nothing over.
+    pub selected: Option<usize>,
+}
+
+impl CarryOver {
+    pub fn new(source: GameState) -> Self {
+        Self {
+            processors: carry_over::importable(&source),
+            source,
+            selected: None,
+        }
+    }
+
+    pub fn chosen(&self) -> Option<&ProcessorState> {
+        let processors = self.processors.as_ref().ok()?;
+        processors.get(self.selected?)
+    }
+
+    /// Steps through "nothing" and each importable unit.
+    pub fn cycle(&mut self, forward: bool) {
+        let Ok(processors) = &self.processors else {
+            return;
+        };
+        let slots = processors.len() + 1;
+        let current = self.selected.map_or(0, |index| index + 1);
+        let next = if forward {
+            (current + 1) % slots
+        } else {
+            (current + slots - 1) % slots
+        };
+        self.selected = next.checked_sub(1);
     }
 }
 
+/// Rejection reason shown briefly in place of the footer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Flash {
+    pub reason: String,
+    pub remaining: Duration,
+}
+
 #[derive(Debug, Default)]
 pub struct App {
     focus: FocusTarget,
+    /// Position among the jobs the tag filter shows, not a board index.
     pub selected_job: usize,
-    pub selected_processor: usize,
+    /// Tag the job board is narrowed to; None shows every posting. Only the
+    /// view and the player's keys honour it, never the daemons.
+    pub job_filter: Option<String>,
+    /// Id of the picked processor, so reordering, buying and selling never
+    /// move the selection onto another unit.
+    pub selected_processor: Option<u64>,
     pub selected_store_item: usize,
     pub store_open: bool,
-    pub pending_job: Option<Job>,
+    pub museum_open: bool,
+    pub heatmap_open: bool,
+    /// Show each unit's projected electricity bill in the processor list.
+    pub power_column: bool,
+    pub help_open: bool,
+    /// Highlighted category while the message routing page is open.
+    pub settings: Option<usize>,
+    pub glossary: Option<GlossaryState>,
+    pub log_focus: Option<LogFocus>,
+    pub retire_confirm: Option<u64>,
+    /// Offline unit the player has been shown the salvage price of; a
+    /// second Shift+X strips it.
+    pub salvage_confirm: Option<u64>,
+    pub quick_pick: Option<QuickPick>,
+    pub affinity_editor: Option<AffinityEditor>,
+    pub trade_in: Option<TradeInPicker>,
+    /// Highlighted loadout while the new-game screen is shown.
+    pub new_game: Option<usize>,
+    pub carry_over: Option<CarryOver>,
+    /// Mutators toggled on the new-game screen, sorted.
+    pub mutators: Vec<Mutator>,
+    /// `--seed`: the run started from the new-game screen uses it.
+    pub seed: Option<u64>,
+    /// Hardcore toggled on the new-game screen.
+    pub hardcore: bool,
+    /// A hardcore run was lost; only the game-over screen remains.
+    pub game_over: bool,
+    pub reserve_hold: Option<ReserveHold>,
+    pub flash: Option<Flash>,
+    /// Startup popup listing save content the quarantine detached.
+    pub quarantine_open: bool,
+    /// Frame profiler overlay; only reachable when profiling is compiled in.
+    pub profiler_open: bool,
+    /// Contract whose deposit the player has been shown; a second Enter accepts.
+    pub escrow_confirm: Option<u64>,
+    /// Attract mode: a policy plays until the first keypress.
+    pub demo: bool,
+    /// Where Ctrl+S writes; unset when the session has no save of its own.
+    pub save_path: Option<PathBuf>,
 }
 
 impl App {
@@ -27,10 +245,35 @@ impl App {
         Self {
             focus: FocusTarget::Jobs,
             selected_job: 0,
-            selected_processor: 0,
+            job_filter: None,
+            selected_processor: None,
             selected_store_item: 0,
             store_open: false,
-            pending_job: None,
+            museum_open: false,
+            heatmap_open: false,
+            power_column: false,
+            help_open: false,
+            settings: None,
+            glossary: None,
+            log_focus: None,
+            retire_confirm: None,
+            salvage_confirm: None,
+            quick_pick: None,
+            affinity_editor: None,
+            trade_in: None,
+            new_game: None,
+            carry_over: None,
+            mutators: Vec::new(),
+            seed: None,
+            hardcore: false,
+            game_over: false,
+            reserve_hold: None,
+            flash: None,
+            quarantine_open: false,
+            profiler_open: false,
+            escrow_confirm: None,
+            demo: false,
+            save_path: None,
         }
     }
 
@@ -51,12 +294,111 @@ impl App {
 
     pub fn toggle_store(&mut self) {
         self.store_open = !self.store_open;
+        self.trade_in = None;
         if self.store_open {
             self.selected_store_item = 0;
         }
     }
 
-    pub fn clamp_job_selection(&mut self, len: usize) {
+    pub fn toggle_museum(&mut self) {
+        self.museum_open = !self.museum_open;
+    }
+
+    pub fn flash(&mut self, reason: String) {
+        self.flash = Some(Flash {
+            reason,
+            remaining: FLASH_DURATION,
+        });
+    }
+
+    pub fn age_flash(&mut self, delta: Duration) {
+        if let Some(flash) = &mut self.flash {
+            flash.remaining = flash.remaining.saturating_sub(delta);
+            if flash.remaining.is_zero() {
+                self.flash = None;
+            }
+        }
+    }
+
+    pub fn toggle_heatmap(&mut self) {
+        self.heatmap_open = !self.heatmap_open;
+    }
+
+    pub fn toggle_power_column(&mut self) {
+        self.power_column = !self.power_column;
+    }
+
+    pub fn open_quick_pick(&mut self, processor_index: usize) {
+        self.quick_pick = Some(QuickPick {
+            processor_index,
+            selected: 0,
+        });
+    }
+
+    pub fn clamp_quick_pick(&mut self, len: usize) {
+        if let Some(pick) = &mut self.quick_pick {
+            if len == 0 {
+                pick.selected = 0;
+            } else if pick.selected >= len {
+                pick.selected = len - 1;
+            }
+        }
+    }
+
+    /// Board indices of the jobs the tag filter lets through, in board order.
+    pub fn visible_jobs(&self, jobs: &[Job]) -> Vec<usize> {
+        jobs.iter()
+            .enumerate()
+            .filter(|(_, job)| self.job_filter.as_ref().is_none_or(|tag| job.tag == *tag))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Board index of the highlighted job, looked up through the filter.
+    pub fn job_selection(&self, jobs: &[Job]) -> Option<usize> {
+        self.visible_jobs(jobs).get(self.selected_job).copied()
+    }
+
+    /// Steps the filter ALL → GENERAL → SIMD → … → ALL, skipping tags with
+    /// nothing on the board.
+    pub fn cycle_job_filter(&mut self, jobs: &[Job]) {
+        let start = match &self.job_filter {
+            None => 0,
+            Some(tag) => KNOWN_TAGS
+                .iter()
+                .position(|known| known == tag)
+                .map_or(KNOWN_TAGS.len(), |index| index + 1),
+        };
+        self.job_filter = KNOWN_TAGS[start..]
+            .iter()
+            .find(|tag| jobs.iter().any(|job| job.tag == **tag))
+            .map(|tag| tag.to_string());
+        self.selected_job = 0;
+    }
+
+    /// Highlights the job at board `index`, dropping a filter that hides it.
+    pub fn select_job(&mut self, jobs: &[Job], index: usize) {
+        if self
+            .job_filter
+            .as_ref()
+            .is_some_and(|tag| jobs.get(index).is_some_and(|job| job.tag != *tag))
+        {
+            self.job_filter = None;
+        }
+        self.selected_job = self
+            .visible_jobs(jobs)
+            .iter()
+            .position(|&visible| visible == index)
+            .unwrap_or(0);
+    }
+
+    /// Keeps the selection on a visible job; a filter that would leave the
+    /// board empty falls back to ALL.
+    pub fn clamp_job_selection(&mut self, jobs: &[Job]) {
+        let mut len = self.visible_jobs(jobs).len();
+        if len == 0 && self.job_filter.take().is_some() {
+            len = jobs.len();
+        }
         if len == 0 {
             self.selected_job = 0;
         } else if self.selected_job >= len {
@@ -64,14 +406,26 @@ impl App {
         }
     }
 
-    pub fn clamp_processor_selection(&mut self, len: usize) {
-        if len == 0 {
-            self.selected_processor = 0;
-        } else if self.selected_processor >= len {
-            self.selected_processor = len - 1;
+    /// Index of the selected processor; the first unit until one is picked.
+    /// None once the picked unit has left the fleet, so nothing else is
+    /// acted on in its place.
+    pub fn processor_selection(&self, game: &Game) -> Option<usize> {
+        match self.selected_processor {
+            Some(id) => game.processor_index_by_id(id),
+            None => (!game.state.processors.is_empty()).then_some(0),
         }
     }
 
+    /// Picks the unit at `index`, or the last one when the fleet has shrunk
+    /// past it.
+    pub fn select_processor(&mut self, game: &Game, index: usize) {
+        let processors = &game.state.processors;
+        self.selected_processor = processors
+            .get(index)
+            .or_else(|| processors.last())
+            .map(|processor| processor.id);
+    }
+
     pub fn clamp_store_selection(&mut self, len: usize) {
         if len == 0 {
             self.selected_store_item = 0;
@@ -0,0 +1,43 @@
+//! Local control surface for external tools. Requests are executed on the
+//! main loop; the unix socket front end is behind the `control-socket`
+//! feature.
+
+use crate::sim::command::{Command, Response};
+use crate::sim::game::Game;
+use std::path::{Path, PathBuf};
+use tokio::sync::oneshot;
+
+#[cfg(feature = "control-socket")]
+mod socket;
+
+#[cfg(feature = "control-socket")]
+pub use socket::{bind, serve};
+
+/// Name of the socket, created next to the save file.
+pub const SOCKET_FILE: &str = "control.sock";
+
+/// The socket for the session saving to `save`, whichever directory the
+/// game was launched from.
+pub fn socket_path_for(save: &Path) -> PathBuf {
+    save.with_file_name(SOCKET_FILE)
+}
+
+/// A command from an external client and the channel its answer goes back on.
+#[derive(Debug)]
+pub struct ControlRequest {
+    pub command: Command,
+    pub reply: oneshot::Sender<Response>,
+}
+
+impl ControlRequest {
+    pub fn respond(self, game: &mut Game) {
+        // The client may have hung up; its answer is simply dropped.
+        let _ = self.reply.send(game.execute(self.command));
+    }
+
+    pub fn reject(self, error: impl Into<String>) {
+        let _ = self.reply.send(Response::Error {
+            error: error.into(),
+        });
+    }
+}
@@ -0,0 +1,124 @@
+use super::ControlRequest;
+use crate::sim::command::{Command, Response};
+use std::fs;
+use std::io;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::{mpsc, oneshot};
+
+/// Binds the socket at `path`, creating its directory and replacing a stale
+/// one. Only the owner may connect; filesystem permissions are the whole of
+/// the authentication.
+pub fn bind(path: &Path) -> io::Result<UnixListener> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    match fs::remove_file(path) {
+        Ok(()) => {}
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err),
+    }
+    let listener = UnixListener::bind(path)?;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+    Ok(listener)
+}
+
+/// Accepts clients until the listener fails, forwarding each command to the
+/// main loop through `requests`.
+pub async fn serve(listener: UnixListener, requests: mpsc::UnboundedSender<ControlRequest>) {
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(handle_client(stream, requests.clone()));
+    }
+}
+
+/// One command per line in, one JSON response per line out.
+async fn handle_client(
+    stream: UnixStream,
+    requests: mpsc::UnboundedSender<ControlRequest>,
+) -> io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = match serde_json::from_str::<Command>(&line) {
+            Ok(command) => dispatch(command, &requests).await,
+            Err(err) => Response::Error {
+                error: format!("bad command: {err}"),
+            },
+        };
+        let mut out = serde_json::to_string(&response).map_err(io::Error::other)?;
+        out.push('\n');
+        writer.write_all(out.as_bytes()).await?;
+    }
+    Ok(())
+}
+
+async fn dispatch(command: Command, requests: &mpsc::UnboundedSender<ControlRequest>) -> Response {
+    let (reply, response) = oneshot::channel();
+    if requests.send(ControlRequest { command, reply }).is_err() {
+        return Response::Error {
+            error: "game is shutting down".to_string(),
+        };
+    }
+    response.await.unwrap_or_else(|_| Response::Error {
+        error: "game dropped the request".to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::game::Game;
+
+    #[tokio::test]
+    async fn client_commands_round_trip_through_the_main_loop() {
+        let path = std::env::temp_dir().join(format!("aob-control-{}.sock", std::process::id()));
+        let listener = bind(&path).expect("binds");
+        let mode = fs::metadata(&path)
+            .expect("socket exists")
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        tokio::spawn(serve(listener, tx));
+
+        let client_path = path.clone();
+        let client = tokio::spawn(async move {
+            let stream = UnixStream::connect(&client_path).await.expect("connects");
+            let (reader, mut writer) = stream.into_split();
+            writer
+                .write_all(
+                    b"{\"command\":\"summary\"}\nnot json\n{\"command\":\"assign\",\"job_id\":99,\"processor\":0}\n",
+                )
+                .await
+                .expect("writes");
+            let mut lines = BufReader::new(reader).lines();
+            let mut replies = Vec::new();
+            for _ in 0..3 {
+                replies.push(lines.next_line().await.expect("reads").expect("reply"));
+            }
+            replies
+        });
+
+        // Stand-in for the UI loop: the game stays on this task.
+        let mut game = Game::fresh();
+        for _ in 0..2 {
+            rx.recv().await.expect("request").respond(&mut game);
+        }
+        let replies = client.await.expect("client finishes");
+        let _ = fs::remove_file(&path);
+
+        let summary: Response = serde_json::from_str(&replies[0]).expect("json");
+        let Response::Summary(summary) = summary else {
+            panic!("summary expected, got {}", replies[0]);
+        };
+        assert_eq!(summary.credits, game.state.credits);
+        assert!(replies[1].contains("bad command"));
+        assert!(replies[2].contains("\"status\":\"error\""));
+        assert!(replies[2].contains("job 99"));
+    }
+}
@@ -0,0 +1,1776 @@
+use crate::app::{
+    AffinityEditor, App, FocusTarget, GlossaryState, HoldAction, LoadChoice, LogFocus,
+    ProfileChoice, ProfilePicker, ReserveHold, TradeInPicker,
+};
+use crate::persist;
+use crate::profiler;
+use crate::sim::carry_over;
+use crate::sim::economy;
+use crate::sim::game::{ASSIST_CHOICES, Game, MAX_PENDING_JOBS, PurchaseError};
+use crate::sim::jobs::Job;
+use crate::sim::loadout::Loadout;
+use crate::sim::log::LogSubject;
+use crate::sim::mutators::{self, Mutator};
+use crate::sim::processors::AssignmentError;
+use crate::sim::settings::MessageCategory;
+use crate::ui;
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+/// Result of handling one input event. Rejections carry the reason so the UI
+/// can flash it and tests can assert on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActionOutcome {
+    Performed,
+    Rejected { reason: String },
+    Ignored,
+    Quit,
+}
+
+fn rejected(reason: impl Into<String>) -> ActionOutcome {
+    ActionOutcome::Rejected {
+        reason: reason.into(),
+    }
+}
+
+/// Presses (or auto-repeats while held) needed to spend through an enforced
+/// reserve.
+const RESERVE_HOLD_PRESSES: u8 = 3;
+
+pub fn clamp_selections(app: &mut App, game: &Game) {
+    app.clamp_job_selection(&game.state.jobs);
+    app.clamp_store_selection(game.store_items().len());
+    if let Some(pick) = app.quick_pick {
+        app.clamp_quick_pick(game.job_candidates(pick.processor_index).len());
+    }
+}
+
+pub fn handle_event(event: Event, app: &mut App, game: &mut Game) -> ActionOutcome {
+    match event {
+        Event::Key(key) if key.kind == KeyEventKind::Press => {
+            let outcome = handle_key_event(key, app, game);
+            if let Some(index) = app.processor_selection(game) {
+                game.note_manual_intent(index);
+            }
+            outcome
+        }
+        Event::Resize(_, _) => {
+            clamp_selections(app, game);
+            ActionOutcome::Performed
+        }
+        _ => ActionOutcome::Ignored,
+    }
+}
+
+pub fn handle_key_event(key: KeyEvent, app: &mut App, game: &mut Game) -> ActionOutcome {
+    if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+        return ActionOutcome::Quit;
+    }
+
+    if app.demo {
+        app.demo = false;
+        game.add_message("Demo over: you have the controls. This run saves on quit.");
+        return ActionOutcome::Performed;
+    }
+
+    if app.new_game.is_some() {
+        return handle_new_game_key(key, app, game);
+    }
+
+    if app.game_over {
+        return match key.code {
+            KeyCode::Enter | KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => {
+                ActionOutcome::Quit
+            }
+            _ => ActionOutcome::Ignored,
+        };
+    }
+
+    if app.quarantine_open {
+        if matches!(key.code, KeyCode::Esc | KeyCode::Enter) {
+            app.quarantine_open = false;
+            return ActionOutcome::Performed;
+        }
+        return ActionOutcome::Ignored;
+    }
+
+    if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('s') {
+        return save_now(app, game);
+    }
+
+    let reserve_hold = app.reserve_hold.take();
+    let escrow_confirm = app.escrow_confirm.take();
+
+    if app.store_open {
+        return handle_store_key(key, app, game, reserve_hold);
+    }
+
+    if app.quick_pick.is_some() {
+        return handle_quick_pick_key(key, app, game, escrow_confirm);
+    }
+
+    if app.affinity_editor.is_some() {
+        return handle_affinity_key(key, app, game);
+    }
+
+    if app.museum_open {
+        if matches!(
+            key.code,
+            KeyCode::Esc | KeyCode::Char('m') | KeyCode::Char('M')
+        ) {
+            app.toggle_museum();
+            return ActionOutcome::Performed;
+        }
+        return ActionOutcome::Ignored;
+    }
+
+    if app.glossary.is_some() {
+        return handle_glossary_key(key, app);
+    }
+
+    if app.log_focus.is_some() {
+        return handle_log_key(key, app, game);
+    }
+
+    if app.help_open {
+        return match key.code {
+            KeyCode::Esc | KeyCode::Char('?') => {
+                app.help_open = false;
+                ActionOutcome::Performed
+            }
+            KeyCode::Char('g') | KeyCode::Char('G') => {
+                app.help_open = false;
+                app.glossary = Some(GlossaryState::default());
+                ActionOutcome::Performed
+            }
+            _ => ActionOutcome::Ignored,
+        };
+    }
+
+    if let Some(selected) = app.settings {
+        return handle_settings_key(key, app, game, selected);
+    }
+
+    if app.heatmap_open {
+        if matches!(
+            key.code,
+            KeyCode::Esc | KeyCode::Char('h') | KeyCode::Char('H')
+        ) {
+            app.toggle_heatmap();
+            return ActionOutcome::Performed;
+        }
+        return ActionOutcome::Ignored;
+    }
+
+    let retire_confirm = app.retire_confirm.take();
+    let salvage_confirm = app.salvage_confirm.take();
+
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Char('Q') => ActionOutcome::Quit,
+        KeyCode::Esc => {
+            if game.release_pending() {
+                app.clamp_job_selection(&game.state.jobs);
+                ActionOutcome::Performed
+            } else {
+                ActionOutcome::Ignored
+            }
+        }
+        KeyCode::Char('s') | KeyCode::Char('S') => {
+            app.toggle_store();
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('d') | KeyCode::Char('D')
+            if key.modifiers.contains(KeyModifiers::CONTROL) =>
+        {
+            game.cycle_fleet_daemon_mode();
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('d') | KeyCode::Char('D') => {
+            let index = match focused_processor(app, game, "adjust automation") {
+                Ok(index) => index,
+                Err(outcome) => return outcome,
+            };
+            if key.modifiers.contains(KeyModifiers::SHIFT) {
+                game.toggle_honor_cooling(index);
+            } else {
+                game.cycle_daemon_mode(index);
+            }
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            if key.modifiers.contains(KeyModifiers::CONTROL) {
+                let index = match focused_processor(app, game, "repair hardware") {
+                    Ok(index) => index,
+                    Err(outcome) => return outcome,
+                };
+                return purchase_with_hold(
+                    app,
+                    game,
+                    HoldAction::Repair(index),
+                    reserve_hold,
+                    "Repair",
+                );
+            }
+            let index = match focused_processor(app, game, "replace hardware") {
+                Ok(index) => index,
+                Err(outcome) => return outcome,
+            };
+            let action = if key.modifiers.contains(KeyModifiers::SHIFT) {
+                HoldAction::ReplaceModel(index)
+            } else {
+                HoldAction::Replace(index)
+            };
+            purchase_with_hold(app, game, action, reserve_hold, "Replacement")
+        }
+        KeyCode::Char('c') | KeyCode::Char('C') => {
+            let index = match focused_processor(app, game, "cancel its job") {
+                Ok(index) => index,
+                Err(outcome) => return outcome,
+            };
+            if key.modifiers.contains(KeyModifiers::SHIFT) {
+                game.toggle_daemon_preempt(index);
+                return ActionOutcome::Performed;
+            }
+            let outcome = match game.cancel_job(index) {
+                Ok((name, penalty)) => {
+                    game.add_message(format!(
+                        "Cancelled {name}; returned to the board (-{penalty} cr)."
+                    ));
+                    ActionOutcome::Performed
+                }
+                Err(err) => rejected(format!("Cancel failed: {err}")),
+            };
+            app.clamp_job_selection(&game.state.jobs);
+            outcome
+        }
+        KeyCode::Char('m') | KeyCode::Char('M') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+            let index = match focused_processor(app, game, "mount it in a rack") {
+                Ok(index) => index,
+                Err(outcome) => return outcome,
+            };
+            match game.cycle_rack(index) {
+                Ok(_) => ActionOutcome::Performed,
+                Err(err) => rejected(format!("Rack change failed: {err}")),
+            }
+        }
+        KeyCode::Char('m') | KeyCode::Char('M') => {
+            app.toggle_museum();
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('h') | KeyCode::Char('H') => {
+            app.toggle_heatmap();
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('u') | KeyCode::Char('U') => {
+            let index = match focused_processor(app, game, "toggle utility work") {
+                Ok(index) => index,
+                Err(outcome) => return outcome,
+            };
+            game.toggle_allow_utility(index);
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('t') | KeyCode::Char('T') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+            app.cycle_job_filter(&game.state.jobs);
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('t') | KeyCode::Char('T') => {
+            let index = match focused_processor(app, game, "start a daemon trial") {
+                Ok(index) => index,
+                Err(outcome) => return outcome,
+            };
+            match game.start_daemon_trial(index) {
+                Ok(()) => ActionOutcome::Performed,
+                Err(err) => rejected(format!("Daemon trial unavailable: {err}")),
+            }
+        }
+        KeyCode::Char('i') | KeyCode::Char('I') => {
+            let selected = match selected_processor(app, game) {
+                Ok(index) => index,
+                Err(outcome) => return outcome,
+            };
+            match game.firmware_shortcut(selected) {
+                Some((store_index, processor_index)) => {
+                    if !app.store_open {
+                        app.toggle_store();
+                    }
+                    app.selected_store_item = store_index;
+                    app.select_processor(game, processor_index);
+                    ActionOutcome::Performed
+                }
+                None => rejected("Daemon firmware is not sold here."),
+            }
+        }
+        KeyCode::Char('o') | KeyCode::Char('O') => {
+            app.settings = Some(0);
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('`') if profiler::ENABLED => {
+            app.profiler_open = !app.profiler_open;
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('?') => {
+            app.help_open = true;
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('g') | KeyCode::Char('G') => {
+            app.glossary = Some(GlossaryState::default());
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('l') | KeyCode::Char('L') => {
+            app.log_focus = Some(LogFocus {
+                selected: game.log().last_seq(),
+                ..LogFocus::default()
+            });
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('f') | KeyCode::Char('F') => {
+            game.cycle_reserve_mode();
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('n') | KeyCode::Char('N') => {
+            if key.modifiers.contains(KeyModifiers::SHIFT) {
+                game.cycle_number_separator();
+            } else {
+                game.cycle_number_style();
+            }
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('e') | KeyCode::Char('E') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+            app.toggle_power_column();
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('e') | KeyCode::Char('E') => share_snapshot(game),
+        KeyCode::Char('b') | KeyCode::Char('B') => {
+            game.toggle_power_budget();
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('x') | KeyCode::Char('X') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+            let index = match focused_processor(app, game, "salvage it") {
+                Ok(index) => index,
+                Err(outcome) => return outcome,
+            };
+            let processor_id = game.state.processors[index].id;
+            let name = game.state.processors[index].label();
+            let Some(value) = game.salvage_value(index) else {
+                return rejected(format!(
+                    "{name} still runs; only burnt-out or destroyed units can be salvaged."
+                ));
+            };
+            if salvage_confirm == Some(processor_id) {
+                let result = game.salvage_processor(index);
+                app.select_processor(game, index);
+                match result {
+                    Ok(_) => ActionOutcome::Performed,
+                    Err(err) => rejected(format!("Salvage failed: {err}")),
+                }
+            } else {
+                game.add_message(format!(
+                    "Press Shift+X again to salvage {name} for {value} cr."
+                ));
+                app.salvage_confirm = Some(processor_id);
+                ActionOutcome::Performed
+            }
+        }
+        KeyCode::Char('x') | KeyCode::Char('X') => {
+            let index = match focused_processor(app, game, "retire it") {
+                Ok(index) => index,
+                Err(outcome) => return outcome,
+            };
+            let processor_id = game.state.processors[index].id;
+            if retire_confirm == Some(processor_id) {
+                let result = game.retire_processor(index);
+                app.select_processor(game, index);
+                match result {
+                    Ok(_) => ActionOutcome::Performed,
+                    Err(err) => rejected(format!("Retirement failed: {err}")),
+                }
+            } else {
+                let value = game.resale_value(index).unwrap_or(0);
+                let name = game.state.processors[index].name.clone();
+                game.add_message(format!("Press X again to retire {name} for {value} cr."));
+                app.retire_confirm = Some(processor_id);
+                ActionOutcome::Performed
+            }
+        }
+        KeyCode::Tab | KeyCode::BackTab => {
+            app.next_focus();
+            ActionOutcome::Performed
+        }
+        KeyCode::Left => {
+            app.set_focus(FocusTarget::Processors);
+            ActionOutcome::Performed
+        }
+        KeyCode::Right => {
+            app.set_focus(FocusTarget::Jobs);
+            ActionOutcome::Performed
+        }
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => move_selection(app, game, -1),
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => move_selection(app, game, 1),
+        KeyCode::Char('a') | KeyCode::Char('A')
+            if key.modifiers.contains(KeyModifiers::CONTROL) =>
+        {
+            let index = match focused_processor(app, game, "toggle auto-replace") {
+                Ok(index) => index,
+                Err(outcome) => return outcome,
+            };
+            game.toggle_auto_replace(index);
+            ActionOutcome::Performed
+        }
+        KeyCode::Enter | KeyCode::Char('a') | KeyCode::Char('A') => {
+            handle_enter(app, game, escrow_confirm)
+        }
+        KeyCode::Char(c @ '1'..='3') => {
+            let index = match focused_processor(app, game, "pick an Assist suggestion") {
+                Ok(index) => index,
+                Err(outcome) => return outcome,
+            };
+            let rank = c as usize - '1' as usize;
+            take_assist_choice(app, game, index, rank, escrow_confirm, &c.to_string())
+        }
+        KeyCode::Char('[') | KeyCode::Char(']') => {
+            let index = match focused_processor(app, game, "move it in the rack") {
+                Ok(index) => index,
+                Err(outcome) => return outcome,
+            };
+            match game.move_processor(index, key.code == KeyCode::Char('[')) {
+                Some(_) => ActionOutcome::Performed,
+                None => rejected("Already at the end of the rack."),
+            }
+        }
+        KeyCode::Char(c @ ('+' | '=' | '-')) => {
+            let index = match focused_processor(app, game, "change its daemon priority") {
+                Ok(index) => index,
+                Err(outcome) => return outcome,
+            };
+            game.adjust_daemon_priority(index, if c == '-' { -1 } else { 1 });
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('w') | KeyCode::Char('W') => {
+            let index = match focused_processor(app, game, "edit its daemon affinities") {
+                Ok(index) => index,
+                Err(outcome) => return outcome,
+            };
+            app.affinity_editor = Some(AffinityEditor {
+                processor_index: index,
+                selected: 0,
+            });
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('v') | KeyCode::Char('V') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+            let index = match focused_processor(app, game, "change its standby") {
+                Ok(index) => index,
+                Err(outcome) => return outcome,
+            };
+            match game.cycle_standby(index) {
+                Ok(()) => ActionOutcome::Performed,
+                Err(err) => rejected(format!("Standby change failed: {err}.")),
+            }
+        }
+        KeyCode::Char('v') | KeyCode::Char('V') => {
+            let index = match focused_processor(app, game, "change its clock") {
+                Ok(index) => index,
+                Err(outcome) => return outcome,
+            };
+            match game.cycle_clock_mode(index) {
+                Ok(()) => ActionOutcome::Performed,
+                Err(err) => rejected(format!("Clock change failed: {err}.")),
+            }
+        }
+        KeyCode::Char('p') | KeyCode::Char('P') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+            let index = match focused_processor(app, game, "pause it") {
+                Ok(index) => index,
+                Err(outcome) => return outcome,
+            };
+            match game.toggle_paused(index) {
+                Ok(()) => ActionOutcome::Performed,
+                Err(AssignmentError::ProcessorBusy) => {
+                    rejected("Finish or cancel the unit's job before pausing it.")
+                }
+                Err(err) => rejected(format!("Pause failed: {err}")),
+            }
+        }
+        KeyCode::Char('y') | KeyCode::Char('Y') => {
+            let index = match focused_processor(app, game, "schedule maintenance") {
+                Ok(index) => index,
+                Err(outcome) => return outcome,
+            };
+            match game.toggle_maintenance(index) {
+                Ok(()) => ActionOutcome::Performed,
+                Err(err) => rejected(format!("Maintenance failed: {err}")),
+            }
+        }
+        KeyCode::Char('p') | KeyCode::Char('P') => {
+            match focused_processor(app, game, "pick a job for it") {
+                Ok(index) => open_quick_pick(app, game, index),
+                Err(outcome) => outcome,
+            }
+        }
+        _ => ActionOutcome::Ignored,
+    }
+}
+
+/// Index of the selected processor, or a rejection explaining why there is
+/// none to `purpose`.
+fn focused_processor(app: &App, game: &Game, purpose: &str) -> Result<usize, ActionOutcome> {
+    if app.focus() != FocusTarget::Processors {
+        return Err(rejected(format!("Focus a processor to {purpose}.")));
+    }
+    selected_processor(app, game)
+}
+
+/// The selected unit for actions that don't need the processor panel
+/// focused; rejected rather than retargeted once it has left the fleet.
+fn selected_processor(app: &App, game: &Game) -> Result<usize, ActionOutcome> {
+    if game.state.processors.is_empty() {
+        return Err(rejected("No processors available."));
+    }
+    app.processor_selection(game)
+        .ok_or_else(|| rejected("The selected unit has left the fleet; pick another."))
+}
+
+fn move_selection(app: &mut App, game: &Game, delta: isize) -> ActionOutcome {
+    let (len, selected) = match app.focus() {
+        FocusTarget::Jobs => (
+            app.visible_jobs(&game.state.jobs).len(),
+            Some(app.selected_job),
+        ),
+        FocusTarget::Processors => (game.state.processors.len(), app.processor_selection(game)),
+    };
+    if len == 0 {
+        return ActionOutcome::Ignored;
+    }
+    // A unit that left the fleet restarts the walk from the top.
+    let mut idx = selected.map_or(0, |selected| selected as isize + delta);
+    if idx < 0 {
+        idx = len as isize - 1;
+    } else if idx >= len as isize {
+        idx = 0;
+    }
+    match app.focus() {
+        FocusTarget::Jobs => app.selected_job = idx as usize,
+        FocusTarget::Processors => app.select_processor(game, idx as usize),
+    }
+    ActionOutcome::Performed
+}
+
+/// Ctrl+S: writes the save immediately, through the same atomic path as the
+/// save on quit.
+fn save_now(app: &App, game: &mut Game) -> ActionOutcome {
+    let Some(path) = &app.save_path else {
+        return rejected("This session has no save to write.");
+    };
+    if game.state.hardcore {
+        return rejected("Hardcore runs only save when a day ends.");
+    }
+    match persist::save_game_to(&game.state, path) {
+        Ok(()) => {
+            game.add_message("Game saved.");
+            ActionOutcome::Performed
+        }
+        Err(err) => rejected(format!("Save failed: {err}")),
+    }
+}
+
+fn share_snapshot(game: &mut Game) -> ActionOutcome {
+    let recent: Vec<String> = game.messages().cloned().collect();
+    let text = ui::summary::render(&game.state, &recent);
+    let path = match persist::write_summary(&text) {
+        Ok(path) => path,
+        Err(err) => return rejected(format!("Snapshot export failed: {err}")),
+    };
+    match persist::copy_to_clipboard(&text) {
+        Ok(()) => game.add_message(format!("Snapshot saved to {path} and copied.")),
+        Err(_) => game.add_message(format!("Snapshot saved to {path}.")),
+    }
+    ActionOutcome::Performed
+}
+
+/// Shows a contract's deposit before the first acceptance attempt. Returns
+/// `None` once the player has confirmed or the job needs no deposit.
+fn confirm_escrow(
+    app: &mut App,
+    game: &mut Game,
+    job: &Job,
+    escrow_confirm: Option<u64>,
+    key: &str,
+) -> Option<ActionOutcome> {
+    let deposit = economy::escrow_deposit(job);
+    if deposit == 0 || job.escrow > 0 || escrow_confirm == Some(job.id) {
+        return None;
+    }
+    if deposit > game.state.credits {
+        return Some(rejected(format!(
+            "{} needs a {deposit} cr deposit; only {} cr available.",
+            job.name, game.state.credits
+        )));
+    }
+    game.add_message(format!(
+        "{} escrows {deposit} cr: returned on success, forfeited on burnout. Press {key} again to accept.",
+        job.name
+    ));
+    app.escrow_confirm = Some(job.id);
+    Some(ActionOutcome::Performed)
+}
+
+fn handle_enter(app: &mut App, game: &mut Game, escrow_confirm: Option<u64>) -> ActionOutcome {
+    match app.focus() {
+        FocusTarget::Jobs => {
+            if game.pending_full() {
+                return rejected(format!(
+                    "The pending queue is full ({MAX_PENDING_JOBS} jobs); assign or return one first."
+                ));
+            }
+            let Some(name) = app
+                .job_selection(&game.state.jobs)
+                .and_then(|index| game.queue_pending(index))
+            else {
+                return rejected("No jobs available to queue.");
+            };
+            app.clamp_job_selection(&game.state.jobs);
+            game.add_message(format!("{name} queued for assignment."));
+            ActionOutcome::Performed
+        }
+        FocusTarget::Processors => {
+            if game.state.processors.is_empty() {
+                return rejected("No processors available.");
+            }
+            let Some(idx) = app.processor_selection(game) else {
+                return rejected("The selected unit has left the fleet; pick another.");
+            };
+            if let Some(job) = game.state.pending_jobs.first().cloned() {
+                if let Some(outcome) = confirm_escrow(app, game, &job, escrow_confirm, "Enter") {
+                    return outcome;
+                }
+                match game.assign_pending(idx) {
+                    Ok(()) => ActionOutcome::Performed,
+                    Err(err) => rejected(format!("Assignment failed: {err}")),
+                }
+            } else if game.assist_suggestion(idx).is_some() {
+                take_assist_choice(app, game, idx, 0, escrow_confirm, "Enter")
+            } else {
+                open_quick_pick(app, game, idx)
+            }
+        }
+    }
+}
+
+/// Accepts the Assist suggestion at `rank`, asking first when it escrows a
+/// deposit; `key` is what the player presses again to confirm.
+fn take_assist_choice(
+    app: &mut App,
+    game: &mut Game,
+    processor_index: usize,
+    rank: usize,
+    escrow_confirm: Option<u64>,
+    key: &str,
+) -> ActionOutcome {
+    let suggestions = game.assist_suggestions(processor_index, ASSIST_CHOICES);
+    let Some(suggestion) = suggestions.get(rank) else {
+        let label = game.state.processors[processor_index].label();
+        return if suggestions.is_empty() {
+            rejected(format!("{label} has no Assist suggestions."))
+        } else {
+            rejected(format!("{label} has no suggestion #{}.", rank + 1))
+        };
+    };
+    if let Some(job) = game.suggested_job(suggestion).cloned()
+        && let Some(outcome) = confirm_escrow(app, game, &job, escrow_confirm, key)
+    {
+        return outcome;
+    }
+    if game.accept_assist_choice(processor_index, rank) {
+        app.clamp_job_selection(&game.state.jobs);
+        ActionOutcome::Performed
+    } else {
+        rejected("Assist suggestion could not be applied.")
+    }
+}
+
+fn open_quick_pick(app: &mut App, game: &Game, processor_index: usize) -> ActionOutcome {
+    let processor = &game.state.processors[processor_index];
+    if !processor.is_functional() {
+        rejected(format!(
+            "{} is offline and cannot take work.",
+            processor.name
+        ))
+    } else if !processor.is_idle() {
+        rejected(format!("{} is already working.", processor.name))
+    } else if game.job_candidates(processor_index).is_empty() {
+        rejected(format!(
+            "No compatible jobs on the board for {}.",
+            processor.name
+        ))
+    } else {
+        app.open_quick_pick(processor_index);
+        ActionOutcome::Performed
+    }
+}
+
+fn handle_glossary_key(key: KeyEvent, app: &mut App) -> ActionOutcome {
+    let Some(glossary) = &mut app.glossary else {
+        return ActionOutcome::Ignored;
+    };
+    if glossary.searching {
+        match key.code {
+            KeyCode::Enter | KeyCode::Esc => glossary.searching = false,
+            KeyCode::Backspace => {
+                glossary.query.pop();
+                glossary.selected = 0;
+            }
+            KeyCode::Char(c) => {
+                glossary.query.push(c);
+                glossary.selected = 0;
+            }
+            _ => return ActionOutcome::Ignored,
+        }
+        return ActionOutcome::Performed;
+    }
+    let matches = ui::glossary::matching(&glossary.query).len();
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('g') | KeyCode::Char('G') => app.glossary = None,
+        KeyCode::Char('/') => glossary.searching = true,
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => {
+            glossary.selected = glossary.selected.saturating_sub(1);
+        }
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J')
+            if glossary.selected + 1 < matches =>
+        {
+            glossary.selected += 1;
+        }
+        _ => return ActionOutcome::Ignored,
+    }
+    ActionOutcome::Performed
+}
+
+fn handle_log_key(key: KeyEvent, app: &mut App, game: &mut Game) -> ActionOutcome {
+    let Some(focus) = &mut app.log_focus else {
+        return ActionOutcome::Ignored;
+    };
+    let log = game.log();
+    focus.search.sync(log);
+    // The highlighted entry may have been evicted since the last key.
+    focus.selected = focus
+        .selected
+        .map(|seq| seq.max(log.first_seq()))
+        .filter(|_| !log.is_empty());
+    if focus.searching {
+        let mut query = focus.search.query().to_string();
+        match key.code {
+            KeyCode::Enter | KeyCode::Esc => {
+                focus.searching = false;
+                return ActionOutcome::Performed;
+            }
+            KeyCode::Backspace => {
+                query.pop();
+            }
+            KeyCode::Char(c) => query.push(c),
+            _ => return ActionOutcome::Ignored,
+        }
+        focus.search.set_query(query, log);
+        // Incremental: land on the most recent match as the query narrows.
+        if let Some(seq) = focus.search.previous(None) {
+            focus.selected = Some(seq);
+        }
+        return ActionOutcome::Performed;
+    }
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('l') | KeyCode::Char('L') => app.log_focus = None,
+        KeyCode::Char('/') => focus.searching = true,
+        KeyCode::Char('n') => match focus.search.next(focus.selected) {
+            Some(seq) => focus.selected = Some(seq),
+            None => return rejected("No matches in the log."),
+        },
+        KeyCode::Char('N') => match focus.search.previous(focus.selected) {
+            Some(seq) => focus.selected = Some(seq),
+            None => return rejected("No matches in the log."),
+        },
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => {
+            focus.selected = focus
+                .selected
+                .map(|seq| seq.saturating_sub(1).max(log.first_seq()));
+        }
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => {
+            focus.selected = focus
+                .selected
+                .map(|seq| (seq + 1).min(log.last_seq().unwrap_or(seq)));
+        }
+        KeyCode::Enter => {
+            let subject = focus
+                .selected
+                .and_then(|seq| log.get(seq))
+                .and_then(|entry| entry.subject);
+            let Some(subject) = subject else {
+                return rejected("That entry doesn't refer to a unit or job.");
+            };
+            return jump_to_subject(app, game, subject);
+        }
+        _ => return ActionOutcome::Ignored,
+    }
+    ActionOutcome::Performed
+}
+
+/// Moves focus and selection to a unit or job named by a log entry. A job
+/// that is running jumps to the unit working on it.
+fn jump_to_subject(app: &mut App, game: &Game, subject: LogSubject) -> ActionOutcome {
+    let processors = &game.state.processors;
+    let target = match subject {
+        LogSubject::Processor(id) => processors
+            .iter()
+            .position(|processor| processor.id == id)
+            .map(|index| (FocusTarget::Processors, index)),
+        LogSubject::Job(id) => game
+            .state
+            .jobs
+            .iter()
+            .position(|job| job.id == id)
+            .map(|index| (FocusTarget::Jobs, index))
+            .or_else(|| {
+                processors
+                    .iter()
+                    .position(|processor| processor.slots().any(|work| work.job.id == id))
+                    .map(|index| (FocusTarget::Processors, index))
+            }),
+    };
+    let Some((focus, index)) = target else {
+        return rejected("That unit or job no longer exists.");
+    };
+    match focus {
+        FocusTarget::Jobs => app.select_job(&game.state.jobs, index),
+        FocusTarget::Processors => app.select_processor(game, index),
+    }
+    app.set_focus(focus);
+    app.log_focus = None;
+    ActionOutcome::Performed
+}
+
+fn purchase_with_hold(
+    app: &mut App,
+    game: &mut Game,
+    action: HoldAction,
+    previous: Option<ReserveHold>,
+    label: &str,
+) -> ActionOutcome {
+    let presses = previous
+        .filter(|hold| hold.action == action)
+        .map_or(0, |hold| hold.presses)
+        + 1;
+    if presses >= RESERVE_HOLD_PRESSES {
+        game.override_reserve_once();
+    }
+    let result = match action {
+        HoldAction::Store { item, processor } => game.purchase_item(item, processor),
+        HoldAction::Replace(index) => game.replace_processor_direct(index),
+        HoldAction::ReplaceModel(index) => game.replace_model_direct(index),
+        HoldAction::Repair(index) => game.repair_processor_direct(index),
+    };
+    game.clear_reserve_override();
+    match result {
+        Ok(()) => ActionOutcome::Performed,
+        Err(PurchaseError::ReserveBreach { reserved, .. }) => {
+            app.reserve_hold = Some(ReserveHold { action, presses });
+            if presses == 1 {
+                rejected(format!(
+                    "{label} blocked by the {reserved} cr reserve; hold the key to override."
+                ))
+            } else {
+                // Still holding: the first rejection is on screen already.
+                ActionOutcome::Ignored
+            }
+        }
+        Err(err) => rejected(format!("{label} failed: {err}")),
+    }
+}
+
+/// Keys on the load screen; `None` keeps it open.
+pub fn handle_load_key(key: KeyEvent, selected: &mut usize) -> Option<LoadChoice> {
+    if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+        return Some(LoadChoice::Quit);
+    }
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => Some(LoadChoice::Quit),
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => {
+            *selected = selected.saturating_sub(1);
+            None
+        }
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => {
+            *selected = (*selected + 1).min(LoadChoice::MENU.len() - 1);
+            None
+        }
+        KeyCode::Enter => LoadChoice::MENU.get(*selected).copied(),
+        KeyCode::Char('c') | KeyCode::Char('C') => Some(LoadChoice::Continue),
+        KeyCode::Char('n') | KeyCode::Char('N') => Some(LoadChoice::NewGame),
+        _ => None,
+    }
+}
+
+/// Keys on the profile picker; `None` keeps it open.
+pub fn handle_profile_key(key: KeyEvent, picker: &mut ProfilePicker) -> Option<ProfileChoice> {
+    if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+        return Some(ProfileChoice::Quit);
+    }
+    if let Some(name) = &mut picker.naming {
+        match key.code {
+            KeyCode::Esc => {
+                picker.naming = None;
+                picker.error = None;
+            }
+            KeyCode::Enter => match persist::validate_profile_name(name) {
+                Ok(()) => return Some(ProfileChoice::Named(name.clone())),
+                Err(err) => picker.error = Some(format!("Can't create profile: {err}.")),
+            },
+            KeyCode::Backspace => {
+                name.pop();
+            }
+            KeyCode::Char(c) => name.push(c),
+            _ => {}
+        }
+        return None;
+    }
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => Some(ProfileChoice::Quit),
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => {
+            picker.selected = picker.selected.saturating_sub(1);
+            None
+        }
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => {
+            picker.selected = (picker.selected + 1).min(picker.profiles.len());
+            None
+        }
+        KeyCode::Char('n') | KeyCode::Char('N') => {
+            picker.naming = Some(String::new());
+            None
+        }
+        KeyCode::Enter => Some(match picker.selected.checked_sub(1) {
+            None => ProfileChoice::Default,
+            Some(index) => ProfileChoice::Named(picker.profiles.get(index)?.clone()),
+        }),
+        _ => None,
+    }
+}
+
+fn handle_new_game_key(key: KeyEvent, app: &mut App, game: &mut Game) -> ActionOutcome {
+    let Some(selected) = app.new_game else {
+        return ActionOutcome::Ignored;
+    };
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => ActionOutcome::Quit,
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => {
+            app.new_game = Some(selected.saturating_sub(1));
+            ActionOutcome::Performed
+        }
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => {
+            app.new_game = Some((selected + 1).min(Loadout::ALL.len() - 1));
+            ActionOutcome::Performed
+        }
+        KeyCode::Left | KeyCode::Right => {
+            let Some(carry) = &mut app.carry_over else {
+                return ActionOutcome::Ignored;
+            };
+            if let Err(err) = &carry.processors {
+                return rejected(format!("Cannot carry over: {err}."));
+            }
+            carry.cycle(key.code == KeyCode::Right);
+            ActionOutcome::Performed
+        }
+        KeyCode::Char(c @ '1'..='4') => {
+            let index = c as usize - '1' as usize;
+            mutators::toggle(&mut app.mutators, Mutator::ALL[index]);
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('h') | KeyCode::Char('H') => {
+            app.hardcore = !app.hardcore;
+            ActionOutcome::Performed
+        }
+        KeyCode::Enter => {
+            let loadout = Loadout::ALL[selected.min(Loadout::ALL.len() - 1)];
+            app.new_game = None;
+            let mut state = loadout.state();
+            state.mutators = std::mem::take(&mut app.mutators);
+            state.hardcore = std::mem::take(&mut app.hardcore);
+            state.profile_name = std::mem::take(&mut game.state.profile_name);
+            state.seed_starter_board(app.seed.take().unwrap_or_else(rand::random));
+            let carry = app.carry_over.take();
+            let imported = carry.as_ref().and_then(|carry| {
+                let unit = carry.chosen()?.clone();
+                let name = unit.name.clone();
+                carry_over::import(&mut state, unit, &carry.source);
+                Some(name)
+            });
+            *game = Game::from_state(state);
+            app.selected_processor = None;
+            game.add_message("Welcome to the Array of Babel.");
+            game.add_message(loadout.tip());
+            if let Some(name) = imported {
+                game.add_message(format!(
+                    "{name} carried over from your previous run; starting credits reduced."
+                ));
+            }
+            if let Some(first) = game.state.jobs.first() {
+                let hint = format!(
+                    "Tutorial: select {} on the board, press Enter, then Tab and Enter to assign it.",
+                    first.name
+                );
+                game.add_message(hint);
+            }
+            clamp_selections(app, game);
+            ActionOutcome::Performed
+        }
+        _ => ActionOutcome::Ignored,
+    }
+}
+
+fn handle_affinity_key(key: KeyEvent, app: &mut App, game: &mut Game) -> ActionOutcome {
+    let Some(editor) = &mut app.affinity_editor else {
+        return ActionOutcome::Ignored;
+    };
+    let tags = game.state.unlocked_tags.len();
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('w') | KeyCode::Char('W') => {
+            app.affinity_editor = None;
+            ActionOutcome::Performed
+        }
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => {
+            editor.selected = editor.selected.saturating_sub(1);
+            ActionOutcome::Performed
+        }
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => {
+            editor.selected = (editor.selected + 1).min(tags.saturating_sub(1));
+            ActionOutcome::Performed
+        }
+        KeyCode::Left | KeyCode::Right => {
+            let Some(tag) = game.state.unlocked_tags.get(editor.selected).cloned() else {
+                return ActionOutcome::Ignored;
+            };
+            let delta = if key.code == KeyCode::Right {
+                0.1
+            } else {
+                -0.1
+            };
+            match game.adjust_daemon_affinity(editor.processor_index, &tag, delta) {
+                Some(_) => ActionOutcome::Performed,
+                None => {
+                    app.affinity_editor = None;
+                    rejected("That processor is gone.")
+                }
+            }
+        }
+        KeyCode::Char(' ') | KeyCode::Char('b') | KeyCode::Char('B') => {
+            let Some(tag) = game.state.unlocked_tags.get(editor.selected).cloned() else {
+                return ActionOutcome::Ignored;
+            };
+            match game.toggle_daemon_block(editor.processor_index, &tag) {
+                Some(_) => ActionOutcome::Performed,
+                None => {
+                    app.affinity_editor = None;
+                    rejected("That processor is gone.")
+                }
+            }
+        }
+        _ => ActionOutcome::Ignored,
+    }
+}
+
+fn handle_quick_pick_key(
+    key: KeyEvent,
+    app: &mut App,
+    game: &mut Game,
+    escrow_confirm: Option<u64>,
+) -> ActionOutcome {
+    let Some(pick) = app.quick_pick else {
+        return ActionOutcome::Ignored;
+    };
+    let candidates = game.job_candidates(pick.processor_index);
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('p') | KeyCode::Char('P') => {
+            app.quick_pick = None;
+            ActionOutcome::Performed
+        }
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => {
+            if let Some(pick) = &mut app.quick_pick {
+                pick.selected = pick.selected.saturating_sub(1);
+            }
+            ActionOutcome::Performed
+        }
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => {
+            if let Some(pick) = &mut app.quick_pick
+                && pick.selected + 1 < candidates.len()
+            {
+                pick.selected += 1;
+            }
+            ActionOutcome::Performed
+        }
+        KeyCode::Enter => {
+            let Some(candidate) = candidates.get(pick.selected) else {
+                app.quick_pick = None;
+                return rejected("No compatible job selected.");
+            };
+            if let Some(job) = game.state.jobs.get(candidate.job_index).cloned()
+                && let Some(outcome) = confirm_escrow(app, game, &job, escrow_confirm, "Enter")
+            {
+                return outcome;
+            }
+            app.quick_pick = None;
+            let result = game.assign_board_job(candidate.job_index, pick.processor_index);
+            app.clamp_job_selection(&game.state.jobs);
+            match result {
+                Ok(()) => ActionOutcome::Performed,
+                Err(err) => rejected(format!("Assignment failed: {err}")),
+            }
+        }
+        _ => ActionOutcome::Ignored,
+    }
+}
+
+fn handle_settings_key(
+    key: KeyEvent,
+    app: &mut App,
+    game: &mut Game,
+    selected: usize,
+) -> ActionOutcome {
+    let last = MessageCategory::ALL.len() - 1;
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('o') | KeyCode::Char('O') => app.settings = None,
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => {
+            app.settings = Some(selected.saturating_sub(1));
+        }
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => {
+            app.settings = Some((selected + 1).min(last));
+        }
+        KeyCode::Enter | KeyCode::Char(' ') => {
+            game.cycle_message_route(MessageCategory::ALL[selected.min(last)]);
+        }
+        KeyCode::Char('t') | KeyCode::Char('T') => game.preview_routing(),
+        KeyCode::Char('c') | KeyCode::Char('C') => game.toggle_save_compression(),
+        KeyCode::Char('d') | KeyCode::Char('D') => game.toggle_spare_worn_units(),
+        KeyCode::Char('+') | KeyCode::Char('=') => game.adjust_auto_replace_floor(true),
+        KeyCode::Char('-') => game.adjust_auto_replace_floor(false),
+        _ => return ActionOutcome::Ignored,
+    }
+    ActionOutcome::Performed
+}
+
+fn handle_store_key(
+    key: KeyEvent,
+    app: &mut App,
+    game: &mut Game,
+    reserve_hold: Option<ReserveHold>,
+) -> ActionOutcome {
+    if app.trade_in.is_some() {
+        return handle_trade_in_key(key, app, game);
+    }
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('s') | KeyCode::Char('S') => {
+            app.toggle_store();
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('t') | KeyCode::Char('T') => {
+            if game.state.processors.is_empty() {
+                return rejected("No processor to trade in.");
+            }
+            let processor_index = match selected_processor(app, game) {
+                Ok(index) => index,
+                Err(outcome) => return outcome,
+            };
+            app.trade_in = Some(TradeInPicker {
+                processor_index,
+                selected: 0,
+            });
+            ActionOutcome::Performed
+        }
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => {
+            if app.selected_store_item == 0 {
+                return ActionOutcome::Ignored;
+            }
+            app.selected_store_item -= 1;
+            ActionOutcome::Performed
+        }
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => {
+            if app.selected_store_item + 1 >= game.store_items().len() {
+                return ActionOutcome::Ignored;
+            }
+            app.selected_store_item += 1;
+            ActionOutcome::Performed
+        }
+        KeyCode::Char('g') | KeyCode::Char('G') => match game.store_recommendation() {
+            Some(pick) => {
+                app.selected_store_item = pick.store_index;
+                app.select_processor(game, pick.processor_index);
+                ActionOutcome::Performed
+            }
+            None => rejected("No upgrade recommendation right now."),
+        },
+        KeyCode::Enter => {
+            if app.selected_store_item >= game.store_items().len() {
+                return rejected("No store item selected.");
+            }
+            let processor_index = app.processor_selection(game);
+            let action = HoldAction::Store {
+                item: app.selected_store_item,
+                processor: processor_index,
+            };
+            purchase_with_hold(app, game, action, reserve_hold, "Purchase")
+        }
+        _ => ActionOutcome::Ignored,
+    }
+}
+
+fn handle_trade_in_key(key: KeyEvent, app: &mut App, game: &mut Game) -> ActionOutcome {
+    let Some(picker) = &mut app.trade_in else {
+        return ActionOutcome::Ignored;
+    };
+    let models = Game::catalog_models();
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('t') | KeyCode::Char('T') => {
+            app.trade_in = None;
+            ActionOutcome::Performed
+        }
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => {
+            picker.selected = picker.selected.saturating_sub(1);
+            ActionOutcome::Performed
+        }
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => {
+            picker.selected = (picker.selected + 1).min(models.len().saturating_sub(1));
+            ActionOutcome::Performed
+        }
+        KeyCode::Enter => {
+            let Some(model) = models.get(picker.selected) else {
+                return ActionOutcome::Ignored;
+            };
+            match game.trade_in(picker.processor_index, model) {
+                Ok(()) => {
+                    app.trade_in = None;
+                    ActionOutcome::Performed
+                }
+                Err(err) => rejected(format!("Trade-in failed: {err}.")),
+            }
+        }
+        _ => ActionOutcome::Ignored,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::CarryOver;
+    use crate::sim::economy::ReserveMode;
+    use crate::sim::game::{GameState, StoreAction};
+    use crate::sim::jobs::{self, Job};
+    use crate::sim::processors::{ProcessorState, ProcessorStatus};
+
+    fn press(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn run_script(app: &mut App, game: &mut Game, keys: &[KeyCode]) -> Vec<ActionOutcome> {
+        keys.iter()
+            .map(|code| handle_key_event(press(*code), app, game))
+            .collect()
+    }
+
+    fn board_job(id: u64) -> Job {
+        Job {
+            id,
+            name: format!("Scripted #{id}"),
+            tag: jobs::GENERAL_TAG.to_string(),
+            base_time_ms: 5_000,
+            base_reward: 100,
+            quality_target: 60,
+            data_output: 5,
+            ..Job::default()
+        }
+    }
+
+    #[test]
+    fn scripted_keys_report_exact_outcomes() {
+        let mut app = App::new();
+        let mut game = Game::fresh();
+        game.state.jobs.clear();
+
+        let outcomes = run_script(
+            &mut app,
+            &mut game,
+            &[
+                KeyCode::Enter,
+                KeyCode::Char('d'),
+                KeyCode::Char('z'),
+                KeyCode::Tab,
+                KeyCode::Char('p'),
+                KeyCode::Char('q'),
+            ],
+        );
+        let name = game.state.processors[0].name.clone();
+        assert_eq!(
+            outcomes,
+            vec![
+                rejected("No jobs available to queue."),
+                rejected("Focus a processor to adjust automation."),
+                ActionOutcome::Ignored,
+                ActionOutcome::Performed,
+                rejected(format!("No compatible jobs on the board for {name}.")),
+                ActionOutcome::Quit,
+            ]
+        );
+    }
+
+    #[test]
+    fn tag_filter_narrows_the_board_and_enter_takes_the_visible_job() {
+        let shift_t = KeyEvent::new(KeyCode::Char('T'), KeyModifiers::SHIFT);
+        let mut app = App::new();
+        let mut game = Game::fresh();
+        let simd = Job {
+            tag: jobs::SIMD_TAG.to_string(),
+            ..board_job(2)
+        };
+        game.state.jobs = vec![board_job(1), simd, board_job(3)];
+
+        handle_key_event(shift_t, &mut app, &mut game);
+        assert_eq!(app.job_filter.as_deref(), Some(jobs::GENERAL_TAG));
+        assert_eq!(app.visible_jobs(&game.state.jobs), vec![0, 2]);
+        run_script(&mut app, &mut game, &[KeyCode::Down, KeyCode::Enter]);
+        assert_eq!(game.state.pending_jobs.first().map(|job| job.id), Some(3));
+        assert!(game.release_pending());
+
+        handle_key_event(shift_t, &mut app, &mut game);
+        assert_eq!(app.job_filter.as_deref(), Some(jobs::SIMD_TAG));
+        // No RADIATION, ANGEL or SURVEILLANCE postings: straight back to ALL.
+        handle_key_event(shift_t, &mut app, &mut game);
+        assert_eq!(app.job_filter, None);
+
+        app.job_filter = Some(jobs::SIMD_TAG.to_string());
+        game.state.jobs.retain(|job| job.tag != jobs::SIMD_TAG);
+        clamp_selections(&mut app, &game);
+        assert_eq!(app.job_filter, None);
+    }
+
+    #[test]
+    fn enter_queues_up_to_the_cap_and_esc_returns_the_latest() {
+        let mut app = App::new();
+        let mut game = Game::fresh();
+        game.state.jobs = (1..=4).map(board_job).collect();
+
+        let outcomes = run_script(&mut app, &mut game, &[KeyCode::Enter; 4]);
+        assert_eq!(
+            outcomes.last(),
+            Some(&rejected(
+                "The pending queue is full (3 jobs); assign or return one first."
+            ))
+        );
+        let queued = |game: &Game| -> Vec<u64> {
+            game.state.pending_jobs.iter().map(|job| job.id).collect()
+        };
+        assert_eq!(queued(&game), vec![1, 2, 3]);
+
+        run_script(
+            &mut app,
+            &mut game,
+            &[KeyCode::Esc, KeyCode::Tab, KeyCode::Enter],
+        );
+        assert_eq!(queued(&game), vec![2]);
+        assert_eq!(game.state.jobs.first().map(|job| job.id), Some(3));
+        assert!(!game.state.processors[0].is_idle());
+    }
+
+    #[test]
+    fn ctrl_s_saves_with_the_store_open_and_reports_failures() {
+        let path =
+            std::env::temp_dir().join(format!("array-of-babel-ctrl-s-{}.ron", std::process::id()));
+        let ctrl_s = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+        let mut app = App::new();
+        let mut game = Game::fresh();
+        app.save_path = Some(path.clone());
+        app.toggle_store();
+
+        assert_eq!(
+            handle_key_event(ctrl_s, &mut app, &mut game),
+            ActionOutcome::Performed
+        );
+        assert!(app.store_open);
+        assert_eq!(
+            game.messages().last().map(String::as_str),
+            Some("Game saved.")
+        );
+        assert!(persist::load_game_from(&path).expect("reads").is_some());
+        std::fs::remove_file(&path).ok();
+        std::fs::remove_file(persist::meta_path_for(&path)).ok();
+
+        app.save_path = Some(path.join("no-such-dir").join("save.ron"));
+        assert!(matches!(
+            handle_key_event(ctrl_s, &mut app, &mut game),
+            ActionOutcome::Rejected { reason } if reason.starts_with("Save failed")
+        ));
+    }
+
+    #[test]
+    fn profile_picker_refuses_unsafe_names_and_picks_existing_ones() {
+        let mut picker = ProfilePicker::new(vec!["ada".to_string(), "sam".to_string()]);
+        assert_eq!(
+            handle_profile_key(press(KeyCode::Char('n')), &mut picker),
+            None
+        );
+        for c in "../x".chars() {
+            handle_profile_key(press(KeyCode::Char(c)), &mut picker);
+        }
+        assert_eq!(handle_profile_key(press(KeyCode::Enter), &mut picker), None);
+        assert!(
+            picker
+                .error
+                .as_deref()
+                .is_some_and(|error| error.contains("only use letters"))
+        );
+        assert_eq!(handle_profile_key(press(KeyCode::Esc), &mut picker), None);
+        assert_eq!(picker.naming, None);
+
+        handle_profile_key(press(KeyCode::Down), &mut picker);
+        handle_profile_key(press(KeyCode::Down), &mut picker);
+        assert_eq!(
+            handle_profile_key(press(KeyCode::Enter), &mut picker),
+            Some(ProfileChoice::Named("sam".to_string()))
+        );
+    }
+
+    #[test]
+    fn new_game_can_carry_over_a_previous_unit() {
+        let mut previous = GameState::default();
+        previous.processors[0].jobs_completed = 12;
+        let mut app = App::new();
+        app.new_game = Some(0);
+        app.carry_over = Some(CarryOver::new(previous));
+        let mut game = Game::fresh();
+
+        let outcomes = run_script(&mut app, &mut game, &[KeyCode::Right, KeyCode::Enter]);
+
+        assert!(outcomes.iter().all(|o| *o == ActionOutcome::Performed));
+        assert_eq!(game.state.processors.len(), 2);
+        assert_eq!(game.state.processors[1].jobs_completed, 12);
+        assert_ne!(game.state.processors[0].id, game.state.processors[1].id);
+        assert_eq!(game.state.statistics.imports.len(), 1);
+        assert!(app.carry_over.is_none());
+    }
+
+    #[test]
+    fn affinity_editor_steps_the_highlighted_tag() {
+        let mut app = App::new();
+        let mut game = Game::fresh();
+        game.state.unlocked_tags.push("SIMD".to_string());
+
+        let outcomes = run_script(
+            &mut app,
+            &mut game,
+            &[
+                KeyCode::Tab,
+                KeyCode::Char('w'),
+                KeyCode::Down,
+                KeyCode::Right,
+                KeyCode::Right,
+                KeyCode::Up,
+                KeyCode::Left,
+                KeyCode::Esc,
+            ],
+        );
+
+        assert!(outcomes.iter().all(|o| *o == ActionOutcome::Performed));
+        assert!(app.affinity_editor.is_none());
+        let affinity = &game.state.processors[0].daemon_affinity;
+        assert_eq!(affinity.get("SIMD"), Some(&0.2));
+        assert_eq!(affinity.get("GENERAL"), Some(&-0.1));
+    }
+
+    #[test]
+    fn hardcore_is_chosen_at_new_game_and_refuses_manual_saves() {
+        let mut app = App::new();
+        app.new_game = Some(0);
+        app.save_path = Some(std::env::temp_dir().join("array-of-babel-unused.ron"));
+        let mut game = Game::fresh();
+
+        run_script(&mut app, &mut game, &[KeyCode::Char('h'), KeyCode::Enter]);
+        assert!(game.state.hardcore);
+        assert!(!app.hardcore);
+        let ctrl_s = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+        assert_eq!(
+            handle_key_event(ctrl_s, &mut app, &mut game),
+            rejected("Hardcore runs only save when a day ends.")
+        );
+
+        app.game_over = true;
+        assert_eq!(
+            handle_key_event(press(KeyCode::Tab), &mut app, &mut game),
+            ActionOutcome::Ignored
+        );
+        assert_eq!(
+            handle_key_event(press(KeyCode::Enter), &mut app, &mut game),
+            ActionOutcome::Quit
+        );
+    }
+
+    #[test]
+    fn queue_and_assign_are_performed() {
+        let mut app = App::new();
+        let mut game = Game::fresh();
+        game.state.jobs = vec![board_job(1)];
+
+        let outcomes = run_script(
+            &mut app,
+            &mut game,
+            &[KeyCode::Enter, KeyCode::Tab, KeyCode::Enter],
+        );
+        assert!(outcomes.iter().all(|o| *o == ActionOutcome::Performed));
+        assert!(!game.state.processors[0].is_idle());
+        assert!(game.state.pending_jobs.is_empty());
+    }
+
+    #[test]
+    fn store_enter_out_of_range_is_rejected() {
+        let mut app = App::new();
+        let mut game = Game::fresh();
+        app.store_open = true;
+        app.selected_store_item = game.store_items().len();
+        assert_eq!(
+            handle_key_event(press(KeyCode::Enter), &mut app, &mut game),
+            rejected("No store item selected.")
+        );
+    }
+
+    #[test]
+    fn store_trade_in_picks_a_model_and_swaps_the_unit() {
+        let mut app = App::new();
+        let mut game = Game::fresh();
+        game.state.credits = 1_000;
+        app.store_open = true;
+
+        let outcomes = run_script(
+            &mut app,
+            &mut game,
+            &[
+                KeyCode::Char('t'),
+                KeyCode::Enter,
+                KeyCode::Down,
+                KeyCode::Enter,
+            ],
+        );
+        assert_eq!(
+            outcomes,
+            vec![
+                ActionOutcome::Performed,
+                rejected("Trade-in failed: selected processor is already that model."),
+                ActionOutcome::Performed,
+                ActionOutcome::Performed,
+            ]
+        );
+        assert!(app.trade_in.is_none() && app.store_open);
+        assert_eq!(game.state.processors[0].name, Game::catalog_models()[1]);
+    }
+
+    #[test]
+    fn failed_override_press_does_not_leak_into_later_spending() {
+        let mut app = App::new();
+        let mut game = Game::fresh();
+        let clock = Game::store_index_for(StoreAction::IncreaseSpeed).expect("clock tuning");
+        let cost = game.item_cost(clock, None).expect("purchasable");
+        game.state.reserve_mode = ReserveMode::Enforce;
+        game.state.credits = cost + game.reserved_credits() - 1;
+
+        // The third press overrides, but replacing a working unit fails first.
+        let held = ReserveHold {
+            action: HoldAction::Replace(0),
+            presses: RESERVE_HOLD_PRESSES - 1,
+        };
+        let outcome = purchase_with_hold(
+            &mut app,
+            &mut game,
+            HoldAction::Replace(0),
+            Some(held),
+            "Replacement",
+        );
+        assert!(matches!(outcome, ActionOutcome::Rejected { .. }));
+
+        assert!(matches!(
+            game.purchase_item(clock, None),
+            Err(PurchaseError::ReserveBreach { .. })
+        ));
+    }
+
+    #[test]
+    fn trade_in_and_firmware_refuse_a_salvaged_selection() {
+        let mut app = App::new();
+        let mut game = Game::fresh();
+        game.state.processors.push(ProcessorState {
+            id: game.state.next_processor_id,
+            status: ProcessorStatus::BurntOut,
+            ..ProcessorState::starter()
+        });
+        app.select_processor(&game, 1);
+        // Stripped outside the key handler, which would reselect a neighbour.
+        game.salvage_processor(1).expect("salvages");
+
+        let stale = rejected("The selected unit has left the fleet; pick another.");
+        assert_eq!(
+            run_script(&mut app, &mut game, &[KeyCode::Char('i')]),
+            vec![stale.clone()]
+        );
+        app.store_open = true;
+        assert_eq!(
+            run_script(&mut app, &mut game, &[KeyCode::Char('T')]),
+            vec![stale]
+        );
+        assert!(app.trade_in.is_none());
+    }
+
+    #[test]
+    fn log_search_jumps_to_the_job_an_entry_names() {
+        let mut app = App::new();
+        let mut game = Game::fresh();
+        game.state.jobs.clear();
+        for _ in 0..2 {
+            game.update(std::time::Duration::from_secs(3));
+        }
+        assert_eq!(game.state.jobs.len(), 1);
+        app.set_focus(FocusTarget::Processors);
+
+        let search: Vec<KeyCode> = [KeyCode::Char('L'), KeyCode::Char('/')]
+            .into_iter()
+            .chain("POSTED".chars().map(KeyCode::Char))
+            .chain([KeyCode::Enter, KeyCode::Enter])
+            .collect();
+        let outcomes = run_script(&mut app, &mut game, &search);
+        assert!(
+            outcomes
+                .iter()
+                .all(|outcome| *outcome == ActionOutcome::Performed)
+        );
+        assert_eq!(app.focus(), FocusTarget::Jobs);
+        assert_eq!(app.selected_job, 0);
+        assert!(app.log_focus.is_none());
+
+        game.state.jobs.clear();
+        let outcomes = run_script(&mut app, &mut game, &search);
+        assert_eq!(
+            outcomes.last(),
+            Some(&rejected("That unit or job no longer exists."))
+        );
+        assert!(app.log_focus.is_some());
+    }
+
+    #[test]
+    fn salvage_asks_twice_and_keeps_the_selection_in_range() {
+        let mut app = App::new();
+        let mut game = Game::fresh();
+        game.state.processors = vec![ProcessorState::starter(), ProcessorState::budget()];
+        game.state.processors[1].id = 1;
+        game.state.processors[1].status = ProcessorStatus::Destroyed;
+        app.set_focus(FocusTarget::Processors);
+        let salvage = KeyEvent::new(KeyCode::Char('X'), KeyModifiers::SHIFT);
+
+        assert!(matches!(
+            handle_key_event(salvage, &mut app, &mut game),
+            ActionOutcome::Rejected { .. }
+        ));
+        app.select_processor(&game, 1);
+        assert_eq!(
+            handle_key_event(salvage, &mut app, &mut game),
+            ActionOutcome::Performed
+        );
+        assert_eq!(game.state.processors.len(), 2);
+        assert_eq!(
+            handle_key_event(salvage, &mut app, &mut game),
+            ActionOutcome::Performed
+        );
+        assert_eq!(game.state.processors.len(), 1);
+        assert_eq!(app.processor_selection(&game), Some(0));
+    }
+
+    #[test]
+    fn number_keys_take_a_ranked_assist_suggestion() {
+        let mut app = App::new();
+        let mut game = Game::fresh();
+        let processor = &mut game.state.processors[0];
+        processor.daemon_firmware_level = 1;
+        processor.daemon_mode = crate::sim::processors::DaemonMode::Assist;
+        game.state.jobs = vec![
+            Job {
+                base_reward: 300,
+                ..board_job(1)
+            },
+            board_job(2),
+        ];
+
+        assert!(matches!(
+            handle_key_event(press(KeyCode::Char('2')), &mut app, &mut game),
+            ActionOutcome::Rejected { .. }
+        ));
+        app.set_focus(FocusTarget::Processors);
+        assert!(matches!(
+            handle_key_event(press(KeyCode::Char('3')), &mut app, &mut game),
+            ActionOutcome::Rejected { .. }
+        ));
+        assert_eq!(
+            handle_key_event(press(KeyCode::Char('2')), &mut app, &mut game),
+            ActionOutcome::Performed
+        );
+        let ProcessorStatus::Working(work) = &game.state.processors[0].status else {
+            panic!("takes the second pick");
+        };
+        assert_eq!(work.job.id, 2);
+        assert_eq!(game.state.jobs.len(), 1);
+    }
+
+    #[test]
+    fn shift_e_toggles_the_power_column_without_sharing() {
+        let mut app = App::new();
+        let mut game = Game::fresh();
+        let toggle = KeyEvent::new(KeyCode::Char('E'), KeyModifiers::SHIFT);
+
+        assert!(!app.power_column);
+        assert_eq!(
+            handle_key_event(toggle, &mut app, &mut game),
+            ActionOutcome::Performed
+        );
+        assert!(app.power_column);
+        handle_key_event(toggle, &mut app, &mut game);
+        assert!(!app.power_column);
+    }
+
+    #[test]
+    fn processor_selection_follows_the_unit_not_the_slot() {
+        let mut app = App::new();
+        let mut game = Game::fresh();
+        game.state.processors = vec![ProcessorState::starter(); 3];
+        for (id, processor) in game.state.processors.iter_mut().enumerate() {
+            processor.id = id as u64 + 1;
+        }
+        app.set_focus(FocusTarget::Processors);
+        app.select_processor(&game, 1);
+
+        assert_eq!(
+            handle_key_event(press(KeyCode::Char(']')), &mut app, &mut game),
+            ActionOutcome::Performed
+        );
+        assert_eq!(app.processor_selection(&game), Some(2));
+        game.state.processors.remove(0);
+        assert_eq!(app.processor_selection(&game), Some(1));
+
+        game.state.processors.remove(1);
+        assert_eq!(app.processor_selection(&game), None);
+        assert!(matches!(
+            handle_key_event(press(KeyCode::Char('x')), &mut app, &mut game),
+            ActionOutcome::Rejected { .. }
+        ));
+        move_selection(&mut app, &game, 1);
+        assert_eq!(app.selected_processor, Some(3));
+    }
+
+    #[test]
+    fn first_key_in_demo_hands_over_control_without_acting() {
+        let mut app = App::new();
+        app.demo = true;
+        let mut game = Game::fresh();
+        game.state.jobs = vec![board_job(1)];
+
+        let outcome = handle_key_event(press(KeyCode::Enter), &mut app, &mut game);
+        assert_eq!(outcome, ActionOutcome::Performed);
+        assert!(!app.demo);
+        assert_eq!(game.state.jobs.len(), 1);
+        assert!(
+            game.messages()
+                .any(|message| message.starts_with("Demo over"))
+        );
+    }
+}
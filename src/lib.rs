@@ -0,0 +1,14 @@
+pub mod app;
+pub mod control;
+#[cfg(feature = "tui")]
+pub mod input;
+pub mod persist;
+pub mod profiler;
+pub mod sim;
+#[cfg(feature = "tui")]
+pub mod ui;
+
+use std::time::Duration;
+
+/// Simulation tick; also the frame budget the profiler warns against.
+pub const TICK_RATE: Duration = Duration::from_millis(100);
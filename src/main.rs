@@ -1,51 +1,467 @@
-mod app;
-mod persist;
-mod sim;
-mod ui;
-
 use anyhow::Result;
-use app::{App, FocusTarget};
-use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use array_of_babel::TICK_RATE;
+use array_of_babel::app::{App, CarryOver, LoadChoice, ProfileChoice, ProfilePicker};
+use array_of_babel::control::{ControlRequest, socket_path_for};
+use array_of_babel::input::{
+    ActionOutcome, clamp_selections, handle_event, handle_load_key, handle_profile_key,
+};
+use array_of_babel::persist::{
+    LEGACY_SAVE_FILE, PACKAGE_FILE, SaveSlot, archive_save, crash_save_for, delete_save,
+    export_package, import_package, job_templates_file, list_profiles, load_game_from,
+    load_job_templates, migrate_legacy_save, package_file, previous_save_for, profile_save_file,
+    read_meta, read_save, save_file, save_game_to, set_aside_corrupt, stored_save,
+    write_crash_save,
+};
+use array_of_babel::profiler::{Phase, Profiler};
+use array_of_babel::sim::care_package;
+use array_of_babel::sim::game::Game;
+use array_of_babel::sim::harness::{self, RunReport};
+use array_of_babel::sim::jobs;
+use array_of_babel::sim::policy::Policy;
+use array_of_babel::ui;
+use crossterm::event::{Event, KeyEvent, KeyEventKind};
 use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
 use crossterm::{execute, terminal};
-use persist::{load_game, save_game};
 use ratatui::Terminal;
 use ratatui::backend::CrosstermBackend;
-use sim::game::Game;
-use std::io;
+use std::any::Any;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 use tokio::task;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let loaded = load_game()?;
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some("summary") {
+        let path = args.get(2).map_or_else(save_file, PathBuf::from);
+        let state = load_game_from(&path)?
+            .ok_or_else(|| anyhow::anyhow!("no save found at {}", path.display()))?;
+        print!("{}", ui::summary::render(&state, &[]));
+        return Ok(());
+    }
+
+    jobs::install_templates(load_job_templates(&job_templates_file())?);
+    match args.get(1).map(String::as_str) {
+        Some("simulate") => return simulate(&args[2..]),
+        Some("export-package") => return export_care_package(&args[2..]),
+        Some("import-package") => return import_care_package(&args[2..]),
+        _ => {}
+    }
+
+    let mut options = SaveOptions::parse(&args)?;
+    install_panic_hook();
+    if args.iter().any(|arg| arg == "--demo") {
+        return demo(options).await;
+    }
+
+    let explicit = flag_value(&args, "--save").is_some() || options.profile.is_some();
+    if !explicit {
+        let profiles: Vec<String> = list_profiles()
+            .into_iter()
+            .map(|profile| profile.name)
+            .collect();
+        if !profiles.is_empty() || !stored_save(&options.path).is_file() {
+            let mut picker = ProfilePicker::new(profiles);
+            match pregame(&mut picker, ui::profile_view::render, handle_profile_key)? {
+                ProfileChoice::Default => {}
+                ProfileChoice::Named(name) => options.use_profile(name)?,
+                ProfileChoice::Quit => return Ok(()),
+            }
+        }
+    }
+
+    // Only the default location inherits a save left behind by older builds.
+    let migrated = if options.profile.is_none() && flag_value(&args, "--save").is_none() {
+        migrate_legacy_save()?
+    } else {
+        None
+    };
+    let mut new_run = args.iter().any(|arg| arg == "--new-run");
+    if !new_run && !options.fresh && stored_save(&options.path).is_file() {
+        let mut selected = 0;
+        let mut status = None;
+        // Re-read each time round so a package sent or received shows.
+        while let Some(meta) = read_meta(&options.path) {
+            let render = |frame: &mut ratatui::Frame, selected: &usize| {
+                ui::load_view::render(frame, &meta, *selected, status.as_deref())
+            };
+            match pregame(&mut selected, render, handle_load_key)? {
+                LoadChoice::Continue => break,
+                LoadChoice::NewGame => {
+                    new_run = true;
+                    break;
+                }
+                LoadChoice::SendPackage => status = Some(send_care_package(&options.path)),
+                LoadChoice::ReceivePackage => {
+                    status = Some(receive_care_package(&options.path));
+                }
+                LoadChoice::Quit => return Ok(()),
+            }
+        }
+    }
+    if new_run {
+        archive_save(&options.path)?;
+    }
+    // `--new` leaves the existing save unread; it is overwritten on quit
+    // unless `--no-save` is also given.
+    let mut corrupt = None;
+    let mut tampered = false;
+    let loaded = if options.fresh {
+        None
+    } else {
+        match read_save(&options.path)? {
+            SaveSlot::Missing => None,
+            SaveSlot::Loaded {
+                state,
+                tampered: modified,
+            } => {
+                tampered = modified;
+                Some(*state)
+            }
+            SaveSlot::Corrupt(err) => {
+                corrupt = Some((set_aside_corrupt(&options.path)?, err));
+                None
+            }
+        }
+    };
+    let new_game = loaded.is_none();
+    // A damaged archive only costs the carry-over offer.
+    let previous = match read_save(previous_save_for(&options.path)) {
+        Ok(SaveSlot::Loaded { state, .. }) if new_game && !options.fresh => Some(*state),
+        _ => None,
+    };
     let mut game = match loaded {
         Some(state) => {
             let mut game = Game::from_state(state);
             game.add_message("Loaded save state.");
+            if tampered {
+                game.mark_tampered();
+            }
             game
         }
-        None => {
-            let mut game = Game::fresh();
-            game.add_message("Welcome to the Array of Babel.");
-            game
-        }
+        None => Game::fresh(),
     };
+    game.state.profile_name = options.profile.clone().unwrap_or_default();
+    if let Some((aside, err)) = corrupt {
+        game.add_message(format!(
+            "The save at {} could not be read ({err:#}). It was moved to {} and a new game started.",
+            options.path.display(),
+            aside.display()
+        ));
+    }
+    let crash = crash_save_for(&options.path);
+    if crash.is_file() {
+        game.add_message(format!(
+            "A crash save from an earlier session is at {}; move it over the save to recover it.",
+            crash.display()
+        ));
+    }
+    if let Some(path) = migrated {
+        game.add_message(format!(
+            "Copied ./{LEGACY_SAVE_FILE} to {}; the old file can be deleted.",
+            path.display()
+        ));
+    }
+
+    let audit = cfg!(debug_assertions) || args.iter().any(|arg| arg == "--audit");
 
+    let mut app = App::new();
+    app.save_path = options.write.then(|| options.path.clone());
+    if new_game {
+        app.new_game = Some(0);
+        app.seed = options.seed;
+        app.carry_over = previous.map(CarryOver::new);
+    }
     let mut terminal = setup_terminal()?;
-    let result = run(&mut terminal, &mut game).await;
+    let socket = socket_path_for(&options.path);
+    let result = run(&mut terminal, &mut app, &mut game, &socket, audit).await;
     restore_terminal(&mut terminal)?;
+    #[cfg(feature = "control-socket")]
+    let _ = std::fs::remove_file(&socket);
+    let result = settle(result, &game, Some(&options.path));
 
-    save_game(&game.state)?;
-    result
+    // Quitting from the new-game screen leaves nothing worth saving, and
+    // hardcore runs only keep what their last day's end saved.
+    if let Ok(false) = result {
+        return Ok(());
+    }
+    if options.write && !game.state.hardcore {
+        save_game_to(&game.state, &options.path)?;
+    }
+    result.map(|_| ())
+}
+
+/// Where the interactive modes read and write their save.
+struct SaveOptions {
+    /// `--save PATH`, or the save in the data directory.
+    path: PathBuf,
+    /// `--new`: start a fresh game without reading the save.
+    fresh: bool,
+    /// Cleared by `--no-save`, which skips saving on quit.
+    write: bool,
+    /// `--seed N`: seed for a new game; ignored when a save is loaded.
+    seed: Option<u64>,
+    /// `--profile NAME`, or the profile picked at launch.
+    profile: Option<String>,
 }
 
-async fn run(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, game: &mut Game) -> Result<()> {
+impl SaveOptions {
+    fn parse(args: &[String]) -> Result<Self> {
+        let mut options = Self {
+            path: flag_value(args, "--save").map_or_else(save_file, PathBuf::from),
+            fresh: args.iter().any(|arg| arg == "--new"),
+            write: !args.iter().any(|arg| arg == "--no-save"),
+            seed: flag_value(args, "--seed")
+                .map(|seed| seed.parse())
+                .transpose()?,
+            profile: None,
+        };
+        if let Some(name) = flag_value(args, "--profile") {
+            if flag_value(args, "--save").is_some() {
+                anyhow::bail!("--profile and --save both choose the save; pass one");
+            }
+            options.use_profile(name.clone())?;
+        }
+        Ok(options)
+    }
+
+    fn use_profile(&mut self, name: String) -> Result<()> {
+        self.path = profile_save_file(&name)
+            .map_err(|err| anyhow::anyhow!("can't use profile {name:?}: {err}"))?;
+        self.profile = Some(name);
+        Ok(())
+    }
+}
+
+/// `--demo`: the conservative policy plays a fixed-seed run in the full UI
+/// until a key is pressed. Nothing is saved unless the player takes over, in
+/// which case the existing save is archived like `--new-run` does.
+async fn demo(options: SaveOptions) -> Result<()> {
+    let mut game = harness::seeded_run(harness::DEMO_SEED);
+    game.add_message("Demo mode: the conservative policy is at the controls.");
     let mut app = App::new();
-    app.clamp_job_selection(game.state.jobs.len());
-    app.clamp_processor_selection(game.state.processors.len());
-    app.clamp_store_selection(game.store_items().len());
+    app.demo = true;
+    let mut terminal = setup_terminal()?;
+    let socket = socket_path_for(&options.path);
+    let result = run(&mut terminal, &mut app, &mut game, &socket, false).await;
+    restore_terminal(&mut terminal)?;
+    #[cfg(feature = "control-socket")]
+    let _ = std::fs::remove_file(&socket);
+    // The policy's run is nobody's to recover until the player takes over.
+    let result = settle(result, &game, (!app.demo).then_some(&options.path));
+
+    if let Ok(false) = result {
+        return Ok(());
+    }
+    if options.write {
+        archive_save(&options.path)?;
+        save_game_to(&game.state, &options.path)?;
+    }
+    result.map(|_| ())
+}
+
+/// Runs a screen shown before the game loads, such as the profile picker or
+/// the save summary, until `handle` returns a choice.
+fn pregame<S, T>(
+    state: &mut S,
+    render: impl Fn(&mut ratatui::Frame, &S),
+    handle: impl Fn(KeyEvent, &mut S) -> Option<T>,
+) -> Result<T> {
+    let mut terminal = setup_terminal()?;
+    let mut choose = || -> Result<T> {
+        loop {
+            terminal.draw(|f| render(f, state))?;
+            if let Event::Key(key) = crossterm::event::read()?
+                && key.kind == KeyEventKind::Press
+                && let Some(choice) = handle(key, state)
+            {
+                return Ok(choice);
+            }
+        }
+    };
+    let choice = choose();
+    restore_terminal(&mut terminal)?;
+    choice
+}
+
+/// `simulate [--days N] [--seed S] [--policy NAME]`: plays a run headlessly
+/// and prints its report.
+fn simulate(args: &[String]) -> Result<()> {
+    let value = |flag| flag_value(args, flag);
+    let days = value("--days").map(|days| days.parse()).transpose()?;
+    let seed = value("--seed").map(|seed| seed.parse()).transpose()?;
+    let policy = value("--policy")
+        .map(|name| Policy::from_name(name).ok_or_else(|| anyhow::anyhow!("unknown policy {name}")))
+        .transpose()?;
+    let game = harness::simulate(days.unwrap_or(50), seed.unwrap_or(1234), policy);
+    let RunReport {
+        days,
+        credits,
+        completions,
+        burnouts,
+    } = RunReport::of(&game);
+    println!(
+        "policy {}: {days} days, {credits} cr, {completions} completions, {burnouts} burnouts",
+        policy.map(Policy::name).unwrap_or("none")
+    );
+    Ok(())
+}
+
+/// `export-package [--save PATH] [--out PATH] [--credits N] [--paste N]`:
+/// moves credits and thermal paste out of a save into a care package.
+fn export_care_package(args: &[String]) -> Result<()> {
+    let save = flag_value(args, "--save").map_or_else(save_file, PathBuf::from);
+    let out = flag_value(args, "--out").map_or(PACKAGE_FILE, String::as_str);
+    let credits = flag_value(args, "--credits")
+        .map(|credits| credits.parse())
+        .transpose()?;
+    let paste = flag_value(args, "--paste")
+        .map(|paste| paste.parse())
+        .transpose()?;
+    let mut state = load_game_from(&save)?
+        .ok_or_else(|| anyhow::anyhow!("no save found at {}", save.display()))?;
+    let package = export_package(
+        &mut state,
+        &save,
+        credits.unwrap_or(0),
+        paste.unwrap_or(0),
+        Path::new(out),
+    )?;
+    let arrives = care_package::delivery(&package);
+    println!(
+        "Packed {} cr and {} paste into {out}; the other save will receive {} cr and {} paste.",
+        package.credits, package.thermal_paste, arrives.credits, arrives.thermal_paste
+    );
+    Ok(())
+}
+
+/// `import-package [--save PATH] [--in PATH]`: delivers a care package once.
+fn import_care_package(args: &[String]) -> Result<()> {
+    let save = flag_value(args, "--save").map_or_else(save_file, PathBuf::from);
+    let input = flag_value(args, "--in").map_or(PACKAGE_FILE, String::as_str);
+    let mut state = load_game_from(&save)?
+        .ok_or_else(|| anyhow::anyhow!("no save found at {}", save.display()))?;
+    let delivery = import_package(&mut state, Path::new(input))?;
+    save_game_to(&state, &save)?;
+    println!(
+        "Received {} cr and {} paste into {}.",
+        delivery.credits,
+        delivery.thermal_paste,
+        save.display()
+    );
+    Ok(())
+}
+
+/// The slot picker's Send entry: packs the fullest package the slot can
+/// afford into the shared package file. Returns the line to show.
+fn send_care_package(save: &Path) -> String {
+    let send = || -> Result<String> {
+        let mut state = load_game_from(save)?
+            .ok_or_else(|| anyhow::anyhow!("no save found at {}", save.display()))?;
+        let (credits, paste) = care_package::fullest(state.credits);
+        let package = export_package(&mut state, save, credits, paste, &package_file())?;
+        let arrives = care_package::delivery(&package);
+        Ok(format!(
+            "Sent {} cr and {} paste; the receiving slot gets {} cr and {} paste.",
+            package.credits, package.thermal_paste, arrives.credits, arrives.thermal_paste
+        ))
+    };
+    send().unwrap_or_else(|err| format!("Send failed: {err:#}"))
+}
+
+/// The slot picker's Receive entry: delivers the waiting package into the
+/// slot once. Returns the line to show.
+fn receive_care_package(save: &Path) -> String {
+    let path = package_file();
+    if !path.is_file() {
+        return "No care package is waiting.".to_string();
+    }
+    let receive = || -> Result<String> {
+        let mut state = load_game_from(save)?
+            .ok_or_else(|| anyhow::anyhow!("no save found at {}", save.display()))?;
+        let delivery = import_package(&mut state, &path)?;
+        save_game_to(&state, save)?;
+        Ok(format!(
+            "Received {} cr and {} paste.",
+            delivery.credits, delivery.thermal_paste
+        ))
+    };
+    receive().unwrap_or_else(|err| format!("Receive failed: {err:#}"))
+}
+
+/// The argument following `flag`, if both are present.
+fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a String> {
+    args.iter()
+        .position(|arg| arg == flag)
+        .and_then(|index| args.get(index + 1))
+}
+
+/// Why the UI loop stopped early. A panic is carried out as a value so the
+/// terminal can be restored and a crash save written before it is re-raised.
+enum Fatal {
+    Error(anyhow::Error),
+    Panic(Box<dyn Any + Send>),
+}
+
+impl<E: Into<anyhow::Error>> From<E> for Fatal {
+    fn from(err: E) -> Self {
+        Fatal::Error(err.into())
+    }
+}
+
+/// Runs one synchronous step of the loop, catching a panic in it.
+fn guard<T>(step: impl FnOnce() -> T) -> Result<T, Fatal> {
+    panic::catch_unwind(AssertUnwindSafe(step)).map_err(Fatal::Panic)
+}
+
+/// Turns the loop's outcome back into a plain result once the terminal is
+/// restored. After a panic the state is dumped to the crash save, if `save`
+/// is given, and the panic resumes.
+fn settle(result: Result<bool, Fatal>, game: &Game, save: Option<&Path>) -> Result<bool> {
+    match result {
+        Ok(finished) => Ok(finished),
+        Err(Fatal::Error(err)) => Err(err),
+        Err(Fatal::Panic(payload)) => {
+            if let Some(save) = save {
+                match write_crash_save(&game.state, save) {
+                    Ok(path) => eprintln!("Crash save written to {}.", path.display()),
+                    Err(err) => eprintln!("Crash save failed: {err:#}"),
+                }
+            }
+            panic::resume_unwind(payload)
+        }
+    }
+}
+
+/// Leaves raw mode and the alternate screen before the panic message is
+/// printed, so it lands on a usable terminal.
+fn install_panic_hook() {
+    let report = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let _ = disable_raw_mode();
+        let _ = execute!(
+            io::stdout(),
+            terminal::LeaveAlternateScreen,
+            crossterm::event::DisableMouseCapture
+        );
+        report(info);
+    }));
+}
+
+/// Runs the UI loop; returns whether the player ended up with a game of
+/// their own, as opposed to quitting the new-game screen or the demo.
+async fn run(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    app: &mut App,
+    game: &mut Game,
+    socket: &Path,
+    audit: bool,
+) -> Result<bool, Fatal> {
+    app.quarantine_open = !game.quarantine_notices().is_empty();
+    clamp_selections(app, game);
 
     let (input_tx, mut input_rx) = mpsc::unbounded_channel();
     task::spawn(async move {
@@ -62,242 +478,118 @@ async fn run(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, game: &mut G
         }
     });
 
+    let (control_tx, mut control_rx) = mpsc::unbounded_channel();
+    start_control_socket(game, socket, control_tx);
+
+    let profiler = Profiler::default();
     let mut last_tick = Instant::now();
-    let tick_rate = Duration::from_millis(100);
     let mut should_quit = false;
 
     loop {
-        terminal.draw(|f| ui::render(f, &app, game))?;
+        guard(|| {
+            profiler.measure(Phase::Draw, || {
+                terminal.draw(|f| ui::render(f, app, game, &profiler))
+            })
+        })??;
+        if game.take_bell() {
+            io::stdout().write_all(b"\x07")?;
+            io::stdout().flush()?;
+        }
         if should_quit {
             break;
         }
 
-        let timeout = tick_rate
+        let timeout = TICK_RATE
             .checked_sub(last_tick.elapsed())
             .unwrap_or_else(|| Duration::from_secs(0));
         tokio::select! {
             Some(event) = input_rx.recv() => {
-                if handle_event(event, &mut app, game)? {
-                    should_quit = true;
+                match guard(|| profiler.measure(Phase::Input, || handle_event(event, app, game)))? {
+                    ActionOutcome::Quit => should_quit = true,
+                    ActionOutcome::Rejected { reason } => {
+                        game.add_message(reason.clone());
+                        app.flash(reason);
+                    }
+                    ActionOutcome::Performed | ActionOutcome::Ignored => {}
+                }
+            }
+            Some(request) = control_rx.recv() => {
+                if app.new_game.is_some() {
+                    request.reject("no game is running yet");
+                } else {
+                    guard(|| request.respond(game))?;
+                    clamp_selections(app, game);
                 }
             }
             _ = tokio::time::sleep(timeout) => {
                 let delta = last_tick.elapsed();
                 last_tick = Instant::now();
-                game.update(delta);
-                app.clamp_job_selection(game.state.jobs.len());
-                app.clamp_processor_selection(game.state.processors.len());
-            }
-        }
-    }
-
-    Ok(())
-}
-
-fn handle_event(event: Event, app: &mut App, game: &mut Game) -> Result<bool> {
-    match event {
-        Event::Key(key) if key.kind == KeyEventKind::Press => handle_key_event(key, app, game),
-        Event::Resize(_, _) => Ok(false),
-        _ => Ok(false),
-    }
-}
-
-fn handle_key_event(key: KeyEvent, app: &mut App, game: &mut Game) -> Result<bool> {
-    if key.modifiers.contains(KeyModifiers::CONTROL) {
-        if key.code == KeyCode::Char('c') {
-            return Ok(true);
-        }
-    }
-
-    if app.store_open {
-        return handle_store_key(key, app, game);
-    }
-
-    match key.code {
-        KeyCode::Char('q') | KeyCode::Char('Q') => Ok(true),
-        KeyCode::Esc => {
-            if let Some(job) = app.pending_job.take() {
-                game.return_job(job);
-                app.clamp_job_selection(game.state.jobs.len());
-            }
-            Ok(false)
-        }
-        KeyCode::Char('s') | KeyCode::Char('S') => {
-            app.toggle_store();
-            Ok(false)
-        }
-        KeyCode::Char('d') | KeyCode::Char('D') => {
-            if app.focus() == FocusTarget::Processors {
-                if game.state.processors.is_empty() {
-                    game.add_message("No processors available.");
-                } else {
-                    let index = app.selected_processor.min(game.state.processors.len() - 1);
-                    if key.modifiers.contains(KeyModifiers::SHIFT) {
-                        game.toggle_honor_cooling(index);
-                    } else {
-                        game.cycle_daemon_mode(index);
-                    }
+                app.age_flash(delta);
+                if app.new_game.is_some() || app.game_over {
+                    continue;
                 }
-            } else {
-                game.add_message("Focus a processor to adjust automation.");
-            }
-            Ok(false)
-        }
-        KeyCode::Char('r') | KeyCode::Char('R') => {
-            if app.focus() == FocusTarget::Processors {
-                if game.state.processors.is_empty() {
-                    game.add_message("No processors available to replace.");
-                } else {
-                    let index = app.selected_processor.min(game.state.processors.len() - 1);
-                    let result = if key.modifiers.contains(KeyModifiers::SHIFT) {
-                        game.replace_model_direct(index)
-                    } else {
-                        game.replace_processor_direct(index)
-                    };
-                    if let Err(err) = result {
-                        game.add_message(format!("Replacement failed: {err}"));
+                guard(|| {
+                    if app.demo {
+                        Policy::Conservative.act(game);
+                    }
+                    profiler.measure(Phase::Update, || game.update(delta));
+                    if audit {
+                        game.audit();
                     }
+                })?;
+                if game.state.hardcore {
+                    checkpoint(app, game);
                 }
-            } else {
-                game.add_message("Focus a processor to replace hardware.");
+                clamp_selections(app, game);
             }
-            Ok(false)
-        }
-        KeyCode::Tab => {
-            app.next_focus();
-            Ok(false)
-        }
-        KeyCode::BackTab => {
-            app.next_focus();
-            Ok(false)
         }
-        KeyCode::Left => {
-            app.set_focus(FocusTarget::Processors);
-            Ok(false)
-        }
-        KeyCode::Right => {
-            app.set_focus(FocusTarget::Jobs);
-            Ok(false)
-        }
-        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => {
-            move_selection(app, game, -1);
-            Ok(false)
-        }
-        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => {
-            move_selection(app, game, 1);
-            Ok(false)
-        }
-        KeyCode::Enter => handle_enter(app, game),
-        KeyCode::Char('a') | KeyCode::Char('A') => handle_enter(app, game),
-        _ => Ok(false),
     }
+
+    Ok(app.new_game.is_none() && !app.demo)
 }
 
-fn move_selection(app: &mut App, game: &Game, delta: isize) {
-    match app.focus() {
-        FocusTarget::Jobs => {
-            let len = game.state.jobs.len();
-            if len > 0 {
-                let mut idx = app.selected_job as isize + delta;
-                if idx < 0 {
-                    idx = len as isize - 1;
-                } else if idx >= len as isize {
-                    idx = 0;
-                }
-                app.selected_job = idx as usize;
-            }
-        }
-        FocusTarget::Processors => {
-            let len = game.state.processors.len();
-            if len > 0 {
-                let mut idx = app.selected_processor as isize + delta;
-                if idx < 0 {
-                    idx = len as isize - 1;
-                } else if idx >= len as isize {
-                    idx = 0;
-                }
-                app.selected_processor = idx as usize;
-            }
+/// Erases a hardcore run's save the moment the run is lost, and otherwise
+/// saves it whenever a day closes.
+fn checkpoint(app: &mut App, game: &mut Game) {
+    if game.is_lost() {
+        app.game_over = true;
+        if let Some(path) = &app.save_path
+            && let Err(err) = delete_save(path)
+        {
+            game.add_message(format!("Could not erase the save: {err}"));
         }
+    } else if game.take_checkpoint()
+        && let Some(path) = &app.save_path
+        && let Err(err) = save_game_to(&game.state, path)
+    {
+        game.add_message(format!("Day-end save failed: {err}"));
     }
 }
 
-fn handle_enter(app: &mut App, game: &mut Game) -> Result<bool> {
-    match app.focus() {
-        FocusTarget::Jobs => {
-            if app.pending_job.is_some() {
-                game.add_message("A job is already awaiting assignment.");
-                return Ok(false);
-            }
-            if let Some(job) = game.take_job(app.selected_job) {
-                let name = job.name.clone();
-                app.pending_job = Some(job);
-                app.clamp_job_selection(game.state.jobs.len());
-                game.add_message(format!("{name} queued for assignment."));
-            } else {
-                game.add_message("No jobs available to queue.");
-            }
-            Ok(false)
-        }
-        FocusTarget::Processors => {
-            if game.state.processors.is_empty() {
-                game.add_message("No processors available.");
-                return Ok(false);
-            }
-            let idx = app
-                .selected_processor
-                .min(game.state.processors.len().saturating_sub(1));
-            if let Some(job) = app.pending_job.take() {
-                let job_clone = job.clone();
-                match game.assign_job_to_processor(job_clone, idx, false) {
-                    Ok(_) => Ok(false),
-                    Err(err) => {
-                        game.add_message(format!("Assignment failed: {err}"));
-                        app.pending_job = Some(job);
-                        Ok(false)
-                    }
-                }
-            } else {
-                if game.accept_assist_suggestion(idx) {
-                    app.clamp_job_selection(game.state.jobs.len());
-                }
-                Ok(false)
-            }
+/// Listens for local tools when built with `control-socket`; otherwise the
+/// sender is dropped and the loop never hears from it.
+#[cfg(feature = "control-socket")]
+fn start_control_socket(
+    game: &mut Game,
+    socket: &Path,
+    requests: mpsc::UnboundedSender<ControlRequest>,
+) {
+    use array_of_babel::control;
+    match control::bind(socket) {
+        Ok(listener) => {
+            task::spawn(control::serve(listener, requests));
+            game.add_message(format!("Control socket listening at {}.", socket.display()));
         }
+        Err(err) => game.add_message(format!("Control socket unavailable: {err}")),
     }
 }
 
-fn handle_store_key(key: KeyEvent, app: &mut App, game: &mut Game) -> Result<bool> {
-    match key.code {
-        KeyCode::Esc | KeyCode::Char('s') | KeyCode::Char('S') => {
-            app.toggle_store();
-            Ok(false)
-        }
-        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('K') => {
-            if app.selected_store_item > 0 {
-                app.selected_store_item -= 1;
-            }
-            Ok(false)
-        }
-        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('J') => {
-            if app.selected_store_item + 1 < game.store_items().len() {
-                app.selected_store_item += 1;
-            }
-            Ok(false)
-        }
-        KeyCode::Enter => {
-            let processor_index = if game.state.processors.is_empty() {
-                None
-            } else {
-                Some(app.selected_processor.min(game.state.processors.len() - 1))
-            };
-            if let Err(err) = game.purchase_item(app.selected_store_item, processor_index) {
-                game.add_message(format!("Purchase failed: {err}"));
-            }
-            Ok(false)
-        }
-        _ => Ok(false),
-    }
+#[cfg(not(feature = "control-socket"))]
+fn start_control_socket(
+    _game: &mut Game,
+    _socket: &Path,
+    _requests: mpsc::UnboundedSender<ControlRequest>,
+) {
 }
 
 fn setup_terminal() -> Result<Terminal<CrosstermBackend<io::Stdout>>> {
@@ -0,0 +1,19 @@
+use crate::sim::audit::AuditViolation;
+use anyhow::Result;
+use std::fs::OpenOptions;
+use std::io::Write;
+
+use super::AUDIT_FILE;
+
+pub fn write_audit_dump(violations: &[AuditViolation]) -> Result<String> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(AUDIT_FILE)?;
+    for violation in violations {
+        writeln!(file, "== {} ==", violation.key())?;
+        writeln!(file, "{}", violation.dump)?;
+        writeln!(file)?;
+    }
+    Ok(AUDIT_FILE.to_string())
+}
@@ -0,0 +1,65 @@
+//! A CRC32 trailer on saves, so edits made outside the game can be noticed.
+//! It is a comment, so the file stays plain RON and unsealed saves still load.
+
+const TRAILER: &str = "// crc32: ";
+
+/// What the trailer says about a save's contents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Integrity {
+    /// Written before saves were sealed.
+    Unsealed,
+    Intact,
+    Modified,
+}
+
+/// Appends the trailer for `body`.
+pub fn seal(mut body: String) -> String {
+    if !body.ends_with('\n') {
+        body.push('\n');
+    }
+    let crc = crc32(body.as_bytes());
+    body.push_str(&format!("{TRAILER}{crc:08x}\n"));
+    body
+}
+
+pub fn verify(content: &str) -> Integrity {
+    let trimmed = content.trim_end_matches('\n');
+    let (body, last) = match trimmed.rfind('\n') {
+        Some(split) => (&content[..split + 1], &trimmed[split + 1..]),
+        None => ("", trimmed),
+    };
+    let Some(stored) = last.strip_prefix(TRAILER) else {
+        return Integrity::Unsealed;
+    };
+    match u32::from_str_radix(stored.trim(), 16) {
+        Ok(stored) if stored == crc32(body.as_bytes()) => Integrity::Intact,
+        _ => Integrity::Modified,
+    }
+}
+
+/// CRC-32 (IEEE, reflected), bit by bit; saves are small enough.
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trailer_detects_edits_and_tolerates_its_absence() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        let sealed = seal("(credits: 120)".to_string());
+        assert_eq!(verify(&sealed), Integrity::Intact);
+        assert_eq!(verify(&sealed.replace("120", "999")), Integrity::Modified);
+        assert_eq!(verify("(credits: 120)\n"), Integrity::Unsealed);
+    }
+}
@@ -0,0 +1,67 @@
+//! Optional gzip for saves. Loading sniffs the magic bytes, so either form
+//! reads back whatever the file is called.
+
+use anyhow::{Result, bail};
+use flate2::Compression;
+use flate2::bufread::GzDecoder;
+use flate2::write::GzEncoder;
+use std::ffi::OsString;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// `save.ron` is written as `save.ron.gz` when compression is on.
+pub fn gz_path_for(path: &Path) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(".gz");
+    PathBuf::from(name)
+}
+
+/// The file holding the save at `path`: its compressed twin if there is
+/// one, else `path` itself.
+pub fn stored_save(path: &Path) -> PathBuf {
+    let gz = gz_path_for(path);
+    if gz.is_file() { gz } else { path.to_path_buf() }
+}
+
+pub fn compress(text: &str) -> Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(text.as_bytes())?;
+    Ok(encoder.finish()?)
+}
+
+/// Save text from file bytes, decompressing them if they are gzip.
+pub fn decode(bytes: Vec<u8>) -> Result<String> {
+    if !bytes.starts_with(&GZIP_MAGIC) {
+        return Ok(String::from_utf8(bytes)?);
+    }
+    let mut decoder = GzDecoder::new(bytes.as_slice());
+    let mut text = String::new();
+    decoder.read_to_string(&mut text)?;
+    if !decoder.into_inner().is_empty() {
+        bail!("unexpected data after the compressed save");
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_and_compressed_text_decode_alike() {
+        let text = "(credits: 120)\n";
+        let packed = compress(text).expect("compresses");
+        assert!(packed.starts_with(&GZIP_MAGIC));
+        assert_eq!(decode(packed.clone()).expect("inflates"), text);
+        assert_eq!(
+            decode(text.as_bytes().to_vec()).expect("passes through"),
+            text
+        );
+
+        let mut garbage = packed;
+        garbage.extend_from_slice(b"\0junk");
+        assert!(decode(garbage).is_err());
+    }
+}
@@ -2,16 +2,73 @@ use crate::sim::game::GameState;
 use anyhow::Result;
 use std::fs;
 use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use super::SAVE_FILE;
+use super::checksum::{self, Integrity};
+use super::compress::{self, stored_save};
+use super::{MigrationError, migrate, save_file};
+
+/// What was found at a save path.
+#[derive(Debug)]
+pub enum SaveSlot {
+    Missing,
+    Loaded {
+        state: Box<GameState>,
+        /// The checksum no longer matches: edited outside the game.
+        tampered: bool,
+    },
+    /// The file exists but doesn't parse as any known save version.
+    Corrupt(anyhow::Error),
+}
 
 pub fn load_game() -> Result<Option<GameState>> {
-    match fs::read_to_string(SAVE_FILE) {
-        Ok(content) => {
-            let state = ron::from_str(&content)?;
-            Ok(Some(state))
-        }
-        Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
-        Err(err) => Err(err.into()),
+    load_game_from(save_file())
+}
+
+/// Like `read_save`, but a corrupt file is an error.
+pub fn load_game_from(path: impl AsRef<Path>) -> Result<Option<GameState>> {
+    match read_save(path)? {
+        SaveSlot::Missing => Ok(None),
+        SaveSlot::Loaded { state, .. } => Ok(Some(*state)),
+        SaveSlot::Corrupt(err) => Err(err),
     }
 }
+
+/// Reads and migrates the save at `path`, or its compressed twin. I/O
+/// failures and saves from a newer build are errors, since the file itself
+/// may be fine.
+pub fn read_save(path: impl AsRef<Path>) -> Result<SaveSlot> {
+    let bytes = match fs::read(stored_save(path.as_ref())) {
+        Ok(bytes) => bytes,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(SaveSlot::Missing),
+        Err(err) => return Err(err.into()),
+    };
+    let content = match compress::decode(bytes) {
+        Ok(content) => content,
+        Err(err) => return Ok(SaveSlot::Corrupt(err)),
+    };
+    match migrate::parse(&content) {
+        Ok(state) => Ok(SaveSlot::Loaded {
+            state: Box::new(state),
+            tampered: checksum::verify(&content) == Integrity::Modified,
+        }),
+        Err(err) if err.is::<MigrationError>() => Err(err),
+        Err(err) => Ok(SaveSlot::Corrupt(err)),
+    }
+}
+
+/// Renames a corrupt save (or its compressed twin) to
+/// `<file>.corrupt-<unix seconds>` so a fresh game can take its place without
+/// losing it. Returns the new path.
+pub fn set_aside_corrupt(path: &Path) -> Result<PathBuf> {
+    let path = &stored_save(path);
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".corrupt-{stamp}"));
+    let aside = PathBuf::from(name);
+    fs::rename(path, &aside)?;
+    Ok(aside)
+}
@@ -0,0 +1,58 @@
+use crate::sim::game::{GameState, SAVE_VERSION};
+use anyhow::Result;
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Summary written beside each save so the load screen can describe it
+/// without parsing the whole state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaveMeta {
+    pub credits: u64,
+    /// Day in progress, counting from 1.
+    pub day: u64,
+    pub processors: usize,
+    pub play_time_ms: u64,
+    /// Unix seconds when the save was written.
+    pub saved_at: u64,
+    /// Crate version of the build that wrote it.
+    pub game_version: String,
+    pub save_version: u32,
+}
+
+impl SaveMeta {
+    pub fn of(state: &GameState) -> Self {
+        Self {
+            credits: state.credits,
+            day: state.statistics.day(),
+            processors: state.processors.len(),
+            play_time_ms: state.statistics.play_time_ms,
+            saved_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            game_version: env!("CARGO_PKG_VERSION").to_string(),
+            save_version: SAVE_VERSION,
+        }
+    }
+}
+
+/// `save.ron` keeps its summary in `save.meta.ron`.
+pub fn meta_path_for(save: &Path) -> PathBuf {
+    save.with_extension("meta.ron")
+}
+
+pub(super) fn meta_bytes(state: &GameState) -> Result<String> {
+    Ok(ron::ser::to_string_pretty(
+        &SaveMeta::of(state),
+        PrettyConfig::new(),
+    )?)
+}
+
+/// The summary of the save at `save`, if one was written and still parses.
+/// Saves from before summaries existed have none.
+pub fn read_meta(save: impl AsRef<Path>) -> Option<SaveMeta> {
+    let content = fs::read_to_string(meta_path_for(save.as_ref())).ok()?;
+    ron::from_str(&content).ok()
+}
@@ -0,0 +1,91 @@
+//! Upgrades saves written by older builds, one schema version at a time.
+
+use crate::sim::game::{GameState, SAVE_VERSION};
+use crate::sim::processors::{ClockMode, DaemonMode};
+use serde::Deserialize;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum MigrationError {
+    #[error("save is version {found}, but this build only reads up to version {supported}")]
+    NewerVersion { found: u32, supported: u32 },
+}
+
+/// Just the version, read before committing to the full schema.
+#[derive(Deserialize)]
+struct Header {
+    #[serde(default)]
+    version: u32,
+}
+
+/// Parses a save of any supported version and brings it up to date.
+pub fn parse(content: &str) -> anyhow::Result<GameState> {
+    let Header { version } = ron::from_str(content)?;
+    if version > SAVE_VERSION {
+        return Err(MigrationError::NewerVersion {
+            found: version,
+            supported: SAVE_VERSION,
+        }
+        .into());
+    }
+    let mut state: GameState = ron::from_str(content)?;
+    state.version = version;
+    migrate(&mut state);
+    Ok(state)
+}
+
+/// Migrations indexed by the version they upgrade from.
+const MIGRATIONS: [fn(&mut GameState); SAVE_VERSION as usize] =
+    [v0_to_v1, v1_to_v2, v2_to_v3, v3_to_v4, v4_to_v5];
+
+fn migrate(state: &mut GameState) {
+    while state.version < SAVE_VERSION {
+        MIGRATIONS[state.version as usize](state);
+        state.version += 1;
+    }
+}
+
+/// v0 had one fleet-wide automation switch; it becomes Auto on every unit
+/// that had no mode of its own.
+fn v0_to_v1(state: &mut GameState) {
+    if std::mem::take(&mut state.daemon_enabled) {
+        for processor in &mut state.processors {
+            if processor.daemon_mode == DaemonMode::Off {
+                processor.daemon_mode = DaemonMode::Auto;
+            }
+        }
+    }
+}
+
+/// Before v2 daemon firmware was unlocked fleet-wide; it is now per unit.
+fn v1_to_v2(state: &mut GameState) {
+    if state.daemon_unlocked {
+        for processor in &mut state.processors {
+            processor.daemon_unlocked = true;
+        }
+    }
+}
+
+/// v2 could only overclock; the switch became one of several clock modes.
+fn v2_to_v3(state: &mut GameState) {
+    for processor in &mut state.processors {
+        if std::mem::take(&mut processor.overclocked) {
+            processor.clock = ClockMode::Overclock;
+        }
+    }
+}
+
+/// v3 firmware was a single switch; it now comes in levels, and the old
+/// install counts as the first.
+fn v3_to_v4(state: &mut GameState) {
+    for processor in &mut state.processors {
+        if std::mem::take(&mut processor.daemon_unlocked) {
+            processor.daemon_firmware_level = processor.daemon_firmware_level.max(1);
+        }
+    }
+}
+
+/// v4 held one pending job; it heads the pending queue that replaced it.
+fn v4_to_v5(state: &mut GameState) {
+    state.pending_jobs.extend(state.pending_job.take());
+}
@@ -1,7 +1,34 @@
+mod audit;
+mod checksum;
+mod compress;
+mod export;
 mod load;
+mod meta;
+mod migrate;
+mod package;
+mod paths;
+mod profiles;
 mod save;
+mod templates;
 
-pub use load::load_game;
-pub use save::save_game;
+pub use audit::write_audit_dump;
+pub use compress::{gz_path_for, stored_save};
+pub use export::{copy_to_clipboard, write_summary};
+pub use load::{SaveSlot, load_game, load_game_from, read_save, set_aside_corrupt};
+pub use meta::{SaveMeta, meta_path_for, read_meta};
+pub use migrate::MigrationError;
+pub use package::{export_package, import_package, package_file};
+pub use paths::{
+    LEGACY_SAVE_FILE, crash_save_for, data_dir, migrate_legacy_save, previous_save_for, save_file,
+};
+pub use profiles::{
+    MAX_PROFILE_NAME, PROFILES_DIR, ProfileInfo, ProfileNameError, list_profiles,
+    profile_save_file, validate_profile_name,
+};
+pub use save::{archive_save, delete_save, save_game, save_game_to, write_crash_save};
+pub use templates::{JOB_TEMPLATES_FILE, job_templates_file, load_job_templates};
 
-pub const SAVE_FILE: &str = "save.ron";
+/// Default care package path for `export-package` / `import-package`.
+pub const PACKAGE_FILE: &str = "care_package.ron";
+pub const AUDIT_FILE: &str = "audit.log";
+pub const SUMMARY_FILE: &str = "summary.txt";
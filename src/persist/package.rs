@@ -0,0 +1,74 @@
+use super::PACKAGE_FILE;
+use super::paths::data_dir;
+use super::save::{save_game_to, temp_path};
+use crate::sim::care_package::{self, CarePackage, Delivery};
+use crate::sim::game::GameState;
+use anyhow::{Result, bail};
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Mixed into the signature so a package edited by hand is rejected. This
+/// catches tampering in a text editor, not a determined forger.
+const SIGNING_KEY: &[u8] = b"array-of-babel care package v1";
+
+#[derive(Serialize, Deserialize)]
+struct SignedPackage {
+    package: CarePackage,
+    signature: u64,
+}
+
+/// FNV-1a over the key and the package's RON form.
+fn signature(package: &CarePackage) -> Result<u64> {
+    let body = ron::to_string(package)?;
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in SIGNING_KEY.iter().chain(body.as_bytes()) {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    Ok(hash)
+}
+
+/// Where the slot picker sends and receives packages, shared by every slot.
+pub fn package_file() -> PathBuf {
+    data_dir().join(PACKAGE_FILE)
+}
+
+/// Packs credits and paste out of `state`, saves the debited state to `save`
+/// and only then publishes the signed package at `path`. A failed save
+/// publishes nothing, so the credits can't end up in both slots.
+pub fn export_package(
+    state: &mut GameState,
+    save: &Path,
+    credits: u64,
+    thermal_paste: u32,
+    path: &Path,
+) -> Result<CarePackage> {
+    let package = care_package::pack(state, rand::random(), credits, thermal_paste)?;
+    let signed = SignedPackage {
+        signature: signature(&package)?,
+        package: package.clone(),
+    };
+    let staged = temp_path(path);
+    fs::write(
+        &staged,
+        ron::ser::to_string_pretty(&signed, PrettyConfig::new())?,
+    )?;
+    if let Err(err) = save_game_to(state, save) {
+        let _ = fs::remove_file(&staged);
+        return Err(err);
+    }
+    fs::rename(&staged, path)?;
+    Ok(package)
+}
+
+/// Verifies the package at `path` and delivers it into `state`; a package
+/// already recorded in `state` is refused. The caller saves `state`.
+pub fn import_package(state: &mut GameState, path: &Path) -> Result<Delivery> {
+    let signed: SignedPackage = ron::from_str(&fs::read_to_string(path)?)?;
+    if signature(&signed.package)? != signed.signature {
+        bail!("care package signature does not match; the file was altered");
+    }
+    Ok(care_package::unpack(state, &signed.package)?)
+}
@@ -0,0 +1,111 @@
+use anyhow::Result;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Where saves lived before they moved to the data directory.
+pub const LEGACY_SAVE_FILE: &str = "save.ron";
+pub(super) const SAVE_NAME: &str = "save.ron";
+const APP_DIR: &str = "array-of-babel";
+
+/// The platform's per-user data directory for the game, created if missing.
+/// Falls back to the working directory if it can't be resolved or created.
+pub fn data_dir() -> PathBuf {
+    platform_data_home()
+        .map(|home| home.join(APP_DIR))
+        .filter(|dir| fs::create_dir_all(dir).is_ok())
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+pub fn save_file() -> PathBuf {
+    data_dir().join(SAVE_NAME)
+}
+
+/// Where `save` is moved when a new run starts over it; its fleet can be
+/// carried over. `save.ron` becomes `save.prev.ron`.
+pub fn previous_save_for(save: &Path) -> PathBuf {
+    save.with_extension("prev.ron")
+}
+
+/// Where a crash dumps the state it was holding: beside `save`, never over it.
+pub fn crash_save_for(save: &Path) -> PathBuf {
+    let mut name = save.as_os_str().to_owned();
+    name.push(".crash");
+    PathBuf::from(name)
+}
+
+/// `$XDG_DATA_HOME`, or `~/.local/share`.
+#[cfg(all(unix, not(target_os = "macos")))]
+fn platform_data_home() -> Option<PathBuf> {
+    absolute_var("XDG_DATA_HOME")
+        .or_else(|| absolute_var("HOME").map(|home| home.join(".local").join("share")))
+}
+
+/// `~/Library/Application Support`.
+#[cfg(target_os = "macos")]
+fn platform_data_home() -> Option<PathBuf> {
+    absolute_var("HOME").map(|home| home.join("Library").join("Application Support"))
+}
+
+/// `%APPDATA%` (roaming).
+#[cfg(windows)]
+fn platform_data_home() -> Option<PathBuf> {
+    absolute_var("APPDATA")
+}
+
+#[cfg(not(any(unix, windows)))]
+fn platform_data_home() -> Option<PathBuf> {
+    None
+}
+
+/// Relative values are ignored, as the XDG spec asks.
+fn absolute_var(name: &str) -> Option<PathBuf> {
+    env::var_os(name)
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+}
+
+/// Copies a save left in the working directory by an older build to the data
+/// directory, unless one is already there. Returns the destination if it
+/// copied. The old file is left alone so nothing is lost if this goes wrong.
+pub fn migrate_legacy_save() -> Result<Option<PathBuf>> {
+    let target = save_file();
+    let copied = copy_legacy_save(Path::new(LEGACY_SAVE_FILE), &target)?;
+    Ok(copied.then_some(target))
+}
+
+fn copy_legacy_save(legacy: &Path, target: &Path) -> Result<bool> {
+    // Also covers the data directory falling back to `.`, where the two
+    // paths name the same file.
+    if !legacy.is_file() || target.exists() {
+        return Ok(false);
+    }
+    fs::copy(legacy, target)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_save_is_copied_once_and_never_over_a_newer_one() {
+        let root = env::temp_dir().join(format!("array-of-babel-paths-{}", std::process::id()));
+        let data = root.join("data");
+        fs::create_dir_all(&data).expect("creates dirs");
+        let legacy = root.join(SAVE_NAME);
+        let target = data.join(SAVE_NAME);
+        fs::write(&legacy, "old").expect("writes");
+
+        assert!(copy_legacy_save(&legacy, &target).expect("copies"));
+        assert_eq!(fs::read_to_string(&target).expect("reads"), "old");
+        assert!(legacy.exists());
+
+        fs::write(&target, "new").expect("writes");
+        assert!(!copy_legacy_save(&legacy, &target).expect("checks"));
+        assert_eq!(fs::read_to_string(&target).expect("reads"), "new");
+
+        assert!(!copy_legacy_save(&legacy, &legacy).expect("same file"));
+        fs::remove_dir_all(&root).ok();
+    }
+}
@@ -0,0 +1,152 @@
+use super::data_dir;
+use anyhow::Result;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use thiserror::Error;
+
+/// Subdirectory of the data directory holding one directory per profile.
+pub const PROFILES_DIR: &str = "profiles";
+pub const MAX_PROFILE_NAME: usize = 32;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ProfileNameError {
+    #[error("a profile name can't be empty")]
+    Empty,
+    #[error("profile names are at most {MAX_PROFILE_NAME} characters")]
+    TooLong,
+    #[error("profile names may only use letters, digits, spaces, '-' and '_' (found {0:?})")]
+    InvalidChar(char),
+    #[error("profile names can't start or end with a space")]
+    EdgeSpace,
+    #[error("{0:?} is reserved by Windows")]
+    Reserved(String),
+}
+
+/// Checks that `name` is safe as a directory name on every platform.
+pub fn validate_profile_name(name: &str) -> Result<(), ProfileNameError> {
+    if name.is_empty() {
+        return Err(ProfileNameError::Empty);
+    }
+    if name.chars().count() > MAX_PROFILE_NAME {
+        return Err(ProfileNameError::TooLong);
+    }
+    if let Some(bad) = name
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, ' ' | '-' | '_')))
+    {
+        return Err(ProfileNameError::InvalidChar(bad));
+    }
+    if name.starts_with(' ') || name.ends_with(' ') {
+        return Err(ProfileNameError::EdgeSpace);
+    }
+    const RESERVED: [&str; 4] = ["CON", "PRN", "AUX", "NUL"];
+    let upper = name.to_ascii_uppercase();
+    let numbered = ["COM", "LPT"].iter().any(|prefix| {
+        upper
+            .strip_prefix(prefix)
+            .is_some_and(|digit| digit.len() == 1 && digit.as_bytes()[0].is_ascii_digit())
+    });
+    if RESERVED.contains(&upper.as_str()) || numbered {
+        return Err(ProfileNameError::Reserved(name.to_string()));
+    }
+    Ok(())
+}
+
+/// The save for profile `name`, creating its directory if needed.
+pub fn profile_save_file(name: &str) -> Result<PathBuf> {
+    validate_profile_name(name)?;
+    let dir = data_dir().join(PROFILES_DIR).join(name);
+    fs::create_dir_all(&dir)?;
+    Ok(dir.join(super::paths::SAVE_NAME))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileInfo {
+    pub name: String,
+    /// When its save was last written, or the profile created if unsaved.
+    pub modified: SystemTime,
+}
+
+/// Existing profiles, most recently played first.
+pub fn list_profiles() -> Vec<ProfileInfo> {
+    list_profiles_in(&data_dir().join(PROFILES_DIR))
+}
+
+fn list_profiles_in(root: &Path) -> Vec<ProfileInfo> {
+    let Ok(entries) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+    let mut profiles: Vec<ProfileInfo> = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            validate_profile_name(&name).ok()?;
+            let save = entry.path().join(super::paths::SAVE_NAME);
+            let modified = fs::metadata(&save)
+                .or_else(|_| entry.metadata())
+                .and_then(|meta| meta.modified())
+                .unwrap_or(SystemTime::UNIX_EPOCH);
+            Some(ProfileInfo { name, modified })
+        })
+        .collect();
+    profiles.sort_by(|a, b| b.modified.cmp(&a.modified).then(a.name.cmp(&b.name)));
+    profiles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn names_that_are_unsafe_on_some_platform_are_rejected() {
+        assert_eq!(validate_profile_name("Ada_2"), Ok(()));
+        assert_eq!(validate_profile_name("night shift"), Ok(()));
+        assert_eq!(validate_profile_name(""), Err(ProfileNameError::Empty));
+        assert_eq!(
+            validate_profile_name("../ada"),
+            Err(ProfileNameError::InvalidChar('.'))
+        );
+        assert_eq!(
+            validate_profile_name("a/b"),
+            Err(ProfileNameError::InvalidChar('/'))
+        );
+        assert_eq!(
+            validate_profile_name(" ada"),
+            Err(ProfileNameError::EdgeSpace)
+        );
+        assert!(matches!(
+            validate_profile_name("com1"),
+            Err(ProfileNameError::Reserved(_))
+        ));
+        assert_eq!(
+            validate_profile_name(&"x".repeat(MAX_PROFILE_NAME + 1)),
+            Err(ProfileNameError::TooLong)
+        );
+    }
+
+    #[test]
+    fn profiles_list_most_recently_saved_first() {
+        let root =
+            std::env::temp_dir().join(format!("array-of-babel-profiles-{}", std::process::id()));
+        let base = SystemTime::now() - Duration::from_secs(600);
+        for (name, age) in [("older", 300), ("newest", 0), ("oldest", 500)] {
+            let dir = root.join(name);
+            fs::create_dir_all(&dir).expect("creates profile");
+            let save =
+                fs::File::create(dir.join(super::super::paths::SAVE_NAME)).expect("creates save");
+            save.set_modified(base + Duration::from_secs(500 - age))
+                .expect("sets mtime");
+        }
+        fs::create_dir_all(root.join(".hidden")).expect("creates stray dir");
+
+        let names: Vec<String> = list_profiles_in(&root)
+            .into_iter()
+            .map(|profile| profile.name)
+            .collect();
+        fs::remove_dir_all(&root).ok();
+        assert_eq!(names, ["newest", "older", "oldest"]);
+    }
+}
@@ -1,13 +1,133 @@
 use crate::sim::game::GameState;
 use anyhow::Result;
 use ron::ser::PrettyConfig;
-use std::fs;
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
 
-use super::SAVE_FILE;
+use super::checksum;
+use super::compress::{self, gz_path_for};
+use super::meta::{meta_bytes, meta_path_for};
+use super::{crash_save_for, previous_save_for, save_file};
 
 pub fn save_game(state: &GameState) -> Result<()> {
+    save_game_to(state, save_file())
+}
+
+/// Writes the save, then its summary for the load screen. With
+/// `compress_saves` on it goes to `<path>.gz` instead, and whichever form
+/// wasn't written is removed so a stale copy can't shadow it.
+pub fn save_game_to(state: &GameState, path: impl AsRef<Path>) -> Result<()> {
+    let path = path.as_ref();
     let pretty = PrettyConfig::new();
-    let serialized = ron::ser::to_string_pretty(state, pretty)?;
-    fs::write(SAVE_FILE, serialized)?;
+    let serialized = checksum::seal(ron::ser::to_string_pretty(state, pretty)?);
+    let gz = gz_path_for(path);
+    if state.compress_saves {
+        write_atomically(&gz, &compress::compress(&serialized)?)?;
+        remove_if_present(path)?;
+    } else {
+        write_atomically(path, serialized.as_bytes())?;
+        remove_if_present(&gz)?;
+    }
+    write_atomically(&meta_path_for(path), meta_bytes(state)?.as_bytes())
+}
+
+fn remove_if_present(path: &Path) -> Result<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != ErrorKind::NotFound => Err(err.into()),
+        _ => Ok(()),
+    }
+}
+
+/// Best-effort dump of the state a crashed session held, written to the
+/// crash save so a state that broke the game can't replace a good save.
+pub fn write_crash_save(state: &GameState, save: &Path) -> Result<PathBuf> {
+    let path = crash_save_for(save);
+    let serialized = checksum::seal(ron::ser::to_string_pretty(state, PrettyConfig::new())?);
+    write_atomically(&path, serialized.as_bytes())?;
+    Ok(path)
+}
+
+/// Writes `contents` to a sibling temp file, then renames it over `path`, so
+/// a crash mid-write leaves the previous file intact. `fs::rename` replaces
+/// an existing target on both Unix (atomically) and Windows.
+fn write_atomically(path: &Path, contents: &[u8]) -> Result<()> {
+    let temp = temp_path(path);
+    let written = File::create(&temp).and_then(|mut file| {
+        file.write_all(contents)?;
+        file.sync_all()
+    });
+    if let Err(err) = written.and_then(|()| fs::rename(&temp, path)) {
+        let _ = fs::remove_file(&temp);
+        return Err(err.into());
+    }
+    Ok(())
+}
+
+pub(super) fn temp_path(path: &Path) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(".tmp");
+    PathBuf::from(name)
+}
+
+/// Moves the save at `path`, in either form, and its summary aside so the
+/// next launch starts a new run.
+pub fn archive_save(path: &Path) -> Result<()> {
+    let previous = previous_save_for(path);
+    remove_if_present(&previous)?;
+    remove_if_present(&gz_path_for(&previous))?;
+    for (from, to) in [
+        (path.to_path_buf(), previous.clone()),
+        (gz_path_for(path), gz_path_for(&previous)),
+        (meta_path_for(path), meta_path_for(&previous)),
+    ] {
+        match fs::rename(from, to) {
+            Ok(()) => {}
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+    }
     Ok(())
 }
+
+/// Erases the save at `path` in either form, and its summary, for a lost
+/// hardcore run.
+pub fn delete_save(path: &Path) -> Result<()> {
+    remove_if_present(path)?;
+    remove_if_present(&gz_path_for(path))?;
+    remove_if_present(&meta_path_for(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::persist::load_game_from;
+
+    #[test]
+    fn interrupted_write_leaves_the_previous_save_intact() {
+        let path =
+            std::env::temp_dir().join(format!("array-of-babel-atomic-{}.ron", std::process::id()));
+        let path = path.to_str().expect("utf-8 temp path");
+        let mut state = GameState {
+            credits: 321,
+            ..GameState::default()
+        };
+        save_game_to(&state, path).expect("saves");
+
+        // A kill mid-write leaves only a truncated temp file behind.
+        let serialized = fs::read_to_string(path).expect("reads");
+        let temp = temp_path(Path::new(path));
+        fs::write(&temp, &serialized[..serialized.len() / 2]).expect("writes partial");
+        let survived = load_game_from(path).expect("parses").expect("save exists");
+        assert_eq!(survived.credits, 321);
+
+        state.credits = 654;
+        save_game_to(&state, path).expect("saves over the stale temp file");
+        let reloaded = load_game_from(path).expect("parses").expect("save exists");
+        assert_eq!(reloaded.credits, 654);
+        assert!(!temp.exists());
+        fs::remove_file(path).ok();
+        fs::remove_file(meta_path_for(Path::new(path))).ok();
+    }
+}
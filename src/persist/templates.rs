@@ -0,0 +1,23 @@
+use crate::sim::jobs::{DEFAULT_TEMPLATES, JobTemplates};
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::data_dir;
+
+/// Name of the file in the data directory that overrides the job templates.
+pub const JOB_TEMPLATES_FILE: &str = "jobs.ron";
+
+pub fn job_templates_file() -> PathBuf {
+    data_dir().join(JOB_TEMPLATES_FILE)
+}
+
+/// The templates at `path` if that file exists, else the shipped defaults.
+/// A file that is there but malformed is an error, not a silent fallback.
+pub fn load_job_templates(path: &Path) -> Result<JobTemplates> {
+    if !path.is_file() {
+        return Ok(JobTemplates::from_ron(DEFAULT_TEMPLATES)?);
+    }
+    let text = fs::read_to_string(path)?;
+    JobTemplates::from_ron(&text).with_context(|| format!("in {}", path.display()))
+}
@@ -0,0 +1,137 @@
+use crate::sim::ring::RingBuffer;
+use std::cell::RefCell;
+use std::time::Duration;
+
+/// Whether frame timings are recorded; always on in debug builds.
+pub const ENABLED: bool = cfg!(any(debug_assertions, feature = "profiling"));
+
+/// Samples kept per phase for the rolling average.
+pub const SAMPLE_WINDOW: usize = 60;
+
+/// Work measured each frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Input,
+    Update,
+    Header,
+    Processors,
+    Jobs,
+    Systems,
+    Footer,
+    Overlays,
+    Draw,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 9] = [
+        Phase::Input,
+        Phase::Update,
+        Phase::Header,
+        Phase::Processors,
+        Phase::Jobs,
+        Phase::Systems,
+        Phase::Footer,
+        Phase::Overlays,
+        Phase::Draw,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Phase::Input => "input",
+            Phase::Update => "sim update",
+            Phase::Header => "header",
+            Phase::Processors => "processors",
+            Phase::Jobs => "jobs",
+            Phase::Systems => "systems",
+            Phase::Footer => "footer",
+            Phase::Overlays => "overlays",
+            Phase::Draw => "draw (total)",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Rolling per-phase timings. Recording goes through a `RefCell` so nested
+/// phases can be timed from inside a measured closure.
+#[derive(Debug)]
+pub struct Profiler {
+    samples: RefCell<Vec<RingBuffer<Duration>>>,
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self {
+            samples: RefCell::new(
+                Phase::ALL
+                    .iter()
+                    .map(|_| RingBuffer::new(SAMPLE_WINDOW))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl Profiler {
+    #[cfg(any(debug_assertions, feature = "profiling"))]
+    pub fn measure<T>(&self, phase: Phase, work: impl FnOnce() -> T) -> T {
+        let start = std::time::Instant::now();
+        let result = work();
+        self.record(phase, start.elapsed());
+        result
+    }
+
+    #[cfg(not(any(debug_assertions, feature = "profiling")))]
+    #[inline(always)]
+    pub fn measure<T>(&self, _phase: Phase, work: impl FnOnce() -> T) -> T {
+        work()
+    }
+
+    #[cfg(any(debug_assertions, feature = "profiling"))]
+    fn record(&self, phase: Phase, elapsed: Duration) {
+        self.samples.borrow_mut()[phase.index()].push(elapsed);
+    }
+
+    pub fn average(&self, phase: Phase) -> Option<Duration> {
+        let samples = self.samples.borrow();
+        let buffer = &samples[phase.index()];
+        if buffer.is_empty() {
+            return None;
+        }
+        Some(buffer.iter().sum::<Duration>() / buffer.len() as u32)
+    }
+
+    /// Input, update and draw time of the most recent frame.
+    pub fn last_frame(&self) -> Duration {
+        let samples = self.samples.borrow();
+        [Phase::Input, Phase::Update, Phase::Draw]
+            .iter()
+            .filter_map(|phase| samples[phase.index()].iter().next_back())
+            .sum()
+    }
+}
+
+#[cfg(all(test, any(debug_assertions, feature = "profiling")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn averages_roll_over_the_sample_window() {
+        let profiler = Profiler::default();
+        assert_eq!(profiler.average(Phase::Update), None);
+
+        profiler.record(Phase::Update, Duration::from_millis(90));
+        for _ in 0..SAMPLE_WINDOW {
+            profiler.record(Phase::Update, Duration::from_millis(3));
+        }
+        profiler.record(Phase::Draw, Duration::from_millis(5));
+
+        assert_eq!(
+            profiler.average(Phase::Update),
+            Some(Duration::from_millis(3))
+        );
+        assert_eq!(profiler.last_frame(), Duration::from_millis(8));
+    }
+}
@@ -0,0 +1,307 @@
+use super::game::{DAY_DURATION, GameState, STORE_ITEMS, StoreAction, upgrade_cost};
+use super::history::JobRecord;
+use super::jobs::Job;
+use super::processors::ProcessorState;
+use super::tuning::{TUNING, Tuning};
+
+/// Reliability is rolled once per main-loop tick while a unit works.
+const TICK_MS: f64 = 100.0;
+/// Idle share above which daemon firmware is worth suggesting.
+const FIRMWARE_IDLE_THRESHOLD: f64 = 0.4;
+/// Portion of idle time the daemon is expected to fill.
+const FIRMWARE_RECOVERY: f64 = 0.5;
+/// Share of a unit's recent jobs that must be hazardous before hardening is
+/// suggested.
+const HAZARD_DOMINANCE: f64 = 0.5;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recommendation {
+    pub processor_index: usize,
+    pub store_index: usize,
+    pub item_name: &'static str,
+    pub cost: u64,
+    pub payback_days: f64,
+}
+
+/// What a processor has recently been doing, from its job history (or the
+/// fleet's when it has none yet).
+#[derive(Debug)]
+struct Workload {
+    avg_duration_ms: f64,
+    avg_payout: f64,
+    main_tag: String,
+    hazard_tag: Option<String>,
+    hazard_share: f64,
+    idle_share: f64,
+}
+
+impl Workload {
+    fn from_records(records: &[&JobRecord], idle_share: f64) -> Option<Self> {
+        if records.is_empty() {
+            return None;
+        }
+        let count = records.len() as f64;
+        let avg_duration_ms = records.iter().map(|r| r.duration_ms as f64).sum::<f64>() / count;
+        let avg_payout = records.iter().map(|r| r.payout as f64).sum::<f64>() / count;
+        let main_tag = most_common(records.iter().map(|r| r.tag.as_str()))?;
+        let hazardous: Vec<&str> = records
+            .iter()
+            .map(|r| r.tag.as_str())
+            .filter(|tag| TUNING.tag_hazard(tag) > 0.0)
+            .collect();
+        let hazard_share = hazardous.len() as f64 / count;
+        Some(Self {
+            avg_duration_ms: avg_duration_ms.max(1.0),
+            avg_payout,
+            main_tag,
+            hazard_tag: most_common(hazardous.into_iter()),
+            hazard_share,
+            idle_share,
+        })
+    }
+
+    fn jobs_per_day(&self) -> f64 {
+        DAY_DURATION.as_millis() as f64 / self.avg_duration_ms * (1.0 - self.idle_share)
+    }
+}
+
+/// Best-payback upgrade for each processor, cheapest payback first.
+/// `idle_share` reports the fraction of recent time a processor sat idle;
+/// `tuning` is the run's, so items the store would refuse are never picked.
+pub fn recommend(
+    state: &GameState,
+    tuning: &Tuning,
+    idle_share: impl Fn(u64) -> Option<f64>,
+) -> Vec<Recommendation> {
+    let mut picks: Vec<Recommendation> = state
+        .processors
+        .iter()
+        .enumerate()
+        .filter(|(_, processor)| processor.is_functional())
+        .filter_map(|(index, processor)| {
+            let idle = idle_share(processor.id).unwrap_or(0.0).clamp(0.0, 1.0);
+            let own: Vec<&JobRecord> = state
+                .job_history
+                .iter()
+                .filter(|record| record.processor_id == processor.id)
+                .collect();
+            let workload = Workload::from_records(&own, idle).or_else(|| {
+                let fleet: Vec<&JobRecord> = state.job_history.iter().collect();
+                Workload::from_records(&fleet, idle)
+            })?;
+            best_for_processor(index, processor, &workload, tuning)
+        })
+        .collect();
+    picks.sort_by(|a, b| a.payback_days.total_cmp(&b.payback_days));
+    picks
+}
+
+pub fn best(
+    state: &GameState,
+    tuning: &Tuning,
+    idle_share: impl Fn(u64) -> Option<f64>,
+) -> Option<Recommendation> {
+    recommend(state, tuning, idle_share).into_iter().next()
+}
+
+fn best_for_processor(
+    processor_index: usize,
+    processor: &ProcessorState,
+    workload: &Workload,
+    tuning: &Tuning,
+) -> Option<Recommendation> {
+    STORE_ITEMS
+        .iter()
+        .enumerate()
+        .filter_map(|(store_index, item)| {
+            let cost = upgrade_cost(item, processor)?;
+            let daily_benefit = match item.action {
+                StoreAction::UpgradeCooling => cooling_benefit(processor, workload),
+                StoreAction::UpgradeHardening => hardening_benefit(processor, workload),
+                StoreAction::InstallDaemonFirmware
+                    if tuning.daemon_unlockable && !processor.daemon_unlocked() =>
+                {
+                    firmware_benefit(workload)
+                }
+                _ => None,
+            }?;
+            if !daily_benefit.is_finite() || daily_benefit <= 0.0 {
+                return None;
+            }
+            Some(Recommendation {
+                processor_index,
+                store_index,
+                item_name: item.name,
+                cost,
+                payback_days: cost as f64 / daily_benefit,
+            })
+        })
+        .min_by(|a, b| a.payback_days.total_cmp(&b.payback_days))
+}
+
+/// Only when heat, not hazard, is what drags reliability down.
+fn cooling_benefit(processor: &ProcessorState, workload: &Workload) -> Option<f64> {
+    let evaluation = processor.evaluate_job(&tagged(&workload.main_tag), 0, 0.0);
+    let heat_loss =
+        evaluation.heat.max(0.0) * (TUNING.heat_failure_multiplier + processor.total_fragility());
+    if heat_loss <= evaluation.hazard_penalty {
+        return None;
+    }
+    let mut upgraded = processor.clone();
+    upgraded.cooling_level += 1;
+    Some(reliability_benefit(
+        processor,
+        &upgraded,
+        &workload.main_tag,
+        workload,
+    ))
+}
+
+/// Only when hazardous tags dominate the unit's recent work.
+fn hardening_benefit(processor: &ProcessorState, workload: &Workload) -> Option<f64> {
+    if workload.hazard_share < HAZARD_DOMINANCE {
+        return None;
+    }
+    let tag = workload.hazard_tag.as_deref()?;
+    let mut upgraded = processor.clone();
+    upgraded.hardening_level += 1;
+    Some(reliability_benefit(processor, &upgraded, tag, workload))
+}
+
+/// Only for units that idle a lot; the daemon reclaims part of that time.
+fn firmware_benefit(workload: &Workload) -> Option<f64> {
+    if workload.idle_share < FIRMWARE_IDLE_THRESHOLD {
+        return None;
+    }
+    let full_day_jobs = DAY_DURATION.as_millis() as f64 / workload.avg_duration_ms;
+    Some(workload.idle_share * FIRMWARE_RECOVERY * full_day_jobs * workload.avg_payout)
+}
+
+/// Credits per day saved by fewer burnouts, each costing a replacement plus
+/// the lost job.
+fn reliability_benefit(
+    before: &ProcessorState,
+    after: &ProcessorState,
+    tag: &str,
+    workload: &Workload,
+) -> f64 {
+    let job = tagged(tag);
+    let ticks = workload.avg_duration_ms / TICK_MS;
+    let failure = |processor: &ProcessorState| {
+        1.0 - processor.evaluate_job(&job, 0, 0.0).reliability.powf(ticks)
+    };
+    let avoided = (failure(before) - failure(after)).max(0.0);
+    let burnout_cost =
+        before.purchase_cost as f64 * before.replace_cost_ratio + workload.avg_payout;
+    workload.jobs_per_day() * avoided * burnout_cost
+}
+
+fn tagged(tag: &str) -> Job {
+    Job {
+        tag: tag.to_string(),
+        ..Job::default()
+    }
+}
+
+fn most_common<'a>(tags: impl Iterator<Item = &'a str>) -> Option<String> {
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+    for tag in tags {
+        match counts.iter_mut().find(|(seen, _)| *seen == tag) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((tag, 1)),
+        }
+    }
+    counts
+        .into_iter()
+        .max_by_key(|(_, count)| *count)
+        .map(|(tag, _)| tag.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::jobs::GENERAL_TAG;
+    use crate::sim::mutators::Mutator;
+
+    fn record(processor_id: u64, tag: &str) -> JobRecord {
+        JobRecord {
+            processor_id,
+            tag: tag.to_string(),
+            duration_ms: 6_000,
+            quality: 70,
+            payout: 120,
+        }
+    }
+
+    fn state_with(processor: ProcessorState, tags: &[&str]) -> GameState {
+        let mut state = GameState {
+            processors: vec![processor],
+            ..GameState::default()
+        };
+        state.processors[0].id = 1;
+        state.job_history = tags.iter().map(|tag| record(1, tag)).collect();
+        state
+    }
+
+    fn action_of(recommendation: &Recommendation) -> StoreAction {
+        STORE_ITEMS[recommendation.store_index].action
+    }
+
+    #[test]
+    fn hot_unit_gets_cooling() {
+        let mut processor = ProcessorState::starter();
+        processor.heat_output_base = 2.5;
+        processor.daemon_firmware_level = 1;
+        let state = state_with(processor, &[GENERAL_TAG; 6]);
+
+        let pick = best(&state, &TUNING, |_| Some(0.0)).expect("recommendation");
+        assert_eq!(action_of(&pick), StoreAction::UpgradeCooling);
+        assert!(pick.payback_days.is_finite() && pick.payback_days > 0.0);
+    }
+
+    #[test]
+    fn hazard_heavy_unit_gets_hardening() {
+        let mut processor = ProcessorState::starter();
+        processor.heat_output_base = 0.01;
+        processor.daemon_firmware_level = 1;
+        processor.instruction_set.push("RADIATION".to_string());
+        let state = state_with(
+            processor,
+            &["RADIATION", "RADIATION", "RADIATION", GENERAL_TAG],
+        );
+
+        let pick = best(&state, &TUNING, |_| Some(0.0)).expect("recommendation");
+        assert_eq!(action_of(&pick), StoreAction::UpgradeHardening);
+    }
+
+    #[test]
+    fn idle_unit_gets_firmware() {
+        let mut processor = ProcessorState::starter();
+        processor.cooling_level = processor.cooling_cap;
+        let state = state_with(processor, &[GENERAL_TAG; 3]);
+
+        let pick = best(&state, &TUNING, |_| Some(0.8)).expect("recommendation");
+        assert_eq!(action_of(&pick), StoreAction::InstallDaemonFirmware);
+        assert!(best(&state, &TUNING, |_| Some(0.1)).is_none());
+    }
+
+    #[test]
+    fn silent_daemon_never_gets_firmware() {
+        let mut processor = ProcessorState::starter();
+        processor.cooling_level = processor.cooling_cap;
+        let state = state_with(processor, &[GENERAL_TAG; 3]);
+        let tuning = Tuning::with_mutators(&[Mutator::SilentDaemon]);
+
+        assert!(
+            recommend(&state, &tuning, |_| Some(0.9))
+                .iter()
+                .all(|pick| action_of(pick) != StoreAction::InstallDaemonFirmware)
+        );
+    }
+
+    #[test]
+    fn no_history_means_no_advice() {
+        let state = state_with(ProcessorState::starter(), &[]);
+        assert!(recommend(&state, &TUNING, |_| Some(0.9)).is_empty());
+    }
+}
@@ -0,0 +1,353 @@
+use super::game::GameState;
+use super::ledger::Ledger;
+use super::processors::{ProcessorState, ProcessorStatus, STALL_TICK_LIMIT};
+use ron::ser::PrettyConfig;
+use serde::Serialize;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone)]
+pub struct AuditViolation {
+    pub check: &'static str,
+    pub detail: String,
+    pub dump: String,
+}
+
+impl AuditViolation {
+    fn new(check: &'static str, detail: String, entity: &impl Serialize) -> Self {
+        let dump = ron::ser::to_string_pretty(entity, PrettyConfig::new())
+            .unwrap_or_else(|err| format!("<unserializable: {err}>"));
+        Self {
+            check,
+            detail,
+            dump,
+        }
+    }
+
+    pub fn key(&self) -> String {
+        format!("{}: {}", self.check, self.detail)
+    }
+}
+
+pub fn run_all(state: &GameState, ledger: &Ledger, catalog_len: usize) -> Vec<AuditViolation> {
+    let mut violations = Vec::new();
+    violations.extend(check_working_durations(state));
+    violations.extend(check_stalled_work(state));
+    violations.extend(check_storage(state));
+    violations.extend(check_store_purchases(state, catalog_len));
+    violations.extend(check_ledger(state, ledger));
+    violations.extend(check_finite_values(state));
+    violations.extend(check_unique_job_ids(state));
+    violations
+}
+
+pub fn check_working_durations(state: &GameState) -> Vec<AuditViolation> {
+    state
+        .processors
+        .iter()
+        .enumerate()
+        .filter_map(|(index, processor)| match &processor.status {
+            ProcessorStatus::Working(work) if work.total_ms == 0 => Some(AuditViolation::new(
+                "working-duration",
+                format!(
+                    "processor {index} ({}) is working with total_ms 0",
+                    processor.name
+                ),
+                processor,
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Work that has not advanced for a fifth of the watchdog limit; reported
+/// well before the watchdog steps in so the cause can still be inspected.
+pub fn check_stalled_work(state: &GameState) -> Vec<AuditViolation> {
+    state
+        .processors
+        .iter()
+        .enumerate()
+        .filter_map(|(index, processor)| match &processor.status {
+            ProcessorStatus::Working(work) if work.stalled_ticks >= STALL_TICK_LIMIT / 5 => {
+                Some(AuditViolation::new(
+                    "stalled-work",
+                    format!(
+                        "processor {index} ({}) has made no progress for {} ticks",
+                        processor.name, work.stalled_ticks
+                    ),
+                    processor,
+                ))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn check_storage(state: &GameState) -> Vec<AuditViolation> {
+    let storage = &state.storage;
+    if storage.stored > storage.capacity {
+        vec![AuditViolation::new(
+            "storage-capacity",
+            format!(
+                "storage holds {} units above capacity {}",
+                storage.stored, storage.capacity
+            ),
+            storage,
+        )]
+    } else {
+        Vec::new()
+    }
+}
+
+pub fn check_store_purchases(state: &GameState, catalog_len: usize) -> Vec<AuditViolation> {
+    if state.store_purchases.len() != catalog_len {
+        vec![AuditViolation::new(
+            "store-purchases",
+            format!(
+                "store_purchases has {} entries but catalog has {catalog_len}",
+                state.store_purchases.len()
+            ),
+            &state.store_purchases,
+        )]
+    } else {
+        Vec::new()
+    }
+}
+
+pub fn check_ledger(state: &GameState, ledger: &Ledger) -> Vec<AuditViolation> {
+    let mut violations = Vec::new();
+    for (attempted, available) in ledger.underflows() {
+        violations.push(AuditViolation::new(
+            "credit-underflow",
+            format!("debit of {attempted} cr attempted with only {available} cr available"),
+            &state.credits,
+        ));
+    }
+    match ledger.expected_balance() {
+        Some(expected) if expected == state.credits => {}
+        expected => violations.push(AuditViolation::new(
+            "credit-balance",
+            format!(
+                "credits {} do not match ledger balance {expected:?}",
+                state.credits
+            ),
+            &state.credits,
+        )),
+    }
+    violations
+}
+
+pub fn check_finite_values(state: &GameState) -> Vec<AuditViolation> {
+    state
+        .processors
+        .iter()
+        .enumerate()
+        .filter_map(|(index, processor)| {
+            let fields = non_finite_fields(processor);
+            if fields.is_empty() {
+                None
+            } else {
+                Some(AuditViolation::new(
+                    "finite-floats",
+                    format!(
+                        "processor {index} ({}) has non-finite {}",
+                        processor.name,
+                        fields.join(", ")
+                    ),
+                    processor,
+                ))
+            }
+        })
+        .collect()
+}
+
+fn non_finite_fields(processor: &ProcessorState) -> Vec<String> {
+    let scalars = [
+        ("speed", processor.speed),
+        ("reliability_base", processor.reliability_base),
+        ("wear", processor.wear),
+        ("fragility", processor.fragility),
+        ("replace_cost_ratio", processor.replace_cost_ratio),
+        ("power_draw_base", processor.power_draw_base),
+        ("heat_output_base", processor.heat_output_base),
+        (
+            "daemon_penalty.time_multiplier",
+            processor.daemon_penalty.time_multiplier,
+        ),
+        ("last_reliability", processor.last_reliability),
+        ("last_heat", processor.last_heat),
+        ("last_power_draw", processor.last_power_draw),
+        ("energy_today", processor.energy_today),
+    ];
+    let mut fields: Vec<String> = scalars
+        .iter()
+        .filter(|(_, value)| !value.is_finite())
+        .map(|(name, _)| name.to_string())
+        .collect();
+    for (tag, value) in &processor.power_draw_mod {
+        if !value.is_finite() {
+            fields.push(format!("power_draw_mod[{tag}]"));
+        }
+    }
+    for (tag, value) in &processor.daemon_affinity {
+        if !value.is_finite() {
+            fields.push(format!("daemon_affinity[{tag}]"));
+        }
+    }
+    fields.sort();
+    fields
+}
+
+pub fn check_unique_job_ids(state: &GameState) -> Vec<AuditViolation> {
+    let working = state
+        .processors
+        .iter()
+        .flat_map(|processor| processor.slots().map(|work| &work.job));
+    let mut seen = HashSet::new();
+    let mut violations = Vec::new();
+    let scheduled = state.scheduled_jobs.iter().map(|entry| &entry.job);
+    for job in state
+        .jobs
+        .iter()
+        .chain(&state.pending_jobs)
+        .chain(working)
+        .chain(scheduled)
+    {
+        if !seen.insert(job.id) {
+            violations.push(AuditViolation::new(
+                "unique-job-ids",
+                format!("job id {} ({}) appears more than once", job.id, job.name),
+                job,
+            ));
+        }
+    }
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::jobs::{GENERAL_TAG, Job};
+    use crate::sim::processors::ProcessorWork;
+
+    fn job(id: u64) -> Job {
+        Job {
+            id,
+            name: format!("Audit Job #{id}"),
+            tag: GENERAL_TAG.to_string(),
+            base_time_ms: 5_000,
+            base_reward: 100,
+            quality_target: 60,
+            data_output: 10,
+            ..Job::default()
+        }
+    }
+
+    #[test]
+    fn healthy_state_passes_every_check() {
+        let state = GameState {
+            pending_jobs: vec![job(1)],
+            ..GameState::default()
+        };
+        let ledger = Ledger::new(state.credits);
+        let violations = run_all(&state, &ledger, state.store_purchases.len());
+        assert!(violations.is_empty(), "{violations:?}");
+    }
+
+    #[test]
+    fn working_with_zero_duration_is_flagged() {
+        let mut state = GameState::default();
+        state.processors[0].status = ProcessorStatus::Working(Box::new(ProcessorWork {
+            job: job(1),
+            remaining_ms: 0,
+            total_ms: 0,
+            daemon_penalty: None,
+            overheating: false,
+            paused: false,
+            stalled_ticks: 0,
+            script_step: 0,
+            heat_spike: 0.0,
+            throttle: 0.0,
+            strained_ticks: 0,
+            throttle_warned: false,
+        }));
+        let violations = check_working_durations(&state);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].dump.contains("Model F12-Scalar"));
+    }
+
+    #[test]
+    fn stalled_work_is_flagged() {
+        let mut state = GameState::default();
+        state.processors[0].status = ProcessorStatus::Working(Box::new(ProcessorWork {
+            job: job(1),
+            remaining_ms: 5_000,
+            total_ms: 5_000,
+            daemon_penalty: None,
+            overheating: false,
+            paused: false,
+            stalled_ticks: STALL_TICK_LIMIT / 5,
+            script_step: 0,
+            heat_spike: 0.0,
+            throttle: 0.0,
+            strained_ticks: 0,
+            throttle_warned: false,
+        }));
+        let violations = check_stalled_work(&state);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].check, "stalled-work");
+    }
+
+    #[test]
+    fn overfull_storage_is_flagged() {
+        let mut state = GameState::default();
+        state.storage.stored = state.storage.capacity + 1;
+        assert_eq!(check_storage(&state).len(), 1);
+    }
+
+    #[test]
+    fn store_purchase_length_mismatch_is_flagged() {
+        let mut state = GameState::default();
+        let catalog_len = state.store_purchases.len();
+        state.store_purchases.pop();
+        assert_eq!(check_store_purchases(&state, catalog_len).len(), 1);
+    }
+
+    #[test]
+    fn ledger_underflow_and_drift_are_flagged() {
+        let mut state = GameState::default();
+        let mut ledger = Ledger::new(state.credits);
+        ledger.record_underflow(500, state.credits);
+        let violations = check_ledger(&state, &ledger);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].check, "credit-underflow");
+
+        let ledger = Ledger::new(state.credits);
+        state.credits += 10;
+        let violations = check_ledger(&state, &ledger);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].check, "credit-balance");
+    }
+
+    #[test]
+    fn non_finite_processor_values_are_flagged() {
+        let mut state = GameState::default();
+        state.processors[0].speed = f64::NAN;
+        state.processors[0]
+            .daemon_affinity
+            .insert(GENERAL_TAG.to_string(), f64::INFINITY);
+        let violations = check_finite_values(&state);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].detail.contains("speed"));
+        assert!(violations[0].detail.contains("daemon_affinity[GENERAL]"));
+    }
+
+    #[test]
+    fn duplicate_job_ids_across_board_and_pending_are_flagged() {
+        let mut state = GameState::default();
+        state.jobs.push(job(7));
+        state.pending_jobs.push(job(7));
+        let violations = check_unique_job_ids(&state);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].detail.contains("job id 7"));
+    }
+}
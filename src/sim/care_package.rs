@@ -0,0 +1,186 @@
+use super::game::{DAY_DURATION, GameState, STORE_ITEMS, StoreAction};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+pub const MAX_PACKAGE_CREDITS: u64 = 200;
+/// Thermal paste doses a package may carry.
+pub const MAX_PACKAGE_PASTE: u32 = 3;
+/// Share of a package lost on the way to the other save.
+pub const EXCHANGE_PENALTY: f64 = 0.5;
+
+/// Credits and consumables sent from one save to another.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CarePackage {
+    pub id: u64,
+    pub from_run_seed: u64,
+    pub credits: u64,
+    pub thermal_paste: u32,
+}
+
+/// What actually arrives once the exchange penalty is taken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Delivery {
+    pub credits: u64,
+    pub thermal_paste: u32,
+}
+
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq)]
+pub enum PackageError {
+    #[error("a package holds at most {MAX_PACKAGE_CREDITS} cr")]
+    TooManyCredits,
+    #[error("a package holds at most {MAX_PACKAGE_PASTE} consumables")]
+    TooManyConsumables,
+    #[error("the package is empty")]
+    Empty,
+    #[error("packing costs {needed} cr but only {available} cr are available")]
+    InsufficientCredits { needed: u64, available: u64 },
+    #[error("package {0} was already imported")]
+    AlreadyImported(u64),
+    #[error("package {0} came from this save")]
+    OwnPackage(u64),
+}
+
+/// Paste is bought at its base store price when packed.
+fn paste_price() -> u64 {
+    STORE_ITEMS
+        .iter()
+        .find(|item| item.action == StoreAction::ApplyThermalPaste)
+        .map(|item| item.base_cost)
+        .unwrap_or(0)
+}
+
+/// Debits `credits` plus the price of `thermal_paste` doses from `state`
+/// and records the package as sent.
+pub fn pack(
+    state: &mut GameState,
+    id: u64,
+    credits: u64,
+    thermal_paste: u32,
+) -> Result<CarePackage, PackageError> {
+    if credits > MAX_PACKAGE_CREDITS {
+        return Err(PackageError::TooManyCredits);
+    }
+    if thermal_paste > MAX_PACKAGE_PASTE {
+        return Err(PackageError::TooManyConsumables);
+    }
+    if credits == 0 && thermal_paste == 0 {
+        return Err(PackageError::Empty);
+    }
+    let needed = credits + paste_price() * thermal_paste as u64;
+    if needed > state.credits {
+        return Err(PackageError::InsufficientCredits {
+            needed,
+            available: state.credits,
+        });
+    }
+    state.credits -= needed;
+    state.packages_sent.push(id);
+    Ok(CarePackage {
+        id,
+        from_run_seed: state.run_seed,
+        credits,
+        thermal_paste,
+    })
+}
+
+/// Credits and paste doses of the fullest package `available` credits pay
+/// for: credits first, then as many doses as the rest covers.
+pub fn fullest(available: u64) -> (u64, u32) {
+    let credits = available.min(MAX_PACKAGE_CREDITS);
+    let paste = (available - credits)
+        .checked_div(paste_price())
+        .unwrap_or(0)
+        .min(MAX_PACKAGE_PASTE as u64) as u32;
+    (credits, paste)
+}
+
+/// What `package` is worth after the exchange penalty.
+pub fn delivery(package: &CarePackage) -> Delivery {
+    let kept = 1.0 - EXCHANGE_PENALTY;
+    Delivery {
+        credits: (package.credits as f64 * kept).floor() as u64,
+        thermal_paste: (package.thermal_paste as f64 * kept).floor() as u32,
+    }
+}
+
+/// Credits the delivery to `state` once per package id; each paste dose
+/// extends the cooling bonus by a day.
+pub fn unpack(state: &mut GameState, package: &CarePackage) -> Result<Delivery, PackageError> {
+    if state.packages_received.contains(&package.id) {
+        return Err(PackageError::AlreadyImported(package.id));
+    }
+    if state.packages_sent.contains(&package.id) {
+        return Err(PackageError::OwnPackage(package.id));
+    }
+    let delivery = delivery(package);
+    state.credits += delivery.credits;
+    state.thermal_paste_timer_ms += DAY_DURATION.as_millis() as u64 * delivery.thermal_paste as u64;
+    state.packages_received.push(package.id);
+    Ok(delivery)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn penalty_halves_credits_and_rounds_consumables_down() {
+        let mut sender = GameState {
+            credits: 500,
+            ..GameState::default()
+        };
+        let package = pack(&mut sender, 7, 200, 3).expect("packs");
+        assert_eq!(sender.credits, 500 - 200 - 3 * paste_price());
+        assert_eq!(
+            delivery(&package),
+            Delivery {
+                credits: 100,
+                thermal_paste: 1
+            }
+        );
+        assert_eq!(
+            pack(&mut sender, 8, 201, 0),
+            Err(PackageError::TooManyCredits)
+        );
+    }
+
+    #[test]
+    fn the_fullest_package_takes_credits_before_paste() {
+        assert_eq!(fullest(150), (150, 0));
+        assert_eq!(fullest(200 + paste_price() * 2), (200, 2));
+        assert_eq!(fullest(10_000), (MAX_PACKAGE_CREDITS, MAX_PACKAGE_PASTE));
+        let (credits, paste) = fullest(10_000);
+        let mut sender = GameState {
+            credits: 10_000,
+            ..GameState::default()
+        };
+        assert!(pack(&mut sender, 9, credits, paste).is_ok());
+    }
+
+    #[test]
+    fn a_package_is_imported_only_once() {
+        let mut sender = GameState {
+            credits: 500,
+            ..GameState::default()
+        };
+        let package = pack(&mut sender, 7, 150, 2).expect("packs");
+        let mut receiver = GameState::default();
+        let before = receiver.credits;
+
+        let delivery = unpack(&mut receiver, &package).expect("imports");
+        assert_eq!(receiver.credits, before + 75);
+        assert_eq!(
+            receiver.thermal_paste_timer_ms,
+            DAY_DURATION.as_millis() as u64 * delivery.thermal_paste as u64
+        );
+        assert_eq!(
+            unpack(&mut receiver, &package),
+            Err(PackageError::AlreadyImported(7))
+        );
+        assert_eq!(receiver.credits, before + 75);
+        assert_eq!(
+            unpack(&mut sender, &package),
+            Err(PackageError::OwnPackage(7))
+        );
+    }
+}
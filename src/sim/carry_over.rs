@@ -0,0 +1,117 @@
+use super::game::GameState;
+use super::processors::{DaemonMode, ProcessorState, ProcessorStatus};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Share of the loadout's starting credits kept when a unit is carried over.
+pub const IMPORT_CREDIT_RATIO: f64 = 0.5;
+
+/// Where an imported processor came from, kept for statistics.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportRecord {
+    pub name: String,
+    pub from_run_seed: u64,
+    pub from_day: u64,
+    pub jobs_completed: u64,
+}
+
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq)]
+pub enum ImportError {
+    #[error("the previous run was a sandbox; its fleet cannot be carried over")]
+    Sandbox,
+    #[error("the previous run has no processors to carry over")]
+    EmptyFleet,
+}
+
+/// Processors of a finished run that a new game may carry over.
+pub fn importable(previous: &GameState) -> Result<Vec<ProcessorState>, ImportError> {
+    if previous.sandbox {
+        return Err(ImportError::Sandbox);
+    }
+    if previous.processors.is_empty() {
+        return Err(ImportError::EmptyFleet);
+    }
+    Ok(previous.processors.clone())
+}
+
+/// Splices `processor` into a fresh run: transient state is reset, upgrades
+/// and experience are kept, and starting credits are cut.
+pub fn import(state: &mut GameState, mut processor: ProcessorState, source: &GameState) {
+    let record = ImportRecord {
+        name: processor.name.clone(),
+        from_run_seed: source.run_seed,
+        from_day: source.statistics.days_elapsed,
+        jobs_completed: processor.jobs_completed,
+    };
+    processor.status = ProcessorStatus::Idle;
+    processor.daemon_mode = DaemonMode::Off;
+    processor.daemon_firmware_level = 0;
+    processor.daemon_trial = false;
+    processor.warranty_days_remaining = 0;
+    processor.insured = false;
+    processor.energy_today = 0.0;
+    processor.heat_seconds_today = 0.0;
+    processor.id = 0;
+    processor.ensure_runtime_defaults();
+    state.processors.push(processor);
+    state.credits = (state.credits as f64 * IMPORT_CREDIT_RATIO).round() as u64;
+    state.statistics.imports.push(record);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::jobs::Job;
+
+    fn veteran_run() -> GameState {
+        let mut previous = GameState {
+            run_seed: 99,
+            ..GameState::default()
+        };
+        previous.statistics.days_elapsed = 14;
+        let unit = &mut previous.processors[0];
+        unit.cooling_level = 2;
+        unit.jobs_completed = 40;
+        unit.energy_today = 3.0;
+        unit.daemon_mode = DaemonMode::Auto;
+        unit.assign(Job::default(), 5_000, None);
+        previous
+    }
+
+    #[test]
+    fn import_keeps_upgrades_and_resets_transient_state() {
+        let previous = veteran_run();
+        let unit = importable(&previous).expect("importable")[0].clone();
+        let mut state = GameState::default();
+        let credits = state.credits;
+
+        import(&mut state, unit, &previous);
+
+        let imported = state.processors.last().expect("spliced");
+        assert_eq!(state.processors.len(), 2);
+        assert!(imported.is_idle());
+        assert_eq!(imported.cooling_level, 2);
+        assert_eq!(imported.jobs_completed, 40);
+        assert_eq!(imported.energy_today, 0.0);
+        assert_eq!(imported.daemon_mode, DaemonMode::Off);
+        assert_eq!(state.credits, credits / 2);
+        assert_eq!(
+            state.statistics.imports,
+            vec![ImportRecord {
+                name: imported.name.clone(),
+                from_run_seed: 99,
+                from_day: 14,
+                jobs_completed: 40,
+            }]
+        );
+    }
+
+    #[test]
+    fn sandbox_runs_cannot_be_imported() {
+        let previous = GameState {
+            sandbox: true,
+            ..veteran_run()
+        };
+        assert!(matches!(importable(&previous), Err(ImportError::Sandbox)));
+    }
+}
@@ -0,0 +1,164 @@
+//! Front-end independent actions on a running game, used by the control
+//! socket so external tools drive the same code paths as the keyboard.
+
+use super::game::Game;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum Command {
+    Summary,
+    ListJobs,
+    /// Moves the board job with `job_id` onto the processor at `processor`.
+    Assign {
+        job_id: u64,
+        processor: usize,
+    },
+    /// Buys store item `item`, targeting `processor` for per-unit upgrades.
+    Purchase {
+        item: usize,
+        #[serde(default)]
+        processor: Option<usize>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum Response {
+    Summary(StateSummary),
+    Jobs { jobs: Vec<JobSummary> },
+    Done { message: String },
+    Error { error: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateSummary {
+    pub credits: u64,
+    pub day: u64,
+    pub processors: usize,
+    pub working: usize,
+    pub jobs: usize,
+    pub pending: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobSummary {
+    pub id: u64,
+    pub name: String,
+    pub tag: String,
+    pub base_reward: u64,
+    pub base_time_ms: u64,
+}
+
+impl Game {
+    pub fn execute(&mut self, command: Command) -> Response {
+        match command {
+            Command::Summary => Response::Summary(StateSummary {
+                credits: self.state.credits,
+                day: self.state.statistics.days_elapsed,
+                processors: self.state.processors.len(),
+                working: self
+                    .state
+                    .processors
+                    .iter()
+                    .filter(|processor| {
+                        !matches!(
+                            processor.status,
+                            crate::sim::processors::ProcessorStatus::Idle
+                        )
+                    })
+                    .count(),
+                jobs: self.state.jobs.len(),
+                pending: self
+                    .state
+                    .pending_jobs
+                    .iter()
+                    .map(|job| job.name.clone())
+                    .collect(),
+            }),
+            Command::ListJobs => Response::Jobs {
+                jobs: self
+                    .state
+                    .jobs
+                    .iter()
+                    .map(|job| JobSummary {
+                        id: job.id,
+                        name: job.name.clone(),
+                        tag: job.tag.clone(),
+                        base_reward: job.base_reward,
+                        base_time_ms: job.base_time_ms,
+                    })
+                    .collect(),
+            },
+            Command::Assign { job_id, processor } => {
+                let Some(job_index) = self.state.jobs.iter().position(|job| job.id == job_id)
+                else {
+                    return Response::Error {
+                        error: format!("job {job_id} is not on the board"),
+                    };
+                };
+                let name = self.state.jobs[job_index].name.clone();
+                match self.assign_board_job(job_index, processor) {
+                    Ok(()) => Response::Done {
+                        message: format!("assigned {name} to processor {processor}"),
+                    },
+                    Err(err) => Response::Error {
+                        error: err.to_string(),
+                    },
+                }
+            }
+            Command::Purchase { item, processor } => {
+                let name = self
+                    .store_items()
+                    .get(item)
+                    .map(|item| item.name)
+                    .unwrap_or("item");
+                match self.purchase_item(item, processor) {
+                    Ok(()) => Response::Done {
+                        message: format!("purchased {name}"),
+                    },
+                    Err(err) => Response::Error {
+                        error: err.to_string(),
+                    },
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::jobs::{GENERAL_TAG, Job};
+
+    #[test]
+    fn assign_moves_a_board_job_by_id() {
+        let mut game = Game::fresh();
+        game.state.jobs = vec![Job {
+            id: 42,
+            name: "Index Sweep".to_string(),
+            tag: GENERAL_TAG.to_string(),
+            base_time_ms: 5_000,
+            base_reward: 60,
+            quality_target: 60,
+            ..Job::default()
+        }];
+
+        let missing = game.execute(Command::Assign {
+            job_id: 7,
+            processor: 0,
+        });
+        assert!(matches!(missing, Response::Error { .. }));
+
+        let done = game.execute(Command::Assign {
+            job_id: 42,
+            processor: 0,
+        });
+        assert!(matches!(done, Response::Done { .. }), "{done:?}");
+        assert!(game.state.jobs.is_empty());
+        let Response::Summary(summary) = game.execute(Command::Summary) else {
+            panic!("summary expected");
+        };
+        assert_eq!(summary.working, 1);
+    }
+}
@@ -0,0 +1,87 @@
+use super::jobs::{self, Job};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// Jobs in a contract, run one after another.
+pub const CONTRACT_STAGES: u8 = 3;
+/// Chance a job spawn opens a contract instead, while there is room for one.
+pub const CONTRACT_CHANCE: f64 = 0.08;
+/// Contracts that may run at once.
+pub const MAX_ACTIVE_CONTRACTS: usize = 1;
+/// Each stage runs this much longer and pays this much more than the last,
+/// starting from a plain posting.
+const STAGE_SCALE: f64 = 1.25;
+/// The completion bonus, as a multiple of the first stage's reward.
+const COMPLETION_BONUS_MULTIPLIER: f64 = 3.0;
+
+/// A contract in progress. Exactly one of its stages is on the board,
+/// pending, or running at a time.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractState {
+    pub id: u64,
+    pub tag: String,
+    /// The stage currently out, counting from 1.
+    pub stage: u8,
+    pub stages: u8,
+    /// Paid when the final stage completes.
+    pub bonus: u64,
+}
+
+impl ContractState {
+    pub fn new(id: u64, tag: &str) -> Self {
+        Self {
+            id,
+            tag: tag.to_string(),
+            stage: 1,
+            stages: CONTRACT_STAGES,
+            bonus: 0,
+        }
+    }
+
+    pub fn is_final_stage(&self) -> bool {
+        self.stage >= self.stages
+    }
+
+    /// The job for the current stage, scaled up from a plain posting.
+    pub fn stage_job(&self, id: u64, reputation: i32, rng: &mut impl Rng) -> Job {
+        let mut job = jobs::generate_job_with_tag(id, &self.tag, reputation, rng);
+        let scale = STAGE_SCALE.powi(self.stage as i32);
+        job.name = format!("Contract #{} Stage {}/{}", self.id, self.stage, self.stages);
+        job.base_time_ms = (job.base_time_ms as f64 * scale).round() as u64;
+        job.base_reward = (job.base_reward as f64 * scale).round() as u64;
+        job.data_output = (job.data_output as f64 * scale).round() as u64;
+        job.contract_id = Some(self.id);
+        job.stage = self.stage;
+        job.expires_in_ms = Some(job.expiry_window_ms());
+        job
+    }
+}
+
+/// Completion bonus for a contract whose first stage pays `first_reward`.
+pub fn completion_bonus(first_reward: u64) -> u64 {
+    (first_reward as f64 * COMPLETION_BONUS_MULTIPLIER).round() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn later_stages_run_longer_and_pay_more() {
+        let mut contract = ContractState::new(4, jobs::GENERAL_TAG);
+        let first = contract.stage_job(10, 0, &mut StdRng::seed_from_u64(1));
+        contract.stage = 3;
+        let last = contract.stage_job(11, 0, &mut StdRng::seed_from_u64(1));
+
+        assert_eq!(first.name, "Contract #4 Stage 1/3");
+        assert_eq!((first.contract_id, first.stage), (Some(4), 1));
+        assert_eq!((last.contract_id, last.stage), (Some(4), 3));
+        assert!(last.base_time_ms > first.base_time_ms);
+        assert!(last.base_reward > first.base_reward);
+        assert_eq!(last.expires_in_ms, Some(last.expiry_window_ms()));
+        assert!(contract.is_final_stage());
+        assert_eq!(completion_bonus(first.base_reward), first.base_reward * 3);
+    }
+}
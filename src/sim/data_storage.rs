@@ -4,6 +4,9 @@ use serde::{Deserialize, Serialize};
 pub struct DataStorage {
     pub capacity: u64,
     pub stored: u64,
+    /// Space held for running jobs; counts against free capacity.
+    #[serde(default)]
+    pub reserved: u64,
 }
 
 impl DataStorage {
@@ -11,6 +14,7 @@ impl DataStorage {
         Self {
             capacity,
             stored: 0,
+            reserved: 0,
         }
     }
 
@@ -22,7 +26,22 @@ impl DataStorage {
     }
 
     pub fn free_capacity(&self) -> u64 {
-        self.capacity.saturating_sub(self.stored)
+        self.capacity
+            .saturating_sub(self.stored)
+            .saturating_sub(self.reserved)
+    }
+
+    /// Holds `amount` of free space, or nothing if there isn't that much.
+    pub fn reserve(&mut self, amount: u64) -> bool {
+        if amount > self.free_capacity() {
+            return false;
+        }
+        self.reserved += amount;
+        true
+    }
+
+    pub fn release(&mut self, amount: u64) {
+        self.reserved = self.reserved.saturating_sub(amount);
     }
 
     pub fn expand(&mut self, extra: u64) {
@@ -1,8 +1,97 @@
 use crate::sim::jobs::Job;
-use crate::sim::processors::{DaemonPenalty, ProcessorState};
+use crate::sim::processors::{DaemonPenalty, ProcessorState, STANDBY_SPIN_UP_MS};
+use crate::sim::tuning::TUNING;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 
 pub const ELECTRICITY_RATE: f64 = 4.0;
+/// Daily premium of an insurance contract, as a share of the unit's price.
+pub const INSURANCE_PREMIUM_RATIO: f64 = 0.04;
+/// Shares of the replacement bill and of the lost job's reward paid out
+/// when a covered unit burns out or is destroyed.
+pub const INSURANCE_REPLACEMENT_SHARE: f64 = 0.6;
+pub const INSURANCE_REWARD_SHARE: f64 = 0.5;
+
+/// How discretionary spending treats the credits held back for the next
+/// daily bill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ReserveMode {
+    #[default]
+    Off,
+    Warn,
+    Enforce,
+}
+
+impl ReserveMode {
+    pub fn next(self) -> Self {
+        match self {
+            ReserveMode::Off => ReserveMode::Warn,
+            ReserveMode::Warn => ReserveMode::Enforce,
+            ReserveMode::Enforce => ReserveMode::Off,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ReserveMode::Off => "off",
+            ReserveMode::Warn => "warn",
+            ReserveMode::Enforce => "enforce",
+        }
+    }
+}
+
+/// Default panel capacity before any Panel Upgrade, in kWh of instantaneous draw.
+pub const BASE_POWER_BUDGET: f64 = 12.0;
+/// Capacity added by each Panel Upgrade.
+pub const PANEL_UPGRADE_STEP: f64 = 6.0;
+
+/// Facility panel limit; when enabled, draw above `capacity` trips a breaker.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PowerBudget {
+    pub enabled: bool,
+    pub capacity: f64,
+    pub trips: u64,
+}
+
+impl Default for PowerBudget {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            capacity: BASE_POWER_BUDGET,
+            trips: 0,
+        }
+    }
+}
+
+impl PowerBudget {
+    /// True when `draw` fits under the panel, or the budget is off.
+    pub fn allows(&self, draw: f64) -> bool {
+        !self.enabled || draw <= self.capacity
+    }
+}
+
+/// Jobs paying at least this much are contracts that require a deposit.
+pub const ESCROW_REWARD_THRESHOLD: u64 = 160;
+/// Share of the base reward escrowed when a contract is accepted.
+pub const ESCROW_RATIO: f64 = 0.2;
+
+/// Deposit owed to accept `job`: follow-up chains and high-value jobs are
+/// contracts; everything else is free to take.
+pub fn escrow_deposit(job: &Job) -> u64 {
+    if job.is_utility() {
+        return 0;
+    }
+    if job.follow_up_of.is_none() && job.base_reward < ESCROW_REWARD_THRESHOLD {
+        return 0;
+    }
+    (job.base_reward as f64 * ESCROW_RATIO).round() as u64
+}
+
+/// True when spending `cost` would leave less than `reserved` credits.
+pub fn breaches_reserve(credits: u64, cost: u64, reserved: u64) -> bool {
+    reserved > 0 && cost > 0 && credits.saturating_sub(cost) < reserved
+}
 
 pub fn assignment_duration_ms(
     job: &Job,
@@ -10,42 +99,143 @@ pub fn assignment_duration_ms(
     penalty: Option<&DaemonPenalty>,
 ) -> u64 {
     let base = job.base_time_ms as f64;
-    let mut duration = base / processor.speed.max(0.1);
+    let speed = processor.effective_speed() * processor.clock.speed();
+    let mut duration = base / speed.max(0.1);
     if let Some(penalty) = penalty {
         duration *= penalty.time_multiplier.max(0.0);
     }
-    duration.round().max(1.0) as u64
+    // A corrupt speed or multiplier must not yield a job that never ends.
+    if !duration.is_finite() {
+        duration = base;
+    }
+    let spin_up = if processor.in_standby() {
+        STANDBY_SPIN_UP_MS
+    } else {
+        0
+    };
+    duration.round().max(1.0) as u64 + spin_up
 }
 
+/// Rolls delivered quality. `fleet_bonus` is whole quality points added after
+/// the penalty, so even a single point survives to the result.
 pub fn roll_quality(
     job: &Job,
     processor: &ProcessorState,
     penalty: Option<&DaemonPenalty>,
+    fleet_bonus: u8,
     rng: &mut impl Rng,
 ) -> u8 {
-    let noise: i8 = rng.gen_range(-4..=4);
+    let noise: i8 = rng.gen_range(-TUNING.quality_noise..=TUNING.quality_noise);
     let mut quality = job.quality_target as i16 + processor.quality_bias as i16 + noise as i16;
     if let Some(penalty) = penalty {
         quality += penalty.quality as i16;
     }
+    quality += fleet_bonus as i16;
     quality.clamp(0, 100) as u8
 }
 
-pub fn payout_for_quality(job: &Job, quality: u8) -> u64 {
-    let factor = 0.7 + (quality as f64 / 100.0) * 0.5;
+/// Credits paid for `job` delivered at `quality`. `bonus` is a fractional
+/// multiplier supplied by the caller (e.g. 0.15 for +15%).
+pub fn payout_for_quality(job: &Job, quality: u8, bonus: f64) -> u64 {
+    let factor = (0.7 + (quality as f64 / 100.0) * 0.5) * (1.0 + bonus.max(0.0));
     ((job.base_reward as f64) * factor).round() as u64
 }
 
+/// Expected cost of letting the daemon run a job instead of assigning it by
+/// hand: credits lost to the quality penalty and extra wall time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DaemonTax {
+    pub credits: u64,
+    pub extra_ms: u64,
+}
+
+/// Quality before noise, which averages out to zero.
+fn expected_quality(job: &Job, processor: &ProcessorState, penalty: Option<&DaemonPenalty>) -> u8 {
+    let mut quality = job.quality_target as i16 + processor.quality_bias as i16;
+    if let Some(penalty) = penalty {
+        quality += penalty.quality as i16;
+    }
+    quality.clamp(0, 100) as u8
+}
+
+/// Compares a manual assignment against a daemon one using expected quality
+/// and duration. `applied` is the penalty a running job was assigned under;
+/// without one, the processor's current penalty is priced.
+pub fn daemon_tax_estimate(
+    job: &Job,
+    processor: &ProcessorState,
+    applied: Option<&DaemonPenalty>,
+) -> DaemonTax {
+    let penalty = applied.unwrap_or(&processor.daemon_penalty);
+    let manual_payout = payout_for_quality(job, expected_quality(job, processor, None), 0.0);
+    let daemon_payout =
+        payout_for_quality(job, expected_quality(job, processor, Some(penalty)), 0.0);
+    let manual_ms = assignment_duration_ms(job, processor, None);
+    let daemon_ms = assignment_duration_ms(job, processor, Some(penalty));
+    DaemonTax {
+        credits: manual_payout.saturating_sub(daemon_payout),
+        extra_ms: daemon_ms.saturating_sub(manual_ms),
+    }
+}
+
+/// Unit upkeep, insurance premiums, and the surcharge of any job that
+/// carries one.
 pub fn upkeep_total(processors: &[ProcessorState]) -> u64 {
-    processors.iter().map(|p| p.upkeep_cost).sum()
+    processors
+        .iter()
+        .map(|p| {
+            p.upkeep_cost
+                + insurance_premium(p)
+                + p.slots().map(|work| work.job.upkeep).sum::<u64>()
+        })
+        .sum()
+}
+
+/// What covering the unit costs per day, whether or not it is covered yet.
+pub fn insurance_quote(processor: &ProcessorState) -> u64 {
+    ((processor.purchase_cost as f64 * INSURANCE_PREMIUM_RATIO).round() as u64).max(1)
+}
+
+/// The unit's daily premium; zero when it is not insured.
+pub fn insurance_premium(processor: &ProcessorState) -> u64 {
+    if processor.insured {
+        insurance_quote(processor)
+    } else {
+        0
+    }
+}
+
+/// Claim paid on a covered loss: shares of the replacement bill and of the
+/// reward of the job that went down with the unit.
+pub fn insurance_payout(replacement_cost: u64, job_reward: u64) -> u64 {
+    (replacement_cost as f64 * INSURANCE_REPLACEMENT_SHARE
+        + job_reward as f64 * INSURANCE_REWARD_SHARE)
+        .round() as u64
 }
 
-pub fn electricity_cost(processors: &[ProcessorState]) -> u64 {
-    let draw: f64 = processors
+/// One unit's share of the bill: the energy it metered so far plus its
+/// current draw held for the `day_remaining` fraction of the day. Bills are
+/// summed from these so a breakdown always adds up to the total.
+pub fn unit_electricity_cost(processor: &ProcessorState, rate: f64, day_remaining: f64) -> u64 {
+    let energy = processor.energy_today + processor.last_power_draw() * day_remaining.max(0.0);
+    (energy * rate).round().max(0.0) as u64
+}
+
+/// Bills the energy each unit metered over the day.
+pub fn electricity_cost(processors: &[ProcessorState], rate: f64) -> u64 {
+    projected_electricity_cost(processors, rate, 0.0)
+}
+
+/// Metered energy so far plus the current draw held until the day closes.
+pub fn projected_electricity_cost(
+    processors: &[ProcessorState],
+    rate: f64,
+    day_remaining: f64,
+) -> u64 {
+    processors
         .iter()
-        .map(|processor| processor.last_power_draw())
-        .sum();
-    (draw * ELECTRICITY_RATE).round().max(0.0) as u64
+        .map(|processor| unit_electricity_cost(processor, rate, day_remaining))
+        .sum()
 }
 
 pub fn passive_income(stored_data: u64) -> u64 {
@@ -55,3 +245,143 @@ pub fn passive_income(stored_data: u64) -> u64 {
         (((stored_data as f64) * 0.05).round() as u64).max(1)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job() -> Job {
+        Job {
+            base_time_ms: 10_000,
+            base_reward: 200,
+            quality_target: 60,
+            ..Job::default()
+        }
+    }
+
+    #[test]
+    fn daemon_tax_prices_quality_and_time_penalties() {
+        let mut processor = ProcessorState::starter();
+        processor.speed = 1.0;
+        processor.quality_bias = 0;
+        processor.daemon_penalty = DaemonPenalty {
+            quality: -10,
+            time_multiplier: 1.1,
+        };
+
+        let tax = daemon_tax_estimate(&job(), &processor, None);
+
+        // 200 * (0.7 + 0.30) = 200 versus 200 * (0.7 + 0.25) = 190.
+        assert_eq!(tax.credits, 10);
+        assert_eq!(tax.extra_ms, 1_000);
+
+        // Running work keeps the penalty it was assigned under.
+        let applied = DaemonPenalty {
+            quality: 0,
+            time_multiplier: 1.0,
+        };
+        let running = daemon_tax_estimate(&job(), &processor, Some(&applied));
+        assert_eq!(
+            running,
+            DaemonTax {
+                credits: 0,
+                extra_ms: 0
+            }
+        );
+    }
+
+    #[test]
+    fn one_museum_exhibit_raises_quality() {
+        use crate::sim::museum::{MuseumEntry, fleet_quality_bonus};
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let processor = ProcessorState::starter();
+        let museum = [MuseumEntry::from_processor(&processor, &[])];
+        let roll = |bonus| {
+            roll_quality(
+                &job(),
+                &processor,
+                None,
+                bonus,
+                &mut StdRng::seed_from_u64(7),
+            )
+        };
+
+        let plain = roll(0);
+        let honored = roll(fleet_quality_bonus(&museum));
+        assert_eq!(honored, plain + 1);
+        assert!(payout_for_quality(&job(), honored, 0.0) > payout_for_quality(&job(), plain, 0.0));
+    }
+
+    #[test]
+    fn daemon_tax_is_zero_without_a_penalty() {
+        let mut processor = ProcessorState::starter();
+        processor.daemon_penalty = DaemonPenalty {
+            quality: 0,
+            time_multiplier: 1.0,
+        };
+
+        let tax = daemon_tax_estimate(&job(), &processor, None);
+
+        assert_eq!(
+            tax,
+            DaemonTax {
+                credits: 0,
+                extra_ms: 0
+            }
+        );
+    }
+
+    #[test]
+    fn degenerate_speeds_still_yield_a_positive_duration() {
+        let mut processor = ProcessorState::starter();
+        let short = Job {
+            base_time_ms: 1,
+            ..job()
+        };
+        processor.speed = f64::INFINITY;
+        assert_eq!(assignment_duration_ms(&short, &processor, None), 1);
+        processor.speed = f64::NAN;
+        assert!(assignment_duration_ms(&job(), &processor, None) >= 1);
+
+        processor.speed = 1.0;
+        let penalty = DaemonPenalty {
+            quality: 0,
+            time_multiplier: f64::INFINITY,
+        };
+        assert_eq!(
+            assignment_duration_ms(&job(), &processor, Some(&penalty)),
+            10_000
+        );
+    }
+
+    #[test]
+    fn metered_bill_tracks_time_spent_working() {
+        const TICKS_PER_DAY: u32 = 180;
+        let mut worker = ProcessorState::starter();
+        let mut idler = ProcessorState::starter();
+        let idle_draw = idler.idle_power_draw();
+        let working_draw = idle_draw * 3.0;
+
+        for tick in 0..TICKS_PER_DAY {
+            worker.last_power_draw = if tick < TICKS_PER_DAY / 2 {
+                working_draw
+            } else {
+                idle_draw
+            };
+            idler.last_power_draw = idle_draw;
+            worker.meter_energy(1.0 / TICKS_PER_DAY as f64);
+            idler.meter_energy(1.0 / TICKS_PER_DAY as f64);
+        }
+
+        // Both units are idle at the boundary, yet the worker spent half the
+        // day at triple draw: (3 + 1) / 2 = 2x the idle bill.
+        let rate = 10.0;
+        let worker_bill = electricity_cost(std::slice::from_ref(&worker), rate);
+        let idler_bill = electricity_cost(std::slice::from_ref(&idler), rate);
+        assert_eq!(worker.last_power_draw(), idler.last_power_draw());
+        let ratio = worker_bill as f64 / idler_bill as f64;
+        assert!((ratio - 2.0).abs() < 0.05, "ratio {ratio}");
+    }
+}
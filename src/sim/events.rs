@@ -0,0 +1,23 @@
+use super::log::LogSubject;
+use super::settings::MessageCategory;
+
+/// Simulation outcomes emitted during an update and consumed once per update
+/// for bookkeeping such as statistics and progression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameEvent {
+    JobCompleted {
+        processor_id: u64,
+        tag: String,
+        quality: u8,
+        payout: u64,
+    },
+    /// A contract failed and its deposit was kept.
+    EscrowForfeited { job_name: String, amount: u64 },
+    /// Player-facing notice, surfaced according to the message routing.
+    Notice {
+        category: MessageCategory,
+        subject: Option<LogSubject>,
+        message: String,
+        critical: bool,
+    },
+}
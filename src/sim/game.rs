@@ -1,39 +1,174 @@
+use super::advisor::{self, Recommendation};
+use super::audit;
+use super::contracts::{self, CONTRACT_CHANCE, ContractState, MAX_ACTIVE_CONTRACTS};
 use super::data_storage::DataStorage;
-use super::economy;
-use super::jobs::{self, Job};
+use super::economy::{self, PowerBudget, ReserveMode};
+use super::events::GameEvent;
+use super::history::{JobRecord, MAX_JOB_HISTORY};
+use super::jobs::{self, Job, ScriptEffect};
+use super::ledger::Ledger;
+use super::loadout::Loadout;
+use super::log::{EventLog, LogSubject, MAX_LOG_ENTRIES};
+use super::market::{DailyOutlook, OUTLOOK_DAYS};
+use super::museum::{self, MuseumEntry};
+use super::mutators::Mutator;
 use super::processors::{
-    AssignmentError, CompletedJob, DaemonMode, JobEvaluation, ProcessorEvent, ProcessorState,
+    AssignmentError, ClockMode, CompletedJob, DaemonMode, DaemonPenalty, MAX_DAEMON_AFFINITY,
+    MAX_DAEMON_FIRMWARE_LEVEL, MAX_DAEMON_PRIORITY, MAX_HARDENING_CAP, ProcessorEvent,
+    ProcessorState, ProcessorStatus, STANDBY_SPIN_UP_MS,
 };
-use rand::Rng;
-use rand::rngs::ThreadRng;
-use rand::thread_rng;
+use super::quarantine::{self, Quarantine};
+use super::racks::{self, MAX_RACK_COOLING, RackError, RackState};
+use super::reputation::{self, ReputationTier};
+use super::settings::{DisplaySettings, MessageCategory, MessageRoute};
+use super::stats::Statistics;
+use super::timeline::FleetTimeline;
+use super::tuning::{TUNING, Tuning};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, HashSet, VecDeque};
 use std::time::Duration;
 use thiserror::Error;
 
 const MAX_JOBS: usize = 5;
-const MAX_MESSAGES: usize = 8;
+const MAX_TOASTS: usize = 3;
+const TOAST_DURATION_MS: u64 = 4_000;
+const TICKER_DURATION_MS: u64 = 8_000;
 const JOB_SPAWN_INTERVAL: Duration = Duration::from_secs(6);
-const DAY_DURATION: Duration = Duration::from_secs(18);
+pub const DAY_DURATION: Duration = Duration::from_secs(18);
+const TIMELINE_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+/// Longest frame simulated as-is. Anything beyond it is time away (sleep,
+/// a suspended terminal) and is settled like time between sessions.
+pub const MAX_FRAME_DELTA: Duration = Duration::from_secs(5);
 pub const DAEMON_UNLOCK_CREDITS: u64 = 500;
+/// Treasury at which the boss contract is offered, once per run.
+pub const BOSS_UNLOCK_CREDITS: u64 = 1_500;
+/// Lifetime credits at which ANGEL work opens up, once per run.
+pub const ANGEL_UNLOCK_CREDITS: u64 = 5_000;
+/// Chance a pick of the ANGEL stream actually posts; otherwise the spawn
+/// falls back to the other unlocked tags.
+const ANGEL_POST_CHANCE: f64 = 0.25;
+/// Chance an ANGEL job completed under its quality target destroys an
+/// unshrouded unit, whatever its hardening.
+pub const ANGEL_BACKLASH_CHANCE: f64 = 0.4;
+/// Share of a SURVEILLANCE payout paid in credits on completion; the rest
+/// comes through the day's data dividend.
+const SURVEILLANCE_CREDIT_SHARE: f64 = 0.6;
+/// Data dividend multiplier each SURVEILLANCE completion adds until the
+/// day closes.
+pub const SURVEILLANCE_DIVIDEND_BOOST: f64 = 0.1;
+const FOLLOW_UP_MIN_QUALITY: u8 = 80;
+const FOLLOW_UP_CHANCE: f64 = 0.25;
+const FOLLOW_UP_DELAY_MS: std::ops::Range<u64> = 6_000..14_000;
+pub const FOLLOW_UP_PREFERRED_BONUS: f64 = 0.15;
+const RESALE_RATIO: f64 = 0.25;
+/// Share of an offline unit's purchase price its parts fetch when salvaged.
+const SALVAGE_RATIO: f64 = 0.1;
+/// Odds a salvaged unit yields a usable tube of thermal paste.
+const SALVAGE_PASTE_CHANCE: f64 = 0.25;
+pub const CANCEL_PENALTY_RATIO: f64 = 0.1;
+const PREEMPT_PROGRESS_LIMIT: f64 = 0.2;
+pub const DIAGNOSTIC_DISCOUNT: f64 = 0.25;
+const DIAGNOSTIC_DISCOUNT_DAYS: u32 = 2;
+/// Day-cycles a newly bought unit's first burnout is replaced free.
+pub const WARRANTY_DAYS: u8 = 3;
+/// Nightly chance per heat-second of work that a unit's cooling wears down
+/// a level, and the most one night can carry.
+const COOLING_WEAR_PER_HEAT_SEC: f64 = 0.004;
+const COOLING_WEAR_MAX_CHANCE: f64 = 0.25;
+/// Share of the Cooling Kit price a worn level costs to recharge.
+pub const COOLING_RECHARGE_RATIO: f64 = 0.5;
+/// Wear one repair takes off, and the least worth paying a repair for.
+pub const REPAIR_WEAR_STEP: f64 = 0.4;
+const REPAIR_MIN_WEAR: f64 = 0.01;
+/// Jobs already on the board when a new run starts.
+const STARTER_JOBS: usize = 3;
+/// How long Auto holds off a unit the player selected with a job pending.
+const MANUAL_INTENT_HOLD: Duration = Duration::from_secs(8);
+/// Trial firmware runs a little rougher than the installed kind.
+const TRIAL_QUALITY_PENALTY: i8 = -2;
+const TRIAL_TIME_PENALTY: f64 = 0.05;
 
 #[derive(Debug, Clone)]
 pub struct AssistSuggestion {
     pub job_index: usize,
+    /// `job_index` is a slot in the pending queue rather than on the board.
+    pub pending: bool,
     pub eta_secs: f64,
     pub reliability: f64,
     pub heat: f64,
+    pub reward: u64,
+    /// Credits per second of runtime; suggestions rank by this.
+    pub score: f64,
 }
 
+/// A unit's buying-relevant stats as they stand today.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelStats {
+    pub name: String,
+    /// Speed after wear and clock.
+    pub speed: f64,
+    pub upkeep: u64,
+    /// Working draw at the unit's clock, before job load modifiers.
+    pub power_draw: f64,
+    pub cooling_cap: u8,
+    pub instruction_set: Vec<String>,
+    /// How long the pending job would take on the unit, if there is one it
+    /// can run.
+    pub pending_eta_ms: Option<u64>,
+}
+
+impl ModelStats {
+    fn of(processor: &ProcessorState, pending: Option<&Job>) -> Self {
+        Self {
+            name: processor.name.clone(),
+            speed: processor.effective_speed() * processor.clock.speed(),
+            upkeep: processor.upkeep_cost,
+            power_draw: processor.power_draw_base * processor.clock.power(),
+            cooling_cap: processor.cooling_cap(),
+            instruction_set: processor.instruction_set.clone(),
+            pending_eta_ms: pending
+                .filter(|job| processor.supports(&job.tag))
+                .map(|job| economy::assignment_duration_ms(job, processor, None)),
+        }
+    }
+}
+
+/// A catalog model next to the unit the player has selected.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelComparison {
+    pub candidate: ModelStats,
+    pub current: Option<ModelStats>,
+    /// Name of the pending job both are projected against.
+    pub pending_job: Option<String>,
+}
+
+/// How far each press moves the auto-replace credit floor.
+pub const AUTO_REPLACE_FLOOR_STEP: u64 = 250;
+
+/// How many ranked suggestions Assist units put in front of the player.
+pub const ASSIST_CHOICES: usize = 3;
+
+/// How many jobs can wait off the board for a manual assignment at once.
+pub const MAX_PENDING_JOBS: usize = 3;
+
+/// Schema version written into saves; `persist` migrates older ones.
+pub const SAVE_VERSION: u32 = 5;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameState {
+    /// Schema version; absent in saves from before versioning (v0).
+    #[serde(default)]
+    pub version: u32,
     pub credits: u64,
     pub processors: Vec<ProcessorState>,
     pub jobs: Vec<Job>,
     pub storage: DataStorage,
     pub daemon_unlocked: bool,
+    /// v0 fleet-wide automation switch, superseded by per-unit modes.
+    #[serde(default)]
     pub daemon_enabled: bool,
     #[serde(default)]
     pub thermal_paste_timer_ms: u64,
@@ -42,12 +177,130 @@ pub struct GameState {
     pub unlocked_tags: Vec<String>,
     #[serde(default = "default_store_purchases")]
     pub store_purchases: Vec<u32>,
+    #[serde(default)]
+    pub next_processor_id: u64,
+    #[serde(default)]
+    pub scheduled_jobs: Vec<ScheduledJob>,
+    /// Multi-stage contracts with a stage still out.
+    #[serde(default)]
+    pub contracts: Vec<ContractState>,
+    #[serde(default)]
+    pub contract_counter: u64,
+    /// Client standing, raised by on-target work and lowered by lost or
+    /// expired jobs. Sets the reward range of new postings.
+    #[serde(default)]
+    pub reputation: i32,
+    /// Extra data dividend multiplier earned today from SURVEILLANCE work.
+    #[serde(default)]
+    pub dividend_boost: f64,
+    #[serde(default)]
+    pub museum: Vec<MuseumEntry>,
+    #[serde(default)]
+    pub market_today: DailyOutlook,
+    #[serde(default)]
+    pub market_outlook: VecDeque<DailyOutlook>,
+    #[serde(default)]
+    pub statistics: Statistics,
+    #[serde(default)]
+    pub loadout: Loadout,
+    #[serde(default)]
+    pub reserve_mode: ReserveMode,
+    /// Days left on diagnostic replacement discounts, keyed by model name.
+    #[serde(default)]
+    pub replacement_discounts: BTreeMap<String, u32>,
+    #[serde(default)]
+    pub job_history: VecDeque<JobRecord>,
+    #[serde(default)]
+    pub power_budget: PowerBudget,
+    #[serde(default)]
+    pub display: DisplaySettings,
+    #[serde(default)]
+    pub quarantine: Quarantine,
+    /// Version 4's single pending slot, read only by the migration to
+    /// `pending_jobs`.
+    #[serde(default)]
+    pub pending_job: Option<Job>,
+    /// Jobs taken off the board and awaiting a manual assignment, oldest
+    /// first; at most `MAX_PENDING_JOBS`.
+    #[serde(default)]
+    pub pending_jobs: Vec<Job>,
+    /// Seed the run was started from; 0 for saves that predate it.
+    #[serde(default)]
+    pub run_seed: u64,
+    #[serde(default)]
+    pub boss_posted: bool,
+    /// Practice runs; their fleets cannot be carried into a new game.
+    #[serde(default)]
+    pub sandbox: bool,
+    /// The run's one free daemon trial; kept after it ends so it cannot repeat.
+    #[serde(default)]
+    pub daemon_trial: Option<DaemonTrial>,
+    /// Care packages exported from and imported into this save, by id.
+    #[serde(default)]
+    pub packages_sent: Vec<u64>,
+    #[serde(default)]
+    pub packages_received: Vec<u64>,
+    /// Rule changes chosen at new-game time, sorted.
+    #[serde(default)]
+    pub mutators: Vec<Mutator>,
+    /// Tail of the event log, oldest first, so a reload keeps its context.
+    #[serde(default)]
+    pub recent_messages: Vec<String>,
+    /// Profile the save belongs to; empty for the default save.
+    #[serde(default)]
+    pub profile_name: String,
+    /// Write the save gzip-compressed.
+    #[serde(default)]
+    pub compress_saves: bool,
+    /// Saved only when a day closes, and erased when the run is lost.
+    #[serde(default)]
+    pub hardcore: bool,
+    /// The day's weather heat, drifting a little each day; negative is cool.
+    #[serde(default)]
+    pub ambient_temperature: f64,
+    /// Days left in the current heat wave, zero when there isn't one.
+    #[serde(default)]
+    pub heat_wave_days: u32,
+    /// Automation hands work to near-death units only after the rest.
+    #[serde(default)]
+    pub spare_worn_units: bool,
+    /// Credits auto-replace always leaves in the treasury.
+    #[serde(default)]
+    pub auto_replace_floor: u64,
+    #[serde(default)]
+    pub racks: Vec<RackState>,
+    /// Mode Ctrl+D last put the whole fleet in.
+    #[serde(default)]
+    pub fleet_daemon_mode: DaemonMode,
+}
+
+/// Daemon firmware lent to one processor until the next day boundary.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DaemonTrial {
+    pub processor_id: u64,
+    /// Penalty the unit had before the trial, restored when it ends.
+    pub penalty: DaemonPenalty,
+    pub jobs: u64,
+    pub earned: u64,
+    pub active: bool,
+}
+
+/// A job that will be posted to the board once its delay elapses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledJob {
+    pub delay_ms: u64,
+    pub job: Job,
 }
 
 fn default_store_purchases() -> Vec<u32> {
     vec![0; STORE_ITEMS.len()]
 }
 
+/// One sample per interval across a full day.
+fn timeline_capacity() -> usize {
+    (DAY_DURATION.as_millis() / TIMELINE_SAMPLE_INTERVAL.as_millis()) as usize
+}
+
 fn default_unlocked_tags() -> Vec<String> {
     vec![jobs::GENERAL_TAG.to_string()]
 }
@@ -61,20 +314,111 @@ impl Default for GameState {
             storage: DataStorage::new(120),
             daemon_unlocked: false,
             daemon_enabled: false,
+            version: SAVE_VERSION,
             thermal_paste_timer_ms: 0,
             job_counter: 0,
             unlocked_tags: default_unlocked_tags(),
             store_purchases: default_store_purchases(),
+            next_processor_id: 0,
+            scheduled_jobs: Vec::new(),
+            contracts: Vec::new(),
+            contract_counter: 0,
+            reputation: 0,
+            dividend_boost: 0.0,
+            museum: Vec::new(),
+            market_today: DailyOutlook::default(),
+            market_outlook: VecDeque::new(),
+            statistics: Statistics::default(),
+            loadout: Loadout::default(),
+            reserve_mode: ReserveMode::default(),
+            replacement_discounts: BTreeMap::new(),
+            job_history: VecDeque::new(),
+            power_budget: PowerBudget::default(),
+            display: DisplaySettings::default(),
+            quarantine: Quarantine::default(),
+            pending_job: None,
+            pending_jobs: Vec::new(),
+            run_seed: 0,
+            boss_posted: false,
+            sandbox: false,
+            daemon_trial: None,
+            packages_sent: Vec::new(),
+            packages_received: Vec::new(),
+            mutators: Vec::new(),
+            recent_messages: Vec::new(),
+            profile_name: String::new(),
+            compress_saves: false,
+            hardcore: false,
+            ambient_temperature: 0.0,
+            heat_wave_days: 0,
+            spare_worn_units: false,
+            auto_replace_floor: 0,
+            racks: Vec::new(),
+            fleet_daemon_mode: DaemonMode::Off,
+        }
+    }
+}
+
+impl GameState {
+    /// Posts the starter jobs a new run opens with, derived from `seed`.
+    pub fn seed_starter_board(&mut self, seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        self.run_seed = seed;
+        for _ in 0..STARTER_JOBS {
+            if self.jobs.len() >= MAX_JOBS {
+                break;
+            }
+            self.job_counter += 1;
+            self.jobs
+                .push(jobs::generate_starter_job(self.job_counter, &mut rng));
         }
     }
 }
 
+/// A short-lived announcement shown on top of the main layout.
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub message: String,
+    pub remaining_ms: u64,
+    pub level: ToastLevel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastLevel {
+    Info,
+    /// Needs a reaction from the player.
+    Critical,
+}
+
 pub struct Game {
     pub state: GameState,
     job_spawn_timer: Duration,
     day_timer: Duration,
-    rng: ThreadRng,
-    messages: VecDeque<String>,
+    /// Game time not yet handed to the processors; see `tick_processors`.
+    processor_timer: Duration,
+    /// Base tuning with this run's mutators applied.
+    tuning: Tuning,
+    rng: StdRng,
+    log: EventLog,
+    ledger: Ledger,
+    reported_violations: HashSet<String>,
+    events: Vec<GameEvent>,
+    toasts: VecDeque<Toast>,
+    /// Latest headline routed to the ticker.
+    ticker: Option<Toast>,
+    /// A notice asked for the terminal bell since the last frame.
+    bell: bool,
+    timeline: FleetTimeline,
+    timeline_timer: Duration,
+    reserve_override: bool,
+    quarantine_notices: Vec<String>,
+    /// The save this game was loaded from was edited outside the game.
+    tampered: bool,
+    /// A hardcore run reached a point it should be saved at.
+    checkpoint: bool,
+    /// Id of the processor the player is about to assign the pending job to,
+    /// and how much longer Auto leaves it alone.
+    manual_intent: Option<(u64, Duration)>,
 }
 
 impl Game {
@@ -82,11 +426,32 @@ impl Game {
         Self::from_state(GameState::default())
     }
 
+    /// Rolls are derived from the run seed and the play time, so loading
+    /// the same save twice and giving it the same inputs replays the same
+    /// run. Saves without a seed are given one.
     pub fn from_state(mut state: GameState) -> Self {
+        if state.run_seed == 0 {
+            state.run_seed = rand::random();
+        }
+        let position = state
+            .statistics
+            .play_time_ms
+            .wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        let rng = StdRng::seed_from_u64(state.run_seed ^ position);
+        Self::with_rng(state, rng)
+    }
+
+    /// Like `from_state`, but every roll is derived from `seed` so the same
+    /// inputs replay the same run.
+    pub fn seeded(state: GameState, seed: u64) -> Self {
+        Self::with_rng(state, StdRng::seed_from_u64(seed))
+    }
+
+    fn with_rng(mut state: GameState, rng: StdRng) -> Self {
+        let quarantine_notices = quarantine::run(&mut state, STORE_ITEMS.len());
         if state.store_purchases.len() < STORE_ITEMS.len() {
             state.store_purchases.resize(STORE_ITEMS.len(), 0);
         }
-        state.daemon_enabled = false;
         if state.unlocked_tags.is_empty() {
             state.unlocked_tags = default_unlocked_tags();
         }
@@ -97,35 +462,84 @@ impl Game {
         {
             state.unlocked_tags.insert(0, jobs::GENERAL_TAG.to_string());
         }
+        let highest_id = state.processors.iter().map(|p| p.id).max().unwrap_or(0);
+        state.next_processor_id = state.next_processor_id.max(highest_id + 1);
+        let mut seen_ids = HashSet::new();
         for processor in &mut state.processors {
-            processor.ensure_runtime_defaults();
-            if state.daemon_unlocked {
-                processor.daemon_unlocked = true;
-            }
-            if state.daemon_enabled && processor.daemon_mode == DaemonMode::Off {
-                processor.daemon_mode = DaemonMode::Auto;
+            if processor.id == 0 || !seen_ids.insert(processor.id) {
+                processor.id = state.next_processor_id;
+                state.next_processor_id += 1;
+                seen_ids.insert(processor.id);
             }
-            for tag in &state.unlocked_tags {
-                if !processor.supports(tag) {
-                    processor.instruction_set.push(tag.clone());
-                }
+            processor.ensure_runtime_defaults();
+        }
+        let trial_id = state
+            .daemon_trial
+            .as_ref()
+            .filter(|trial| trial.active)
+            .map(|trial| trial.processor_id);
+        for processor in &mut state.processors {
+            // A trial in progress keeps its loaned firmware across save/load.
+            processor.daemon_trial = trial_id == Some(processor.id);
+            if processor.daemon_trial {
+                processor.daemon_firmware_level = processor.daemon_firmware_level.max(1);
             }
         }
-        Self {
+        let tuning = Tuning::with_mutators(&state.mutators);
+        for processor in &mut state.processors {
+            processor.extra_fragility = tuning.extra_fragility;
+        }
+        let ledger = Ledger::new(state.credits);
+        let excess = state.recent_messages.len().saturating_sub(MAX_LOG_ENTRIES);
+        state.recent_messages.drain(..excess);
+        let mut log = EventLog::new(MAX_LOG_ENTRIES);
+        for message in &state.recent_messages {
+            log.push(None, None, message.clone());
+        }
+        // A hardcore run is written once before play starts, so quitting
+        // ahead of the first day's end can't fall back to an older save.
+        let checkpoint = state.hardcore && state.statistics.play_time_ms == 0;
+        let mut game = Self {
             state,
             job_spawn_timer: Duration::default(),
             day_timer: Duration::default(),
-            rng: thread_rng(),
-            messages: VecDeque::with_capacity(MAX_MESSAGES),
-        }
+            processor_timer: Duration::default(),
+            tuning,
+            rng,
+            log,
+            ledger,
+            reported_violations: HashSet::new(),
+            events: Vec::new(),
+            toasts: VecDeque::with_capacity(MAX_TOASTS),
+            ticker: None,
+            bell: false,
+            timeline: FleetTimeline::new(timeline_capacity()),
+            timeline_timer: Duration::default(),
+            reserve_override: false,
+            quarantine_notices,
+            tampered: false,
+            checkpoint,
+            manual_intent: None,
+        };
+        game.fill_market_outlook();
+        game
     }
 
     pub fn update(&mut self, delta: Duration) {
+        let delta = if delta > MAX_FRAME_DELTA {
+            self.skip_time_away(delta - MAX_FRAME_DELTA);
+            MAX_FRAME_DELTA
+        } else {
+            delta
+        };
+        self.state.statistics.play_time_ms += delta.as_millis() as u64;
+        self.age_manual_intent(delta);
         self.job_spawn_timer += delta;
         while self.job_spawn_timer >= JOB_SPAWN_INTERVAL {
             self.job_spawn_timer -= JOB_SPAWN_INTERVAL;
             self.spawn_job_if_possible();
         }
+        self.age_board_jobs(delta);
 
         self.day_timer += delta;
         while self.day_timer >= DAY_DURATION {
@@ -134,6 +548,8 @@ impl Game {
         }
 
         self.tick_processors(delta);
+        self.advance_scheduled_jobs(delta);
+        self.sample_timeline(delta);
 
         if self.state.thermal_paste_timer_ms > 0 {
             let delta_ms = delta.as_millis() as u64;
@@ -147,18 +563,74 @@ impl Game {
             }
         }
 
-        if !self.state.daemon_unlocked && self.state.credits >= DAEMON_UNLOCK_CREDITS {
+        if !self.state.daemon_unlocked
+            && self.tuning.daemon_unlockable
+            && self.state.credits >= DAEMON_UNLOCK_CREDITS
+        {
             self.state.daemon_unlocked = true;
             for processor in &mut self.state.processors {
-                processor.daemon_unlocked = true;
+                processor.daemon_firmware_level = processor.daemon_firmware_level.max(1);
             }
-            self.push_message(
+            self.notify(
+                MessageCategory::Automation,
                 "Daemon automation unlocked. Focus a processor and press D to cycle modes."
                     .to_string(),
             );
         }
 
+        if !self.state.boss_posted && self.state.credits >= BOSS_UNLOCK_CREDITS {
+            self.post_boss_contract();
+        }
+
+        if self.state.statistics.credits_earned >= ANGEL_UNLOCK_CREDITS
+            && self.unlock_instruction_tag(jobs::ANGEL_TAG)
+        {
+            self.notify_critical(
+                MessageCategory::Jobs,
+                "ANGEL contact established: install ANGEL microcode on a unit to take its \
+                 jobs. A missed quality target can destroy a unit without a Faraday Shroud."
+                    .to_string(),
+            );
+        }
+
         self.try_daemon_assignment();
+        self.process_events();
+        self.age_toasts(delta);
+    }
+
+    /// Skips a gap in play. A run doesn't advance between sessions, so a wake
+    /// from sleep is treated the same way: the gap is reported and no game
+    /// time is applied for it.
+    fn skip_time_away(&mut self, gap: Duration) {
+        let minutes = gap.as_secs() / 60;
+        let away = if minutes >= 60 {
+            format!("{}h {}m", minutes / 60, minutes % 60)
+        } else if minutes > 0 {
+            format!("{minutes}m")
+        } else {
+            format!("{}s", gap.as_secs())
+        };
+        self.push_message(format!(
+            "System slept for {away} — the run paused meanwhile and that time was skipped."
+        ));
+    }
+
+    /// Offers the boss contract once the board has room for it.
+    fn post_boss_contract(&mut self) {
+        if self.state.jobs.len() >= MAX_JOBS {
+            return;
+        }
+        self.state.boss_posted = true;
+        self.state.job_counter += 1;
+        let job = jobs::boss_contract(self.state.job_counter);
+        self.notify_critical(
+            MessageCategory::Jobs,
+            format!(
+                "Boss contract posted: {} ({} cr, +{} cr/day upkeep while running).",
+                job.name, job.base_reward, job.upkeep
+            ),
+        );
+        self.state.jobs.push(job);
     }
 
     pub fn take_job(&mut self, index: usize) -> Option<Job> {
@@ -169,8 +641,94 @@ impl Game {
         }
     }
 
+    /// No more jobs can be queued until one is assigned or returned.
+    pub fn pending_full(&self) -> bool {
+        self.state.pending_jobs.len() >= MAX_PENDING_JOBS
+    }
+
+    /// Moves a board job to the back of the pending queue, returning its name.
+    pub fn queue_pending(&mut self, index: usize) -> Option<String> {
+        if self.pending_full() {
+            return None;
+        }
+        let job = self.take_job(index)?;
+        let name = job.name.clone();
+        self.state.pending_jobs.push(job);
+        Some(name)
+    }
+
+    /// Puts the most recently queued job back on the board.
+    pub fn release_pending(&mut self) -> bool {
+        match self.state.pending_jobs.pop() {
+            Some(job) => {
+                self.return_job(job);
+                if self.state.pending_jobs.is_empty() {
+                    self.manual_intent = None;
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Assigns the job at the front of the pending queue.
+    pub fn assign_pending(&mut self, processor_index: usize) -> Result<(), AssignmentError> {
+        self.assign_pending_at(0, processor_index)
+    }
+
+    /// Assigns the pending job in `slot`; on failure it keeps its place.
+    pub fn assign_pending_at(
+        &mut self,
+        slot: usize,
+        processor_index: usize,
+    ) -> Result<(), AssignmentError> {
+        if slot >= self.state.pending_jobs.len() {
+            return Err(AssignmentError::NothingPending);
+        }
+        let job = self.state.pending_jobs.remove(slot);
+        match self.assign_job_to_processor(job.clone(), processor_index, false) {
+            Ok(()) => {
+                if self.state.pending_jobs.is_empty() {
+                    self.manual_intent = None;
+                }
+                Ok(())
+            }
+            Err(err) => {
+                self.state.pending_jobs.insert(slot, job);
+                Err(err)
+            }
+        }
+    }
+
+    /// Holds Auto off the selected unit while a job is pending for it.
+    pub fn note_manual_intent(&mut self, processor_index: usize) {
+        if !self.state.pending_jobs.is_empty()
+            && let Some(processor) = self.state.processors.get(processor_index)
+        {
+            self.manual_intent = Some((processor.id, MANUAL_INTENT_HOLD));
+        }
+    }
+
+    fn age_manual_intent(&mut self, delta: Duration) {
+        if let Some((_, remaining)) = &mut self.manual_intent {
+            *remaining = remaining.saturating_sub(delta);
+            if remaining.is_zero() || self.state.pending_jobs.is_empty() {
+                self.manual_intent = None;
+            }
+        }
+    }
+
+    fn held_for_manual(&self, processor_index: usize) -> bool {
+        !self.state.pending_jobs.is_empty()
+            && matches!(
+                (self.manual_intent, self.state.processors.get(processor_index)),
+                (Some((id, _)), Some(processor)) if id == processor.id
+            )
+    }
+
     pub fn return_job(&mut self, job: Job) {
-        if self.state.jobs.len() >= MAX_JOBS {
+        // A contract stage keeps its place even on a full board.
+        if self.state.jobs.len() >= MAX_JOBS && job.contract_id.is_none() {
             self.push_message("Job board full; discarded returned job.".to_string());
         } else {
             self.state.jobs.insert(0, job);
@@ -192,6 +750,9 @@ impl Game {
         let processor_name;
         {
             let processor = &mut self.state.processors[processor_index];
+            if processor.is_servicing() {
+                return Err(AssignmentError::ProcessorServicing);
+            }
             if !processor.is_idle() {
                 return Err(AssignmentError::ProcessorBusy);
             }
@@ -201,6 +762,43 @@ impl Game {
             if !processor.is_functional() {
                 return Err(AssignmentError::ProcessorInoperative);
             }
+            if processor.paused {
+                return Err(AssignmentError::ProcessorPaused);
+            }
+        }
+        let cooling_bonus = self.cooling_bonus_levels();
+        let draw = self.projected_power_draw(processor_index, &job, cooling_bonus);
+        if !self.state.power_budget.allows(draw) {
+            return Err(AssignmentError::PowerBudgetExceeded {
+                draw,
+                capacity: self.state.power_budget.capacity,
+            });
+        }
+        let mut job = job;
+        let needed = job.storage_demand();
+        if job.storage_reserved == 0 && needed > self.state.storage.free_capacity() {
+            return Err(AssignmentError::InsufficientStorage {
+                needed,
+                free: self.state.storage.free_capacity(),
+            });
+        }
+        if job.escrow == 0 {
+            let deposit = economy::escrow_deposit(&job);
+            if deposit > self.state.credits {
+                return Err(AssignmentError::InsufficientEscrow { deposit });
+            }
+            if deposit > 0 {
+                self.state.credits -= deposit;
+                self.ledger.record_escrow(deposit);
+                job.escrow = deposit;
+                self.push_message(format!("{deposit} cr escrowed for {job_name}."));
+            }
+        }
+        if job.storage_reserved == 0 && self.state.storage.reserve(needed) {
+            job.storage_reserved = needed;
+        }
+        {
+            let processor = &mut self.state.processors[processor_index];
             let penalty = if daemon {
                 Some(processor.daemon_penalty.clone())
             } else {
@@ -208,7 +806,7 @@ impl Game {
             };
             duration_ms = economy::assignment_duration_ms(&job, processor, penalty.as_ref());
             processor.assign(job, duration_ms, penalty);
-            processor_name = processor.name.clone();
+            processor_name = processor.label();
         }
         let seconds = duration_ms as f64 / 1000.0;
         if daemon {
@@ -223,6 +821,13 @@ impl Game {
         Ok(())
     }
 
+    pub fn processor_index_by_id(&self, id: u64) -> Option<usize> {
+        self.state
+            .processors
+            .iter()
+            .position(|processor| processor.id == id)
+    }
+
     pub fn job_spawn_progress(&self) -> f64 {
         (self.job_spawn_timer.as_secs_f64() / JOB_SPAWN_INTERVAL.as_secs_f64()).min(1.0)
     }
@@ -232,7 +837,29 @@ impl Game {
     }
 
     pub fn messages(&self) -> impl Iterator<Item = &String> {
-        self.messages.iter()
+        self.log.iter().map(|entry| &entry.text)
+    }
+
+    pub fn log(&self) -> &EventLog {
+        &self.log
+    }
+
+    /// What the load-time quarantine detached from this save, if anything.
+    pub fn quarantine_notices(&self) -> &[String] {
+        &self.quarantine_notices
+    }
+
+    /// Flags a save whose checksum didn't match, with a warning that stays
+    /// on screen a while.
+    pub fn mark_tampered(&mut self) {
+        self.tampered = true;
+        let warning = "Save file was modified externally; loaded it anyway.".to_string();
+        self.push_message(warning.clone());
+        self.push_toast(warning, ToastLevel::Critical);
+    }
+
+    pub fn tampered(&self) -> bool {
+        self.tampered
     }
 
     pub fn add_message<S: Into<String>>(&mut self, message: S) {
@@ -252,41 +879,62 @@ impl Game {
         match item.action {
             StoreAction::ReplaceProcessor => {
                 let processor = processor_index.and_then(|idx| self.state.processors.get(idx))?;
-                let cost = replacement_cost_for_processor(processor);
-                if cost == 0 { None } else { Some(cost) }
+                (!processor.is_functional()).then(|| {
+                    replacement_cost_for_processor(
+                        processor,
+                        self.replacement_discount(&processor.name),
+                    )
+                })
             }
             StoreAction::ReplaceModel => {
                 let processor = processor_index.and_then(|idx| self.state.processors.get(idx))?;
-                let cost = self.replacement_cost_for_model(&processor.name);
-                if cost == 0 { None } else { Some(cost) }
+                self.has_offline_of_model(&processor.name)
+                    .then(|| self.replacement_cost_for_model(&processor.name))
             }
-            StoreAction::UpgradeCooling => {
+            StoreAction::InstallDaemonFirmware if !self.tuning.daemon_unlockable => None,
+            StoreAction::UpgradeCooling
+            | StoreAction::UpgradeHardening
+            | StoreAction::ReinforceChassis
+            | StoreAction::InstallDaemonFirmware => {
                 let processor = processor_index.and_then(|idx| self.state.processors.get(idx))?;
-                if processor.cooling_level >= processor.cooling_cap {
-                    return None;
-                }
-                Some(item.base_cost + item.cost_step * processor.cooling_level as u64)
+                upgrade_cost(item, processor)
             }
-            StoreAction::UpgradeHardening => {
+            StoreAction::ServiceProcessor => {
                 let processor = processor_index.and_then(|idx| self.state.processors.get(idx))?;
-                if processor.hardening_level >= 3 {
-                    return None;
-                }
-                Some(item.base_cost + item.cost_step * processor.hardening_level as u64)
+                service_cost(item, processor)
             }
-            StoreAction::InstallDaemonFirmware => {
+            StoreAction::RepairProcessor => {
                 let processor = processor_index.and_then(|idx| self.state.processors.get(idx))?;
-                if processor.daemon_unlocked {
-                    return None;
-                }
-                Some(item.base_cost + item.cost_step * processor.daemon_priority.max(0) as u64)
+                repair_cost(item, processor)
+            }
+            StoreAction::InsureProcessor => {
+                let processor = processor_index.and_then(|idx| self.state.processors.get(idx))?;
+                (processor.is_functional() && !processor.insured).then_some(item.base_cost)
+            }
+            StoreAction::InstallFaradayShroud => {
+                let processor = processor_index.and_then(|idx| self.state.processors.get(idx))?;
+                (!processor.faraday_shroud).then_some(item.base_cost)
+            }
+            StoreAction::InstallInstruction { tag } => {
+                let processor = processor_index.and_then(|idx| self.state.processors.get(idx))?;
+                (self.is_instruction_unlocked(tag) && !processor.supports(tag))
+                    .then_some(item.base_cost)
+            }
+            StoreAction::UpgradeRackCooling => {
+                let processor = processor_index.and_then(|idx| self.state.processors.get(idx))?;
+                let rack = self.state.racks.get(processor.rack_id? as usize)?;
+                (rack.cooling_level < MAX_RACK_COOLING)
+                    .then(|| item.base_cost + item.cost_step * rack.cooling_level as u64)
+            }
+            StoreAction::BuyProcessor { model } => {
+                ProcessorState::of_model(model).map(|processor| processor.purchase_cost)
             }
             _ => {
                 let purchases = *self.state.store_purchases.get(index).unwrap_or(&0);
-                if let Some(max) = item.max_purchases {
-                    if purchases >= max {
-                        return None;
-                    }
+                if let Some(max) = item.max_purchases
+                    && purchases >= max
+                {
+                    return None;
                 }
                 Some(item.base_cost + item.cost_step * purchases as u64)
             }
@@ -304,20 +952,20 @@ impl Game {
     ) -> Result<(), PurchaseError> {
         let item = STORE_ITEMS.get(index).ok_or(PurchaseError::InvalidItem)?;
         let purchases = *self.state.store_purchases.get(index).unwrap_or(&0);
-        if let Some(max) = item.max_purchases {
-            if purchases >= max {
-                return Err(PurchaseError::MaxedOut { item: item.name });
-            }
+        if let Some(max) = item.max_purchases
+            && purchases >= max
+        {
+            return Err(PurchaseError::MaxedOut { item: item.name });
         }
         match item.action {
             StoreAction::ReplaceProcessor | StoreAction::ReplaceModel => {}
             _ => {
-                if let StoreAction::UnlockInstructionSet { tag } = item.action {
-                    if self.is_instruction_unlocked(tag) {
-                        return Err(PurchaseError::InstructionAlreadyUnlocked {
-                            tag: tag.to_string(),
-                        });
-                    }
+                if let StoreAction::UnlockInstructionSet { tag } = item.action
+                    && self.is_instruction_unlocked(tag)
+                {
+                    return Err(PurchaseError::InstructionAlreadyUnlocked {
+                        tag: tag.to_string(),
+                    });
                 }
             }
         }
@@ -326,57 +974,124 @@ impl Game {
                 let processor = processor_index
                     .and_then(|idx| self.state.processors.get(idx))
                     .ok_or(PurchaseError::ProcessorSelectionRequired)?;
-                let cost = replacement_cost_for_processor(processor);
-                if cost == 0 {
+                if processor.is_functional() {
                     return Err(PurchaseError::ProcessorHealthy);
                 }
-                cost
+                replacement_cost_for_processor(
+                    processor,
+                    self.replacement_discount(&processor.name),
+                )
             }
             StoreAction::ReplaceModel => {
                 let processor = processor_index
                     .and_then(|idx| self.state.processors.get(idx))
                     .ok_or(PurchaseError::ProcessorSelectionRequired)?;
-                let cost = self.replacement_cost_for_model(&processor.name);
-                if cost == 0 {
+                if !self.has_offline_of_model(&processor.name) {
                     return Err(PurchaseError::NoMatchingProcessors);
                 }
-                cost
+                self.replacement_cost_for_model(&processor.name)
             }
             StoreAction::UpgradeCooling => {
                 let processor = processor_index
                     .and_then(|idx| self.state.processors.get(idx))
                     .ok_or(PurchaseError::ProcessorSelectionRequired)?;
-                if processor.cooling_level >= processor.cooling_cap {
-                    return Err(PurchaseError::UpgradeAtCap);
-                }
-                item.base_cost + item.cost_step * processor.cooling_level as u64
+                upgrade_cost(item, processor).ok_or(PurchaseError::UpgradeAtCap)?
             }
             StoreAction::UpgradeHardening => {
                 let processor = processor_index
                     .and_then(|idx| self.state.processors.get(idx))
                     .ok_or(PurchaseError::ProcessorSelectionRequired)?;
-                if processor.hardening_level >= 3 {
-                    return Err(PurchaseError::UpgradeAtCap);
+                upgrade_cost(item, processor).ok_or(PurchaseError::UpgradeAtCap)?
+            }
+            StoreAction::ReinforceChassis => {
+                let processor = processor_index
+                    .and_then(|idx| self.state.processors.get(idx))
+                    .ok_or(PurchaseError::ProcessorSelectionRequired)?;
+                upgrade_cost(item, processor).ok_or(PurchaseError::UpgradeAtCap)?
+            }
+            StoreAction::InsureProcessor => {
+                let processor = processor_index
+                    .and_then(|idx| self.state.processors.get(idx))
+                    .ok_or(PurchaseError::ProcessorSelectionRequired)?;
+                if !processor.is_functional() {
+                    return Err(PurchaseError::ProcessorOffline);
+                }
+                if processor.insured {
+                    return Err(PurchaseError::AlreadyInsured);
                 }
-                item.base_cost + item.cost_step * processor.hardening_level as u64
+                item.base_cost
+            }
+            StoreAction::InstallFaradayShroud => {
+                let processor = processor_index
+                    .and_then(|idx| self.state.processors.get(idx))
+                    .ok_or(PurchaseError::ProcessorSelectionRequired)?;
+                if processor.faraday_shroud {
+                    return Err(PurchaseError::AlreadyShrouded);
+                }
+                item.base_cost
             }
             StoreAction::InstallDaemonFirmware => {
                 let processor = processor_index
                     .and_then(|idx| self.state.processors.get(idx))
                     .ok_or(PurchaseError::ProcessorSelectionRequired)?;
-                if processor.daemon_unlocked {
-                    return Err(PurchaseError::DaemonAlreadyInstalled);
+                if !self.tuning.daemon_unlockable {
+                    return Err(PurchaseError::DaemonDisabled);
+                }
+                upgrade_cost(item, processor).ok_or(PurchaseError::DaemonFirmwareMaxed)?
+            }
+            StoreAction::ServiceProcessor => {
+                let processor = processor_index
+                    .and_then(|idx| self.state.processors.get(idx))
+                    .ok_or(PurchaseError::ProcessorSelectionRequired)?;
+                service_cost(item, processor).ok_or(PurchaseError::NothingToService)?
+            }
+            StoreAction::RepairProcessor => {
+                let processor = processor_index
+                    .and_then(|idx| self.state.processors.get(idx))
+                    .ok_or(PurchaseError::ProcessorSelectionRequired)?;
+                if !processor.is_functional() {
+                    return Err(PurchaseError::ProcessorOffline);
+                }
+                repair_cost(item, processor).ok_or(PurchaseError::NothingToService)?
+            }
+            StoreAction::InstallInstruction { tag } => {
+                let processor = processor_index
+                    .and_then(|idx| self.state.processors.get(idx))
+                    .ok_or(PurchaseError::ProcessorSelectionRequired)?;
+                if !self.is_instruction_unlocked(tag) {
+                    return Err(PurchaseError::InstructionLocked {
+                        tag: tag.to_string(),
+                    });
+                }
+                if processor.supports(tag) {
+                    return Err(PurchaseError::InstructionAlreadyInstalled {
+                        tag: tag.to_string(),
+                    });
+                }
+                item.base_cost
+            }
+            StoreAction::UpgradeRackCooling => {
+                let processor = processor_index
+                    .and_then(|idx| self.state.processors.get(idx))
+                    .ok_or(PurchaseError::ProcessorSelectionRequired)?;
+                let rack = processor
+                    .rack_id
+                    .and_then(|rack| self.state.racks.get(rack as usize))
+                    .ok_or(PurchaseError::NotRacked)?;
+                if rack.cooling_level >= MAX_RACK_COOLING {
+                    return Err(PurchaseError::UpgradeAtCap);
                 }
-                item.base_cost + item.cost_step * processor.daemon_priority.max(0) as u64
+                item.base_cost + item.cost_step * rack.cooling_level as u64
+            }
+            StoreAction::BuyProcessor { model } => {
+                ProcessorState::of_model(model)
+                    .ok_or(PurchaseError::InvalidItem)?
+                    .purchase_cost
             }
             _ => item.base_cost + item.cost_step * purchases as u64,
         };
 
-        if self.state.credits < cost {
-            return Err(PurchaseError::InsufficientCredits { cost });
-        }
-
-        self.state.credits -= cost;
+        self.spend(cost)?;
         match item.action {
             StoreAction::IncreaseSpeed => {
                 for processor in &mut self.state.processors {
@@ -400,76 +1115,217 @@ impl Game {
             StoreAction::UnlockInstructionSet { tag } => {
                 if self.unlock_instruction_tag(tag) {
                     self.push_message(format!(
-                        "Microcode integrated: processors now accept {tag} workloads."
+                        "{tag} microcode licensed: install it on the units that should run it."
                     ));
                     self.push_message(
-                        "Advanced job stream unlocked; watch for specialized contracts."
+                        "Advanced job stream unlocked; contracts post once a unit supports them."
                             .to_string(),
                     );
                 }
             }
+            StoreAction::InstallInstruction { tag } => {
+                let name = {
+                    let processor = processor_index
+                        .and_then(|idx| self.state.processors.get_mut(idx))
+                        .ok_or(PurchaseError::ProcessorSelectionRequired)?;
+                    processor.instruction_set.push(tag.to_string());
+                    processor.label()
+                };
+                self.push_message(format!("{name} now runs {tag} workloads."));
+            }
             StoreAction::UpgradeCooling => {
-                let (name, level) = {
+                let (name, level, recharged) = {
                     let processor = processor_index
                         .and_then(|idx| self.state.processors.get_mut(idx))
                         .ok_or(PurchaseError::ProcessorSelectionRequired)?;
                     if processor.cooling_level >= processor.cooling_cap {
                         return Err(PurchaseError::UpgradeAtCap);
                     }
+                    let recharged = processor.cooling_worn > 0;
+                    processor.cooling_worn = processor.cooling_worn.saturating_sub(1);
                     processor.cooling_level += 1;
                     processor.ensure_runtime_defaults();
-                    (processor.name.clone(), processor.cooling_level)
+                    (processor.label(), processor.cooling_level, recharged)
                 };
-                self.push_message(format!("{name} cooling upgraded to level {level}."));
+                self.push_message(if recharged {
+                    format!("{name} cooling recharged to level {level}.")
+                } else {
+                    format!("{name} cooling upgraded to level {level}.")
+                });
             }
             StoreAction::UpgradeHardening => {
                 let (name, level) = {
                     let processor = processor_index
                         .and_then(|idx| self.state.processors.get_mut(idx))
                         .ok_or(PurchaseError::ProcessorSelectionRequired)?;
-                    if processor.hardening_level >= 3 {
+                    if processor.hardening_level >= processor.hardening_cap {
                         return Err(PurchaseError::UpgradeAtCap);
                     }
                     processor.hardening_level += 1;
-                    (processor.name.clone(), processor.hardening_level)
+                    (processor.label(), processor.hardening_level)
                 };
                 self.push_message(format!("{name} hardening increased to level {level}."));
             }
-            StoreAction::ApplyThermalPaste => {
-                self.state.thermal_paste_timer_ms = DAY_DURATION.as_millis() as u64;
-                self.push_message(
-                    "Thermal paste refreshed: cooling bonus active this cycle.".to_string(),
-                );
-            }
-            StoreAction::InstallDaemonFirmware => {
-                let name = {
+            StoreAction::ReinforceChassis => {
+                let (name, cap) = {
                     let processor = processor_index
                         .and_then(|idx| self.state.processors.get_mut(idx))
                         .ok_or(PurchaseError::ProcessorSelectionRequired)?;
-                    processor.daemon_unlocked = true;
-                    processor.daemon_penalty.quality = processor.daemon_penalty.quality.max(-3);
-                    processor.daemon_penalty.time_multiplier =
-                        (processor.daemon_penalty.time_multiplier - 0.02).max(1.02);
-                    processor.name.clone()
+                    processor.hardening_cap += 1;
+                    (processor.label(), processor.hardening_cap)
                 };
                 self.push_message(format!(
-                    "{name} daemon firmware installed. Automation penalties eased."
+                    "{name} chassis reinforced: hardening now goes to level {cap}."
                 ));
             }
-            StoreAction::ReplaceProcessor => {
+            StoreAction::InsureProcessor => {
+                let (name, premium) = {
+                    let processor = processor_index
+                        .and_then(|idx| self.state.processors.get_mut(idx))
+                        .ok_or(PurchaseError::ProcessorSelectionRequired)?;
+                    processor.insured = true;
+                    (processor.label(), economy::insurance_quote(processor))
+                };
+                self.push_message(format!(
+                    "{name} insured for {premium} cr/day: a burnout or loss pays {:.0}% of the \
+                     replacement and {:.0}% of the job it takes down.",
+                    economy::INSURANCE_REPLACEMENT_SHARE * 100.0,
+                    economy::INSURANCE_REWARD_SHARE * 100.0
+                ));
+            }
+            StoreAction::InstallFaradayShroud => {
+                let name = {
+                    let processor = processor_index
+                        .and_then(|idx| self.state.processors.get_mut(idx))
+                        .ok_or(PurchaseError::ProcessorSelectionRequired)?;
+                    processor.faraday_shroud = true;
+                    processor.label()
+                };
+                self.push_message(format!(
+                    "{name} fitted with a Faraday Shroud: ANGEL backlash can no longer destroy it."
+                ));
+            }
+            StoreAction::ApplyThermalPaste => {
+                self.state.thermal_paste_timer_ms = DAY_DURATION.as_millis() as u64;
+                self.push_message(
+                    "Thermal paste refreshed: cooling bonus active this cycle.".to_string(),
+                );
+            }
+            StoreAction::InstallDaemonFirmware => {
+                let on_trial = processor_index
+                    .and_then(|idx| self.state.processors.get(idx))
+                    .is_some_and(|processor| processor.daemon_trial);
+                if on_trial {
+                    self.end_daemon_trial(true);
+                }
+                let (name, level) = {
+                    let processor = processor_index
+                        .and_then(|idx| self.state.processors.get_mut(idx))
+                        .ok_or(PurchaseError::ProcessorSelectionRequired)?;
+                    // The trial's loaned firmware becomes the bought level 1.
+                    let level = if on_trial {
+                        1
+                    } else {
+                        (processor.daemon_firmware_level + 1).min(MAX_DAEMON_FIRMWARE_LEVEL)
+                    };
+                    let eased = DaemonPenalty::for_firmware(level);
+                    processor.daemon_firmware_level = level;
+                    processor.daemon_penalty.quality =
+                        processor.daemon_penalty.quality.max(eased.quality);
+                    processor.daemon_penalty.time_multiplier = processor
+                        .daemon_penalty
+                        .time_multiplier
+                        .min(eased.time_multiplier);
+                    (processor.label(), level)
+                };
+                let message = if level == 1 {
+                    format!("{name} daemon firmware installed. Automation penalties eased.")
+                } else {
+                    format!("{name} daemon firmware upgraded to level {level}.")
+                };
+                self.push_message(message);
+            }
+            StoreAction::InstallForecast => {
+                let days = self.forecast_days() + 1;
+                self.push_message(format!(
+                    "Forecast terminal online: market outlook visible {days} day(s) ahead."
+                ));
+            }
+            StoreAction::UpgradePanel => {
+                self.state.power_budget.capacity += economy::PANEL_UPGRADE_STEP;
+                self.push_message(format!(
+                    "Service panel upgraded: power budget now {:.1} kWh.",
+                    self.state.power_budget.capacity
+                ));
+            }
+            StoreAction::ServiceProcessor => {
                 let name = {
+                    let processor = processor_index
+                        .and_then(|idx| self.state.processors.get_mut(idx))
+                        .ok_or(PurchaseError::ProcessorSelectionRequired)?;
+                    processor.service();
+                    processor.label()
+                };
+                self.push_message(format!("{name} serviced: condition restored to Good."));
+            }
+            StoreAction::BuyRack => {
+                self.state.racks.push(RackState::default());
+                self.push_message(format!(
+                    "Rack {} installed; Shift+M mounts the focused unit.",
+                    self.state.racks.len()
+                ));
+            }
+            StoreAction::UpgradeRackCooling => {
+                let rack = processor_index
+                    .and_then(|idx| self.state.processors.get(idx))
+                    .and_then(|processor| processor.rack_id)
+                    .ok_or(PurchaseError::NotRacked)?;
+                let level = {
+                    let rack = &mut self.state.racks[rack as usize];
+                    rack.cooling_level += 1;
+                    rack.cooling_level
+                };
+                self.push_message(format!(
+                    "Rack {} cooling now +{level} for every unit in it.",
+                    rack + 1
+                ));
+            }
+            StoreAction::RepairProcessor => {
+                let (name, wear) = {
+                    let processor = processor_index
+                        .and_then(|idx| self.state.processors.get_mut(idx))
+                        .ok_or(PurchaseError::ProcessorSelectionRequired)?;
+                    processor.repair(REPAIR_WEAR_STEP);
+                    (processor.label(), processor.wear)
+                };
+                self.push_message(format!(
+                    "{name} patched up: wear down to {:.0}%.",
+                    wear * 100.0
+                ));
+            }
+            StoreAction::ReplaceProcessor => {
+                let (name, model, warranty) = {
                     let processor = processor_index
                         .and_then(|idx| self.state.processors.get_mut(idx))
                         .ok_or(PurchaseError::ProcessorSelectionRequired)?;
                     if processor.is_functional() {
                         return Err(PurchaseError::ProcessorHealthy);
                     }
+                    let warranty = processor.under_warranty();
                     processor.replace();
-                    processor.name.clone()
+                    processor.warranty_days_remaining = 0;
+                    (processor.label(), processor.name.clone(), warranty)
                 };
-                self.push_message(format!(
-                    "Replaced {name} chassis. Unit restored to service."
-                ));
+                if warranty {
+                    self.push_message(format!(
+                        "{name} covered under warranty: chassis replaced free. The warranty is used up."
+                    ));
+                } else {
+                    self.state.replacement_discounts.remove(&model);
+                    self.push_message(format!(
+                        "Replaced {name} chassis. Unit restored to service."
+                    ));
+                }
             }
             StoreAction::ReplaceModel => {
                 let name = {
@@ -482,26 +1338,43 @@ impl Game {
                 for unit in &mut self.state.processors {
                     if unit.name == name && !unit.is_functional() {
                         unit.replace();
+                        unit.warranty_days_remaining = 0;
                         replaced += 1;
                     }
                 }
                 if replaced == 0 {
                     return Err(PurchaseError::NoMatchingProcessors);
                 }
+                self.state.replacement_discounts.remove(&name);
                 self.push_message(format!(
                     "Replaced {replaced} units of {name}. Fleet restored.",
                 ));
             }
+            StoreAction::BuyProcessor { model } => {
+                let mut processor =
+                    ProcessorState::of_model(model).ok_or(PurchaseError::InvalidItem)?;
+                processor.id = self.state.next_processor_id;
+                self.state.next_processor_id += 1;
+                processor.extra_fragility = self.tuning.extra_fragility;
+                processor.warranty_days_remaining = WARRANTY_DAYS;
+                self.state.processors.push(processor);
+                self.push_message(format!(
+                    "{model} racked as unit {}, under warranty for {WARRANTY_DAYS} days.",
+                    self.state.processors.len()
+                ));
+            }
         }
         if !matches!(
             item.action,
             StoreAction::ReplaceProcessor | StoreAction::ReplaceModel
-        ) {
-            if let Some(entry) = self.state.store_purchases.get_mut(index) {
-                *entry += 1;
-            }
+        ) && let Some(entry) = self.state.store_purchases.get_mut(index)
+        {
+            *entry += 1;
         }
-        self.push_message(format!("Purchased {} (-{cost} cr)", item.name));
+        self.notify(
+            MessageCategory::Store,
+            format!("Purchased {} (-{cost} cr)", item.name),
+        );
         Ok(())
     }
 
@@ -509,8 +1382,98 @@ impl Game {
         economy::upkeep_total(&self.state.processors)
     }
 
+    /// The insurance premiums inside `total_upkeep`.
+    pub fn insurance_upkeep(&self) -> u64 {
+        self.state
+            .processors
+            .iter()
+            .map(economy::insurance_premium)
+            .sum()
+    }
+
+    /// Today's electricity bill as it stands if current draw holds.
     pub fn total_electricity_cost(&self) -> u64 {
-        economy::electricity_cost(&self.state.processors)
+        economy::projected_electricity_cost(
+            &self.state.processors,
+            self.electricity_rate(),
+            1.0 - self.day_progress(),
+        )
+    }
+
+    /// Each unit's projected share of today's electricity bill, by fleet
+    /// index; the shares sum to `total_electricity_cost`.
+    pub fn electricity_breakdown(&self) -> Vec<(usize, u64)> {
+        let rate = self.electricity_rate();
+        let day_remaining = 1.0 - self.day_progress();
+        self.state
+            .processors
+            .iter()
+            .enumerate()
+            .map(|(index, processor)| {
+                (
+                    index,
+                    economy::unit_electricity_cost(processor, rate, day_remaining),
+                )
+            })
+            .collect()
+    }
+
+    pub fn tuning(&self) -> &Tuning {
+        &self.tuning
+    }
+
+    /// Today's market rate after mutators.
+    pub fn electricity_rate(&self) -> f64 {
+        self.state.market_today.electricity_rate * self.tuning.electricity_multiplier
+    }
+
+    /// Credits held back for the next daily bill, or 0 when the reserve is off.
+    pub fn reserved_credits(&self) -> u64 {
+        match self.state.reserve_mode {
+            ReserveMode::Off => 0,
+            ReserveMode::Warn | ReserveMode::Enforce => {
+                self.total_upkeep() + self.total_electricity_cost()
+            }
+        }
+    }
+
+    pub fn cycle_reserve_mode(&mut self) {
+        self.state.reserve_mode = self.state.reserve_mode.next();
+        self.push_message(format!(
+            "Credit reserve: {}.",
+            self.state.reserve_mode.label()
+        ));
+    }
+
+    /// Lets the next purchase ignore an enforced reserve.
+    pub fn override_reserve_once(&mut self) {
+        self.reserve_override = true;
+    }
+
+    /// Drops an override the purchase never consumed, e.g. one that failed
+    /// before reaching the reserve check.
+    pub fn clear_reserve_override(&mut self) {
+        self.reserve_override = false;
+    }
+
+    /// Days of market outlook revealed by the installed Forecast Terminal tier.
+    pub fn forecast_days(&self) -> usize {
+        Self::store_index_for(StoreAction::InstallForecast)
+            .and_then(|index| self.store_purchases(index))
+            .map(|tier| (tier as usize).min(OUTLOOK_DAYS))
+            .unwrap_or(0)
+    }
+
+    /// Upcoming market conditions visible to the player, soonest first.
+    pub fn visible_forecast(&self) -> impl Iterator<Item = &DailyOutlook> {
+        self.state.market_outlook.iter().take(self.forecast_days())
+    }
+
+    fn fill_market_outlook(&mut self) {
+        while self.state.market_outlook.len() < OUTLOOK_DAYS {
+            let outlook = DailyOutlook::roll(&mut self.rng);
+            self.state.market_outlook.push_back(outlook);
+        }
     }
 
     pub fn total_power_draw(&self) -> f64 {
@@ -521,72 +1484,346 @@ impl Game {
             .sum()
     }
 
+    pub fn cycle_number_style(&mut self) {
+        let display = &mut self.state.display;
+        display.number_style = display.number_style.next();
+        let label = display.number_style.label();
+        self.push_message(format!("Number style: {label}."));
+    }
+
+    pub fn cycle_number_separator(&mut self) {
+        let display = &mut self.state.display;
+        display.separator = display.separator.next();
+        let label = display.separator.label();
+        self.push_message(format!("Thousands separator: {label}."));
+    }
+
+    pub fn toggle_save_compression(&mut self) {
+        self.state.compress_saves = !self.state.compress_saves;
+        self.push_message(if self.state.compress_saves {
+            "Saves will be gzip-compressed (save.ron.gz).".to_string()
+        } else {
+            "Saves will be written as plain RON.".to_string()
+        });
+    }
+
+    pub fn toggle_spare_worn_units(&mut self) {
+        self.state.spare_worn_units = !self.state.spare_worn_units;
+        self.push_message(if self.state.spare_worn_units {
+            "Automation now saves near-death units for last.".to_string()
+        } else {
+            "Automation treats near-death units like the rest.".to_string()
+        });
+    }
+
+    /// Steps the auto-replace floor by `AUTO_REPLACE_FLOOR_STEP`.
+    pub fn adjust_auto_replace_floor(&mut self, raise: bool) {
+        let floor = &mut self.state.auto_replace_floor;
+        *floor = if raise {
+            floor.saturating_add(AUTO_REPLACE_FLOOR_STEP)
+        } else {
+            floor.saturating_sub(AUTO_REPLACE_FLOOR_STEP)
+        };
+        let floor = *floor;
+        self.push_message(format!(
+            "Auto-replace keeps at least {floor} cr in the treasury."
+        ));
+    }
+
+    pub fn toggle_power_budget(&mut self) {
+        let budget = &mut self.state.power_budget;
+        budget.enabled = !budget.enabled;
+        let message = if budget.enabled {
+            format!("Power budget enforced at {:.1} kWh.", budget.capacity)
+        } else {
+            "Power budget disabled; breakers reset.".to_string()
+        };
+        self.push_message(message);
+        if !self.state.power_budget.enabled {
+            let cooling_bonus = self.cooling_bonus_levels();
+            self.restore_paused_processors(cooling_bonus);
+        }
+    }
+
+    /// Draw the panel is committed to: live draw plus what paused units will
+    /// pull once their breakers close again.
+    fn committed_power_draw(&self, cooling_bonus_levels: u8) -> f64 {
+        let ambient = self.ambient_temperature();
+        self.state
+            .processors
+            .iter()
+            .map(|processor| match &processor.status {
+                ProcessorStatus::Working(work) if work.paused => {
+                    let cooling = cooling_bonus_levels + self.rack_cooling(processor);
+                    processor
+                        .evaluate_job(&work.job, cooling, ambient)
+                        .power_draw
+                }
+                _ => processor.last_power_draw(),
+            })
+            .sum()
+    }
+
+    /// Trips breakers on the highest-draw working units until the fleet fits
+    /// the budget, or closes them again once there is headroom.
+    fn enforce_power_budget(&mut self, cooling_bonus_levels: u8) {
+        if !self.state.power_budget.enabled {
+            self.restore_paused_processors(cooling_bonus_levels);
+            return;
+        }
+        let capacity = self.state.power_budget.capacity;
+        let mut total = self.total_power_draw();
+        let mut tripped = false;
+        while total > capacity {
+            let victim = self
+                .state
+                .processors
+                .iter()
+                .enumerate()
+                .filter(|(_, processor)| {
+                    matches!(&processor.status, ProcessorStatus::Working(work) if !work.paused)
+                })
+                .max_by(|(_, a), (_, b)| {
+                    a.last_power_draw()
+                        .partial_cmp(&b.last_power_draw())
+                        .unwrap_or(Ordering::Equal)
+                })
+                .map(|(index, _)| index);
+            let Some(index) = victim else {
+                break;
+            };
+            let processor = &mut self.state.processors[index];
+            let ProcessorStatus::Working(work) = &mut processor.status else {
+                break;
+            };
+            work.paused = true;
+            total -= processor.last_power_draw;
+            processor.last_power_draw = 0.0;
+            let name = processor.label();
+            self.state.power_budget.trips += 1;
+            tripped = true;
+            self.push_message(format!(
+                "Breaker tripped: {name} paused to hold {capacity:.1} kWh."
+            ));
+        }
+        if tripped {
+            return;
+        }
+        let ambient = self.ambient_temperature();
+        for index in 0..self.state.processors.len() {
+            let processor = &mut self.state.processors[index];
+            let ProcessorStatus::Working(work) = &processor.status else {
+                continue;
+            };
+            if !work.paused {
+                continue;
+            }
+            let cooling = cooling_bonus_levels + racks::cooling_for(&self.state.racks, processor);
+            let demand = processor
+                .evaluate_job(&work.job, cooling, ambient)
+                .power_draw;
+            if total + demand > capacity {
+                continue;
+            }
+            total += demand;
+            processor.last_power_draw = demand;
+            if let ProcessorStatus::Working(work) = &mut processor.status {
+                work.paused = false;
+            }
+            let name = processor.label();
+            self.push_message(format!("Breaker reset: {name} back online."));
+        }
+    }
+
+    fn restore_paused_processors(&mut self, cooling_bonus_levels: u8) {
+        let ambient = self.ambient_temperature();
+        for processor in &mut self.state.processors {
+            let ProcessorStatus::Working(work) = &processor.status else {
+                continue;
+            };
+            if !work.paused {
+                continue;
+            }
+            let cooling = cooling_bonus_levels + racks::cooling_for(&self.state.racks, processor);
+            processor.last_power_draw = processor
+                .evaluate_job(&work.job, cooling, ambient)
+                .power_draw;
+            if let ProcessorStatus::Working(work) = &mut processor.status {
+                work.paused = false;
+            }
+        }
+    }
+
     pub fn thermal_paste_active(&self) -> bool {
         self.state.thermal_paste_timer_ms > 0
     }
 
     pub fn accept_assist_suggestion(&mut self, processor_index: usize) -> bool {
+        self.accept_assist_choice(processor_index, 0)
+    }
+
+    /// Takes the suggestion at `rank` (0 is the top pick) from the unit's
+    /// ranked list.
+    pub fn accept_assist_choice(&mut self, processor_index: usize, rank: usize) -> bool {
         let processor_name = {
             let Some(processor) = self.state.processors.get(processor_index) else {
                 self.push_message("Select a valid processor.".to_string());
                 return false;
             };
-            if !processor.daemon_unlocked || processor.daemon_mode != DaemonMode::Assist {
+            if !processor.daemon_unlocked() || processor.daemon_mode != DaemonMode::Assist {
                 self.push_message(format!(
                     "{} is not running Assist automation.",
-                    processor.name
+                    processor.label()
                 ));
                 return false;
             }
             if !processor.is_functional() {
                 self.push_message(format!(
                     "{} is offline and cannot take suggestions.",
-                    processor.name
+                    processor.label()
                 ));
                 return false;
             }
             if !processor.is_idle() {
-                self.push_message(format!("{} is already working.", processor.name));
+                self.push_message(format!("{} is already working.", processor.label()));
                 return false;
             }
-            processor.name.clone()
+            processor.label()
         };
 
-        let Some(suggestion) = self.assist_suggestion(processor_index) else {
-            self.push_message(format!(
-                "{processor_name} has no suggestions ready. Queue a job manually."
-            ));
+        let suggestions = self.assist_suggestions(processor_index, ASSIST_CHOICES);
+        let Some(suggestion) = suggestions.into_iter().nth(rank) else {
+            if rank == 0 {
+                self.push_message(format!(
+                    "{processor_name} has no suggestions ready. Queue a job manually."
+                ));
+            } else {
+                self.push_message(format!("{processor_name} has no suggestion #{}.", rank + 1));
+            }
             return false;
         };
 
-        if suggestion.job_index >= self.state.jobs.len() {
+        let result = if suggestion.pending {
+            self.assign_pending_at(suggestion.job_index, processor_index)
+        } else if suggestion.job_index >= self.state.jobs.len() {
             self.push_message("Suggested job is no longer available.".to_string());
             return false;
-        }
-
-        let job = self.state.jobs.remove(suggestion.job_index);
-        let job_clone = job.clone();
-        match self.assign_job_to_processor(job_clone, processor_index, false) {
+        } else {
+            self.assign_board_job(suggestion.job_index, processor_index)
+        };
+        match result {
             Ok(()) => true,
             Err(err) => {
-                let reinsertion = suggestion.job_index.min(self.state.jobs.len());
-                self.state.jobs.insert(reinsertion, job);
                 self.push_message(format!("Assist assignment failed: {err}"));
                 false
             }
         }
     }
 
+    /// The job a suggestion points at, whether pending or on the board.
+    pub fn suggested_job(&self, suggestion: &AssistSuggestion) -> Option<&Job> {
+        if suggestion.pending {
+            self.state.pending_jobs.get(suggestion.job_index)
+        } else {
+            self.state.jobs.get(suggestion.job_index)
+        }
+    }
+
+    /// Every board job the processor can run, annotated with the ETA and risk
+    /// it would carry on that unit (manual assignment, no daemon penalty).
+    pub fn job_candidates(&self, processor_index: usize) -> Vec<AssistSuggestion> {
+        let Some(processor) = self.state.processors.get(processor_index) else {
+            return Vec::new();
+        };
+        let cooling_bonus = self.cooling_bonus_levels() + self.rack_cooling(processor);
+        let ambient = self.ambient_temperature();
+        self.state
+            .jobs
+            .iter()
+            .enumerate()
+            .filter(|(_, job)| processor.supports(&job.tag))
+            .map(|(job_index, job)| {
+                let evaluation = processor.evaluate_job(job, cooling_bonus, ambient);
+                let eta_secs =
+                    economy::assignment_duration_ms(job, processor, None) as f64 / 1000.0;
+                AssistSuggestion {
+                    job_index,
+                    pending: false,
+                    eta_secs,
+                    reliability: evaluation.reliability,
+                    heat: evaluation.heat,
+                    reward: job.base_reward,
+                    score: reward_rate(job.base_reward, eta_secs),
+                }
+            })
+            .collect()
+    }
+
+    /// Moves a board job straight onto a processor, leaving the board untouched
+    /// if the assignment is rejected.
+    pub fn assign_board_job(
+        &mut self,
+        job_index: usize,
+        processor_index: usize,
+    ) -> Result<(), AssignmentError> {
+        let Some(job) = self.state.jobs.get(job_index).cloned() else {
+            return Err(AssignmentError::InvalidJob);
+        };
+        self.assign_job_to_processor(job, processor_index, false)?;
+        self.state.jobs.remove(job_index);
+        Ok(())
+    }
+
+    fn cooling_bonus_levels(&self) -> u8 {
+        if self.state.thermal_paste_timer_ms > 0 {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// Shared cooling the unit gets from its rack, if it's in one.
+    fn rack_cooling(&self, processor: &ProcessorState) -> u8 {
+        racks::cooling_for(&self.state.racks, processor)
+    }
+
+    /// Weather heat every unit takes on: the day's drift plus any heat wave.
+    pub fn ambient_temperature(&self) -> f64 {
+        let wave = if self.state.heat_wave_days > 0 {
+            TUNING.heat_wave_heat
+        } else {
+            0.0
+        };
+        self.state.ambient_temperature + wave
+    }
+
+    fn has_offline_of_model(&self, name: &str) -> bool {
+        self.state
+            .processors
+            .iter()
+            .any(|processor| processor.name == name && !processor.is_functional())
+    }
+
     fn replacement_cost_for_model(&self, name: &str) -> u64 {
+        let discount = self.replacement_discount(name);
         self.state
             .processors
             .iter()
             .filter(|processor| processor.name == name && !processor.is_functional())
-            .map(replacement_cost_for_processor)
+            .map(|processor| replacement_cost_for_processor(processor, discount))
             .sum()
     }
 
-    fn store_index_for(action: StoreAction) -> Option<usize> {
+    /// Fractional discount earned from diagnostics on this model, if active.
+    pub fn replacement_discount(&self, model: &str) -> f64 {
+        if self.state.replacement_discounts.contains_key(model) {
+            DIAGNOSTIC_DISCOUNT
+        } else {
+            0.0
+        }
+    }
+
+    pub fn store_index_for(action: StoreAction) -> Option<usize> {
         STORE_ITEMS.iter().position(|item| item.action == action)
     }
 
@@ -597,6 +1834,13 @@ impl Game {
         self.purchase_item(store_index, processor_index)
     }
 
+    pub fn repair_processor_direct(&mut self, index: usize) -> Result<(), PurchaseError> {
+        let store_index = Self::store_index_for(StoreAction::RepairProcessor)
+            .ok_or(PurchaseError::InvalidItem)?;
+        let processor_index = Some(index);
+        self.purchase_item(store_index, processor_index)
+    }
+
     pub fn replace_model_direct(&mut self, index: usize) -> Result<(), PurchaseError> {
         let store_index =
             Self::store_index_for(StoreAction::ReplaceModel).ok_or(PurchaseError::InvalidItem)?;
@@ -604,48 +1848,275 @@ impl Game {
         self.purchase_item(store_index, processor_index)
     }
 
-    pub fn cycle_daemon_mode(&mut self, index: usize) {
-        let message = if let Some(processor) = self.state.processors.get_mut(index) {
-            if !self.state.daemon_unlocked || !processor.daemon_unlocked {
-                Some(format!(
-                    "{} lacks daemon firmware. Install microcode to unlock.",
-                    processor.name
-                ))
-            } else if !processor.is_functional() {
-                Some(format!(
-                    "{} is offline and cannot change automation mode.",
-                    processor.name
-                ))
-            } else {
-                processor.daemon_mode = match processor.daemon_mode {
-                    DaemonMode::Off => DaemonMode::Assist,
-                    DaemonMode::Assist => DaemonMode::Auto,
-                    DaemonMode::Auto => DaemonMode::Off,
-                };
-                let label = match processor.daemon_mode {
-                    DaemonMode::Off => "Off",
-                    DaemonMode::Assist => "Assist",
-                    DaemonMode::Auto => "Auto",
-                };
-                Some(format!("{} automation mode -> {label}.", processor.name))
-            }
-        } else {
-            Some("Select a valid processor.".to_string())
-        };
-        if let Some(msg) = message {
-            self.push_message(msg);
+    /// Credits returned for retiring the processor: functional units resell
+    /// at a wear-adjusted fraction of their price, offline units scrap for 0.
+    pub fn resale_value(&self, index: usize) -> Option<u64> {
+        let processor = self.state.processors.get(index)?;
+        if !processor.is_functional() {
+            return Some(0);
         }
+        let condition = (1.0 - processor.wear).clamp(0.0, 1.0);
+        Some((processor.purchase_cost as f64 * RESALE_RATIO * condition).round() as u64)
     }
 
-    pub fn toggle_honor_cooling(&mut self, index: usize) {
-        let message = if let Some(processor) = self.state.processors.get_mut(index) {
-            processor.honor_cooling_mins = !processor.honor_cooling_mins;
-            let state = if processor.honor_cooling_mins {
+    /// Sells a functional processor or scraps an offline one, enshrining
+    /// long-serving units in the museum.
+    pub fn retire_processor(&mut self, index: usize) -> Result<u64, RetireError> {
+        let processor = self
+            .state
+            .processors
+            .get(index)
+            .ok_or(RetireError::InvalidProcessor)?;
+        if matches!(processor.status, ProcessorStatus::Working(_)) {
+            return Err(RetireError::ProcessorBusy);
+        }
+        if self.state.processors.len() == 1 {
+            return Err(RetireError::LastProcessor);
+        }
+        let value = self.resale_value(index).unwrap_or(0);
+        let processor = self.detach_processor(index);
+        if value > 0 {
+            self.credit(value);
+            self.push_message(format!("Sold {} for {value} cr.", processor.label()));
+        } else {
+            self.push_message(format!("Scrapped {}.", processor.label()));
+        }
+        self.enshrine(&processor);
+        Ok(value)
+    }
+
+    /// Models the store sells, in catalog order.
+    pub fn catalog_models() -> Vec<&'static str> {
+        STORE_ITEMS
+            .iter()
+            .filter_map(|item| match item.action {
+                StoreAction::BuyProcessor { model } => Some(model),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Stats of a fresh `model` beside the processor at `processor_index`,
+    /// with both projected against the pending job.
+    pub fn compare_models(
+        &self,
+        model: &str,
+        processor_index: Option<usize>,
+    ) -> Option<ModelComparison> {
+        let candidate = ProcessorState::of_model(model)?;
+        let pending = self.state.pending_jobs.first();
+        Some(ModelComparison {
+            candidate: ModelStats::of(&candidate, pending),
+            current: processor_index
+                .and_then(|index| self.state.processors.get(index))
+                .map(|processor| ModelStats::of(processor, pending)),
+            pending_job: pending.map(|job| job.name.clone()),
+        })
+    }
+
+    /// What trading the processor at `index` in for `model` costs: the new
+    /// unit's price less the old one's resale value, never below zero.
+    pub fn trade_in_cost(&self, index: usize, model: &str) -> Result<u64, PurchaseError> {
+        let processor = self
+            .state
+            .processors
+            .get(index)
+            .ok_or(PurchaseError::ProcessorSelectionRequired)?;
+        let target = ProcessorState::of_model(model).ok_or(PurchaseError::InvalidItem)?;
+        if matches!(processor.status, ProcessorStatus::Working(_)) {
+            return Err(PurchaseError::ProcessorBusy);
+        }
+        if processor.name == target.name {
+            return Err(PurchaseError::SameModel);
+        }
+        let credit = self.resale_value(index).unwrap_or(0);
+        Ok(target.purchase_cost.saturating_sub(credit))
+    }
+
+    /// Swaps the processor at `index` for a new `model` in the same slot.
+    /// The slot keeps its id, rack, daemon setup, installed microcode and
+    /// lifetime record; the hardware, its upgrades and wear are new.
+    pub fn trade_in(&mut self, index: usize, model: &str) -> Result<(), PurchaseError> {
+        let cost = self.trade_in_cost(index, model)?;
+        let mut unit = ProcessorState::of_model(model).ok_or(PurchaseError::InvalidItem)?;
+        self.spend(cost)?;
+        let old = &self.state.processors[index];
+        unit.id = old.id;
+        unit.rack_id = old.rack_id;
+        for tag in &old.instruction_set {
+            if !unit.supports(tag) {
+                unit.instruction_set.push(tag.clone());
+            }
+        }
+        unit.daemon_mode = old.daemon_mode;
+        unit.daemon_firmware_level = old.daemon_firmware_level;
+        unit.daemon_trial = old.daemon_trial;
+        unit.daemon_penalty = old.daemon_penalty.clone();
+        unit.daemon_affinity = old.daemon_affinity.clone();
+        unit.daemon_blocked_tags = old.daemon_blocked_tags.clone();
+        unit.daemon_priority = old.daemon_priority;
+        unit.honor_cooling_mins = old.honor_cooling_mins;
+        unit.daemon_preempt = old.daemon_preempt;
+        unit.preempt_factor = old.preempt_factor;
+        unit.allow_utility = old.allow_utility;
+        unit.standby_after_ms = old.standby_after_ms;
+        unit.clock = old.clock;
+        unit.jobs_completed = old.jobs_completed;
+        unit.best_quality = old.best_quality;
+        unit.jobs_failed = old.jobs_failed;
+        unit.credits_earned = old.credits_earned;
+        unit.total_work_ms = old.total_work_ms;
+        unit.energy_today = old.energy_today;
+        unit.extra_fragility = self.tuning.extra_fragility;
+        unit.warranty_days_remaining = WARRANTY_DAYS;
+        unit.insured = old.insured;
+        unit.auto_replace = old.auto_replace;
+        let old_name = std::mem::replace(&mut self.state.processors[index], unit).label();
+        self.push_message(format!(
+            "Traded {old_name} in for a {model} in slot {} ({cost} cr).",
+            index + 1
+        ));
+        Ok(())
+    }
+
+    /// Credits the parts of a burnt-out or destroyed unit fetch; None for
+    /// units still running.
+    pub fn salvage_value(&self, index: usize) -> Option<u64> {
+        let processor = self.state.processors.get(index)?;
+        if processor.is_functional() {
+            return None;
+        }
+        Some((processor.purchase_cost as f64 * SALVAGE_RATIO).round() as u64)
+    }
+
+    /// Strips an offline unit for parts: it leaves the fleet for a small
+    /// refund and, now and then, a free application of thermal paste.
+    pub fn salvage_processor(&mut self, index: usize) -> Result<u64, RetireError> {
+        let processor = self
+            .state
+            .processors
+            .get(index)
+            .ok_or(RetireError::InvalidProcessor)?;
+        if processor.is_functional() {
+            return Err(RetireError::StillOperational);
+        }
+        if self.state.processors.len() == 1 {
+            return Err(RetireError::LastProcessor);
+        }
+        let value = self.salvage_value(index).unwrap_or(0);
+        let processor = self.detach_processor(index);
+        self.credit(value);
+        self.push_message(format!(
+            "Salvaged {} for parts: {value} cr.",
+            processor.label()
+        ));
+        if self.rng.gen_bool(SALVAGE_PASTE_CHANCE) {
+            self.state.thermal_paste_timer_ms = DAY_DURATION.as_millis() as u64;
+            self.push_message(
+                "The teardown turned up sealed thermal paste: cooling bonus active this cycle."
+                    .to_string(),
+            );
+        }
+        self.enshrine(&processor);
+        Ok(value)
+    }
+
+    /// Takes the unit out of the fleet, dropping any manual hold on it.
+    fn detach_processor(&mut self, index: usize) -> ProcessorState {
+        let processor = self.state.processors.remove(index);
+        if matches!(self.manual_intent, Some((held, _)) if held == processor.id) {
+            self.manual_intent = None;
+        }
+        processor
+    }
+
+    /// Adds long-serving units leaving the fleet to the museum.
+    fn enshrine(&mut self, processor: &ProcessorState) {
+        if museum::qualifies(processor) {
+            self.state
+                .museum
+                .push(MuseumEntry::from_processor(processor, &self.state.mutators));
+            self.push_message(format!(
+                "{} retires with honors after {} jobs; added to the museum.",
+                processor.label(),
+                processor.jobs_completed
+            ));
+        }
+    }
+
+    pub fn museum_quality_bonus(&self) -> u8 {
+        museum::fleet_quality_bonus(&self.state.museum)
+    }
+
+    pub fn cycle_daemon_mode(&mut self, index: usize) {
+        let message = if let Some(processor) = self.state.processors.get_mut(index) {
+            if !self.state.daemon_unlocked || !processor.daemon_unlocked() {
+                Some(format!(
+                    "{} lacks daemon firmware. Install microcode to unlock.",
+                    processor.label()
+                ))
+            } else if !processor.is_functional() {
+                Some(format!(
+                    "{} is offline and cannot change automation mode.",
+                    processor.label()
+                ))
+            } else {
+                processor.daemon_mode = processor.daemon_mode.next();
+                Some(format!(
+                    "{} automation mode -> {}.",
+                    processor.label(),
+                    processor.daemon_mode.label()
+                ))
+            }
+        } else {
+            Some("Select a valid processor.".to_string())
+        };
+        if let Some(msg) = message {
+            self.push_message(msg);
+        }
+    }
+
+    /// Steps the fleet default to the next mode and applies it everywhere.
+    pub fn cycle_fleet_daemon_mode(&mut self) -> usize {
+        let mode = self.state.fleet_daemon_mode.next();
+        self.set_all_daemon_modes(mode)
+    }
+
+    /// Puts every unit with firmware that is still running into `mode`;
+    /// locked and offline units are skipped. Returns how many were set.
+    pub fn set_all_daemon_modes(&mut self, mode: DaemonMode) -> usize {
+        self.state.fleet_daemon_mode = mode;
+        let unlocked = self.state.daemon_unlocked;
+        let mut set = 0;
+        for processor in &mut self.state.processors {
+            if unlocked && processor.daemon_unlocked() && processor.is_functional() {
+                processor.daemon_mode = mode;
+                set += 1;
+            }
+        }
+        let skipped = self.state.processors.len() - set;
+        let label = mode.label();
+        self.push_message(if skipped == 0 {
+            format!("{set} processors set to {label}.")
+        } else {
+            format!("{set} processors set to {label}, {skipped} skipped: locked/offline.")
+        });
+        set
+    }
+
+    pub fn toggle_honor_cooling(&mut self, index: usize) {
+        let message = if let Some(processor) = self.state.processors.get_mut(index) {
+            processor.honor_cooling_mins = !processor.honor_cooling_mins;
+            let state = if processor.honor_cooling_mins {
                 "will honor cooling minimums"
             } else {
                 "will override cooling minimums"
             };
-            Some(format!("{} {} when auto-assigning.", processor.name, state))
+            Some(format!(
+                "{} {} when auto-assigning.",
+                processor.label(),
+                state
+            ))
         } else {
             Some("Select a valid processor.".to_string())
         };
@@ -654,20 +2125,248 @@ impl Game {
         }
     }
 
+    pub fn toggle_daemon_preempt(&mut self, index: usize) {
+        let message = if let Some(processor) = self.state.processors.get_mut(index) {
+            processor.daemon_preempt = !processor.daemon_preempt;
+            if processor.daemon_preempt {
+                format!(
+                    "{} may now abandon fresh daemon jobs for {:.1}x better work.",
+                    processor.label(),
+                    processor.preempt_factor
+                )
+            } else {
+                format!(
+                    "{} will finish every daemon job it starts.",
+                    processor.label()
+                )
+            }
+        } else {
+            "Select a valid processor.".to_string()
+        };
+        self.push_message(message);
+    }
+
+    /// Benches or resumes the processor at `index`. A unit mid-job must
+    /// finish or cancel it first.
+    pub fn toggle_paused(&mut self, index: usize) -> Result<(), AssignmentError> {
+        let processor = self
+            .state
+            .processors
+            .get_mut(index)
+            .ok_or(AssignmentError::InvalidProcessor)?;
+        let idle = matches!(processor.status, ProcessorStatus::Idle);
+        if !processor.paused && !idle && processor.is_functional() {
+            return Err(AssignmentError::ProcessorBusy);
+        }
+        processor.paused = !processor.paused;
+        if idle {
+            processor.last_power_draw = processor.idle_power_draw();
+        }
+        let message = if processor.paused {
+            format!(
+                "{} paused; it takes no work until resumed.",
+                processor.label()
+            )
+        } else {
+            format!("{} resumed.", processor.label())
+        };
+        self.push_message(message);
+        Ok(())
+    }
+
+    /// Books the processor at `index` for maintenance once its job is done,
+    /// or cancels the booking. Idle units go down on the next tick.
+    pub fn toggle_maintenance(&mut self, index: usize) -> Result<(), AssignmentError> {
+        let processor = self
+            .state
+            .processors
+            .get_mut(index)
+            .ok_or(AssignmentError::InvalidProcessor)?;
+        if !processor.is_functional() {
+            return Err(AssignmentError::ProcessorInoperative);
+        }
+        if processor.is_servicing() {
+            return Err(AssignmentError::ProcessorServicing);
+        }
+        processor.maintenance_scheduled = !processor.maintenance_scheduled;
+        let idle = matches!(processor.status, ProcessorStatus::Idle);
+        let message = match (processor.maintenance_scheduled, idle) {
+            (true, true) => format!("{} going down for maintenance.", processor.label()),
+            (true, false) => format!(
+                "{} booked for maintenance after its current job.",
+                processor.label()
+            ),
+            (false, _) => format!("{} maintenance cancelled.", processor.label()),
+        };
+        self.push_message(message);
+        Ok(())
+    }
+
+    /// Steps the processor at `index` through Stock, Eco, and Overclock;
+    /// offline units can't be changed. A job already running keeps its
+    /// duration.
+    pub fn cycle_clock_mode(&mut self, index: usize) -> Result<(), AssignmentError> {
+        let processor = self
+            .state
+            .processors
+            .get_mut(index)
+            .ok_or(AssignmentError::InvalidProcessor)?;
+        if !processor.is_functional() {
+            return Err(AssignmentError::ProcessorInoperative);
+        }
+        processor.clock = processor.clock.next();
+        if matches!(processor.status, ProcessorStatus::Idle) {
+            processor.last_power_draw = processor.idle_power_draw();
+        }
+        let message = match processor.clock {
+            ClockMode::Stock => format!("{} back to stock clocks.", processor.label()),
+            ClockMode::Eco => format!(
+                "{} undervolted: x{:.1} power, jobs run slower.",
+                processor.label(),
+                TUNING.eco_power
+            ),
+            ClockMode::Overclock => format!(
+                "{} overclocked: x{:.1} speed, running hotter and wearing faster.",
+                processor.label(),
+                TUNING.overclock_speed
+            ),
+        };
+        self.push_message(message);
+        Ok(())
+    }
+
+    /// Steps how long the processor at `index` idles before standby, then
+    /// turns standby off so it always answers at once.
+    pub fn cycle_standby(&mut self, index: usize) -> Result<(), AssignmentError> {
+        let processor = self
+            .state
+            .processors
+            .get_mut(index)
+            .ok_or(AssignmentError::InvalidProcessor)?;
+        if !processor.is_functional() {
+            return Err(AssignmentError::ProcessorInoperative);
+        }
+        processor.cycle_standby();
+        if matches!(processor.status, ProcessorStatus::Idle) {
+            processor.last_power_draw = processor.idle_power_draw();
+        }
+        let message = match processor.standby_after_ms {
+            Some(after) => format!(
+                "{} drops to standby after {:.0}s idle; waking adds {:.1}s.",
+                processor.label(),
+                after as f64 / 1000.0,
+                STANDBY_SPIN_UP_MS as f64 / 1000.0
+            ),
+            None => format!(
+                "{} standby off: always ready, full idle draw.",
+                processor.label()
+            ),
+        };
+        self.push_message(message);
+        Ok(())
+    }
+
+    /// Nudges the processor's daemon priority by `delta`, within
+    /// ±`MAX_DAEMON_PRIORITY`. Auto hands jobs to higher priorities first.
+    pub fn adjust_daemon_priority(&mut self, index: usize, delta: i32) {
+        let message = if let Some(processor) = self.state.processors.get_mut(index) {
+            let priority = (processor.daemon_priority + delta)
+                .clamp(-MAX_DAEMON_PRIORITY, MAX_DAEMON_PRIORITY);
+            if priority == processor.daemon_priority {
+                format!(
+                    "{} daemon priority is already {priority:+}.",
+                    processor.label()
+                )
+            } else {
+                processor.daemon_priority = priority;
+                format!("{} daemon priority set to {priority:+}.", processor.label())
+            }
+        } else {
+            "Select a valid processor.".to_string()
+        };
+        self.push_message(message);
+    }
+
+    /// Shifts how much the processor's daemon favors `tag`, in tenths,
+    /// within ±`MAX_DAEMON_AFFINITY`. A neutral tag drops out of the map.
+    pub fn adjust_daemon_affinity(&mut self, index: usize, tag: &str, delta: f64) -> Option<f64> {
+        let processor = self.state.processors.get_mut(index)?;
+        let current = processor.daemon_affinity.get(tag).copied().unwrap_or(0.0);
+        let value = ((current + delta) * 10.0).round() / 10.0;
+        let value = value.clamp(-MAX_DAEMON_AFFINITY, MAX_DAEMON_AFFINITY);
+        if value == 0.0 {
+            processor.daemon_affinity.remove(tag);
+        } else {
+            processor.daemon_affinity.insert(tag.to_string(), value);
+        }
+        Some(value)
+    }
+
+    /// Blocks or unblocks `tag` for the processor's automation. Returns
+    /// whether the tag is now blocked.
+    pub fn toggle_daemon_block(&mut self, index: usize, tag: &str) -> Option<bool> {
+        let processor = self.state.processors.get_mut(index)?;
+        let blocked = &mut processor.daemon_blocked_tags;
+        let now_blocked = match blocked.binary_search_by(|known| known.as_str().cmp(tag)) {
+            Ok(position) => {
+                blocked.remove(position);
+                false
+            }
+            Err(position) => {
+                blocked.insert(position, tag.to_string());
+                true
+            }
+        };
+        let name = processor.label();
+        self.push_message(if now_blocked {
+            format!("{name} automation will never take {tag} jobs.")
+        } else {
+            format!("{name} automation may take {tag} jobs again.")
+        });
+        Some(now_blocked)
+    }
+
+    pub fn toggle_allow_utility(&mut self, index: usize) {
+        let message = if let Some(processor) = self.state.processors.get_mut(index) {
+            processor.allow_utility = !processor.allow_utility;
+            if processor.allow_utility {
+                format!("{} daemon may now run utility jobs.", processor.label())
+            } else {
+                format!("{} daemon will skip utility jobs.", processor.label())
+            }
+        } else {
+            "Select a valid processor.".to_string()
+        };
+        self.push_message(message);
+    }
+
+    pub fn toggle_auto_replace(&mut self, index: usize) {
+        let message = if let Some(processor) = self.state.processors.get_mut(index) {
+            processor.auto_replace = !processor.auto_replace;
+            if processor.auto_replace {
+                format!(
+                    "{} will be replaced automatically when it fails.",
+                    processor.label()
+                )
+            } else {
+                format!("{} waits for a manual replacement.", processor.label())
+            }
+        } else {
+            "Select a valid processor.".to_string()
+        };
+        self.push_message(message);
+    }
+
+    /// Licenses `tag` for the job stream; units still need it installed.
     fn unlock_instruction_tag(&mut self, tag: &str) -> bool {
         if self.is_instruction_unlocked(tag) {
             return false;
         }
         self.state.unlocked_tags.push(tag.to_string());
-        for processor in &mut self.state.processors {
-            if !processor.supports(tag) {
-                processor.instruction_set.push(tag.to_string());
-            }
-        }
         true
     }
 
-    fn choose_job_tag<'a>(&'a mut self) -> &'a str {
+    fn choose_job_tag(&mut self) -> &str {
         let mut pool: Vec<&str> = Vec::new();
         for tag in &self.state.unlocked_tags {
             if !self
@@ -678,8 +2377,10 @@ impl Game {
             {
                 continue;
             }
-            let weight = if tag == jobs::GENERAL_TAG { 4 } else { 2 };
-            for _ in 0..weight {
+            if tag == jobs::ANGEL_TAG && !self.rng.gen_bool(ANGEL_POST_CHANCE) {
+                continue;
+            }
+            for _ in 0..jobs::templates().weight(tag) {
                 pool.push(tag.as_str());
             }
         }
@@ -695,305 +2396,1682 @@ impl Game {
         if self.state.jobs.len() >= MAX_JOBS {
             return;
         }
+        if self.state.contracts.len() < MAX_ACTIVE_CONTRACTS && self.rng.gen_bool(CONTRACT_CHANCE) {
+            self.open_contract();
+            return;
+        }
         self.state.job_counter += 1;
         let tag = self.choose_job_tag().to_string();
-        let job = jobs::generate_job_with_tag(self.state.job_counter, &tag, &mut self.rng);
+        let mut job = jobs::generate_job_with_tag(
+            self.state.job_counter,
+            &tag,
+            self.state.reputation,
+            &mut self.rng,
+        );
+        job.base_reward =
+            ((job.base_reward as f64) * self.state.market_today.demand).round() as u64;
         let job_name = job.name.clone();
+        let subject = LogSubject::Job(job.id);
         self.state.jobs.push(job);
-        self.push_message(format!("New job posted: {job_name} [{tag}]"));
+        self.push_message_about(subject, format!("New job posted: {job_name} [{tag}]"));
     }
 
-    fn tick_processors(&mut self, delta: Duration) {
-        if delta.is_zero() {
+    /// Starts a contract and posts its first stage; the completion bonus is
+    /// set from what that stage pays.
+    fn open_contract(&mut self) {
+        self.state.contract_counter += 1;
+        let tag = self.choose_job_tag().to_string();
+        let mut contract = ContractState::new(self.state.contract_counter, &tag);
+        let first_reward = self.post_contract_stage(&contract);
+        contract.bonus = contracts::completion_bonus(first_reward);
+        self.notify(
+            MessageCategory::Jobs,
+            format!(
+                "Contract #{} opened: {} {tag} stages, {} cr bonus on completing the last.",
+                contract.id, contract.stages, contract.bonus
+            ),
+        );
+        self.state.contracts.push(contract);
+    }
+
+    /// Posts the contract's current stage, past the board cap since the slot
+    /// is reserved for it. Returns the stage's reward.
+    fn post_contract_stage(&mut self, contract: &ContractState) -> u64 {
+        self.state.job_counter += 1;
+        let mut job =
+            contract.stage_job(self.state.job_counter, self.state.reputation, &mut self.rng);
+        job.base_reward =
+            ((job.base_reward as f64) * self.state.market_today.demand).round() as u64;
+        let reward = job.base_reward;
+        let subject = LogSubject::Job(job.id);
+        let name = job.name.clone();
+        self.state.jobs.push(job);
+        self.push_message_about(
+            subject,
+            format!("New job posted: {name} [{}]", contract.tag),
+        );
+        reward
+    }
+
+    /// Moves a contract on once a stage completes: posts the next stage, or
+    /// pays the bonus after the last.
+    fn advance_contract(&mut self, contract_id: u64) {
+        let Some(index) = self
+            .state
+            .contracts
+            .iter()
+            .position(|contract| contract.id == contract_id)
+        else {
             return;
-        }
-        let delta_ms = delta.as_millis() as u64;
-        let cooling_bonus = if self.state.thermal_paste_timer_ms > 0 {
-            1
+        };
+        if self.state.contracts[index].is_final_stage() {
+            let contract = self.state.contracts.remove(index);
+            self.credit(contract.bonus);
+            self.notify_critical(
+                MessageCategory::Jobs,
+                format!(
+                    "Contract #{} complete: +{} cr completion bonus.",
+                    contract.id, contract.bonus
+                ),
+            );
         } else {
-            0
+            self.state.contracts[index].stage += 1;
+            let contract = self.state.contracts[index].clone();
+            self.post_contract_stage(&contract);
+        }
+    }
+
+    /// Moves client reputation by `delta`, announcing any change of tier.
+    fn adjust_reputation(&mut self, delta: i32) {
+        let before = ReputationTier::for_reputation(self.state.reputation);
+        self.state.reputation = reputation::adjust(self.state.reputation, delta);
+        let after = ReputationTier::for_reputation(self.state.reputation);
+        if after == before {
+            return;
+        }
+        let direction = if after > before { "rose" } else { "fell" };
+        self.notify(
+            MessageCategory::Jobs,
+            format!(
+                "Reputation {direction} to {}: new postings pay {:.0}% of the base rate.",
+                after.label(),
+                after.reward_multiplier() * 100.0
+            ),
+        );
+    }
+
+    /// Charges a lost client job against reputation and cancels the
+    /// contract it belonged to. Utility jobs have no client to disappoint.
+    fn lose_job(&mut self, job: &Job, loss: i32) {
+        if !job.is_utility() {
+            self.adjust_reputation(-loss);
+        }
+        self.fail_contract(job);
+    }
+
+    /// Cancels the contract a lost stage job belonged to.
+    fn fail_contract(&mut self, job: &Job) {
+        let Some(contract_id) = job.contract_id else {
+            return;
+        };
+        let Some(index) = self
+            .state
+            .contracts
+            .iter()
+            .position(|contract| contract.id == contract_id)
+        else {
+            return;
         };
+        let contract = self.state.contracts.remove(index);
+        self.notify(
+            MessageCategory::Jobs,
+            format!(
+                "Contract #{} failed at stage {}/{}; its {} cr bonus is lost.",
+                contract.id, contract.stage, contract.stages, contract.bonus
+            ),
+        );
+    }
+
+    /// Advances the fleet in fixed steps of `max_substep_ms`, carrying any
+    /// remainder to the next frame. Evaluation, wear, script thresholds and
+    /// completion are all checked per step, so a long frame at high speed
+    /// plays out exactly like many short ones.
+    fn tick_processors(&mut self, delta: Duration) {
+        let step = Duration::from_millis(TUNING.max_substep_ms.max(1));
+        self.processor_timer += delta;
+        while self.processor_timer >= step {
+            self.processor_timer -= step;
+            self.step_processors(step);
+        }
+    }
+
+    fn step_processors(&mut self, delta: Duration) {
+        let delta_ms = delta.as_millis() as u64;
+        let cooling_bonus = self.cooling_bonus_levels();
+        let ambient = self.ambient_temperature();
+        self.update_ambient_heat();
         let mut events = Vec::new();
         for (index, processor) in self.state.processors.iter_mut().enumerate() {
-            if let Some(event) = processor.tick(delta_ms, &mut self.rng, cooling_bonus) {
-                events.push((index, event));
-            }
+            let cooling = cooling_bonus + racks::cooling_for(&self.state.racks, processor);
+            let stepped = processor.tick(delta_ms, &mut self.rng, cooling, ambient);
+            events.extend(stepped.into_iter().map(|event| (index, event)));
+        }
+        self.enforce_power_budget(cooling_bonus);
+        let day_fraction = delta.as_secs_f64() / DAY_DURATION.as_secs_f64();
+        for processor in &mut self.state.processors {
+            processor.meter_energy(day_fraction);
+            processor.meter_heat(delta.as_secs_f64());
         }
         for (index, event) in events {
             match event {
                 ProcessorEvent::Completed(done) => self.resolve_completed_job(index, done),
                 ProcessorEvent::BurntOut { job } => self.handle_burnout(index, job),
                 ProcessorEvent::Destroyed { job } => self.handle_destruction(index, job),
+                ProcessorEvent::Scripted { job_name, effect } => {
+                    self.announce_script_step(index, &job_name, effect)
+                }
+                ProcessorEvent::CheckpointFailed { job, reliability } => {
+                    self.handle_checkpoint_failure(index, job, reliability)
+                }
+                ProcessorEvent::ServicingDone => self.announce_servicing_done(index),
+                ProcessorEvent::Throttled { job_name, heat } => {
+                    self.announce_throttle(index, &job_name, heat)
+                }
+                ProcessorEvent::Dropped { job } => self.handle_dropped_job(index, job),
             }
         }
+        // Route this step's notices now so they keep their order in the log.
+        self.process_events();
     }
 
-    fn resolve_completed_job(&mut self, processor_index: usize, completed: CompletedJob) {
-        if processor_index >= self.state.processors.len() {
-            return;
-        }
-        let (quality, processor_name) = {
-            let processor = &self.state.processors[processor_index];
-            let processor_name = processor.name.clone();
-            let quality = economy::roll_quality(
-                &completed.job,
-                processor,
-                completed.daemon_penalty.as_ref(),
-                &mut self.rng,
-            );
-            (quality, processor_name)
-        };
-        let payout = economy::payout_for_quality(&completed.job, quality);
-        self.state.credits += payout;
-        let stored = self.state.storage.store(completed.job.data_output);
-        if stored < completed.job.data_output {
-            let lost = completed.job.data_output - stored;
-            if lost > 0 {
-                self.push_message(format!(
-                    "Storage overflow: {lost} data units released back into the ether."
-                ));
-            }
+    /// Sets each unit's ambient term from its neighbors' last heat: every
+    /// rack-mate for racked units, the adjacent slots otherwise.
+    fn update_ambient_heat(&mut self) {
+        let spill: Vec<f64> = self
+            .state
+            .processors
+            .iter()
+            .map(ProcessorState::neighbor_heat)
+            .collect();
+        let rack_ids: Vec<Option<u8>> = self
+            .state
+            .processors
+            .iter()
+            .map(|processor| processor.rack_id)
+            .collect();
+        for (index, processor) in self.state.processors.iter_mut().enumerate() {
+            processor.ambient_heat = match processor.rack_id {
+                // Racked units share one air volume with every rack-mate.
+                Some(rack) => rack_ids
+                    .iter()
+                    .zip(&spill)
+                    .enumerate()
+                    .filter(|(other, (id, _))| *other != index && **id == Some(rack))
+                    .map(|(_, (_, heat))| heat)
+                    .sum(),
+                None => {
+                    let left = index.checked_sub(1).and_then(|i| spill.get(i));
+                    let right = spill.get(index + 1);
+                    left.into_iter().chain(right).sum()
+                }
+            };
         }
-        self.push_message(format!(
-            "{} completed on {processor_name} | quality {quality} | +{payout} cr",
-            completed.job.name
-        ));
     }
 
-    fn handle_burnout(&mut self, processor_index: usize, job: Job) {
-        if let Some(processor) = self.state.processors.get(processor_index) {
-            let processor_name = processor.name.clone();
-            self.push_message(format!(
-                "{processor_name} burnt out while processing {}. Unit offline.",
-                job.name
-            ));
+    /// Swaps the processor with its rack neighbor; returns the new index.
+    pub fn move_processor(&mut self, index: usize, toward_front: bool) -> Option<usize> {
+        let target = if toward_front {
+            index.checked_sub(1)?
+        } else {
+            index + 1
+        };
+        if target >= self.state.processors.len() || index >= self.state.processors.len() {
+            return None;
         }
+        self.state.processors.swap(index, target);
+        self.update_ambient_heat();
+        let name = self.state.processors[target].label();
+        self.push_message(format!("Moved {name} to rack slot {}.", target + 1));
+        Some(target)
     }
 
-    fn handle_destruction(&mut self, processor_index: usize, job: Job) {
-        if let Some(processor) = self.state.processors.get(processor_index) {
-            let processor_name = processor.name.clone();
-            self.push_message(format!(
-                "{processor_name} was destroyed during {}. Replacement required.",
-                job.name
-            ));
+    /// Mounts the unit in the next rack with room, or unracks it after the
+    /// last one. The unit moves to sit with its new rack-mates; returns its
+    /// new index.
+    pub fn cycle_rack(&mut self, index: usize) -> Result<usize, RackError> {
+        let current = self
+            .state
+            .processors
+            .get(index)
+            .ok_or(RackError::InvalidProcessor)?
+            .rack_id;
+        if self.state.racks.is_empty() {
+            return Err(RackError::NoRacks);
         }
+        let next = racks::next_rack_with_room(&self.state.racks, &self.state.processors, current);
+        let mut processor = self.state.processors.remove(index);
+        processor.rack_id = next;
+        let target = match next {
+            Some(rack) => racks::members(&self.state.processors, rack)
+                .last()
+                .map_or(self.state.processors.len(), |last| last + 1),
+            None => self.state.processors.len(),
+        };
+        let message = match next {
+            Some(rack) => format!(
+                "{} mounted in rack {} (cooling +{}).",
+                processor.label(),
+                rack + 1,
+                self.state.racks[rack as usize].cooling_level
+            ),
+            None => format!("{} taken out of its rack.", processor.label()),
+        };
+        self.state.processors.insert(target, processor);
+        self.update_ambient_heat();
+        self.push_message(message);
+        Ok(target)
     }
 
-    fn apply_daily_cycle(&mut self) {
-        let upkeep = self.total_upkeep();
-        let electricity = self.total_electricity_cost();
-        let total_cost = upkeep + electricity;
-        if total_cost > 0 {
-            if self.state.credits >= total_cost {
-                self.state.credits -= total_cost;
-                if electricity > 0 {
-                    self.push_message(format!(
-                        "Paid upkeep {upkeep} cr + electricity {electricity} cr (total {total_cost})."
-                    ));
-                } else {
-                    self.push_message(format!("Paid upkeep of {upkeep} credits."));
-                }
-            } else {
-                self.state.credits = 0;
+    fn resolve_completed_job(&mut self, processor_index: usize, mut completed: CompletedJob) {
+        if processor_index >= self.state.processors.len() {
+            return;
+        }
+        self.return_escrow(&mut completed.job);
+        self.release_storage(&mut completed.job);
+        if completed.forced {
+            let name = self.state.processors[processor_index].label();
+            let message = format!(
+                "Watchdog: {} stalled on {}; forced completion.",
+                name, completed.job.name
+            );
+            self.notify_unit(MessageCategory::Hardware, processor_index, message);
+        }
+        if let Some(model) = completed.job.diagnostic_for.clone() {
+            self.finish_diagnostics(processor_index, &completed.job, &model);
+            return;
+        }
+        let museum_bonus = museum::fleet_quality_bonus(&self.state.museum);
+        let quality = economy::roll_quality(
+            &completed.job,
+            &self.state.processors[processor_index],
+            completed.daemon_penalty.as_ref(),
+            museum_bonus,
+            &mut self.rng,
+        );
+        if self.angel_backlash(processor_index, &completed.job, quality) {
+            let job = completed.job;
+            let dropped = self.state.processors[processor_index].destroy();
+            for other in dropped {
+                self.handle_dropped_job(processor_index, other);
+            }
+            self.handle_destruction(processor_index, job);
+            return;
+        }
+        let (processor_name, processor_id) = {
+            let processor = &mut self.state.processors[processor_index];
+            let processor_name = processor.label();
+            processor.jobs_completed += 1;
+            processor.best_quality = processor.best_quality.max(quality);
+            (processor_name, processor.id)
+        };
+        let preferred = completed.job.preferred_processor_id == Some(processor_id);
+        let preference_bonus = if preferred {
+            FOLLOW_UP_PREFERRED_BONUS
+        } else {
+            0.0
+        };
+        let rank_bonus = self
+            .state
+            .statistics
+            .tag_rank(&completed.job.tag)
+            .map(|rank| rank.payout_bonus())
+            .unwrap_or(0.0);
+        let payout =
+            economy::payout_for_quality(&completed.job, quality, preference_bonus + rank_bonus);
+        let mut payout = (payout as f64 * self.tuning.reward_multiplier).round() as u64;
+        let surveillance = completed.job.tag == jobs::SURVEILLANCE_TAG;
+        if surveillance {
+            payout = (payout as f64 * SURVEILLANCE_CREDIT_SHARE).round() as u64;
+            self.state.dividend_boost += SURVEILLANCE_DIVIDEND_BOOST;
+        }
+        self.credit(payout);
+        self.state.processors[processor_index].credits_earned += payout;
+        if completed.daemon_penalty.is_some() {
+            self.record_trial_earnings(processor_id, payout);
+        }
+        let duration_ms = economy::assignment_duration_ms(
+            &completed.job,
+            &self.state.processors[processor_index],
+            completed.daemon_penalty.as_ref(),
+        );
+        self.record_history(JobRecord {
+            processor_id,
+            tag: completed.job.tag.clone(),
+            duration_ms,
+            quality,
+            payout,
+        });
+        self.events.push(GameEvent::JobCompleted {
+            processor_id,
+            tag: completed.job.tag.clone(),
+            quality,
+            payout,
+        });
+        let data_output = if surveillance {
+            0
+        } else {
+            (completed.job.data_output as f64 * self.tuning.data_output_multiplier).round() as u64
+        };
+        let stored = self.state.storage.store(data_output);
+        if stored < data_output {
+            let lost = data_output - stored;
+            if lost > 0 {
                 self.push_message(format!(
-                    "Operating costs {total_cost} exceeded reserves; treasury depleted."
+                    "Storage overflow: {lost} data units released back into the ether."
                 ));
             }
         }
-        let passive = economy::passive_income(self.state.storage.stored);
-        if passive > 0 {
-            self.state.credits += passive;
-            self.push_message(format!("Passive data dividend +{passive} credits."));
+        self.push_message_about(
+            LogSubject::Processor(processor_id),
+            format!(
+                "{} completed on {processor_name} | quality {quality} | +{payout} cr",
+                completed.job.name
+            ),
+        );
+        if preferred {
+            self.push_message(format!(
+                "Client bonus: {processor_name} handled its own follow-up."
+            ));
+        }
+        if surveillance {
+            self.push_message(format!(
+                "Intercepts filed: today's data dividend is up to x{:.2}.",
+                1.0 + self.state.dividend_boost
+            ));
+        }
+        if quality >= completed.job.quality_target {
+            self.adjust_reputation(reputation::ON_TARGET_GAIN);
+        }
+        if quality >= FOLLOW_UP_MIN_QUALITY && self.rng.gen_bool(FOLLOW_UP_CHANCE) {
+            self.schedule_follow_up(&completed.job, processor_id);
+        }
+        if let Some(contract_id) = completed.job.contract_id {
+            self.advance_contract(contract_id);
         }
     }
 
-    fn try_daemon_assignment(&mut self) {
-        if self.state.jobs.is_empty() {
-            return;
+    /// Rolls whether an ANGEL job that missed its quality target takes its
+    /// unit down with it. Hardening is no help; only a Faraday Shroud is.
+    fn angel_backlash(&mut self, processor_index: usize, job: &Job, quality: u8) -> bool {
+        if job.tag != jobs::ANGEL_TAG || quality >= job.quality_target {
+            return false;
         }
-        let cooling_bonus = if self.state.thermal_paste_timer_ms > 0 {
-            1
-        } else {
-            0
-        };
-        let mut auto_indices: Vec<usize> = self
+        let shrouded = self
             .state
             .processors
-            .iter()
-            .enumerate()
-            .filter(|(_, processor)| {
-                processor.daemon_unlocked
-                    && processor.daemon_mode == DaemonMode::Auto
-                    && processor.is_idle()
-                    && processor.is_functional()
-            })
-            .map(|(index, _)| index)
-            .collect();
+            .get(processor_index)
+            .is_none_or(|processor| processor.faraday_shroud);
+        !shrouded && self.rng.gen_bool(ANGEL_BACKLASH_CHANCE)
+    }
 
-        auto_indices.sort_by(|a, b| {
-            let pa = &self.state.processors[*a];
-            let pb = &self.state.processors[*b];
-            pb.daemon_priority
-                .cmp(&pa.daemon_priority)
-                .then_with(|| pb.speed.partial_cmp(&pa.speed).unwrap_or(Ordering::Equal))
-        });
+    fn record_history(&mut self, record: JobRecord) {
+        if self.state.job_history.len() >= MAX_JOB_HISTORY {
+            self.state.job_history.pop_front();
+        }
+        self.state.job_history.push_back(record);
+    }
 
-        for processor_index in auto_indices {
-            if self.state.jobs.is_empty() {
-                break;
-            }
-            let Some(job_index) = self.choose_daemon_job(processor_index, cooling_bonus) else {
-                continue;
+    fn schedule_follow_up(&mut self, original: &Job, processor_id: u64) {
+        self.state.job_counter += 1;
+        let job = jobs::generate_follow_up_job(
+            self.state.job_counter,
+            original,
+            processor_id,
+            self.state.reputation,
+            &mut self.rng,
+        );
+        let delay_ms = self.rng.gen_range(FOLLOW_UP_DELAY_MS);
+        self.state
+            .scheduled_jobs
+            .push(ScheduledJob { delay_ms, job });
+    }
+
+    /// Counts down unclaimed board postings and withdraws the ones whose
+    /// deadline passed. Pending and running jobs are off the board and keep
+    /// their time.
+    fn age_board_jobs(&mut self, delta: Duration) {
+        let delta_ms = delta.as_millis() as u64;
+        let mut expired = Vec::new();
+        self.state.jobs.retain_mut(|job| {
+            let Some(left) = &mut job.expires_in_ms else {
+                return true;
             };
-            let job = self.state.jobs.remove(job_index);
-            if let Err(err) = self.assign_job_to_processor(job, processor_index, true) {
-                self.push_message(format!("Daemon failed assignment: {err}"));
+            *left = left.saturating_sub(delta_ms);
+            if *left > 0 {
+                return true;
             }
+            expired.push(job.clone());
+            false
+        });
+        for job in expired {
+            self.notify(
+                MessageCategory::Jobs,
+                format!("{} expired unclaimed and was withdrawn.", job.name),
+            );
+            self.lose_job(&job, reputation::EXPIRY_LOSS);
         }
     }
 
-    fn choose_daemon_job(&self, processor_index: usize, cooling_bonus_levels: u8) -> Option<usize> {
-        let processor = self.state.processors.get(processor_index)?;
-        let mut best: Option<(usize, f64)> = None;
-        for (job_index, job) in self.state.jobs.iter().enumerate() {
-            if !processor.supports(&job.tag) {
-                continue;
-            }
-            let evaluation = processor.evaluate_job(job, cooling_bonus_levels);
-            if processor.honor_cooling_mins
-                && processor.requires_cooling_min > evaluation.effective_cooling
-                && job.tag != jobs::GENERAL_TAG
-            {
-                continue;
-            }
-            if evaluation.reliability < 0.35 {
-                continue;
-            }
-            if processor.honor_cooling_mins && evaluation.heat > 1.8 {
+    fn advance_scheduled_jobs(&mut self, delta: Duration) {
+        let delta_ms = delta.as_millis() as u64;
+        let mut index = 0;
+        while index < self.state.scheduled_jobs.len() {
+            let entry = &mut self.state.scheduled_jobs[index];
+            entry.delay_ms = entry.delay_ms.saturating_sub(delta_ms);
+            if entry.delay_ms > 0 || self.state.jobs.len() >= MAX_JOBS {
+                index += 1;
                 continue;
             }
-            let duration =
-                economy::assignment_duration_ms(job, processor, Some(&processor.daemon_penalty))
-                    as f64;
-            let base_score = if duration > 0.0 {
-                (job.base_reward as f64 / duration).max(0.0)
-            } else {
-                job.base_reward as f64
-            };
-            let affinity = processor
-                .daemon_affinity
-                .get(&job.tag)
-                .copied()
-                .unwrap_or(0.0);
-            let safety = (evaluation.reliability - 0.7) * 0.5;
-            let score = base_score + affinity + safety;
-            let update = match &best {
-                Some((_, best_score)) => score > *best_score,
-                None => true,
-            };
-            if update {
-                best = Some((job_index, score));
+            let job = self.state.scheduled_jobs.remove(index).job;
+            let name = job.name.clone();
+            let subject = LogSubject::Job(job.id);
+            self.state.jobs.push(job);
+            self.push_message_about(subject, format!("Returning client posted {name}."));
+        }
+    }
+
+    fn announce_script_step(
+        &mut self,
+        processor_index: usize,
+        job_name: &str,
+        effect: ScriptEffect,
+    ) {
+        let unit = self
+            .state
+            .processors
+            .get(processor_index)
+            .map(|processor| processor.label())
+            .unwrap_or_default();
+        let message = match effect {
+            ScriptEffect::HeatSpike { heat } => format!(
+                "{job_name}: heat spike (+{heat:.1}) on {unit}! Apply thermal paste or add cooling before the checkpoint."
+            ),
+            ScriptEffect::QualityCheckpoint { .. } => {
+                format!("{job_name}: quality checkpoint passed on {unit}.")
             }
+        };
+        self.notify_unit(MessageCategory::Jobs, processor_index, message);
+    }
+
+    fn handle_checkpoint_failure(
+        &mut self,
+        processor_index: usize,
+        mut job: Job,
+        reliability: f64,
+    ) {
+        self.forfeit_escrow(&mut job);
+        self.release_storage(&mut job);
+        self.record_failure(processor_index);
+        let unit = self
+            .state
+            .processors
+            .get(processor_index)
+            .map(|processor| processor.label())
+            .unwrap_or_default();
+        self.notify_unit(
+            MessageCategory::Jobs,
+            processor_index,
+            format!(
+                "{}: failed its quality checkpoint on {unit} (reliability {:.0}%). Contract lost.",
+                job.name,
+                reliability * 100.0
+            ),
+        );
+        self.lose_job(&job, reputation::FAILURE_LOSS);
+    }
+
+    fn announce_servicing_done(&mut self, processor_index: usize) {
+        let Some(processor) = self.state.processors.get(processor_index) else {
+            return;
+        };
+        let message = format!(
+            "{} back from maintenance at {:.0}% wear.",
+            processor.label(),
+            processor.wear * 100.0
+        );
+        self.notify_unit(MessageCategory::Hardware, processor_index, message);
+    }
+
+    fn announce_throttle(&mut self, processor_index: usize, job_name: &str, heat: f64) {
+        let Some(processor) = self.state.processors.get(processor_index) else {
+            return;
+        };
+        let message = format!(
+            "{} throttled {job_name} to {:.0}% speed at heat {heat:.2}; cool it down before it burns out.",
+            processor.label(),
+            (1.0 - TUNING.throttle_slowdown) * 100.0
+        );
+        self.notify_unit(MessageCategory::Hardware, processor_index, message);
+    }
+
+    /// Counts a lost job against the unit's lifetime record.
+    fn record_failure(&mut self, processor_index: usize) {
+        if let Some(processor) = self.state.processors.get_mut(processor_index) {
+            processor.jobs_failed += 1;
         }
-        best.map(|(job_index, _)| job_index)
     }
 
-    pub fn assist_suggestion(&self, index: usize) -> Option<AssistSuggestion> {
-        let processor = self.state.processors.get(index)?;
-        if !processor.daemon_unlocked
-            || processor.daemon_mode != DaemonMode::Assist
-            || !processor.is_idle()
-            || !processor.is_functional()
-        {
-            return None;
+    fn handle_burnout(&mut self, processor_index: usize, mut job: Job) {
+        self.forfeit_escrow(&mut job);
+        self.release_storage(&mut job);
+        self.record_failure(processor_index);
+        self.state.statistics.burnouts += 1;
+        if let Some(processor) = self.state.processors.get(processor_index) {
+            let processor_name = processor.label();
+            let model = processor.name.clone();
+            let temperature = processor.temperature;
+            self.notify_unit(
+                MessageCategory::Hardware,
+                processor_index,
+                format!(
+                    "{processor_name} burnt out at temp {temperature:.2} while processing {}. Unit offline.",
+                    job.name
+                ),
+            );
+            self.post_diagnostics(&model);
         }
-        if self.state.jobs.is_empty() {
-            return None;
+        self.pay_insurance(processor_index, &job);
+        self.lose_job(&job, reputation::FAILURE_LOSS);
+        self.auto_replace(processor_index);
+    }
+
+    /// Buys a flagged unit its replacement chassis, keeping the treasury at
+    /// or above the auto-replace floor.
+    fn auto_replace(&mut self, processor_index: usize) {
+        let Some(processor) = self.state.processors.get(processor_index) else {
+            return;
+        };
+        if !processor.auto_replace || processor.is_functional() {
+            return;
         }
-        let cooling_bonus = if self.state.thermal_paste_timer_ms > 0 {
-            1
-        } else {
-            0
+        let label = processor.label();
+        let Some(cost) = Self::store_index_for(StoreAction::ReplaceProcessor)
+            .and_then(|store_index| self.item_cost(store_index, Some(processor_index)))
+        else {
+            return;
         };
-        let mut best: Option<(usize, f64, f64, JobEvaluation)> = None;
-        for (job_index, job) in self.state.jobs.iter().enumerate() {
-            if !processor.supports(&job.tag) {
-                continue;
+        let floor = self.state.auto_replace_floor;
+        if self.state.credits < cost.saturating_add(floor) {
+            self.notify_unit(
+                MessageCategory::Economy,
+                processor_index,
+                format!("Auto-replace held off on {label}: {cost} cr would dip below the {floor} cr floor."),
+            );
+            return;
+        }
+        let message = match self.replace_processor_direct(processor_index) {
+            Ok(()) => format!("Auto-replace spent {cost} cr returning {label} to service."),
+            Err(err) => format!("Auto-replace failed for {label}: {err}."),
+        };
+        self.notify_unit(MessageCategory::Hardware, processor_index, message);
+    }
+
+    /// Settles a covered unit's claim once it has gone offline.
+    fn pay_insurance(&mut self, processor_index: usize, job: &Job) {
+        let Some(processor) = self.state.processors.get(processor_index) else {
+            return;
+        };
+        if !processor.insured {
+            return;
+        }
+        let replacement =
+            replacement_cost_for_processor(processor, self.replacement_discount(&processor.name));
+        let payout = economy::insurance_payout(replacement, job.base_reward);
+        if payout == 0 {
+            return;
+        }
+        let message = format!(
+            "Insurance paid {payout} cr on the {} claim.",
+            processor.label()
+        );
+        self.credit(payout);
+        self.notify_unit(MessageCategory::Economy, processor_index, message);
+    }
+
+    /// Offers one diagnostics job per model when the board has room; it stays
+    /// optional and free.
+    fn post_diagnostics(&mut self, model: &str) {
+        let already_posted = self
+            .state
+            .jobs
+            .iter()
+            .any(|job| job.diagnostic_for.as_deref() == Some(model));
+        if already_posted || self.state.jobs.len() >= MAX_JOBS {
+            return;
+        }
+        self.state.job_counter += 1;
+        let job = jobs::generate_diagnostics_job(self.state.job_counter, model, &mut self.rng);
+        self.push_message(format!(
+            "Optional {} posted: run it on another unit for {:.0}% off the next {model} replacement.",
+            job.name,
+            DIAGNOSTIC_DISCOUNT * 100.0
+        ));
+        self.state.jobs.push(job);
+    }
+
+    fn finish_diagnostics(&mut self, processor_index: usize, job: &Job, model: &str) {
+        if let Some(processor) = self.state.processors.get_mut(processor_index) {
+            processor.jobs_completed += 1;
+        }
+        self.state
+            .replacement_discounts
+            .insert(model.to_string(), DIAGNOSTIC_DISCOUNT_DAYS);
+        self.push_message(format!(
+            "{} complete: next {model} replacement is {:.0}% off for {DIAGNOSTIC_DISCOUNT_DAYS} days.",
+            job.name,
+            DIAGNOSTIC_DISCOUNT * 100.0
+        ));
+    }
+
+    fn expire_replacement_discounts(&mut self) {
+        let mut expired = Vec::new();
+        self.state.replacement_discounts.retain(|model, days| {
+            *days = days.saturating_sub(1);
+            if *days == 0 {
+                expired.push(model.clone());
             }
-            let evaluation = processor.evaluate_job(job, cooling_bonus);
-            if evaluation.reliability < 0.3 {
+            *days > 0
+        });
+        for model in expired {
+            self.push_message(format!("Diagnostic findings for {model} have gone stale."));
+        }
+    }
+
+    /// Counts down warranties on working units; a unit already waiting on
+    /// a covered replacement keeps its claim.
+    fn age_warranties(&mut self) {
+        let mut expired = Vec::new();
+        for processor in &mut self.state.processors {
+            if processor.warranty_days_remaining == 0 || !processor.is_functional() {
                 continue;
             }
+            processor.warranty_days_remaining -= 1;
+            if processor.warranty_days_remaining == 0 {
+                expired.push(processor.label());
+            }
+        }
+        for name in expired {
+            self.push_message(format!("Warranty on {name} has expired."));
+        }
+    }
+
+    /// Rolls each unit's cooling against the heat it worked through today.
+    /// A unit whose honored cooling minimum is no longer met is flagged, as
+    /// its daemon will pass on anything but general work until recharged.
+    fn wear_cooling(&mut self) {
+        let mut worn = Vec::new();
+        for (index, processor) in self.state.processors.iter_mut().enumerate() {
+            let heat = std::mem::take(&mut processor.heat_seconds_today);
+            let chance = (heat * COOLING_WEAR_PER_HEAT_SEC).min(COOLING_WEAR_MAX_CHANCE);
+            if chance > 0.0 && self.rng.gen_bool(chance) && processor.wear_cooling() {
+                worn.push(index);
+            }
+        }
+        for index in worn {
+            let processor = &self.state.processors[index];
+            let mut message = format!(
+                "{} cooling wore down to level {}; a Cooling Kit recharges it at a discount.",
+                processor.label(),
+                processor.cooling_level
+            );
+            let cooling = processor.cooling_level + self.rack_cooling(processor);
             if processor.honor_cooling_mins
-                && processor.requires_cooling_min > evaluation.effective_cooling
-                && job.tag != jobs::GENERAL_TAG
+                && processor.daemon_mode != DaemonMode::Off
+                && processor.requires_cooling_min > cooling
             {
-                continue;
-            }
-            let duration = economy::assignment_duration_ms(job, processor, None) as f64 / 1000.0;
-            let score = if duration > 0.0 {
-                (job.base_reward as f64 / duration).max(0.0)
-            } else {
-                job.base_reward as f64
-            };
-            let replace = match &best {
-                Some((_, best_score, _, _)) => score > *best_score,
-                None => true,
-            };
-            if replace {
-                best = Some((job_index, score, duration, evaluation));
+                message.push_str(
+                    " It is below its cooling minimum; its daemon sticks to general jobs.",
+                );
             }
+            self.push_message_about(LogSubject::Processor(processor.id), message);
         }
-        best.map(|(job_index, _, duration, evaluation)| AssistSuggestion {
-            job_index,
-            eta_secs: duration,
-            reliability: evaluation.reliability,
-            heat: evaluation.heat,
-        })
     }
 
-    fn push_message(&mut self, message: String) {
-        if self.messages.len() >= MAX_MESSAGES {
-            self.messages.pop_front();
+    /// Credits currently locked in contract deposits.
+    pub fn escrow_locked(&self) -> u64 {
+        let running: u64 = self
+            .state
+            .processors
+            .iter()
+            .flat_map(ProcessorState::slots)
+            .map(|work| work.job.escrow)
+            .sum();
+        let board: u64 = self.state.jobs.iter().map(|job| job.escrow).sum();
+        running + board
+    }
+
+    /// Deposits lost to failed contracts since this session started.
+    pub fn escrow_forfeited(&self) -> u64 {
+        self.ledger.escrow_forfeited()
+    }
+
+    fn return_escrow(&mut self, job: &mut Job) {
+        let amount = std::mem::take(&mut job.escrow);
+        if amount == 0 {
+            return;
         }
-        self.messages.push_back(message);
+        self.state.credits = self.state.credits.saturating_add(amount);
+        self.ledger.record_escrow_return(amount);
+        self.push_message(format!("Escrow of {amount} cr returned for {}.", job.name));
     }
-}
 
-#[derive(Debug, Clone, Copy)]
-pub struct StoreItem {
-    pub name: &'static str,
-    pub description: &'static str,
-    pub base_cost: u64,
-    pub cost_step: u64,
-    pub action: StoreAction,
-    pub max_purchases: Option<u32>,
-}
+    /// Frees the archive space a job held while it ran.
+    fn release_storage(&mut self, job: &mut Job) {
+        let amount = std::mem::take(&mut job.storage_reserved);
+        self.state.storage.release(amount);
+    }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum StoreAction {
-    IncreaseSpeed,
-    ImproveQuality,
-    ExpandStorage,
-    UnlockInstructionSet { tag: &'static str },
-    UpgradeCooling,
-    UpgradeHardening,
-    ApplyThermalPaste,
-    ReplaceProcessor,
-    ReplaceModel,
-    InstallDaemonFirmware,
-}
+    fn forfeit_escrow(&mut self, job: &mut Job) {
+        let amount = std::mem::take(&mut job.escrow);
+        if amount == 0 {
+            return;
+        }
+        self.ledger.record_escrow_forfeit(amount);
+        self.events.push(GameEvent::EscrowForfeited {
+            job_name: job.name.clone(),
+            amount,
+        });
+    }
 
-const STORE_ITEMS: [StoreItem; 10] = [
-    StoreItem {
-        name: "Clock Tuning",
-        description: "Trim execution cycles for all processors (+0.05 speed each purchase).",
-        base_cost: 120,
-        cost_step: 45,
-        action: StoreAction::IncreaseSpeed,
-        max_purchases: None,
-    },
+    /// A job from another core of a unit that just went down.
+    fn handle_dropped_job(&mut self, processor_index: usize, mut job: Job) {
+        self.forfeit_escrow(&mut job);
+        self.release_storage(&mut job);
+        self.record_failure(processor_index);
+        let unit = self
+            .state
+            .processors
+            .get(processor_index)
+            .map(|processor| processor.label())
+            .unwrap_or_default();
+        self.notify_unit(
+            MessageCategory::Jobs,
+            processor_index,
+            format!("{}: lost when {unit} went down.", job.name),
+        );
+        self.lose_job(&job, reputation::FAILURE_LOSS);
+    }
+
+    fn handle_destruction(&mut self, processor_index: usize, mut job: Job) {
+        self.forfeit_escrow(&mut job);
+        self.release_storage(&mut job);
+        self.record_failure(processor_index);
+        if let Some(processor) = self.state.processors.get(processor_index) {
+            let subject = LogSubject::Processor(processor.id);
+            let processor_name = processor.label();
+            self.push_message_about(
+                subject,
+                format!(
+                    "{processor_name} was destroyed during {}. Replacement required.",
+                    job.name
+                ),
+            );
+        }
+        self.pay_insurance(processor_index, &job);
+        self.lose_job(&job, reputation::FAILURE_LOSS);
+        self.auto_replace(processor_index);
+    }
+
+    fn apply_daily_cycle(&mut self) {
+        let day = self.state.statistics.day();
+        self.state.statistics.days_elapsed += 1;
+        let upkeep = self.total_upkeep();
+        let electricity =
+            economy::electricity_cost(&self.state.processors, self.electricity_rate());
+        for processor in &mut self.state.processors {
+            processor.energy_today = 0.0;
+        }
+        let total_cost = upkeep + electricity;
+        if total_cost > 0 {
+            if self.state.credits >= total_cost {
+                self.debit(total_cost);
+                if electricity > 0 {
+                    self.push_message(format!(
+                        "Day {day}: paid upkeep {upkeep} cr + electricity {electricity} cr (total {total_cost})."
+                    ));
+                } else {
+                    self.push_message(format!("Day {day}: paid upkeep of {upkeep} credits."));
+                }
+            } else {
+                self.debit(self.state.credits);
+                self.notify(
+                    MessageCategory::Economy,
+                    format!(
+                        "Day {day}: operating costs {total_cost} exceeded reserves; treasury depleted."
+                    ),
+                );
+            }
+        }
+        let mut passive = (economy::passive_income(self.state.storage.stored) as f64
+            * self.tuning.passive_income_multiplier)
+            .round() as u64;
+        let versatile = std::mem::take(&mut self.state.statistics.versatility_active);
+        if versatile {
+            passive = (passive as f64 * TUNING.versatility_multiplier).round() as u64;
+        }
+        let boost = std::mem::take(&mut self.state.dividend_boost);
+        passive = (passive as f64 * (1.0 + boost)).round() as u64;
+        if passive > 0 {
+            self.credit(passive);
+            if versatile {
+                self.push_message(format!(
+                    "Passive data dividend +{passive} credits (versatility x{:.2}).",
+                    TUNING.versatility_multiplier
+                ));
+            } else {
+                self.push_message(format!("Passive data dividend +{passive} credits."));
+            }
+        }
+        self.close_versatility_day();
+        self.expire_daemon_trial();
+        self.expire_replacement_discounts();
+        self.age_warranties();
+        self.wear_cooling();
+        self.advance_market_day();
+        self.advance_weather();
+        self.checkpoint = self.state.hardcore;
+    }
+
+    /// True once after each day a hardcore run closes; the caller saves.
+    pub fn take_checkpoint(&mut self) -> bool {
+        std::mem::take(&mut self.checkpoint)
+    }
+
+    /// No processor can work and the credits can neither replace one nor
+    /// buy a new one.
+    pub fn is_lost(&self) -> bool {
+        if self
+            .state
+            .processors
+            .iter()
+            .any(|processor| processor.is_functional())
+        {
+            return false;
+        }
+        let cheapest = self
+            .state
+            .processors
+            .iter()
+            .map(|processor| {
+                replacement_cost_for_processor(
+                    processor,
+                    self.replacement_discount(&processor.name),
+                )
+            })
+            .chain(
+                (0..STORE_ITEMS.len()).filter_map(|index| match STORE_ITEMS[index].action {
+                    StoreAction::BuyProcessor { .. } => self.item_cost(index, None),
+                    _ => None,
+                }),
+            )
+            .min();
+        cheapest.is_none_or(|cost| self.state.credits < cost)
+    }
+
+    /// Lends daemon firmware to the processor at `index` until the day ends,
+    /// running on Auto with a slightly worse penalty. Once per run.
+    pub fn start_daemon_trial(&mut self, index: usize) -> Result<(), TrialError> {
+        if !self.tuning.daemon_unlockable {
+            return Err(TrialError::Disabled);
+        }
+        if self.state.daemon_trial.is_some() {
+            return Err(TrialError::AlreadyUsed);
+        }
+        let processor = self
+            .state
+            .processors
+            .get_mut(index)
+            .ok_or(TrialError::InvalidProcessor)?;
+        if processor.daemon_unlocked() {
+            return Err(TrialError::AlreadyInstalled);
+        }
+        if !processor.is_functional() {
+            return Err(TrialError::Offline);
+        }
+        let penalty = processor.daemon_penalty.clone();
+        processor.daemon_firmware_level = 1;
+        processor.daemon_trial = true;
+        processor.daemon_mode = DaemonMode::Auto;
+        processor.daemon_penalty.quality = penalty.quality.saturating_add(TRIAL_QUALITY_PENALTY);
+        processor.daemon_penalty.time_multiplier = penalty.time_multiplier + TRIAL_TIME_PENALTY;
+        let name = processor.label();
+        self.state.daemon_trial = Some(DaemonTrial {
+            processor_id: processor.id,
+            penalty,
+            jobs: 0,
+            earned: 0,
+            active: true,
+        });
+        self.notify(
+            MessageCategory::Automation,
+            format!("{name} is running a free daemon trial on Auto until the day ends."),
+        );
+        Ok(())
+    }
+
+    fn record_trial_earnings(&mut self, processor_id: u64, payout: u64) {
+        if let Some(trial) = &mut self.state.daemon_trial
+            && trial.active
+            && trial.processor_id == processor_id
+        {
+            trial.jobs += 1;
+            trial.earned += payout;
+        }
+    }
+
+    /// Ends an active trial and restores the unit's own penalty. Unless
+    /// `keep_firmware`, the loaned firmware is removed as well. Returns the
+    /// unit's index when it is still in the rack.
+    fn end_daemon_trial(&mut self, keep_firmware: bool) -> Option<usize> {
+        let trial = self
+            .state
+            .daemon_trial
+            .as_mut()
+            .filter(|trial| trial.active)?;
+        trial.active = false;
+        let (id, penalty) = (trial.processor_id, trial.penalty.clone());
+        let index = self.processor_index_by_id(id)?;
+        let processor = &mut self.state.processors[index];
+        if processor.daemon_trial {
+            processor.daemon_trial = false;
+            processor.daemon_penalty = penalty;
+            if !keep_firmware {
+                processor.daemon_firmware_level = u8::from(self.state.daemon_unlocked);
+                if !processor.daemon_unlocked() {
+                    processor.daemon_mode = DaemonMode::Off;
+                }
+            }
+        }
+        Some(index)
+    }
+
+    fn expire_daemon_trial(&mut self) {
+        let Some(trial) = self.state.daemon_trial.clone().filter(|trial| trial.active) else {
+            return;
+        };
+        let offer = self.end_daemon_trial(false).and_then(|index| {
+            let store_index = Self::store_index_for(StoreAction::InstallDaemonFirmware)?;
+            self.item_cost(store_index, Some(index))
+        });
+        let summary = format!(
+            "Trial daemon completed {} jobs for {} cr",
+            trial.jobs, trial.earned
+        );
+        match offer {
+            Some(cost) => self.notify(
+                MessageCategory::Automation,
+                format!("{summary} — install firmware for {cost}? Press I to open it."),
+            ),
+            None => self.notify(MessageCategory::Automation, format!("{summary}.")),
+        }
+    }
+
+    /// Store index of the firmware item and the processor it should target:
+    /// the trial unit while it is still in the rack, otherwise `selected`.
+    pub fn firmware_shortcut(&self, selected: usize) -> Option<(usize, usize)> {
+        let store_index = Self::store_index_for(StoreAction::InstallDaemonFirmware)?;
+        let processor_index = self
+            .state
+            .daemon_trial
+            .as_ref()
+            .and_then(|trial| self.processor_index_by_id(trial.processor_id))
+            .unwrap_or(selected);
+        Some((store_index, processor_index))
+    }
+
+    /// Tags completed today and how many earn tomorrow's versatility bonus.
+    /// The bar never drops, so a run with fewer tags unlocked can't qualify.
+    pub fn versatility_progress(&self) -> (usize, usize) {
+        (
+            self.state.statistics.tags_today.len(),
+            TUNING.versatility_min_tags,
+        )
+    }
+
+    fn close_versatility_day(&mut self) {
+        let (done, required) = self.versatility_progress();
+        self.state.statistics.tags_today.clear();
+        if done >= required {
+            self.state.statistics.versatility_active = true;
+            self.push_message(format!(
+                "Versatility: {done} tags today; tomorrow's dividend x{:.2}.",
+                TUNING.versatility_multiplier
+            ));
+        }
+    }
+
+    fn advance_market_day(&mut self) {
+        self.fill_market_outlook();
+        if let Some(next) = self.state.market_outlook.pop_front() {
+            self.state.market_today = next;
+        }
+        self.fill_market_outlook();
+        let rate = self.electricity_rate();
+        self.push_message(format!(
+            "Market open: electricity {rate:.2} cr/kWh, demand {:.2}x.",
+            self.state.market_today.demand
+        ));
+    }
+
+    /// Drifts the ambient temperature and runs heat waves down, sometimes
+    /// starting a new one.
+    fn advance_weather(&mut self) {
+        let drift = self
+            .rng
+            .gen_range(-TUNING.ambient_drift..=TUNING.ambient_drift);
+        self.state.ambient_temperature = (self.state.ambient_temperature + drift)
+            .clamp(TUNING.ambient_range.0, TUNING.ambient_range.1);
+        if self.state.heat_wave_days > 0 {
+            self.state.heat_wave_days -= 1;
+            if self.state.heat_wave_days == 0 {
+                self.notify(
+                    MessageCategory::Hardware,
+                    "The heat wave has broken.".to_string(),
+                );
+            }
+        } else if self.rng.gen_bool(TUNING.heat_wave_chance) {
+            let (shortest, longest) = TUNING.heat_wave_days;
+            let days = self.rng.gen_range(shortest..=longest);
+            self.state.heat_wave_days = days;
+            self.notify(
+                MessageCategory::Hardware,
+                format!(
+                    "Heat wave: every unit runs {:+.2} hotter for {days} days. Cooling counts.",
+                    TUNING.heat_wave_heat
+                ),
+            );
+        }
+    }
+
+    fn try_daemon_assignment(&mut self) {
+        if self.state.jobs.is_empty() {
+            return;
+        }
+        let cooling_bonus = self.cooling_bonus_levels();
+        self.try_daemon_preemption(cooling_bonus);
+        let mut auto_indices: Vec<usize> = self
+            .state
+            .processors
+            .iter()
+            .enumerate()
+            .filter(|(index, processor)| {
+                processor.daemon_unlocked()
+                    && processor.daemon_mode == DaemonMode::Auto
+                    && processor.is_idle()
+                    && processor.is_functional()
+                    && !processor.paused
+                    && !self.held_for_manual(*index)
+            })
+            .map(|(index, _)| index)
+            .collect();
+
+        let spare_worn = self.state.spare_worn_units;
+        auto_indices.sort_by(|a, b| {
+            let pa = &self.state.processors[*a];
+            let pb = &self.state.processors[*b];
+            let worn = |processor: &ProcessorState| spare_worn && processor.near_end_of_life();
+            worn(pa)
+                .cmp(&worn(pb))
+                .then_with(|| pb.daemon_priority.cmp(&pa.daemon_priority))
+                .then_with(|| {
+                    pb.effective_speed()
+                        .partial_cmp(&pa.effective_speed())
+                        .unwrap_or(Ordering::Equal)
+                })
+        });
+
+        for processor_index in auto_indices {
+            if self.state.jobs.is_empty() {
+                break;
+            }
+            let Some((job_index, _)) = self.choose_daemon_job(processor_index, cooling_bonus)
+            else {
+                continue;
+            };
+            let job = &self.state.jobs[job_index];
+            let draw = self.projected_power_draw(processor_index, job, cooling_bonus);
+            if !self.state.power_budget.allows(draw)
+                || !self.can_post_escrow(job)
+                || !self.can_reserve_storage(job)
+            {
+                continue;
+            }
+            let job = self.state.jobs.remove(job_index);
+            match self.assign_job_to_processor(job, processor_index, true) {
+                Ok(()) => self.state.statistics.daemon_assignments += 1,
+                Err(err) => self.push_message(format!("Daemon failed assignment: {err}")),
+            }
+        }
+    }
+
+    fn can_post_escrow(&self, job: &Job) -> bool {
+        job.escrow > 0 || economy::escrow_deposit(job) <= self.state.credits
+    }
+
+    fn can_reserve_storage(&self, job: &Job) -> bool {
+        job.storage_reserved > 0 || job.storage_demand() <= self.state.storage.free_capacity()
+    }
+
+    /// Committed draw once the processor starts `job`, checked against the
+    /// power budget so neither Auto nor a manual assignment trips the breaker.
+    fn projected_power_draw(
+        &self,
+        processor_index: usize,
+        job: &Job,
+        cooling_bonus_levels: u8,
+    ) -> f64 {
+        let Some(processor) = self.state.processors.get(processor_index) else {
+            return f64::INFINITY;
+        };
+        let cooling = cooling_bonus_levels + self.rack_cooling(processor);
+        let demand = processor
+            .evaluate_job(job, cooling, self.ambient_temperature())
+            .power_draw;
+        self.committed_power_draw(cooling_bonus_levels) - processor.last_power_draw() + demand
+    }
+
+    fn choose_daemon_job(
+        &self,
+        processor_index: usize,
+        cooling_bonus_levels: u8,
+    ) -> Option<(usize, f64)> {
+        let processor = self.state.processors.get(processor_index)?;
+        let ambient = self.ambient_temperature();
+        let cooling = cooling_bonus_levels + self.rack_cooling(processor);
+        let mut best: Option<(usize, f64)> = None;
+        for (job_index, job) in self.state.jobs.iter().enumerate() {
+            let Some(score) = daemon_job_score(processor, job, cooling, ambient) else {
+                continue;
+            };
+            let update = match &best {
+                Some((_, best_score)) => score > *best_score,
+                None => true,
+            };
+            if update {
+                best = Some((job_index, score));
+            }
+        }
+        best
+    }
+
+    /// Lets preempt-enabled Auto units drop a freshly started daemon job for a
+    /// board job that scores sufficiently better.
+    fn try_daemon_preemption(&mut self, cooling_bonus_levels: u8) {
+        for processor_index in 0..self.state.processors.len() {
+            let (progress, current_score, factor) = {
+                let processor = &self.state.processors[processor_index];
+                // A unit with a free core takes new work without dropping any.
+                if !processor.daemon_unlocked()
+                    || processor.daemon_mode != DaemonMode::Auto
+                    || !processor.daemon_preempt
+                    || processor.is_idle()
+                {
+                    continue;
+                }
+                let ProcessorStatus::Working(work) = &processor.status else {
+                    continue;
+                };
+                if work.daemon_penalty.is_none() || work.total_ms == 0 {
+                    continue;
+                }
+                let elapsed = work.total_ms.saturating_sub(work.remaining_ms);
+                let progress = elapsed as f64 / work.total_ms as f64;
+                let current = daemon_job_score(
+                    processor,
+                    &work.job,
+                    cooling_bonus_levels + self.rack_cooling(processor),
+                    self.ambient_temperature(),
+                )
+                .unwrap_or(f64::MIN);
+                (progress, current, processor.preempt_factor)
+            };
+            let Some((job_index, candidate_score)) =
+                self.choose_daemon_job(processor_index, cooling_bonus_levels)
+            else {
+                continue;
+            };
+            let candidate = &self.state.jobs[job_index];
+            let draw = self.projected_power_draw(processor_index, candidate, cooling_bonus_levels);
+            if !should_preempt(progress, current_score, candidate_score, factor)
+                || !self.can_post_escrow(candidate)
+                || !self.can_reserve_storage(candidate)
+                || !self.state.power_budget.allows(draw)
+            {
+                continue;
+            }
+            let job = self.state.jobs.remove(job_index);
+            let job_name = job.name.clone();
+            let Ok((dropped, penalty)) = self.cancel_job(processor_index) else {
+                self.state.jobs.insert(job_index, job);
+                continue;
+            };
+            let processor_name = self.state.processors[processor_index].label();
+            self.push_message(format!(
+                "Daemon preempted {dropped} on {processor_name} at {:.0}% for {job_name} (-{penalty} cr).",
+                progress * 100.0
+            ));
+            self.state.statistics.daemon_preemptions += 1;
+            match self.assign_job_to_processor(job, processor_index, true) {
+                Ok(()) => self.state.statistics.daemon_assignments += 1,
+                Err(err) => self.push_message(format!("Daemon failed assignment: {err}")),
+            }
+        }
+    }
+
+    /// Aborts the processor's current job, returning it to the board and
+    /// charging the cancellation penalty. Returns the job name and penalty paid.
+    pub fn cancel_job(&mut self, processor_index: usize) -> Result<(String, u64), AssignmentError> {
+        let processor = self
+            .state
+            .processors
+            .get_mut(processor_index)
+            .ok_or(AssignmentError::InvalidProcessor)?;
+        let mut job = processor.abort().ok_or(AssignmentError::NotWorking)?;
+        let penalty = ((job.base_reward as f64 * CANCEL_PENALTY_RATIO).round() as u64)
+            .min(self.state.credits);
+        self.debit(penalty);
+        self.return_escrow(&mut job);
+        self.release_storage(&mut job);
+        let name = job.name.clone();
+        self.return_job(job);
+        Ok((name, penalty))
+    }
+
+    pub fn assist_suggestion(&self, index: usize) -> Option<AssistSuggestion> {
+        self.assist_suggestions(index, 1).into_iter().next()
+    }
+
+    /// Up to `n` jobs an Assist unit would take, best reward rate first. Ties
+    /// keep the pending job ahead of the board, and earlier board slots ahead
+    /// of later ones.
+    pub fn assist_suggestions(&self, index: usize, n: usize) -> Vec<AssistSuggestion> {
+        let Some(processor) = self.state.processors.get(index) else {
+            return Vec::new();
+        };
+        if !processor.daemon_unlocked()
+            || processor.daemon_mode != DaemonMode::Assist
+            || !processor.is_idle()
+            || !processor.is_functional()
+            || processor.paused
+        {
+            return Vec::new();
+        }
+        let pending = self
+            .state
+            .pending_jobs
+            .iter()
+            .enumerate()
+            .map(|(slot, job)| (true, slot, job));
+        let board = self
+            .state
+            .jobs
+            .iter()
+            .enumerate()
+            .map(|(index, job)| (false, index, job));
+        let cooling_bonus = self.cooling_bonus_levels() + self.rack_cooling(processor);
+        let ambient = self.ambient_temperature();
+        let mut ranked = Vec::new();
+        for (pending, job_index, job) in pending.chain(board) {
+            if !processor.supports(&job.tag)
+                || processor.daemon_blocks(&job.tag)
+                || (job.is_utility() && !processor.allow_utility)
+            {
+                continue;
+            }
+            let evaluation = processor.evaluate_job(job, cooling_bonus, ambient);
+            if evaluation.reliability < 0.3 {
+                continue;
+            }
+            if processor.honor_cooling_mins
+                && processor.requires_cooling_min > evaluation.effective_cooling
+                && job.tag != jobs::GENERAL_TAG
+            {
+                continue;
+            }
+            let duration = economy::assignment_duration_ms(job, processor, None) as f64 / 1000.0;
+            ranked.push(AssistSuggestion {
+                job_index,
+                pending,
+                eta_secs: duration,
+                reliability: evaluation.reliability,
+                heat: evaluation.heat,
+                reward: job.base_reward,
+                score: reward_rate(job.base_reward, duration),
+            });
+        }
+        // Stable, so equal scores keep the pending-then-board order.
+        ranked.sort_by(|a, b| b.score.total_cmp(&a.score));
+        ranked.truncate(n);
+        ranked
+    }
+
+    /// Checks simulation invariants, dumping any newly seen violations to the
+    /// audit file and raising an in-game warning. Returns the number of new
+    /// violations.
+    pub fn audit(&mut self) -> usize {
+        let violations = audit::run_all(&self.state, &self.ledger, STORE_ITEMS.len());
+        let fresh: Vec<_> = violations
+            .into_iter()
+            .filter(|violation| self.reported_violations.insert(violation.key()))
+            .collect();
+        if fresh.is_empty() {
+            return 0;
+        }
+        let dump_note = match crate::persist::write_audit_dump(&fresh) {
+            Ok(path) => format!("details in {path}"),
+            Err(err) => format!("dump failed: {err}"),
+        };
+        for violation in &fresh {
+            self.push_message(format!("AUDIT: {}", violation.key()));
+        }
+        self.push_message(format!(
+            "AUDIT: {} invariant violation(s) detected; {dump_note}.",
+            fresh.len()
+        ));
+        fresh.len()
+    }
+
+    fn credit(&mut self, amount: u64) {
+        self.state.credits = self.state.credits.saturating_add(amount);
+        self.state.statistics.credits_earned =
+            self.state.statistics.credits_earned.saturating_add(amount);
+        self.ledger.record_income(amount);
+    }
+
+    /// Single gate for discretionary spending; honors the credit reserve.
+    fn spend(&mut self, cost: u64) -> Result<(), PurchaseError> {
+        if self.state.credits < cost {
+            return Err(PurchaseError::InsufficientCredits { cost });
+        }
+        let reserved = self.reserved_credits();
+        let override_reserve = std::mem::take(&mut self.reserve_override);
+        if economy::breaches_reserve(self.state.credits, cost, reserved) && !override_reserve {
+            match self.state.reserve_mode {
+                ReserveMode::Enforce => {
+                    return Err(PurchaseError::ReserveBreach { cost, reserved });
+                }
+                ReserveMode::Warn => self.push_message(format!(
+                    "Warning: spending {cost} cr dips into the {reserved} cr reserve."
+                )),
+                ReserveMode::Off => {}
+            }
+        }
+        self.debit(cost);
+        Ok(())
+    }
+
+    fn debit(&mut self, amount: u64) {
+        match self.state.credits.checked_sub(amount) {
+            Some(remaining) => {
+                self.state.credits = remaining;
+                self.ledger.record_expense(amount);
+            }
+            None => self.ledger.record_underflow(amount, self.state.credits),
+        }
+    }
+
+    /// Best-payback upgrade across the fleet, if any looks worthwhile.
+    pub fn store_recommendation(&self) -> Option<Recommendation> {
+        advisor::best(&self.state, &self.tuning, |id| self.timeline.idle_share(id))
+    }
+
+    pub fn timeline(&self) -> &FleetTimeline {
+        &self.timeline
+    }
+
+    pub fn toasts(&self) -> impl Iterator<Item = &Toast> {
+        self.toasts.iter()
+    }
+
+    fn push_toast(&mut self, message: String, level: ToastLevel) {
+        if self.toasts.len() >= MAX_TOASTS {
+            self.toasts.pop_front();
+        }
+        self.toasts.push_back(Toast {
+            message,
+            remaining_ms: TOAST_DURATION_MS,
+            level,
+        });
+    }
+
+    fn sample_timeline(&mut self, delta: Duration) {
+        self.timeline_timer += delta;
+        while self.timeline_timer >= TIMELINE_SAMPLE_INTERVAL {
+            self.timeline_timer -= TIMELINE_SAMPLE_INTERVAL;
+            self.timeline.record(&self.state.processors);
+        }
+    }
+
+    fn age_toasts(&mut self, delta: Duration) {
+        let delta_ms = delta.as_millis() as u64;
+        for toast in &mut self.toasts {
+            toast.remaining_ms = toast.remaining_ms.saturating_sub(delta_ms);
+        }
+        self.toasts.retain(|toast| toast.remaining_ms > 0);
+        if let Some(ticker) = &mut self.ticker {
+            ticker.remaining_ms = ticker.remaining_ms.saturating_sub(delta_ms);
+            if ticker.remaining_ms == 0 {
+                self.ticker = None;
+            }
+        }
+    }
+
+    /// Queues a notice for routing when this update's events are consumed.
+    fn notify(&mut self, category: MessageCategory, message: String) {
+        self.notice(category, None, message, false);
+    }
+
+    fn notify_critical(&mut self, category: MessageCategory, message: String) {
+        self.notice(category, None, message, true);
+    }
+
+    /// Critical notice about one unit, so the log can jump to it.
+    fn notify_unit(&mut self, category: MessageCategory, processor_index: usize, message: String) {
+        let subject = self
+            .state
+            .processors
+            .get(processor_index)
+            .map(|processor| LogSubject::Processor(processor.id));
+        self.notice(category, subject, message, true);
+    }
+
+    fn notice(
+        &mut self,
+        category: MessageCategory,
+        subject: Option<LogSubject>,
+        message: String,
+        critical: bool,
+    ) {
+        self.events.push(GameEvent::Notice {
+            category,
+            subject,
+            message,
+            critical,
+        });
+    }
+
+    /// Sends a notice to the outputs the routing matrix picks for its
+    /// category; the event log always gets a copy.
+    fn route_notice(
+        &mut self,
+        category: MessageCategory,
+        subject: Option<LogSubject>,
+        message: String,
+        critical: bool,
+    ) {
+        let level = if critical {
+            ToastLevel::Critical
+        } else {
+            ToastLevel::Info
+        };
+        self.log_entry(Some(category), subject, message.clone());
+        match self.state.display.routing.route(category) {
+            MessageRoute::Log => {}
+            MessageRoute::Ticker => {
+                self.ticker = Some(Toast {
+                    message,
+                    remaining_ms: TICKER_DURATION_MS,
+                    level,
+                });
+            }
+            MessageRoute::Toast => self.push_toast(message, level),
+            MessageRoute::ToastBell => {
+                self.push_toast(message, level);
+                self.bell = true;
+            }
+        }
+    }
+
+    pub fn ticker(&self) -> Option<&Toast> {
+        self.ticker.as_ref()
+    }
+
+    /// True once per notice that asked for the terminal bell.
+    pub fn take_bell(&mut self) -> bool {
+        std::mem::take(&mut self.bell)
+    }
+
+    pub fn cycle_message_route(&mut self, category: MessageCategory) {
+        self.state.display.routing.cycle(category);
+    }
+
+    /// Emits a sample notice in every category so the routing can be
+    /// checked without waiting for the real thing.
+    pub fn preview_routing(&mut self) {
+        for category in MessageCategory::ALL {
+            self.notify(category, format!("Test {} notice.", category.label()));
+        }
+        self.process_events();
+    }
+
+    fn process_events(&mut self) {
+        for event in std::mem::take(&mut self.events) {
+            match event {
+                GameEvent::EscrowForfeited { job_name, amount } => {
+                    self.route_notice(
+                        MessageCategory::Economy,
+                        None,
+                        format!("Escrow forfeited: {amount} cr on {job_name}"),
+                        false,
+                    );
+                }
+                GameEvent::JobCompleted { tag, .. } => {
+                    if let Some(rank) = self.state.statistics.record_completion(&tag) {
+                        self.route_notice(
+                            MessageCategory::Jobs,
+                            None,
+                            format!(
+                                "Rank up: {} {tag} (+{:.0}% {tag} payouts)",
+                                rank.label(),
+                                rank.payout_bonus() * 100.0
+                            ),
+                            false,
+                        );
+                    }
+                }
+                GameEvent::Notice {
+                    category,
+                    subject,
+                    message,
+                    critical,
+                } => self.route_notice(category, subject, message, critical),
+            }
+        }
+    }
+
+    fn push_message(&mut self, message: String) {
+        self.log_entry(None, None, message);
+    }
+
+    fn push_message_about(&mut self, subject: LogSubject, message: String) {
+        self.log_entry(None, Some(subject), message);
+    }
+
+    /// Appends to the log and mirrors the text into the state so it is saved.
+    fn log_entry(
+        &mut self,
+        category: Option<MessageCategory>,
+        subject: Option<LogSubject>,
+        message: String,
+    ) {
+        let recent = &mut self.state.recent_messages;
+        if recent.len() >= MAX_LOG_ENTRIES {
+            recent.remove(0);
+        }
+        recent.push(message.clone());
+        self.log.push(category, subject, message);
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StoreItem {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub base_cost: u64,
+    pub cost_step: u64,
+    pub action: StoreAction,
+    pub max_purchases: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StoreAction {
+    IncreaseSpeed,
+    ImproveQuality,
+    ExpandStorage,
+    UnlockInstructionSet {
+        tag: &'static str,
+    },
+    UpgradeCooling,
+    UpgradeHardening,
+    ApplyThermalPaste,
+    ReplaceProcessor,
+    ReplaceModel,
+    InstallDaemonFirmware,
+    InstallForecast,
+    UpgradePanel,
+    ServiceProcessor,
+    /// Takes up to `REPAIR_WEAR_STEP` of wear off a working unit.
+    RepairProcessor,
+    /// Adds an empty rack.
+    BuyRack,
+    /// Raises the shared cooling of the selected unit's rack.
+    UpgradeRackCooling,
+    /// Raises the selected unit's hardening cap once it has reached it.
+    ReinforceChassis,
+    /// Adds a licensed instruction set to the selected unit only.
+    InstallInstruction {
+        tag: &'static str,
+    },
+    /// Racks a new unit of `model`, priced at its purchase cost.
+    BuyProcessor {
+        model: &'static str,
+    },
+    /// Covers the selected unit against burnout and destruction for a
+    /// daily premium.
+    InsureProcessor,
+    /// Shields the selected unit from ANGEL backlash.
+    InstallFaradayShroud,
+}
+
+pub const STORE_ITEMS: [StoreItem; 31] = [
+    StoreItem {
+        name: "Clock Tuning",
+        description: "Trim execution cycles for all processors (+0.05 speed each purchase).",
+        base_cost: 120,
+        cost_step: 45,
+        action: StoreAction::IncreaseSpeed,
+        max_purchases: None,
+    },
     StoreItem {
         name: "Precision Calibration",
         description: "Improve processor quality bias (+1 each purchase).",
@@ -1012,7 +4090,7 @@ const STORE_ITEMS: [StoreItem; 10] = [
     },
     StoreItem {
         name: "Instruction Microcode",
-        description: "Install SIMD microcode; unlocks advanced job stream and adds support to processors.",
+        description: "License SIMD microcode; unlocks the advanced job stream. Install it per unit.",
         base_cost: 260,
         cost_step: 0,
         action: StoreAction::UnlockInstructionSet {
@@ -1046,7 +4124,7 @@ const STORE_ITEMS: [StoreItem; 10] = [
     },
     StoreItem {
         name: "Daemon Microcode",
-        description: "Unlock automation firmware for the selected processor and ease penalties.",
+        description: "Install or upgrade automation firmware on the selected processor; each level eases penalties.",
         base_cost: 180,
         cost_step: 80,
         action: StoreAction::InstallDaemonFirmware,
@@ -1068,162 +4146,3104 @@ const STORE_ITEMS: [StoreItem; 10] = [
         action: StoreAction::ReplaceModel,
         max_purchases: None,
     },
+    StoreItem {
+        name: "Forecast Terminal",
+        description: "Reveal upcoming electricity rates and demand (tier 1: tomorrow, tier 2: two days out).",
+        base_cost: 220,
+        cost_step: 180,
+        action: StoreAction::InstallForecast,
+        max_purchases: Some(OUTLOOK_DAYS as u32),
+    },
+    StoreItem {
+        name: "Panel Upgrade",
+        description: "Heavier service panel; raises the facility power budget by 6 kWh.",
+        base_cost: 150,
+        cost_step: 90,
+        action: StoreAction::UpgradePanel,
+        max_purchases: Some(4),
+    },
+    StoreItem {
+        name: "Field Service",
+        description: "Overhaul the selected processor, clearing its wear; priced by wear.",
+        base_cost: 30,
+        cost_step: 150,
+        action: StoreAction::ServiceProcessor,
+        max_purchases: None,
+    },
+    StoreItem {
+        name: "F12-Scalar Unit",
+        description: "Rack another general-purpose processor.",
+        base_cost: 0,
+        cost_step: 0,
+        action: StoreAction::BuyProcessor {
+            model: "Model F12-Scalar",
+        },
+        max_purchases: None,
+    },
+    StoreItem {
+        name: "F9-Budget Unit",
+        description: "Rack a cheap processor that runs slower and wears fast when hot.",
+        base_cost: 0,
+        cost_step: 0,
+        action: StoreAction::BuyProcessor {
+            model: "Model F9-Budget",
+        },
+        max_purchases: None,
+    },
+    StoreItem {
+        name: "F12-Rugged Unit",
+        description: "Rack a slow processor that ships with level 2 hardening.",
+        base_cost: 0,
+        cost_step: 0,
+        action: StoreAction::BuyProcessor {
+            model: "Model F12-Rugged",
+        },
+        max_purchases: None,
+    },
+    StoreItem {
+        name: "Patch Repair",
+        description: "Take up to 40% wear off the selected working processor; priced by wear removed.",
+        base_cost: 15,
+        cost_step: 120,
+        action: StoreAction::RepairProcessor,
+        max_purchases: None,
+    },
+    StoreItem {
+        name: "Server Rack",
+        description: "Holds four units that share cooling, and each other's heat.",
+        base_cost: 160,
+        cost_step: 80,
+        action: StoreAction::BuyRack,
+        max_purchases: Some(4),
+    },
+    StoreItem {
+        name: "Rack Cooling",
+        description: "Shared cooling for the selected unit's rack (+1 level for every member).",
+        base_cost: 140,
+        cost_step: 120,
+        action: StoreAction::UpgradeRackCooling,
+        max_purchases: None,
+    },
+    StoreItem {
+        name: "F24-Dual Unit",
+        description: "Rack a two-core processor that runs two jobs at once, at double heat and draw.",
+        base_cost: 0,
+        cost_step: 0,
+        action: StoreAction::BuyProcessor {
+            model: "Model F24-Dual",
+        },
+        max_purchases: None,
+    },
+    StoreItem {
+        name: "Reinforced Chassis",
+        description: "Lets a fully hardened processor take one more Hardening Module.",
+        base_cost: 150,
+        cost_step: 75,
+        action: StoreAction::ReinforceChassis,
+        max_purchases: None,
+    },
+    StoreItem {
+        name: "SIMD Microcode Install",
+        description: "Install licensed SIMD microcode on the selected processor.",
+        base_cost: 90,
+        cost_step: 0,
+        action: StoreAction::InstallInstruction {
+            tag: jobs::SIMD_TAG,
+        },
+        max_purchases: None,
+    },
+    StoreItem {
+        name: "X1-Prototype Unit",
+        description: "Experimental: very fast, but wears out for good within days; costly to replace.",
+        base_cost: 0,
+        cost_step: 0,
+        action: StoreAction::BuyProcessor {
+            model: "Model X1-Prototype",
+        },
+        max_purchases: None,
+    },
+    StoreItem {
+        name: "X7-Vector Unit",
+        description: "Experimental: ships with SIMD and a quality edge, on a short lifespan.",
+        base_cost: 0,
+        cost_step: 0,
+        action: StoreAction::BuyProcessor {
+            model: "Model X7-Vector",
+        },
+        max_purchases: None,
+    },
+    StoreItem {
+        name: "Insurance Contract",
+        description: "Cover the selected unit: a daily premium with upkeep; burnouts and losses pay out.",
+        base_cost: 40,
+        cost_step: 0,
+        action: StoreAction::InsureProcessor,
+        max_purchases: None,
+    },
+    StoreItem {
+        name: "Radiation Microcode",
+        description: "License RADIATION microcode; unlocks long, well-paid hazard work. Harden the units that run it.",
+        base_cost: 480,
+        cost_step: 0,
+        action: StoreAction::UnlockInstructionSet {
+            tag: jobs::RADIATION_TAG,
+        },
+        max_purchases: Some(1),
+    },
+    StoreItem {
+        name: "RADIATION Microcode Install",
+        description: "Install licensed RADIATION microcode on the selected processor.",
+        base_cost: 150,
+        cost_step: 0,
+        action: StoreAction::InstallInstruction {
+            tag: jobs::RADIATION_TAG,
+        },
+        max_purchases: None,
+    },
+    StoreItem {
+        name: "ANGEL Microcode Install",
+        description: "Install ANGEL microcode on the selected processor, once ANGEL contact is made.",
+        base_cost: 300,
+        cost_step: 0,
+        action: StoreAction::InstallInstruction {
+            tag: jobs::ANGEL_TAG,
+        },
+        max_purchases: None,
+    },
+    StoreItem {
+        name: "Faraday Shroud",
+        description: "Shield the selected unit so a failed ANGEL job can't destroy it.",
+        base_cost: 900,
+        cost_step: 0,
+        action: StoreAction::InstallFaradayShroud,
+        max_purchases: None,
+    },
+    StoreItem {
+        name: "Surveillance Microcode",
+        description: "License SURVEILLANCE microcode; intercept work that holds archive space and boosts the day's dividend.",
+        base_cost: 360,
+        cost_step: 0,
+        action: StoreAction::UnlockInstructionSet {
+            tag: jobs::SURVEILLANCE_TAG,
+        },
+        max_purchases: Some(1),
+    },
+    StoreItem {
+        name: "SURVEILLANCE Microcode Install",
+        description: "Install licensed SURVEILLANCE microcode on the selected processor.",
+        base_cost: 120,
+        cost_step: 0,
+        action: StoreAction::InstallInstruction {
+            tag: jobs::SURVEILLANCE_TAG,
+        },
+        max_purchases: None,
+    },
 ];
 
-#[derive(Debug, Error)]
-pub enum PurchaseError {
-    #[error("not enough credits (requires {cost})")]
-    InsufficientCredits { cost: u64 },
-    #[error("unknown store item")]
-    InvalidItem,
-    #[error("{item} is sold out")]
-    MaxedOut { item: &'static str },
-    #[error("{tag} instruction set already unlocked")]
-    InstructionAlreadyUnlocked { tag: String },
-    #[error("select a processor first")]
-    ProcessorSelectionRequired,
-    #[error("selected processor is operational")]
-    ProcessorHealthy,
-    #[error("no matching processors require replacement")]
-    NoMatchingProcessors,
-    #[error("upgrade already at maximum level")]
-    UpgradeAtCap,
-    #[error("daemon firmware already installed")]
-    DaemonAlreadyInstalled,
-}
+#[derive(Debug, Error)]
+pub enum PurchaseError {
+    #[error("not enough credits (requires {cost})")]
+    InsufficientCredits { cost: u64 },
+    #[error("{cost} cr would breach the {reserved} cr reserve")]
+    ReserveBreach { cost: u64, reserved: u64 },
+    #[error("unknown store item")]
+    InvalidItem,
+    #[error("{item} is sold out")]
+    MaxedOut { item: &'static str },
+    #[error("{tag} instruction set already unlocked")]
+    InstructionAlreadyUnlocked { tag: String },
+    #[error("{tag} microcode is not licensed yet")]
+    InstructionLocked { tag: String },
+    #[error("selected processor already runs {tag}")]
+    InstructionAlreadyInstalled { tag: String },
+    #[error("select a processor first")]
+    ProcessorSelectionRequired,
+    #[error("selected processor is operational")]
+    ProcessorHealthy,
+    #[error("no matching processors require replacement")]
+    NoMatchingProcessors,
+    #[error("upgrade already at maximum level")]
+    UpgradeAtCap,
+    #[error("daemon firmware already at its top level")]
+    DaemonFirmwareMaxed,
+    #[error("automation is disabled this run")]
+    DaemonDisabled,
+    #[error("selected processor has no wear to service")]
+    NothingToService,
+    #[error("selected processor is offline; replace it instead")]
+    ProcessorOffline,
+    #[error("selected processor is not in a rack")]
+    NotRacked,
+    #[error("selected processor is busy; let its job finish first")]
+    ProcessorBusy,
+    #[error("selected processor is already that model")]
+    SameModel,
+    #[error("selected processor is already insured")]
+    AlreadyInsured,
+    #[error("selected processor already has a Faraday Shroud")]
+    AlreadyShrouded,
+}
+
+#[derive(Debug, Error)]
+pub enum TrialError {
+    #[error("automation is disabled this run")]
+    Disabled,
+    #[error("the daemon trial has already been used this run")]
+    AlreadyUsed,
+    #[error("invalid processor index")]
+    InvalidProcessor,
+    #[error("daemon firmware already installed")]
+    AlreadyInstalled,
+    #[error("processor is offline")]
+    Offline,
+}
+
+#[derive(Debug, Error)]
+pub enum RetireError {
+    #[error("invalid processor index")]
+    InvalidProcessor,
+    #[error("processor is busy")]
+    ProcessorBusy,
+    #[error("cannot retire the last processor")]
+    LastProcessor,
+    #[error("processor still runs; retire it instead")]
+    StillOperational,
+}
+
+/// Credits per second a job pays on a unit that takes `secs` to run it.
+fn reward_rate(reward: u64, secs: f64) -> f64 {
+    if secs > 0.0 {
+        (reward as f64 / secs).max(0.0)
+    } else {
+        reward as f64
+    }
+}
+
+/// Reward-per-time score the daemon assigns a job, or `None` when the
+/// processor should not take it at all.
+fn daemon_job_score(
+    processor: &ProcessorState,
+    job: &Job,
+    cooling_bonus_levels: u8,
+    ambient_temperature: f64,
+) -> Option<f64> {
+    if !processor.supports(&job.tag)
+        || processor.daemon_blocks(&job.tag)
+        || (job.is_utility() && !processor.allow_utility)
+    {
+        return None;
+    }
+    let evaluation = processor.evaluate_job(job, cooling_bonus_levels, ambient_temperature);
+    if processor.honor_cooling_mins
+        && processor.requires_cooling_min > evaluation.effective_cooling
+        && job.tag != jobs::GENERAL_TAG
+    {
+        return None;
+    }
+    if evaluation.reliability < 0.35 {
+        return None;
+    }
+    if processor.honor_cooling_mins && evaluation.heat > 1.8 {
+        return None;
+    }
+    let duration =
+        economy::assignment_duration_ms(job, processor, Some(&processor.daemon_penalty)) as f64;
+    let base_score = if duration > 0.0 {
+        (job.base_reward as f64 / duration).max(0.0)
+    } else {
+        job.base_reward as f64
+    };
+    let affinity = processor
+        .daemon_affinity
+        .get(&job.tag)
+        .copied()
+        .unwrap_or(0.0);
+    let safety = (evaluation.reliability - 0.7) * 0.5;
+    Some(base_score + affinity + safety)
+}
+
+/// Whether a job at `progress` (0..1) should be dropped for a candidate whose
+/// score beats the current one by at least `factor`.
+fn should_preempt(progress: f64, current_score: f64, candidate_score: f64, factor: f64) -> bool {
+    progress < PREEMPT_PROGRESS_LIMIT
+        && candidate_score > current_score
+        && candidate_score > current_score.max(0.0) * factor
+}
+
+/// Price of a per-processor upgrade, or `None` when it no longer applies.
+/// Field service price for the processor, or None when it has nothing to fix.
+pub fn service_cost(item: &StoreItem, processor: &ProcessorState) -> Option<u64> {
+    if processor.wear <= 0.0 || !processor.is_functional() {
+        return None;
+    }
+    Some(item.base_cost + (item.cost_step as f64 * processor.wear.min(1.0)).round() as u64)
+}
+
+/// Patch repair price, charged for the wear it will actually remove, or None
+/// when the unit is offline or barely worn.
+pub fn repair_cost(item: &StoreItem, processor: &ProcessorState) -> Option<u64> {
+    if processor.wear < REPAIR_MIN_WEAR || !processor.is_functional() {
+        return None;
+    }
+    let removed = processor.wear.min(REPAIR_WEAR_STEP);
+    Some(item.base_cost + (item.cost_step as f64 * removed).round() as u64)
+}
+
+pub fn upgrade_cost(item: &StoreItem, processor: &ProcessorState) -> Option<u64> {
+    match item.action {
+        StoreAction::UpgradeCooling if processor.cooling_level < processor.cooling_cap => {
+            let price = item.base_cost + item.cost_step * processor.cooling_level as u64;
+            Some(if processor.cooling_worn > 0 {
+                (price as f64 * COOLING_RECHARGE_RATIO).round() as u64
+            } else {
+                price
+            })
+        }
+        StoreAction::UpgradeHardening if processor.hardening_level < processor.hardening_cap => {
+            // Levels past the full-strength ones pay a second step apiece.
+            let late = processor
+                .hardening_level
+                .saturating_sub(TUNING.hardening_full_levels);
+            Some(
+                item.base_cost
+                    + item.cost_step * processor.hardening_level as u64
+                    + item.cost_step * late as u64,
+            )
+        }
+        StoreAction::ReinforceChassis
+            if processor.hardening_level >= processor.hardening_cap
+                && processor.hardening_cap < MAX_HARDENING_CAP =>
+        {
+            Some(item.base_cost + item.cost_step * processor.hardening_cap as u64)
+        }
+        StoreAction::InstallDaemonFirmware
+            if processor.daemon_trial
+                || processor.daemon_firmware_level < MAX_DAEMON_FIRMWARE_LEVEL =>
+        {
+            // Loaned firmware is not owned; the trial unit pays for level 1.
+            let owned = if processor.daemon_trial {
+                0
+            } else {
+                processor.daemon_firmware_level
+            };
+            Some(item.base_cost + item.cost_step * owned as u64)
+        }
+        _ => None,
+    }
+}
+
+fn replacement_cost_for_processor(processor: &ProcessorState, discount: f64) -> u64 {
+    if processor.is_functional() || processor.under_warranty() {
+        return 0;
+    }
+    let base = processor.purchase_cost as f64 * processor.replace_cost_ratio;
+    ((base * (1.0 - discount.clamp(0.0, 1.0))).round() as u64).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::jobs::{GENERAL_TAG, Job, SIMD_TAG};
+    use crate::sim::processors::{DaemonMode, ProcessorEvent, ProcessorStatus, STALL_TICK_LIMIT};
+    use rand::rngs::mock::StepRng;
+
+    #[test]
+    fn purchasing_microcode_unlocks_simd_tag() {
+        let mut game = Game::fresh();
+        game.state.credits = 1_000;
+        let idx = STORE_ITEMS
+            .iter()
+            .position(|item| matches!(item.action, StoreAction::UnlockInstructionSet { .. }))
+            .expect("microcode item present");
+        let cost = game
+            .item_cost(idx, None)
+            .expect("microcode should be purchasable");
+
+        assert!(!game.is_instruction_unlocked(SIMD_TAG));
+        game.purchase_item(idx, None)
+            .expect("purchase should succeed");
+
+        assert!(game.is_instruction_unlocked(SIMD_TAG));
+        assert!(game.state.unlocked_tags.iter().any(|tag| tag == SIMD_TAG));
+        // The license alone installs nothing.
+        assert!(
+            !game
+                .state
+                .processors
+                .iter()
+                .any(|processor| processor.supports(SIMD_TAG))
+        );
+        assert_eq!(game.store_purchases(idx), Some(1));
+        assert_eq!(game.state.credits, 1_000 - cost);
+        assert!(matches!(
+            game.purchase_item(idx, None),
+            Err(PurchaseError::MaxedOut { .. })
+        ));
+    }
+
+    #[test]
+    fn radiation_microcode_opens_its_own_job_stream() {
+        let mut game = Game::fresh();
+        game.state.credits = 2_000;
+        game.ledger = Ledger::new(2_000);
+        let license = Game::store_index_for(StoreAction::UnlockInstructionSet {
+            tag: jobs::RADIATION_TAG,
+        })
+        .expect("radiation license on sale");
+        let install = Game::store_index_for(StoreAction::InstallInstruction {
+            tag: jobs::RADIATION_TAG,
+        })
+        .expect("radiation install on sale");
+        assert!(game.item_cost(install, Some(0)).is_none());
+        game.purchase_item(license, None).expect("license");
+        game.purchase_item(install, Some(0)).expect("install");
+        assert!(game.state.processors[0].supports(jobs::RADIATION_TAG));
+
+        let mut radiation = 0;
+        for _ in 0..200 {
+            game.state.jobs.clear();
+            game.state.contracts.clear();
+            game.spawn_job_if_possible();
+            if let Some(job) = game.state.jobs.first()
+                && job.tag == jobs::RADIATION_TAG
+            {
+                assert!(job.base_time_ms >= 12_000);
+                radiation += 1;
+            }
+        }
+        assert!(radiation > 0 && radiation < 100, "{radiation}");
+    }
+
+    #[test]
+    fn replacing_burnt_out_processor_spends_credits() {
+        let mut game = Game::fresh();
+        game.state.credits = 500;
+        let processor = &mut game.state.processors[0];
+        processor.status = ProcessorStatus::BurntOut;
+        let expected_cost =
+            ((processor.purchase_cost as f64) * processor.replace_cost_ratio).round() as u64;
+
+        game.replace_processor_direct(0)
+            .expect("replacement should succeed");
+
+        assert_eq!(game.state.credits, 500 - expected_cost);
+        assert!(matches!(
+            game.state.processors[0].status,
+            ProcessorStatus::Idle
+        ));
+        assert!(game.state.processors[0].wear <= f64::EPSILON);
+    }
+
+    #[test]
+    fn cycling_daemon_mode_traverses_states() {
+        let mut game = Game::fresh();
+        game.state.daemon_unlocked = true;
+        let processor = &mut game.state.processors[0];
+        processor.daemon_firmware_level = 1;
+
+        assert_eq!(processor.daemon_mode, DaemonMode::Off);
+        game.cycle_daemon_mode(0);
+        assert_eq!(game.state.processors[0].daemon_mode, DaemonMode::Assist);
+        game.cycle_daemon_mode(0);
+        assert_eq!(game.state.processors[0].daemon_mode, DaemonMode::Auto);
+        game.cycle_daemon_mode(0);
+        assert_eq!(game.state.processors[0].daemon_mode, DaemonMode::Off);
+    }
+
+    #[test]
+    fn cooling_upgrade_respects_cap() {
+        let mut game = Game::fresh();
+        game.state.credits = 1_000;
+        let processor_index = 0;
+        let cooling_idx = STORE_ITEMS
+            .iter()
+            .position(|item| item.action == StoreAction::UpgradeCooling)
+            .expect("cooling kit present");
+
+        game.purchase_item(cooling_idx, Some(processor_index))
+            .expect("upgrade should succeed");
+        assert_eq!(game.state.processors[processor_index].cooling_level, 1);
+
+        // Bump to cap
+        game.purchase_item(cooling_idx, Some(processor_index))
+            .expect("second upgrade should succeed");
+        game.purchase_item(cooling_idx, Some(processor_index))
+            .expect("third upgrade should succeed");
+
+        assert_eq!(game.state.processors[processor_index].cooling_level, 3);
+        assert!(matches!(
+            game.purchase_item(cooling_idx, Some(processor_index)),
+            Err(PurchaseError::UpgradeAtCap)
+        ));
+    }
+
+    #[test]
+    fn hardening_past_the_stock_cap_needs_a_chassis_and_costs_more() {
+        let mut game = Game::fresh();
+        game.state.credits = 10_000;
+        let harden = Game::store_index_for(StoreAction::UpgradeHardening).expect("listed");
+        let chassis = Game::store_index_for(StoreAction::ReinforceChassis).expect("listed");
+        game.state.processors[0].hardening_level = 3;
+        assert_eq!(game.item_cost(harden, Some(0)), None);
+        assert_eq!(game.item_cost(chassis, Some(0)), Some(150 + 75 * 3));
+
+        game.purchase_item(chassis, Some(0)).expect("reinforces");
+        assert_eq!(game.state.processors[0].hardening_cap, 4);
+        assert_eq!(game.item_cost(chassis, Some(0)), None);
+        // Level 4 is priced as usual; each level after it adds a late step.
+        assert_eq!(game.item_cost(harden, Some(0)), Some(140 + 55 * 3));
+        game.purchase_item(harden, Some(0)).expect("hardens");
+        game.state.processors[0].hardening_cap = 5;
+        assert_eq!(game.item_cost(harden, Some(0)), Some(140 + 55 * 4 + 55));
+
+        game.state.processors[0].hardening_level = MAX_HARDENING_CAP;
+        game.state.processors[0].hardening_cap = MAX_HARDENING_CAP;
+        assert!(matches!(
+            game.purchase_item(chassis, Some(0)),
+            Err(PurchaseError::UpgradeAtCap)
+        ));
+    }
+
+    #[test]
+    fn assist_mode_assigns_suggested_job() {
+        let mut game = Game::fresh();
+        game.state.daemon_unlocked = true;
+        let processor = &mut game.state.processors[0];
+        processor.daemon_firmware_level = 1;
+        processor.daemon_mode = DaemonMode::Assist;
+
+        game.state.jobs.push(Job {
+            id: 42,
+            name: "Assist Contract".to_string(),
+            tag: GENERAL_TAG.to_string(),
+            base_time_ms: 5_000,
+            base_reward: 150,
+            quality_target: 60,
+            data_output: 30,
+            ..Job::default()
+        });
+
+        assert!(game.accept_assist_suggestion(0));
+        assert!(game.state.jobs.is_empty());
+        assert!(matches!(
+            game.state.processors[0].status,
+            ProcessorStatus::Working(_)
+        ));
+    }
+
+    #[test]
+    fn scheduled_follow_up_posts_after_delay() {
+        let mut game = Game::fresh();
+        let processor_id = game.state.processors[0].id;
+        let original = Job {
+            id: 1,
+            name: "Original".to_string(),
+            tag: GENERAL_TAG.to_string(),
+            base_time_ms: 5_000,
+            base_reward: 100,
+            quality_target: 60,
+            data_output: 10,
+            ..Job::default()
+        };
+        game.schedule_follow_up(&original, processor_id);
+        let delay_ms = game.state.scheduled_jobs[0].delay_ms;
+
+        game.advance_scheduled_jobs(Duration::from_millis(delay_ms - 1));
+        assert!(game.state.jobs.is_empty());
+        game.advance_scheduled_jobs(Duration::from_millis(1));
+
+        let posted = &game.state.jobs[0];
+        assert!(game.state.scheduled_jobs.is_empty());
+        assert_eq!(posted.follow_up_of, Some(1));
+        assert_eq!(posted.preferred_processor_id, Some(processor_id));
+        assert_eq!(posted.tag, GENERAL_TAG);
+        assert!(posted.base_reward > original.base_reward);
+    }
+
+    #[test]
+    fn preferred_processor_earns_follow_up_bonus() {
+        let job = Job {
+            id: 9,
+            name: "Follow-up".to_string(),
+            tag: GENERAL_TAG.to_string(),
+            base_time_ms: 1_000,
+            base_reward: 200,
+            quality_target: 60,
+            data_output: 0,
+            follow_up_of: Some(1),
+            preferred_processor_id: Some(999),
+            ..Job::default()
+        };
+        let complete = |preferred_id: u64| {
+            let mut game = Game::fresh();
+            game.state.processors[0].id = preferred_id;
+            let before = game.state.credits;
+            game.resolve_completed_job(
+                0,
+                CompletedJob {
+                    job: job.clone(),
+                    daemon_penalty: None,
+                    forced: false,
+                },
+            );
+            game.state.credits - before
+        };
+
+        // Quality noise is +-4 around the target, so the bonus separates the ranges.
+        assert!(complete(999) >= 225);
+        assert!(complete(1) <= 204);
+    }
+
+    #[test]
+    fn retiring_veteran_processor_enters_museum() {
+        let mut game = Game::fresh();
+        let mut veteran = ProcessorState::starter();
+        veteran.id = 77;
+        veteran.jobs_completed = museum::MUSEUM_MIN_COMPLETIONS;
+        game.state.processors.push(veteran);
+        let rookie_index = 0;
+        let before = game.state.credits;
+
+        let value = game.retire_processor(1).expect("veteran retires");
+        assert_eq!(game.state.credits, before + value);
+        assert_eq!(game.state.museum.len(), 1);
+        assert_eq!(game.state.museum[0].processor_id, 77);
+        assert_eq!(
+            game.museum_quality_bonus(),
+            museum::MUSEUM_QUALITY_PER_ENTRY
+        );
+
+        assert!(matches!(
+            game.retire_processor(rookie_index),
+            Err(RetireError::LastProcessor)
+        ));
+    }
+
+    #[test]
+    fn retiring_rookie_or_busy_processor_skips_museum() {
+        let mut game = Game::fresh();
+        game.state.processors.push(ProcessorState::starter());
+        game.state.processors.push(ProcessorState::starter());
+        game.state.processors[0].status = ProcessorStatus::BurntOut;
+        game.state.processors[1].assign(Job::default(), 1_000, None);
+
+        assert!(matches!(
+            game.retire_processor(1),
+            Err(RetireError::ProcessorBusy)
+        ));
+        assert_eq!(game.retire_processor(0).expect("scrap offline unit"), 0);
+        assert!(game.state.museum.is_empty());
+        assert_eq!(game.state.processors.len(), 2);
+    }
+
+    #[test]
+    fn daily_cycle_applies_forecasted_market() {
+        let mut game = Game::fresh();
+        assert_eq!(game.state.market_outlook.len(), OUTLOOK_DAYS);
+        assert_eq!(game.visible_forecast().count(), 0);
+
+        let idx = Game::store_index_for(StoreAction::InstallForecast).expect("terminal present");
+        game.state.credits = 10_000;
+        game.purchase_item(idx, None).expect("tier 1");
+        let forecast: Vec<DailyOutlook> = game.visible_forecast().copied().collect();
+        assert_eq!(forecast.len(), 1);
+        game.purchase_item(idx, None).expect("tier 2");
+        let two_day: Vec<DailyOutlook> = game.visible_forecast().copied().collect();
+        assert_eq!(two_day.len(), 2);
+
+        game.apply_daily_cycle();
+        assert_eq!(game.state.market_today, two_day[0]);
+        assert_eq!(game.state.market_outlook[0], two_day[1]);
+        game.apply_daily_cycle();
+        assert_eq!(game.state.market_today, two_day[1]);
+        assert_eq!(game.state.market_outlook.len(), OUTLOOK_DAYS);
+    }
+
+    #[test]
+    fn job_candidates_cover_compatible_board_jobs() {
+        let mut game = Game::fresh();
+        game.state.jobs.push(Job {
+            id: 1,
+            name: "Vector".to_string(),
+            tag: SIMD_TAG.to_string(),
+            base_time_ms: 4_000,
+            base_reward: 200,
+            ..Job::default()
+        });
+        game.state.jobs.push(Job {
+            id: 2,
+            name: "Scalar".to_string(),
+            tag: GENERAL_TAG.to_string(),
+            base_time_ms: 4_000,
+            base_reward: 100,
+            ..Job::default()
+        });
+
+        let candidates = game.job_candidates(0);
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].job_index, 1);
+        assert!((candidates[0].eta_secs - 4.0).abs() < 1e-9);
+
+        game.assign_board_job(candidates[0].job_index, 0)
+            .expect("assignment succeeds");
+        assert_eq!(game.state.jobs.len(), 1);
+        assert_eq!(game.state.jobs[0].id, 1);
+        assert!(matches!(
+            game.assign_board_job(0, 0),
+            Err(AssignmentError::ProcessorBusy)
+        ));
+        assert_eq!(game.state.jobs.len(), 1);
+    }
+
+    #[test]
+    fn tag_completions_award_ranks_with_toasts() {
+        use crate::sim::stats::TagRank;
+
+        let mut game = Game::fresh();
+        for _ in 0..TagRank::Apprentice.threshold() - 1 {
+            game.events.push(GameEvent::JobCompleted {
+                processor_id: 1,
+                tag: GENERAL_TAG.to_string(),
+                quality: 60,
+                payout: 100,
+            });
+        }
+        game.process_events();
+        assert_eq!(game.state.statistics.tag_rank(GENERAL_TAG), None);
+        assert_eq!(game.toasts().count(), 0);
+
+        game.events.push(GameEvent::JobCompleted {
+            processor_id: 1,
+            tag: GENERAL_TAG.to_string(),
+            quality: 60,
+            payout: 100,
+        });
+        game.process_events();
+        assert_eq!(
+            game.state.statistics.tag_rank(GENERAL_TAG),
+            Some(TagRank::Apprentice)
+        );
+        assert!(
+            game.toasts()
+                .any(|toast| toast.message.contains("Apprentice"))
+        );
+
+        game.age_toasts(Duration::from_millis(TOAST_DURATION_MS));
+        assert_eq!(game.toasts().count(), 0);
+    }
+
+    #[test]
+    fn rank_thresholds_follow_ladder() {
+        use crate::sim::stats::TagRank;
+
+        assert_eq!(TagRank::for_completions(9), None);
+        assert_eq!(TagRank::for_completions(10), Some(TagRank::Apprentice));
+        assert_eq!(TagRank::for_completions(49), Some(TagRank::Apprentice));
+        assert_eq!(TagRank::for_completions(50), Some(TagRank::Journeyman));
+        assert_eq!(TagRank::for_completions(200), Some(TagRank::Master));
+    }
+
+    fn preempt_ready_game() -> Game {
+        let mut game = Game::fresh();
+        game.state.daemon_unlocked = true;
+        let processor = &mut game.state.processors[0];
+        processor.daemon_firmware_level = 1;
+        processor.daemon_mode = DaemonMode::Auto;
+        processor.daemon_preempt = true;
+        let slow = Job {
+            id: 1,
+            name: "Slow Grind".to_string(),
+            tag: GENERAL_TAG.to_string(),
+            base_time_ms: 10_000,
+            base_reward: 50,
+            ..Job::default()
+        };
+        let penalty = processor.daemon_penalty.clone();
+        let total = economy::assignment_duration_ms(&slow, processor, Some(&penalty));
+        processor.assign(slow, total, Some(penalty));
+        game
+    }
+
+    fn rush_order(reward: u64) -> Job {
+        Job {
+            id: 2,
+            name: "Rush Order".to_string(),
+            tag: GENERAL_TAG.to_string(),
+            base_time_ms: 2_000,
+            base_reward: reward,
+            ..Job::default()
+        }
+    }
+
+    fn set_progress(game: &mut Game, progress: f64) {
+        if let ProcessorStatus::Working(work) = &mut game.state.processors[0].status {
+            work.remaining_ms = ((1.0 - progress) * work.total_ms as f64).round() as u64;
+        }
+    }
+
+    fn working_job_id(game: &Game) -> Option<u64> {
+        match &game.state.processors[0].status {
+            ProcessorStatus::Working(work) => Some(work.job.id),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn daemon_preempts_early_job_for_much_better_work() {
+        let mut game = preempt_ready_game();
+        game.state.credits = 100;
+        set_progress(&mut game, 0.1);
+        game.state.jobs.push(rush_order(400));
+
+        game.try_daemon_assignment();
+
+        assert_eq!(working_job_id(&game), Some(2));
+        assert_eq!(game.state.jobs[0].id, 1);
+        assert_eq!(game.state.statistics.daemon_preemptions, 1);
+        // 5 cr cancellation penalty plus the rush contract's 80 cr deposit.
+        assert_eq!(game.state.credits, 15);
+    }
+
+    #[test]
+    fn general_affinity_beats_a_better_paying_simd_job() {
+        let mut game = Game::fresh();
+        game.state.processors[0]
+            .instruction_set
+            .push(jobs::SIMD_TAG.to_string());
+        let job = |id, tag: &str, reward| Job {
+            id,
+            name: format!("{tag} job"),
+            tag: tag.to_string(),
+            base_time_ms: 5_000,
+            base_reward: reward,
+            ..Job::default()
+        };
+        game.state.jobs = vec![job(1, GENERAL_TAG, 50), job(2, jobs::SIMD_TAG, 200)];
+        assert_eq!(
+            game.choose_daemon_job(0, 0).map(|(index, _)| index),
+            Some(1)
+        );
+
+        for _ in 0..10 {
+            game.adjust_daemon_affinity(0, GENERAL_TAG, 0.1);
+        }
+        assert_eq!(
+            game.state.processors[0].daemon_affinity.get(GENERAL_TAG),
+            Some(&1.0)
+        );
+        assert_eq!(
+            game.choose_daemon_job(0, 0).map(|(index, _)| index),
+            Some(0)
+        );
+
+        for _ in 0..40 {
+            game.adjust_daemon_affinity(0, GENERAL_TAG, -0.1);
+        }
+        assert_eq!(
+            game.state.processors[0].daemon_affinity.get(GENERAL_TAG),
+            Some(&-MAX_DAEMON_AFFINITY)
+        );
+    }
+
+    #[test]
+    fn blocked_tags_are_left_to_the_player() {
+        let mut game = Game::fresh();
+        let processor = &mut game.state.processors[0];
+        processor.daemon_firmware_level = 1;
+        processor.daemon_mode = DaemonMode::Assist;
+        game.state.jobs = vec![rush_order(40)];
+        assert!(game.assist_suggestion(0).is_some());
+
+        assert_eq!(game.toggle_daemon_block(0, GENERAL_TAG), Some(true));
+        assert!(game.assist_suggestion(0).is_none());
+        game.state.processors[0].daemon_mode = DaemonMode::Auto;
+        assert!(game.choose_daemon_job(0, 0).is_none());
+        game.try_daemon_assignment();
+        assert!(game.state.processors[0].is_idle());
+
+        game.assign_board_job(0, 0)
+            .expect("manual assignment still works");
+        assert_eq!(game.toggle_daemon_block(0, GENERAL_TAG), Some(false));
+        assert!(game.state.processors[0].daemon_blocked_tags.is_empty());
+    }
+
+    #[test]
+    fn higher_priority_units_get_auto_work_before_faster_ones() {
+        let mut game = Game::fresh();
+        game.state.processors = vec![ProcessorState::starter(); 2];
+        for (id, processor) in game.state.processors.iter_mut().enumerate() {
+            processor.id = id as u64;
+            processor.daemon_firmware_level = 1;
+            processor.daemon_mode = DaemonMode::Auto;
+        }
+        game.state.processors[0].speed = 1.5;
+        game.state.jobs = vec![rush_order(40)];
+
+        game.adjust_daemon_priority(1, 1);
+        game.try_daemon_assignment();
+
+        assert_eq!(game.state.processors[1].daemon_priority, 1);
+        assert!(game.state.processors[0].is_idle());
+        assert!(!game.state.processors[1].is_idle());
+
+        for _ in 0..2 * MAX_DAEMON_PRIORITY {
+            game.adjust_daemon_priority(1, -1);
+        }
+        assert_eq!(
+            game.state.processors[1].daemon_priority,
+            -MAX_DAEMON_PRIORITY
+        );
+    }
+
+    #[test]
+    fn sparing_worn_units_sends_auto_work_to_healthy_ones_first() {
+        let run = |spare: bool| {
+            let mut game = Game::fresh();
+            game.state.processors = vec![ProcessorState::starter(); 2];
+            for (id, processor) in game.state.processors.iter_mut().enumerate() {
+                processor.id = id as u64;
+                processor.daemon_firmware_level = 1;
+                processor.daemon_mode = DaemonMode::Auto;
+                processor.finite_lifespan = true;
+                processor.mttf_ticks = 100_000;
+            }
+            game.state.processors[0].speed = 1.5;
+            game.state.processors[0].wear = 0.95;
+            assert!(game.state.processors[0].near_end_of_life());
+            game.state.spare_worn_units = spare;
+            game.state.jobs = vec![rush_order(40)];
+            game.try_daemon_assignment();
+            game.state.processors[0].is_idle()
+        };
+        assert!(!run(false), "the faster unit wins by default");
+        assert!(run(true), "the dying unit is saved for last");
+    }
+
+    #[test]
+    fn lifetime_stats_track_completions_and_failures_across_replacement() {
+        let mut game = Game::fresh();
+        game.state.credits = 1_000;
+        game.state.processors[0].reliability_base = 2.0;
+        game.assign_job_to_processor(rush_order(40), 0, false)
+            .expect("assigns");
+        for _ in 0..40 {
+            game.step_processors(Duration::from_millis(100));
+        }
+        let unit = &game.state.processors[0];
+        assert!(unit.is_idle());
+        assert_eq!(unit.jobs_completed, 1);
+        assert_eq!(unit.jobs_failed, 0);
+        assert!(unit.credits_earned > 0);
+        assert!(unit.total_work_ms >= 2_000);
+        let (earned, worked) = (unit.credits_earned, unit.total_work_ms);
+
+        game.state.processors[0].reliability_base = 0.0;
+        game.assign_job_to_processor(rush_order(40), 0, false)
+            .expect("assigns");
+        game.step_processors(Duration::from_millis(100));
+        assert!(matches!(
+            game.state.processors[0].status,
+            ProcessorStatus::BurntOut
+        ));
+        assert_eq!(game.state.processors[0].jobs_failed, 1);
+
+        game.replace_processor_direct(0).expect("replaces");
+        let unit = &game.state.processors[0];
+        assert!(unit.is_idle());
+        assert_eq!(
+            (unit.jobs_completed, unit.jobs_failed, unit.credits_earned),
+            (1, 1, earned)
+        );
+        assert_eq!(unit.total_work_ms, worked + 100);
+    }
+
+    #[test]
+    fn paused_units_take_no_work_and_draw_less() {
+        let mut game = preempt_ready_game();
+        assert!(matches!(
+            game.toggle_paused(0),
+            Err(AssignmentError::ProcessorBusy)
+        ));
+        game.cancel_job(0).expect("cancels");
+        game.state.jobs.clear();
+        let idle_draw = game.state.processors[0].last_power_draw();
+
+        game.toggle_paused(0).expect("pauses");
+        assert!(game.state.processors[0].last_power_draw() < idle_draw);
+        game.state.jobs.push(rush_order(40));
+        game.try_daemon_assignment();
+        assert!(game.state.processors[0].is_idle());
+        assert!(matches!(
+            game.assign_job_to_processor(rush_order(40), 0, false),
+            Err(AssignmentError::ProcessorPaused)
+        ));
+
+        game.toggle_paused(0).expect("resumes");
+        assert_eq!(game.state.processors[0].last_power_draw(), idle_draw);
+        game.try_daemon_assignment();
+        assert_eq!(working_job_id(&game), Some(2));
+    }
+
+    #[test]
+    fn booked_units_service_after_their_job_and_refuse_work_meanwhile() {
+        let mut game = preempt_ready_game();
+        game.toggle_maintenance(0).expect("books");
+        game.toggle_maintenance(0).expect("cancels");
+        assert!(!game.state.processors[0].maintenance_scheduled);
+        game.toggle_maintenance(0).expect("books again");
+
+        game.state.processors[0].reliability_base = 2.0;
+        set_progress(&mut game, 1.0);
+        game.step_processors(Duration::from_millis(100));
+        assert!(game.state.processors[0].is_servicing());
+        game.state.jobs.push(rush_order(400));
+        game.try_daemon_assignment();
+        assert!(game.state.processors[0].is_servicing());
+        assert!(matches!(
+            game.assign_job_to_processor(rush_order(40), 0, false),
+            Err(AssignmentError::ProcessorServicing)
+        ));
+        assert!(matches!(
+            game.toggle_maintenance(0),
+            Err(AssignmentError::ProcessorServicing)
+        ));
+
+        for _ in 0..TUNING.maintenance_ms / 100 {
+            game.step_processors(Duration::from_millis(100));
+        }
+        assert!(
+            game.messages()
+                .any(|message| message.contains("back from maintenance"))
+        );
+        game.try_daemon_assignment();
+        assert_eq!(working_job_id(&game), Some(2));
+    }
+
+    #[test]
+    fn daemon_never_preempts_past_progress_threshold() {
+        let mut game = preempt_ready_game();
+        set_progress(&mut game, PREEMPT_PROGRESS_LIMIT);
+        game.state.jobs.push(rush_order(400));
+
+        game.try_daemon_assignment();
+
+        assert_eq!(working_job_id(&game), Some(1));
+        assert_eq!(game.state.statistics.daemon_preemptions, 0);
+    }
+
+    #[test]
+    fn daemon_ignores_gains_below_factor() {
+        assert!(!should_preempt(0.05, 1.0, 1.49, 1.5));
+        assert!(should_preempt(0.05, 1.0, 1.51, 1.5));
+        assert!(!should_preempt(0.25, 1.0, 10.0, 1.5));
+
+        let mut game = preempt_ready_game();
+        set_progress(&mut game, 0.0);
+        game.state.processors[0].preempt_factor = 100.0;
+        game.state.jobs.push(rush_order(400));
+
+        game.try_daemon_assignment();
+
+        assert_eq!(working_job_id(&game), Some(1));
+        assert_eq!(game.state.statistics.daemon_preemptions, 0);
+    }
+
+    #[test]
+    fn timeline_keeps_one_day_and_stays_out_of_saves() {
+        let mut game = Game::fresh();
+        let baseline = ron::to_string(&game.state).expect("serializes");
+
+        for _ in 0..(timeline_capacity() * 3) {
+            game.sample_timeline(TIMELINE_SAMPLE_INTERVAL);
+        }
+
+        assert_eq!(game.timeline().len(), timeline_capacity());
+        let id = game.state.processors[0].id;
+        assert_eq!(game.timeline().row(id).len(), timeline_capacity());
+        let after = ron::to_string(&game.state).expect("serializes");
+        assert_eq!(baseline.len(), after.len());
+    }
+
+    #[test]
+    fn bought_processors_join_the_fleet_and_its_bills() {
+        let mut game = Game::fresh();
+        game.state.credits = 1_000;
+        let upkeep = game.total_upkeep();
+        let electricity = game.total_electricity_cost();
+        let index = Game::store_index_for(StoreAction::BuyProcessor {
+            model: "Model F9-Budget",
+        })
+        .expect("sold");
+
+        game.purchase_item(index, None).expect("buys");
+
+        let budget = ProcessorState::budget();
+        assert_eq!(game.state.credits, 1_000 - budget.purchase_cost);
+        assert_eq!(game.state.processors.len(), 2);
+        let unit = &game.state.processors[1];
+        assert_eq!(unit.name, "Model F9-Budget");
+        assert_ne!(unit.id, game.state.processors[0].id);
+        assert_eq!(game.total_upkeep(), upkeep + budget.upkeep_cost);
+        assert!(game.total_electricity_cost() > electricity);
+        assert_eq!(game.store_purchases(index), Some(1));
+    }
+
+    #[test]
+    fn hot_days_wear_cooling_down_until_a_kit_recharges_it() {
+        let mut game = Game::fresh();
+        game.state.credits = 100_000;
+        game.ledger = Ledger::new(100_000);
+        let kit = Game::store_index_for(StoreAction::UpgradeCooling).expect("sold");
+        game.purchase_item(kit, Some(0)).expect("buys cooling");
+        let processor = &mut game.state.processors[0];
+        processor.instruction_set.push(jobs::SIMD_TAG.to_string());
+        processor.daemon_firmware_level = 1;
+        processor.daemon_mode = DaemonMode::Auto;
+        processor.requires_cooling_min = 1;
+        let job = Job {
+            tag: jobs::SIMD_TAG.to_string(),
+            ..rush_order(120)
+        };
+        assert!(daemon_job_score(&game.state.processors[0], &job, 0, 0.0).is_some());
+
+        game.apply_daily_cycle();
+        assert_eq!(
+            game.state.processors[0].cooling_level, 1,
+            "no heat, no wear"
+        );
+        for _ in 0..200 {
+            game.state.processors[0].heat_seconds_today = 1_000.0;
+            game.apply_daily_cycle();
+            if game.state.processors[0].cooling_level == 0 {
+                break;
+            }
+        }
+        let processor = &game.state.processors[0];
+        assert_eq!(processor.cooling_level, 0);
+        assert_eq!(processor.cooling_worn, 1);
+        assert_eq!(processor.heat_seconds_today, 0.0);
+        assert!(daemon_job_score(processor, &job, 0, 0.0).is_none());
+        assert!(
+            game.messages()
+                .any(|message| message.contains("below its cooling minimum"))
+        );
+
+        let item = &STORE_ITEMS[kit];
+        let recharge = (item.base_cost as f64 * COOLING_RECHARGE_RATIO).round() as u64;
+        assert_eq!(game.item_cost(kit, Some(0)), Some(recharge));
+        game.purchase_item(kit, Some(0)).expect("recharges");
+        assert_eq!(game.state.processors[0].cooling_level, 1);
+        assert_eq!(game.state.processors[0].cooling_worn, 0);
+        assert_eq!(
+            game.item_cost(kit, Some(0)),
+            Some(item.base_cost + item.cost_step)
+        );
+        assert!(daemon_job_score(&game.state.processors[0], &job, 0, 0.0).is_some());
+    }
+
+    #[test]
+    fn insured_units_bill_a_premium_and_pay_out_when_they_burn() {
+        let mut game = Game::fresh();
+        game.state.credits = 1_000;
+        game.ledger = Ledger::new(1_000);
+        let insure = Game::store_index_for(StoreAction::InsureProcessor).expect("sold");
+        let replace = Game::store_index_for(StoreAction::ReplaceProcessor).expect("sold");
+        let upkeep = game.total_upkeep();
+        game.purchase_item(insure, Some(0)).expect("insures");
+        let premium = economy::insurance_quote(&game.state.processors[0]);
+        assert!(game.state.processors[0].insured);
+        assert_eq!(game.total_upkeep(), upkeep + premium);
+        assert_eq!(game.insurance_upkeep(), premium);
+        assert!(matches!(
+            game.purchase_item(insure, Some(0)),
+            Err(PurchaseError::AlreadyInsured)
+        ));
+
+        game.state.processors[0].status = ProcessorStatus::BurntOut;
+        let replacement = game.item_cost(replace, Some(0)).expect("replaceable");
+        let payout = economy::insurance_payout(replacement, 200);
+        assert!(payout > 0);
+        let credits = game.state.credits;
+        game.handle_burnout(0, rush_order(200));
+        game.process_events();
+        assert_eq!(game.state.credits, credits + payout);
+        assert!(
+            game.messages()
+                .any(|message| message.starts_with(&format!("Insurance paid {payout} cr")))
+        );
+        assert_eq!(game.audit(), 0);
+    }
+
+    fn complete(game: &mut Game, job: Job) {
+        game.resolve_completed_job(
+            0,
+            CompletedJob {
+                job,
+                daemon_penalty: None,
+                forced: false,
+            },
+        );
+        game.process_events();
+    }
+
+    fn contract_stage(game: &mut Game) -> Option<Job> {
+        let index = game
+            .state
+            .jobs
+            .iter()
+            .position(|job| job.contract_id.is_some())?;
+        Some(game.state.jobs.remove(index))
+    }
+
+    #[test]
+    fn contract_stages_post_in_turn_and_the_last_pays_the_bonus() {
+        let mut game = Game::fresh();
+        game.state.jobs = vec![rush_order(10); MAX_JOBS];
+        game.open_contract();
+        let bonus = game.state.contracts[0].bonus;
+        assert!(bonus > 0);
+
+        for stage in 1..=contracts::CONTRACT_STAGES {
+            let job = contract_stage(&mut game).expect("stage posted");
+            assert_eq!(job.stage, stage);
+            assert_eq!(game.state.contracts[0].stage, stage);
+            // The next stage is reserved a place on the full board.
+            game.state.jobs.truncate(MAX_JOBS);
+            let credits = game.state.credits;
+            complete(&mut game, job);
+            if stage < contracts::CONTRACT_STAGES {
+                assert_eq!(game.state.jobs.len(), MAX_JOBS + 1);
+            } else {
+                assert!(game.state.credits >= credits + bonus);
+            }
+        }
+        assert!(game.state.contracts.is_empty());
+        assert!(contract_stage(&mut game).is_none());
+        assert!(
+            game.messages().any(|message| message
+                == &format!("Contract #1 complete: +{bonus} cr completion bonus."))
+        );
+    }
+
+    #[test]
+    fn reputation_follows_on_target_work_and_lost_jobs() {
+        let mut game = Game::fresh();
+        game.state.jobs.clear();
+        game.state.reputation = 23;
+        complete(
+            &mut game,
+            Job {
+                quality_target: u8::MAX,
+                ..rush_order(100)
+            },
+        );
+        assert_eq!(game.state.reputation, 23);
+        complete(&mut game, rush_order(100));
+        assert_eq!(game.state.reputation, 25);
+        assert!(
+            game.messages()
+                .any(|message| message.starts_with("Reputation rose to Trusted"))
+        );
+
+        game.state.jobs = vec![rush_order(100)];
+        game.state.jobs[0].expires_in_ms = Some(1);
+        game.age_board_jobs(Duration::from_millis(1));
+        assert_eq!(game.state.reputation, 23);
+
+        game.state.processors[0].status = ProcessorStatus::BurntOut;
+        game.handle_burnout(
+            0,
+            Job {
+                diagnostic_for: Some("Z80".to_string()),
+                ..rush_order(0)
+            },
+        );
+        assert_eq!(game.state.reputation, 23);
+        game.handle_burnout(0, rush_order(100));
+        assert_eq!(game.state.reputation, 23 - reputation::FAILURE_LOSS);
+
+        game.state.reputation = reputation::MIN_REPUTATION;
+        game.handle_dropped_job(0, rush_order(100));
+        assert_eq!(game.state.reputation, reputation::MIN_REPUTATION);
+    }
+
+    #[test]
+    fn angel_contact_opens_once_lifetime_earnings_reach_the_milestone() {
+        let mut game = Game::fresh();
+        game.state.statistics.credits_earned = ANGEL_UNLOCK_CREDITS - 1;
+        game.update(Duration::from_millis(1));
+        assert!(!game.is_instruction_unlocked(jobs::ANGEL_TAG));
+
+        game.credit(1);
+        game.state.credits = 0;
+        game.update(Duration::from_millis(1));
+        assert!(game.is_instruction_unlocked(jobs::ANGEL_TAG));
+        assert!(
+            game.messages()
+                .any(|message| message.starts_with("ANGEL contact established"))
+        );
+    }
+
+    #[test]
+    fn missed_angel_jobs_destroy_units_unless_shrouded() {
+        let angel = |quality_target| Job {
+            tag: jobs::ANGEL_TAG.to_string(),
+            quality_target,
+            ..rush_order(2_000)
+        };
+        let mut game = Game::fresh();
+        let runs = |game: &mut Game, quality_target, shroud, hardening| {
+            let mut destroyed = 0;
+            for _ in 0..40 {
+                let processor = &mut game.state.processors[0];
+                processor.status = ProcessorStatus::Idle;
+                processor.faraday_shroud = shroud;
+                processor.hardening_level = hardening;
+                let credits = game.state.credits;
+                complete(game, angel(quality_target));
+                if matches!(game.state.processors[0].status, ProcessorStatus::Destroyed) {
+                    assert_eq!(game.state.credits, credits);
+                    destroyed += 1;
+                }
+            }
+            destroyed
+        };
+
+        let exposed = runs(&mut game, u8::MAX, false, 0);
+        assert!(exposed > 0 && exposed < 40, "{exposed}");
+        assert_eq!(game.state.processors[0].jobs_failed, exposed);
+        assert!(runs(&mut game, u8::MAX, false, MAX_HARDENING_CAP) > 0);
+        assert_eq!(runs(&mut game, u8::MAX, true, 0), 0);
+        assert_eq!(runs(&mut game, 0, false, 0), 0);
+        assert!(
+            game.messages()
+                .any(|message| message.contains("was destroyed during Rush Order"))
+        );
+    }
+
+    #[test]
+    fn surveillance_jobs_hold_archive_space_only_while_they_run() {
+        let sweep = |data_output| Job {
+            tag: jobs::SURVEILLANCE_TAG.to_string(),
+            data_output,
+            ..rush_order(100)
+        };
+        let mut game = Game::fresh();
+        game.state.processors[0]
+            .instruction_set
+            .push(jobs::SURVEILLANCE_TAG.to_string());
+        game.state.storage = DataStorage::new(300);
+        game.state.storage.stored = 200;
+
+        assert!(matches!(
+            game.assign_job_to_processor(sweep(120), 0, false),
+            Err(AssignmentError::InsufficientStorage {
+                needed: 120,
+                free: 100
+            })
+        ));
+        assert_eq!(game.state.storage.reserved, 0);
+
+        game.state.jobs.clear();
+        game.assign_job_to_processor(sweep(50), 0, false)
+            .expect("fits");
+        assert_eq!(game.state.storage.free_capacity(), 50);
+        game.cancel_job(0).expect("cancels");
+        assert_eq!(game.state.storage.reserved, 0);
+        assert_eq!(game.state.jobs[0].storage_reserved, 0);
+
+        let job = game.state.jobs.remove(0);
+        game.assign_job_to_processor(job, 0, false).expect("fits");
+        let running = game.state.processors[0].abort().expect("running");
+        assert_eq!(running.storage_reserved, 50);
+        let credits = game.state.credits;
+        complete(&mut game, running);
+        assert_eq!(game.state.storage.reserved, 0);
+        assert_eq!(game.state.storage.stored, 200);
+        assert!(game.state.credits - credits < 100);
+        assert!((game.state.dividend_boost - SURVEILLANCE_DIVIDEND_BOOST).abs() < 1e-9);
+
+        game.assign_job_to_processor(sweep(50), 0, false)
+            .expect("fits");
+        let running = game.state.processors[0].abort().expect("running");
+        game.state.processors[0].status = ProcessorStatus::BurntOut;
+        game.handle_burnout(0, running);
+        assert_eq!(game.state.storage.reserved, 0);
+
+        game.apply_daily_cycle();
+        assert_eq!(game.state.dividend_boost, 0.0);
+        assert!(
+            game.messages()
+                .any(|message| message == "Passive data dividend +11 credits.")
+        );
+    }
+
+    #[test]
+    fn losing_a_stage_fails_the_whole_contract() {
+        let mut game = Game::fresh();
+        game.state.jobs.clear();
+        game.open_contract();
+        let first = contract_stage(&mut game).expect("stage posted");
+        complete(&mut game, first);
+        let second = contract_stage(&mut game).expect("next stage");
+
+        game.state.processors[0].status = ProcessorStatus::BurntOut;
+        game.handle_burnout(0, second);
+        game.process_events();
+        assert!(game.state.contracts.is_empty());
+        assert!(
+            game.messages()
+                .any(|message| message.starts_with("Contract #1 failed at stage 2/3"))
+        );
+
+        game.state.jobs.clear();
+        game.open_contract();
+        let window = game.state.jobs[0].expires_in_ms.expect("timed");
+        game.age_board_jobs(Duration::from_millis(window));
+        game.process_events();
+        assert!(game.state.contracts.is_empty());
+        assert!(game.state.jobs.is_empty());
+    }
+
+    #[test]
+    fn board_jobs_expire_while_held_jobs_keep_their_time() {
+        let mut game = Game::fresh();
+        let posted = jobs::generate_job_with_tag(10, GENERAL_TAG, 0, &mut game.rng);
+        assert_eq!(
+            posted.expires_in_ms,
+            Some(posted.base_time_ms * jobs::EXPIRY_FACTOR)
+        );
+        assert_eq!(posted.expiry_share_left(), Some(1.0));
+        let timed = |id, left| Job {
+            id,
+            expires_in_ms: Some(left),
+            ..rush_order(100)
+        };
+        game.state.jobs = vec![timed(1, 1_000), timed(3, 1_000), rush_order(10)];
+        game.assign_board_job(1, 0).expect("assigns");
+
+        game.age_board_jobs(Duration::from_millis(400));
+        assert_eq!(game.state.jobs[0].expires_in_ms, Some(600));
+        game.age_board_jobs(Duration::from_millis(600));
+        game.process_events();
+        let ids: Vec<u64> = game.state.jobs.iter().map(|job| job.id).collect();
+        assert_eq!(ids, vec![2]);
+        assert!(
+            game.messages()
+                .any(|message| message == "Rush Order expired unclaimed and was withdrawn.")
+        );
+
+        game.cancel_job(0).expect("cancels");
+        assert_eq!(game.state.jobs[0].id, 3);
+        assert_eq!(game.state.jobs[0].expires_in_ms, Some(1_000));
+    }
+
+    #[test]
+    fn model_comparison_projects_the_pending_job_on_both_units() {
+        let mut game = Game::fresh();
+        assert!(game.compare_models("Model Z0-Imaginary", Some(0)).is_none());
+        let idle = game
+            .compare_models("Model X1-Prototype", Some(0))
+            .expect("sold");
+        assert_eq!(idle.pending_job, None);
+        assert_eq!(idle.candidate.pending_eta_ms, None);
+
+        let job = Job {
+            base_time_ms: 10_000,
+            ..rush_order(100)
+        };
+        game.state.pending_jobs = vec![job.clone()];
+        let comparison = game
+            .compare_models("Model X1-Prototype", Some(0))
+            .expect("sold");
+        let prototype = ProcessorState::prototype();
+        assert_eq!(comparison.pending_job.as_deref(), Some("Rush Order"));
+        assert_eq!(comparison.candidate.speed, prototype.speed);
+        assert_eq!(comparison.candidate.pending_eta_ms, Some(6_250));
+        assert_eq!(
+            comparison.candidate.pending_eta_ms,
+            Some(economy::assignment_duration_ms(&job, &prototype, None))
+        );
+        let current = comparison.current.expect("selected");
+        assert_eq!(
+            current.pending_eta_ms,
+            Some(economy::assignment_duration_ms(
+                &job,
+                &game.state.processors[0],
+                None
+            ))
+        );
+
+        // Wear and an eco clock slow the unit in hand, not the catalog model.
+        game.state.processors[0].wear = 0.9;
+        game.state.processors[0].clock = ClockMode::Eco;
+        let worn = game
+            .compare_models("Model X1-Prototype", Some(0))
+            .expect("sold");
+        assert_eq!(worn.candidate.pending_eta_ms, Some(6_250));
+        let worn_current = worn.current.expect("selected");
+        assert!(worn_current.speed < current.speed);
+        assert!(worn_current.pending_eta_ms > current.pending_eta_ms);
+
+        game.state.pending_jobs = vec![Job {
+            tag: jobs::SIMD_TAG.to_string(),
+            ..job
+        }];
+        let simd = game
+            .compare_models("Model X7-Vector", Some(0))
+            .expect("sold");
+        assert!(simd.candidate.pending_eta_ms.is_some());
+        assert_eq!(simd.current.expect("selected").pending_eta_ms, None);
+        assert!(
+            game.compare_models("Model X7-Vector", None)
+                .expect("sold")
+                .current
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn auto_replace_buys_a_chassis_only_above_the_floor() {
+        let mut game = Game::fresh();
+        game.state.credits = 1_000;
+        game.ledger = Ledger::new(1_000);
+        let replace = Game::store_index_for(StoreAction::ReplaceProcessor).expect("sold");
+        let model = game.state.processors[0].name.clone();
+        game.state
+            .replacement_discounts
+            .insert(model.clone(), DIAGNOSTIC_DISCOUNT_DAYS);
+        game.toggle_auto_replace(0);
+        assert!(game.state.processors[0].auto_replace);
+
+        game.state.processors[0].status = ProcessorStatus::BurntOut;
+        let cost = game.item_cost(replace, Some(0)).expect("replaceable");
+        game.state.auto_replace_floor = 1_000 - cost + 1;
+        game.handle_burnout(0, rush_order(200));
+        game.process_events();
+        assert!(matches!(
+            game.state.processors[0].status,
+            ProcessorStatus::BurntOut
+        ));
+        assert_eq!(game.state.credits, 1_000);
+        assert!(
+            game.messages()
+                .any(|message| message.starts_with("Auto-replace held off"))
+        );
+
+        game.adjust_auto_replace_floor(false);
+        game.handle_burnout(0, rush_order(200));
+        game.process_events();
+        assert!(game.state.processors[0].is_idle());
+        assert_eq!(game.state.credits, 1_000 - cost);
+        assert!(!game.state.replacement_discounts.contains_key(&model));
+        assert!(game.state.processors[0].auto_replace);
+        assert_eq!(game.audit(), 0);
+
+        game.toggle_auto_replace(0);
+        game.state.processors[0].status = ProcessorStatus::Destroyed;
+        game.handle_destruction(0, rush_order(200));
+        assert!(matches!(
+            game.state.processors[0].status,
+            ProcessorStatus::Destroyed
+        ));
+        assert_eq!(game.state.credits, 1_000 - cost);
+    }
+
+    #[test]
+    fn warranty_replaces_the_first_burnout_free_then_is_spent() {
+        let mut game = Game::fresh();
+        game.state.credits = 1_000;
+        let buy = Game::store_index_for(StoreAction::BuyProcessor {
+            model: "Model F9-Budget",
+        })
+        .expect("sold");
+        let replace = Game::store_index_for(StoreAction::ReplaceProcessor).expect("sold");
+        game.purchase_item(buy, None).expect("buys");
+        assert_eq!(game.state.processors[0].warranty_days_remaining, 0);
+        assert_eq!(
+            game.state.processors[1].warranty_days_remaining,
+            WARRANTY_DAYS
+        );
+
+        game.apply_daily_cycle();
+        game.state.processors[1].status = ProcessorStatus::BurntOut;
+        game.apply_daily_cycle();
+        assert_eq!(
+            game.state.processors[1].warranty_days_remaining,
+            WARRANTY_DAYS - 1,
+            "an offline unit keeps its claim"
+        );
+        assert_eq!(game.item_cost(replace, Some(1)), Some(0));
+        let credits = game.state.credits;
+        game.purchase_item(replace, Some(1)).expect("covered");
+        assert_eq!(game.state.credits, credits);
+        assert!(game.state.processors[1].is_functional());
+        assert_eq!(game.state.processors[1].warranty_days_remaining, 0);
+        assert!(
+            game.messages()
+                .any(|message| message.contains("covered under warranty"))
+        );
+
+        game.state.processors[1].status = ProcessorStatus::Destroyed;
+        assert!(
+            game.item_cost(replace, Some(1))
+                .is_some_and(|cost| cost > 0)
+        );
+    }
+
+    #[test]
+    fn trade_ins_credit_the_worn_unit_and_keep_the_slot_setup() {
+        let mut game = Game::fresh();
+        game.state.credits = 1_000;
+        game.ledger = Ledger::new(1_000);
+        let old = &mut game.state.processors[0];
+        old.wear = 0.5;
+        old.daemon_firmware_level = 2;
+        old.daemon_mode = DaemonMode::Assist;
+        old.daemon_priority = 3;
+        old.jobs_completed = 12;
+        old.cooling_level = 2;
+        let id = old.id;
+        let credit = (old.purchase_cost as f64 * RESALE_RATIO * 0.5).round() as u64;
+        let price = ProcessorState::dual().purchase_cost;
+
+        let cost = game.trade_in_cost(0, "Model F24-Dual").expect("quotes");
+        assert_eq!(cost, price - credit);
+        game.trade_in(0, "Model F24-Dual").expect("trades");
+
+        assert_eq!(game.state.credits, 1_000 - cost);
+        assert_eq!(game.state.processors.len(), 1);
+        let unit = &game.state.processors[0];
+        assert_eq!(unit.name, "Model F24-Dual");
+        assert_eq!(unit.cores, 2);
+        assert_eq!(unit.id, id);
+        assert_eq!(unit.daemon_mode, DaemonMode::Assist);
+        assert_eq!(unit.daemon_priority, 3);
+        assert_eq!(unit.daemon_firmware_level, 2);
+        assert_eq!(unit.jobs_completed, 12);
+        assert_eq!((unit.wear, unit.cooling_level), (0.0, 0));
+        assert_eq!(game.audit(), 0);
+
+        assert!(matches!(
+            game.trade_in(0, "Model F24-Dual"),
+            Err(PurchaseError::SameModel)
+        ));
+        game.state.processors[0].assign(rush_order(1), 5_000, None);
+        assert!(matches!(
+            game.trade_in(0, "Model F12-Scalar"),
+            Err(PurchaseError::ProcessorBusy)
+        ));
+    }
+
+    #[test]
+    fn warranties_run_out_after_their_days() {
+        let mut game = Game::fresh();
+        game.state.credits = 1_000;
+        let buy = Game::store_index_for(StoreAction::BuyProcessor {
+            model: "Model F9-Budget",
+        })
+        .expect("sold");
+        game.purchase_item(buy, None).expect("buys");
+        for _ in 0..WARRANTY_DAYS {
+            game.apply_daily_cycle();
+        }
+        assert_eq!(game.state.processors[1].warranty_days_remaining, 0);
+        assert!(
+            game.messages()
+                .any(|message| message.contains("Warranty on"))
+        );
+
+        game.state.processors[1].status = ProcessorStatus::BurntOut;
+        let replace = Game::store_index_for(StoreAction::ReplaceProcessor).expect("sold");
+        assert!(
+            game.item_cost(replace, Some(1))
+                .is_some_and(|cost| cost > 0)
+        );
+    }
+
+    #[test]
+    fn eco_fleets_run_slower_and_cheaper() {
+        let mut game = Game::fresh();
+        game.state.processors = vec![ProcessorState::starter(); 3];
+        game.update(Duration::from_millis(100));
+        let stock_bill = game.total_electricity_cost();
+        let job = rush_order(40);
+        let stock_ms = economy::assignment_duration_ms(&job, &game.state.processors[0], None);
+
+        for index in 0..3 {
+            game.cycle_clock_mode(index).expect("cycles");
+        }
+        assert!(
+            game.state
+                .processors
+                .iter()
+                .all(|processor| processor.clock == ClockMode::Eco)
+        );
+        assert!(game.total_electricity_cost() < stock_bill);
+        let eco_ms = economy::assignment_duration_ms(&job, &game.state.processors[0], None);
+        assert!(eco_ms > stock_ms * 6 / 5);
+
+        game.state.processors[0].status = ProcessorStatus::BurntOut;
+        assert!(matches!(
+            game.cycle_clock_mode(0),
+            Err(AssignmentError::ProcessorInoperative)
+        ));
+    }
+
+    #[test]
+    fn enforced_reserve_blocks_purchases_until_overridden() {
+        let mut game = Game::fresh();
+        let idx = STORE_ITEMS
+            .iter()
+            .position(|item| item.action == StoreAction::IncreaseSpeed)
+            .expect("clock tuning present");
+        let cost = game.item_cost(idx, None).expect("purchasable");
+        let reserved = game.total_upkeep() + game.total_electricity_cost();
+        game.state.credits = cost + reserved - 1;
+        game.state.reserve_mode = ReserveMode::Enforce;
+        assert_eq!(game.reserved_credits(), reserved);
+
+        assert!(matches!(
+            game.purchase_item(idx, None),
+            Err(PurchaseError::ReserveBreach { .. })
+        ));
+        assert_eq!(game.store_purchases(idx), Some(0));
+
+        game.override_reserve_once();
+        game.purchase_item(idx, None)
+            .expect("override allows purchase");
+        assert_eq!(game.store_purchases(idx), Some(1));
+        assert!(!game.reserve_override);
+    }
+
+    #[test]
+    fn warn_reserve_still_spends() {
+        let mut game = Game::fresh();
+        let idx = STORE_ITEMS
+            .iter()
+            .position(|item| item.action == StoreAction::IncreaseSpeed)
+            .expect("clock tuning present");
+        let cost = game.item_cost(idx, None).expect("purchasable");
+        game.state.credits = cost;
+        game.state.reserve_mode = ReserveMode::Warn;
+
+        game.purchase_item(idx, None).expect("warn mode spends");
+        assert_eq!(game.state.credits, 0);
+        assert!(game.messages().any(|message| message.contains("reserve")));
+    }
+
+    fn burn_out_first_processor(game: &mut Game) {
+        game.state.processors.push(ProcessorState::starter());
+        game.state.processors[1].id = 99;
+        game.state.processors[0].status = ProcessorStatus::BurntOut;
+        game.handle_burnout(0, Job::default());
+    }
+
+    #[test]
+    fn burnout_posts_diagnostics_that_discount_the_next_replacement() {
+        let mut game = Game::fresh();
+        game.state.jobs.clear();
+        burn_out_first_processor(&mut game);
+        let model = game.state.processors[0].name.clone();
+        let full_cost = game.replacement_cost_for_model(&model);
+
+        assert_eq!(game.state.jobs.len(), 1);
+        let job = game.state.jobs[0].clone();
+        assert!(job.is_utility());
+        assert_eq!(job.base_reward, 0);
+        game.handle_burnout(0, Job::default());
+        assert_eq!(game.state.jobs.len(), 1, "one diagnostics job per model");
+
+        game.state.jobs.clear();
+        let credits = game.state.credits;
+        game.resolve_completed_job(
+            1,
+            CompletedJob {
+                job,
+                daemon_penalty: None,
+                forced: false,
+            },
+        );
+        assert_eq!(game.state.credits, credits);
+        let discounted = game.replacement_cost_for_model(&model);
+        assert_eq!(
+            discounted,
+            ((full_cost as f64) * (1.0 - DIAGNOSTIC_DISCOUNT)).round() as u64
+        );
+
+        game.apply_daily_cycle();
+        assert!(game.replacement_discount(&model) > 0.0);
+        game.apply_daily_cycle();
+        assert_eq!(game.replacement_discount(&model), 0.0);
+        assert_eq!(game.replacement_cost_for_model(&model), full_cost);
+    }
+
+    #[test]
+    fn diagnostics_wait_for_room_on_the_board() {
+        let mut game = Game::fresh();
+        game.state.jobs = (0..MAX_JOBS).map(|_| rush_order(100)).collect();
+        burn_out_first_processor(&mut game);
+
+        assert_eq!(game.state.jobs.len(), MAX_JOBS);
+        assert!(
+            game.state
+                .jobs
+                .iter()
+                .all(|job| job.diagnostic_for.is_none())
+        );
+    }
+
+    #[test]
+    fn daemon_skips_utility_jobs_unless_allowed() {
+        let mut game = Game::fresh();
+        game.state.jobs.clear();
+        burn_out_first_processor(&mut game);
+        let processor = &mut game.state.processors[1];
+        processor.daemon_firmware_level = 1;
+        processor.daemon_mode = DaemonMode::Auto;
+        assert!(game.choose_daemon_job(1, 0).is_none());
+
+        game.state.processors[1].allow_utility = true;
+        assert_eq!(
+            game.choose_daemon_job(1, 0).map(|(index, _)| index),
+            Some(0)
+        );
+    }
+
+    fn fleet_drawing(draws: &[f64]) -> Game {
+        let mut game = Game::fresh();
+        let template = game.state.processors[0].clone();
+        game.state.processors.clear();
+        for (index, draw) in draws.iter().enumerate() {
+            let mut processor = template.clone();
+            processor.id = index as u64;
+            processor.power_draw_base = *draw;
+            processor.cooling_level = 0;
+            let mut job = rush_order(50);
+            job.id = index as u64;
+            processor.assign(job, 60_000, None);
+            processor.last_power_draw = *draw;
+            game.state.processors.push(processor);
+        }
+        game.state.power_budget.enabled = true;
+        game
+    }
+
+    fn paused(game: &Game) -> Vec<bool> {
+        game.state
+            .processors
+            .iter()
+            .map(|processor| matches!(&processor.status, ProcessorStatus::Working(work) if work.paused))
+            .collect()
+    }
+
+    #[test]
+    fn breaker_pauses_highest_draw_units_first() {
+        let mut game = fleet_drawing(&[3.0, 8.0, 5.0]);
+        game.state.power_budget.capacity = 10.0;
+        game.enforce_power_budget(0);
+        assert_eq!(paused(&game), vec![false, true, false]);
+        assert_eq!(game.state.power_budget.trips, 1);
+        assert!((game.total_power_draw() - 8.0).abs() < 1e-9);
+
+        game.state.power_budget.capacity = 4.0;
+        game.enforce_power_budget(0);
+        assert_eq!(paused(&game), vec![false, true, true]);
+        assert_eq!(game.state.power_budget.trips, 2);
+    }
+
+    #[test]
+    fn breaker_resets_once_headroom_returns() {
+        let mut game = fleet_drawing(&[3.0, 8.0, 5.0]);
+        game.state.power_budget.capacity = 4.0;
+        game.enforce_power_budget(0);
+        assert_eq!(paused(&game), vec![false, true, true]);
+
+        // Room for the 5 kWh unit but not the 8 kWh one.
+        game.state.power_budget.capacity = 9.0;
+        game.enforce_power_budget(0);
+        assert_eq!(paused(&game), vec![false, true, false]);
+
+        game.toggle_power_budget();
+        assert_eq!(paused(&game), vec![false, false, false]);
+        assert!((game.total_power_draw() - 16.0).abs() < 1e-9);
+        assert_eq!(game.state.power_budget.trips, 2);
+    }
+
+    #[test]
+    fn daemon_holds_jobs_that_would_trip_the_breaker() {
+        let mut game = fleet_drawing(&[3.0, 8.0]);
+        game.state.power_budget.capacity = 9.0;
+        game.enforce_power_budget(0);
+        game.state.processors[0].status = ProcessorStatus::Idle;
+        let processor = &mut game.state.processors[0];
+        processor.daemon_firmware_level = 1;
+        processor.daemon_mode = DaemonMode::Auto;
+        game.state.jobs = vec![rush_order(200)];
+
+        // The paused 8 kWh unit already claims the panel's headroom.
+        game.try_daemon_assignment();
+        assert!(game.state.processors[0].is_idle());
+
+        game.state.power_budget.capacity = 12.0;
+        game.try_daemon_assignment();
+        assert!(!game.state.processors[0].is_idle());
+    }
+
+    #[test]
+    fn manual_assignment_is_refused_over_the_panel() {
+        let mut game = fleet_drawing(&[3.0, 8.0]);
+        game.state.power_budget.capacity = 12.0;
+        game.state.processors[0].status = ProcessorStatus::Idle;
+        game.state.processors[0].last_power_draw = 0.0;
+        game.state.processors[0].power_draw_base = 6.0;
+        game.state.jobs = vec![rush_order(200)];
+
+        assert!(matches!(
+            game.assign_board_job(0, 0),
+            Err(AssignmentError::PowerBudgetExceeded { capacity, .. }) if capacity == 12.0
+        ));
+        assert_eq!(game.state.jobs.len(), 1);
+        assert!(game.state.processors[0].is_idle());
+
+        game.toggle_power_budget();
+        game.assign_board_job(0, 0).expect("no budget, no limit");
+    }
+
+    /// Rack of a furnace running hot, a victim, and an idle unit.
+    fn hot_rack() -> Game {
+        let mut game = Game::fresh();
+        game.state.processors = vec![ProcessorState::starter(); 3];
+        let furnace = &mut game.state.processors[0];
+        furnace.assign(rush_order(100), 60_000, None);
+        furnace.last_heat = 3.0;
+        game.state.processors[1].assign(rush_order(100), 60_000, None);
+        game.update_ambient_heat();
+        game
+    }
+
+    fn victim_reliability(game: &Game, index: usize) -> f64 {
+        game.state.processors[index]
+            .evaluate_job(&rush_order(100), 0, 0.0)
+            .reliability
+    }
+
+    #[test]
+    fn hot_neighbor_degrades_reliability() {
+        let mut game = hot_rack();
+        let beside_furnace = victim_reliability(&game, 1);
+
+        game.state.processors[0].status = ProcessorStatus::Idle;
+        game.update_ambient_heat();
+        let beside_idle = victim_reliability(&game, 1);
+
+        assert!(game.state.processors[1].ambient_heat < 0.0);
+        assert!(
+            beside_furnace + 0.05 < beside_idle,
+            "{beside_furnace} vs {beside_idle}"
+        );
+    }
+
+    #[test]
+    fn reordering_the_rack_moves_the_victim_away_from_the_heat() {
+        let mut game = hot_rack();
+        let before = victim_reliability(&game, 1);
+
+        assert_eq!(game.move_processor(1, false), Some(2));
+        assert!(game.state.processors[2].ambient_heat < 0.0);
+        assert!(victim_reliability(&game, 2) > before);
+        assert_eq!(game.move_processor(2, false), None);
+    }
+
+    #[test]
+    fn rack_mates_share_heat_wherever_they_sit() {
+        let mut game = hot_rack();
+        game.state.racks.push(RackState::default());
+        game.state.processors[0].rack_id = Some(0);
+        game.state.processors[2].rack_id = Some(0);
+        game.update_ambient_heat();
+
+        let furnace = game.state.processors[0].neighbor_heat();
+        assert!((game.state.processors[2].ambient_heat - furnace).abs() < 1e-9);
+        // The unracked unit in between still feels only its slot neighbors.
+        let middle = game.state.processors[1].ambient_heat;
+        let expected = furnace + game.state.processors[2].neighbor_heat();
+        assert!((middle - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn racks_are_bought_filled_and_cooled() {
+        let mut game = Game::fresh();
+        game.state.processors = vec![ProcessorState::starter(); 3];
+        game.state.credits = 1_000;
+        assert!(matches!(game.cycle_rack(0), Err(RackError::NoRacks)));
+
+        let buy = Game::store_index_for(StoreAction::BuyRack).expect("listed");
+        let cool = Game::store_index_for(StoreAction::UpgradeRackCooling).expect("listed");
+        game.purchase_item(buy, None).expect("buys rack");
+        assert!(matches!(
+            game.purchase_item(cool, Some(0)),
+            Err(PurchaseError::NotRacked)
+        ));
+
+        game.state.processors[0].name = "Mounted".to_string();
+        assert_eq!(game.cycle_rack(0).expect("mounts"), 2);
+        assert_eq!(game.state.processors[2].name, "Mounted");
+        assert_eq!(game.cycle_rack(0).expect("mounts"), 2);
+        assert_eq!(racks::members(&game.state.processors, 0).count(), 2);
+
+        let heat = |game: &Game| {
+            let unit = &game.state.processors[2];
+            unit.evaluate_job(&rush_order(100), game.rack_cooling(unit), 0.0)
+                .heat
+        };
+        let before = heat(&game);
+        game.purchase_item(cool, Some(2)).expect("cools");
+        game.purchase_item(cool, Some(2)).expect("cools");
+        assert!(matches!(
+            game.purchase_item(cool, Some(2)),
+            Err(PurchaseError::UpgradeAtCap)
+        ));
+        assert_eq!(game.state.credits, 1_000 - 160 - 140 - 260);
+        assert!(heat(&game) < before);
+
+        // Past the last rack the unit comes back out.
+        assert_eq!(game.cycle_rack(2).expect("unracks"), 2);
+        assert_eq!(game.state.processors[2].rack_id, None);
+    }
+
+    #[test]
+    fn boss_contract_posts_once_and_charges_upkeep_while_running() {
+        let mut game = Game::fresh();
+        game.state.credits = BOSS_UNLOCK_CREDITS;
+        game.ledger = Ledger::new(BOSS_UNLOCK_CREDITS);
+        game.update(Duration::from_millis(1));
+        let boss = game
+            .state
+            .jobs
+            .iter()
+            .position(Job::is_boss)
+            .expect("boss posted");
+        assert!(
+            game.toasts()
+                .any(|toast| toast.level == ToastLevel::Critical)
+        );
+
+        let upkeep = game.total_upkeep();
+        game.assign_board_job(boss, 0).expect("assigns");
+        assert_eq!(game.total_upkeep(), upkeep + 25);
+
+        game.update(Duration::from_millis(1));
+        assert_eq!(
+            game.state.jobs.iter().filter(|job| job.is_boss()).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn field_service_is_priced_by_wear_and_clears_it() {
+        let mut game = Game::fresh();
+        game.state.credits = 500;
+        let service = Game::store_index_for(StoreAction::ServiceProcessor).expect("listed");
+        assert_eq!(game.item_cost(service, Some(0)), None);
+
+        game.state.processors[0].wear = 0.6;
+        assert_eq!(game.item_cost(service, Some(0)), Some(30 + 90));
+        game.purchase_item(service, Some(0)).expect("services");
+        assert_eq!(game.state.processors[0].wear, 0.0);
+        assert_eq!(game.state.credits, 380);
+        assert!(matches!(
+            game.purchase_item(service, Some(0)),
+            Err(PurchaseError::NothingToService)
+        ));
+    }
+
+    #[test]
+    fn patch_repair_takes_off_a_step_of_wear_but_not_from_wrecks() {
+        let mut game = Game::fresh();
+        game.state.credits = 500;
+        let repair = Game::store_index_for(StoreAction::RepairProcessor).expect("listed");
+        game.state.processors[0].wear = 0.005;
+        assert_eq!(game.item_cost(repair, Some(0)), None);
+
+        game.state.processors[0].wear = 0.7;
+        assert_eq!(game.item_cost(repair, Some(0)), Some(15 + 48));
+        game.repair_processor_direct(0).expect("repairs");
+        assert!((game.state.processors[0].wear - 0.3).abs() < 1e-9);
+        assert_eq!(game.state.credits, 437);
+
+        // The second pass only charges for the 0.3 left.
+        assert_eq!(game.item_cost(repair, Some(0)), Some(15 + 36));
+        game.repair_processor_direct(0).expect("repairs");
+        assert_eq!(game.state.processors[0].wear, 0.0);
+        assert_eq!(game.state.credits, 386);
+
+        game.state.processors[0].wear = 0.9;
+        game.state.processors[0].status = ProcessorStatus::Destroyed;
+        assert_eq!(game.item_cost(repair, Some(0)), None);
+        assert!(matches!(
+            game.repair_processor_direct(0),
+            Err(PurchaseError::ProcessorOffline)
+        ));
+        assert_eq!(game.state.credits, 386);
+        assert_eq!(game.state.processors[0].wear, 0.9);
+    }
+
+    #[test]
+    fn daily_cycle_bills_metered_energy_and_resets_meters() {
+        let mut game = Game::fresh();
+        game.state.storage.stored = 0;
+        game.state.market_today.electricity_rate = 2.0;
+        game.state.processors[0].energy_today = 10.0;
+        game.state.processors[0].status = ProcessorStatus::Idle;
+        let upkeep = game.total_upkeep();
+        let before = game.state.credits;
+
+        game.apply_daily_cycle();
+
+        assert_eq!(game.state.credits, before - upkeep - 20);
+        assert_eq!(game.state.processors[0].energy_today, 0.0);
+    }
+
+    #[test]
+    fn electricity_breakdown_adds_up_to_the_bill_it_charges() {
+        let mut game = Game::fresh();
+        game.state.storage.stored = 0;
+        game.state.market_today.electricity_rate = 2.0;
+        game.state.processors = vec![ProcessorState::starter(), ProcessorState::budget()];
+        game.state.processors[1].id = 1;
+        game.state.processors[0].energy_today = 10.0;
+        game.state.processors[1].energy_today = 2.4;
+        for processor in &mut game.state.processors {
+            processor.status = ProcessorStatus::Idle;
+        }
+
+        let breakdown = game.electricity_breakdown();
+        let projected: u64 = breakdown.iter().map(|(_, cost)| cost).sum();
+        assert_eq!(breakdown.len(), 2);
+        assert_eq!(projected, game.total_electricity_cost());
+
+        game.day_timer = DAY_DURATION;
+        let breakdown = game.electricity_breakdown();
+        assert_eq!(breakdown, vec![(0, 20), (1, 5)]);
+        let upkeep = game.total_upkeep();
+        let before = game.state.credits;
+
+        game.apply_daily_cycle();
+
+        assert_eq!(game.state.credits, before - upkeep - 25);
+    }
+
+    #[test]
+    fn loading_the_same_save_twice_replays_the_same_rolls() {
+        let mut state = GameState::default();
+        state.seed_starter_board(42);
+        let mut played = Game::from_state(state);
+        played.update(DAY_DURATION / 2);
+        let save = played.state.clone();
+
+        let replay = |state: GameState| {
+            let mut game = Game::from_state(state);
+            game.queue_pending(0).expect("queues a starter job");
+            game.assign_pending(0).expect("assigns");
+            for _ in 0..100 {
+                game.update(Duration::from_millis(500));
+            }
+            ron::to_string(&game.state).expect("serializes")
+        };
+        assert_eq!(replay(save.clone()), replay(save));
+    }
+
+    #[test]
+    fn days_and_play_time_accumulate_but_sleep_does_not_count() {
+        let mut game = Game::fresh();
+        for _ in 0..4 {
+            game.update(DAY_DURATION / 4);
+        }
+        game.update(MAX_FRAME_DELTA + Duration::from_secs(3600));
+
+        assert_eq!(game.state.statistics.days_elapsed, 1);
+        assert_eq!(game.state.statistics.day(), 2);
+        assert_eq!(
+            game.state.statistics.play_time_ms,
+            (DAY_DURATION + MAX_FRAME_DELTA).as_millis() as u64
+        );
+        assert!(
+            game.messages()
+                .any(|message| message.starts_with("Day 1: paid upkeep"))
+        );
+    }
+
+    #[test]
+    fn starter_board_is_seeded_and_runnable() {
+        let mut state = GameState::default();
+        state.seed_starter_board(7);
+        assert_eq!(state.jobs.len(), STARTER_JOBS);
+        assert_eq!(state.job_counter, STARTER_JOBS as u64);
+        let ids: HashSet<u64> = state.jobs.iter().map(|job| job.id).collect();
+        assert_eq!(ids.len(), STARTER_JOBS);
+
+        let starter = &state.processors[0];
+        for job in &state.jobs {
+            assert_eq!(job.tag, GENERAL_TAG);
+            let evaluation = starter.evaluate_job(job, 0, 0.0);
+            assert_eq!(evaluation.hazard_penalty, 0.0);
+            assert!(evaluation.reliability >= 0.3);
+        }
+
+        let mut again = GameState::default();
+        again.seed_starter_board(7);
+        let names = |state: &GameState| -> Vec<(String, u64)> {
+            state
+                .jobs
+                .iter()
+                .map(|job| (job.name.clone(), job.base_reward))
+                .collect()
+        };
+        assert_eq!(names(&state), names(&again));
+
+        let mut game = Game::from_state(state);
+        game.spawn_job_if_possible();
+        assert_eq!(game.state.jobs.last().map(|job| job.id), Some(4));
+    }
+
+    #[test]
+    fn pending_queue_survives_save_and_load_in_order() {
+        let mut game = Game::fresh();
+        game.state.jobs = (2..=5)
+            .map(|id| Job {
+                id,
+                ..rush_order(120)
+            })
+            .collect();
+        for _ in 0..MAX_PENDING_JOBS {
+            assert_eq!(game.queue_pending(0).as_deref(), Some("Rush Order"));
+        }
+        assert!(game.pending_full());
+        assert_eq!(game.queue_pending(0), None);
+        assert_eq!(game.state.jobs.len(), 1);
+
+        let saved = ron::to_string(&game.state).expect("serializes");
+        let state: GameState = ron::from_str(&saved).expect("deserializes");
+        let mut game = Game::from_state(state);
+        let pending_ids = |game: &Game| -> Vec<u64> {
+            game.state.pending_jobs.iter().map(|job| job.id).collect()
+        };
+        assert_eq!(pending_ids(&game), vec![2, 3, 4]);
+
+        assert!(game.release_pending());
+        assert_eq!(game.state.jobs.first().map(|job| job.id), Some(4));
+        game.assign_pending(0).expect("assigns");
+        assert_eq!(pending_ids(&game), vec![3]);
+    }
+
+    #[test]
+    fn assist_suggests_the_pending_job() {
+        let mut game = Game::fresh();
+        let processor = &mut game.state.processors[0];
+        processor.daemon_firmware_level = 1;
+        processor.daemon_mode = DaemonMode::Assist;
+        game.state.jobs = vec![rush_order(50)];
+        game.state.pending_jobs = vec![
+            Job {
+                id: 8,
+                ..rush_order(100)
+            },
+            Job {
+                id: 9,
+                ..rush_order(400)
+            },
+        ];
+
+        let suggestion = game.assist_suggestion(0).expect("suggests");
+        assert!(suggestion.pending);
+        assert_eq!(suggestion.job_index, 1);
+        assert_eq!(game.suggested_job(&suggestion).map(|job| job.id), Some(9));
+
+        assert!(game.accept_assist_suggestion(0));
+        assert_eq!(
+            game.state
+                .pending_jobs
+                .iter()
+                .map(|job| job.id)
+                .collect::<Vec<_>>(),
+            vec![8]
+        );
+        assert_eq!(game.state.jobs.len(), 1);
+    }
+
+    #[test]
+    fn assist_ranks_its_choices_and_never_offers_risky_jobs() {
+        let mut game = Game::fresh();
+        let processor = &mut game.state.processors[0];
+        processor.daemon_firmware_level = 1;
+        processor.daemon_mode = DaemonMode::Assist;
+        processor.instruction_set.push("HOT".to_string());
+        processor.power_draw_mod.insert("HOT".to_string(), 20.0);
+        let job = |id, reward| Job {
+            id,
+            ..rush_order(reward)
+        };
+        game.state.jobs = vec![
+            job(1, 100),
+            Job {
+                tag: "HOT".to_string(),
+                ..job(2, 10_000)
+            },
+            job(3, 100),
+            job(4, 300),
+            job(5, 50),
+        ];
+        game.state.pending_jobs = vec![job(9, 300)];
+
+        let ranked = game.assist_suggestions(0, ASSIST_CHOICES);
+        let ids: Vec<u64> = ranked
+            .iter()
+            .filter_map(|suggestion| game.suggested_job(suggestion).map(|job| job.id))
+            .collect();
+        assert_eq!(ids, vec![9, 4, 1]);
+        assert!(ranked[0].pending);
+        assert!(ranked[0].score >= ranked[1].score && ranked[1].score > ranked[2].score);
+        let everything = game.assist_suggestions(0, usize::MAX);
+        assert_eq!(everything.len(), 5);
+        assert!(
+            everything
+                .iter()
+                .all(|suggestion| suggestion.reliability >= 0.3)
+        );
+        assert!(
+            everything
+                .iter()
+                .all(|suggestion| suggestion.job_index != 1)
+        );
 
-fn replacement_cost_for_processor(processor: &ProcessorState) -> u64 {
-    if processor.is_functional() {
-        return 0;
+        assert!(game.accept_assist_choice(0, 2));
+        let ProcessorStatus::Working(work) = &game.state.processors[0].status else {
+            panic!("takes the third pick");
+        };
+        assert_eq!(work.job.id, 1);
+        assert!(!game.state.pending_jobs.is_empty());
+        assert!(!game.accept_assist_choice(0, 0));
     }
-    let base = (processor.purchase_cost as f64 * processor.replace_cost_ratio).round() as u64;
-    base.max(1)
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::sim::jobs::{GENERAL_TAG, Job, SIMD_TAG};
-    use crate::sim::processors::{DaemonMode, ProcessorStatus};
+    #[test]
+    fn auto_leaves_the_unit_chosen_for_a_pending_job() {
+        let mut game = Game::fresh();
+        let processor = &mut game.state.processors[0];
+        processor.daemon_firmware_level = 1;
+        processor.daemon_mode = DaemonMode::Auto;
+        game.state.jobs = vec![
+            rush_order(120),
+            Job {
+                id: 3,
+                ..rush_order(80)
+            },
+        ];
+        game.queue_pending(0).expect("queues");
+        game.note_manual_intent(0);
+
+        game.try_daemon_assignment();
+        assert!(game.state.processors[0].is_idle());
+
+        game.age_manual_intent(MANUAL_INTENT_HOLD);
+        game.try_daemon_assignment();
+        assert!(!game.state.processors[0].is_idle());
+    }
 
     #[test]
-    fn purchasing_microcode_unlocks_simd_tag() {
+    fn manual_holds_follow_the_unit_when_the_rack_is_reordered() {
+        let mut game = Game::fresh();
+        let held = &mut game.state.processors[0];
+        held.daemon_firmware_level = 1;
+        held.daemon_mode = DaemonMode::Auto;
+        let label = held.label();
+        let mut spare = ProcessorState::budget();
+        spare.id = game.state.next_processor_id;
+        game.state.processors.push(spare);
+        game.state.jobs = vec![
+            rush_order(120),
+            Job {
+                id: 3,
+                ..rush_order(80)
+            },
+        ];
+        game.queue_pending(0).expect("queues");
+        game.note_manual_intent(0);
+
+        assert_eq!(game.move_processor(0, false), Some(1));
+        game.try_daemon_assignment();
+        assert!(game.state.processors[1].is_idle());
+        assert!(label.starts_with("F12-Scalar #"));
+        assert!(
+            game.messages()
+                .any(|message| message == &format!("Moved {label} to rack slot 2."))
+        );
+    }
+
+    #[test]
+    fn daemon_trial_survives_save_and_expires_with_a_summary() {
+        let mut game = Game::fresh();
+        game.start_daemon_trial(0).expect("starts");
+        assert!(matches!(
+            game.start_daemon_trial(0),
+            Err(TrialError::AlreadyUsed)
+        ));
+        game.state.jobs = vec![rush_order(120)];
+        game.try_daemon_assignment();
+        let ProcessorStatus::Working(work) = game.state.processors[0].status.clone() else {
+            panic!("trial unit should pick up the job");
+        };
+        assert_eq!(work.daemon_penalty.as_ref().map(|p| p.quality), Some(-7));
+        game.state.processors[0].status = ProcessorStatus::Idle;
+        game.resolve_completed_job(
+            0,
+            CompletedJob {
+                job: work.job,
+                daemon_penalty: work.daemon_penalty,
+                forced: false,
+            },
+        );
+
+        let saved = ron::to_string(&game.state).expect("serializes");
+        let mut game = Game::from_state(ron::from_str(&saved).expect("deserializes"));
+        let processor = &game.state.processors[0];
+        assert!(processor.daemon_unlocked() && processor.daemon_trial);
+        assert_eq!(processor.daemon_mode, DaemonMode::Auto);
+        let trial = game.state.daemon_trial.clone().expect("trial kept");
+        assert!(trial.active);
+        assert_eq!(trial.jobs, 1);
+        assert!(trial.earned > 0);
+
+        game.apply_daily_cycle();
+        let processor = &game.state.processors[0];
+        assert!(!processor.daemon_unlocked() && !processor.daemon_trial);
+        assert_eq!(processor.daemon_mode, DaemonMode::Off);
+        assert_eq!(processor.daemon_penalty.quality, -5);
+        game.process_events();
+        let ticker = game.ticker().expect("automation notices use the ticker");
+        assert!(
+            ticker
+                .message
+                .starts_with("Trial daemon completed 1 jobs for")
+        );
+        assert!(ticker.message.contains("install firmware for"));
+        let firmware = Game::store_index_for(StoreAction::InstallDaemonFirmware);
+        assert_eq!(game.firmware_shortcut(3), firmware.map(|index| (index, 0)));
+        assert!(game.start_daemon_trial(0).is_err());
+    }
+
+    #[test]
+    fn buying_firmware_mid_trial_keeps_the_daemon() {
         let mut game = Game::fresh();
         game.state.credits = 1_000;
-        let idx = STORE_ITEMS
-            .iter()
-            .position(|item| matches!(item.action, StoreAction::UnlockInstructionSet { .. }))
-            .expect("microcode item present");
-        let cost = game
-            .item_cost(idx, None)
-            .expect("microcode should be purchasable");
+        game.ledger = Ledger::new(1_000);
+        game.start_daemon_trial(0).expect("starts");
+        let firmware = Game::store_index_for(StoreAction::InstallDaemonFirmware).unwrap();
+        assert!(game.item_cost(firmware, Some(0)).is_some());
 
-        assert!(!game.is_instruction_unlocked(SIMD_TAG));
-        game.purchase_item(idx, None)
-            .expect("purchase should succeed");
+        game.purchase_item(firmware, Some(0)).expect("installs");
+        game.apply_daily_cycle();
 
-        assert!(game.is_instruction_unlocked(SIMD_TAG));
-        assert!(game.state.unlocked_tags.iter().any(|tag| tag == SIMD_TAG));
+        let processor = &game.state.processors[0];
+        assert!(processor.daemon_unlocked() && !processor.daemon_trial);
+        assert_eq!(processor.daemon_mode, DaemonMode::Auto);
+        assert_eq!(processor.daemon_penalty.quality, -3);
+        assert_eq!(processor.daemon_firmware_level, 1);
+        game.process_events();
         assert!(
-            game.state
-                .processors
-                .iter()
-                .all(|processor| processor.supports(SIMD_TAG))
+            !game
+                .messages()
+                .any(|message| message.contains("Trial daemon"))
         );
-        assert_eq!(game.store_purchases(idx), Some(1));
-        assert_eq!(game.state.credits, 1_000 - cost);
+    }
+
+    #[test]
+    fn firmware_levels_ease_the_penalty_and_cost_more_each_time() {
+        let mut game = Game::fresh();
+        game.state.credits = 10_000;
+        game.ledger = Ledger::new(10_000);
+        let firmware = Game::store_index_for(StoreAction::InstallDaemonFirmware).unwrap();
+
+        let mut costs = Vec::new();
+        let mut multipliers = Vec::new();
+        while let Some(cost) = game.item_cost(firmware, Some(0)) {
+            costs.push(cost);
+            game.purchase_item(firmware, Some(0)).expect("upgrades");
+            multipliers.push(game.state.processors[0].daemon_penalty.time_multiplier);
+        }
+
+        let processor = &game.state.processors[0];
+        assert_eq!(processor.daemon_firmware_level, MAX_DAEMON_FIRMWARE_LEVEL);
+        assert_eq!(costs.len(), MAX_DAEMON_FIRMWARE_LEVEL as usize);
+        assert!(costs.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(multipliers.windows(2).all(|pair| pair[0] > pair[1]));
+        assert_eq!(processor.daemon_penalty.quality, 0);
+        assert!(processor.daemon_penalty.time_multiplier < 1.02);
         assert!(matches!(
-            game.purchase_item(idx, None),
-            Err(PurchaseError::MaxedOut { .. })
+            game.purchase_item(firmware, Some(0)),
+            Err(PurchaseError::DaemonFirmwareMaxed)
         ));
     }
 
     #[test]
-    fn replacing_burnt_out_processor_spends_credits() {
+    fn notices_follow_the_routing_matrix() {
         let mut game = Game::fresh();
-        game.state.credits = 500;
-        let processor = &mut game.state.processors[0];
-        processor.status = ProcessorStatus::BurntOut;
-        let expected_cost =
-            ((processor.purchase_cost as f64) * processor.replace_cost_ratio).round() as u64;
+        let routing = &mut game.state.display.routing;
+        routing.economy = MessageRoute::Log;
+        routing.hardware = MessageRoute::ToastBell;
+        routing.store = MessageRoute::Ticker;
+        game.events.push(GameEvent::EscrowForfeited {
+            job_name: "Rush Order".to_string(),
+            amount: 40,
+        });
+        game.notify_critical(MessageCategory::Hardware, "Unit burnt out.".to_string());
+        game.notify(MessageCategory::Store, "Purchased Clock Tuning".to_string());
+        game.process_events();
 
-        game.replace_processor_direct(0)
-            .expect("replacement should succeed");
+        let toasts: Vec<&str> = game.toasts().map(|toast| toast.message.as_str()).collect();
+        assert_eq!(toasts, ["Unit burnt out."]);
+        assert!(game.take_bell());
+        assert!(!game.take_bell());
+        assert_eq!(
+            game.ticker().map(|ticker| ticker.message.as_str()),
+            Some("Purchased Clock Tuning")
+        );
+        assert_eq!(game.messages().count(), 3);
 
-        assert_eq!(game.state.credits, 500 - expected_cost);
+        game.cycle_message_route(MessageCategory::Hardware);
+        assert_eq!(game.state.display.routing.hardware, MessageRoute::Log);
+        game.preview_routing();
+        assert_eq!(game.messages().count(), 8);
+        assert!(!game.take_bell());
+    }
+
+    #[test]
+    fn hardcore_runs_checkpoint_at_the_start_and_each_day_end() {
+        let mut game = Game::fresh();
+        game.apply_daily_cycle();
+        assert!(!game.take_checkpoint());
+
+        let mut game = Game::from_state(GameState {
+            hardcore: true,
+            ..GameState::default()
+        });
+        assert!(game.take_checkpoint());
+        assert!(!game.take_checkpoint());
+        game.apply_daily_cycle();
+        assert!(game.take_checkpoint());
+        assert!(!game.take_checkpoint());
+    }
+
+    #[test]
+    fn run_is_lost_once_nothing_works_and_nothing_can_be_replaced() {
+        let mut game = Game::fresh();
+        assert!(!game.is_lost());
+        game.state.processors[0].status = ProcessorStatus::Destroyed;
+        let cost = game.item_cost(
+            Game::store_index_for(StoreAction::ReplaceProcessor).expect("sold"),
+            Some(0),
+        );
+        let cost = cost.expect("replaceable");
+        game.state.credits = cost;
+        assert!(!game.is_lost());
+        game.state.credits = cost - 1;
+        assert!(game.is_lost());
+
+        let budget = ProcessorState::budget().purchase_cost;
+        game.state.processors.clear();
+        game.state.credits = budget;
+        assert!(!game.is_lost(), "a new unit can still be bought");
+        game.state.credits = budget - 1;
+        assert!(game.is_lost());
+    }
+
+    #[test]
+    fn watchdog_force_completes_a_stalled_job() {
+        let mut game = Game::fresh();
+        game.assign_job_to_processor(rush_order(120), 0, false)
+            .expect("assigns");
+        let mut rng = StepRng::new(0, 0);
+
+        // Sub-millisecond frames round to a zero delta and never drain the job.
+        for _ in 1..STALL_TICK_LIMIT {
+            assert!(
+                game.state.processors[0]
+                    .tick(0, &mut rng, 0, 0.0)
+                    .is_empty()
+            );
+        }
+        let Some(ProcessorEvent::Completed(done)) =
+            game.state.processors[0].tick(0, &mut rng, 0, 0.0).pop()
+        else {
+            panic!("watchdog should complete the job");
+        };
+        assert!(done.forced);
+        assert!(game.state.processors[0].is_idle());
+
+        game.resolve_completed_job(0, done);
+        game.process_events();
+        let unit = LogSubject::Processor(game.state.processors[0].id);
+        assert!(
+            game.log()
+                .iter()
+                .any(|entry| entry.text.starts_with("Watchdog:") && entry.subject == Some(unit))
+        );
+    }
+
+    #[test]
+    fn contract_deposit_returns_on_completion() {
+        let mut game = Game::fresh();
+        game.state.credits = 100;
+        game.ledger = Ledger::new(100);
+        game.assign_job_to_processor(rush_order(400), 0, false)
+            .expect("assigns");
+        assert_eq!(game.state.credits, 20);
+        assert_eq!(game.escrow_locked(), 80);
+
+        let ProcessorStatus::Working(work) = game.state.processors[0].status.clone() else {
+            panic!("processor should be working");
+        };
+        game.state.processors[0].status = ProcessorStatus::Idle;
+        game.resolve_completed_job(
+            0,
+            CompletedJob {
+                job: work.job,
+                daemon_penalty: None,
+                forced: false,
+            },
+        );
+
+        assert_eq!(game.escrow_locked(), 0);
+        assert!(game.state.credits >= 100 + 280);
+        assert_eq!(game.ledger.expected_balance(), Some(game.state.credits));
+    }
+
+    #[test]
+    fn dual_units_carry_two_contracts_and_forfeit_both_when_they_burn() {
+        let mut game = Game::fresh();
+        game.state.processors = vec![ProcessorState::dual()];
+        game.state.credits = 200;
+        game.ledger = Ledger::new(200);
+        let second = Job {
+            id: 3,
+            ..rush_order(400)
+        };
+        game.assign_job_to_processor(rush_order(400), 0, false)
+            .expect("first core");
+        game.assign_job_to_processor(second, 0, false)
+            .expect("second core");
         assert!(matches!(
-            game.state.processors[0].status,
-            ProcessorStatus::Idle
+            game.assign_job_to_processor(
+                Job {
+                    id: 4,
+                    ..rush_order(40)
+                },
+                0,
+                false
+            ),
+            Err(AssignmentError::ProcessorBusy)
         ));
-        assert!(game.state.processors[0].wear <= f64::EPSILON);
+        assert_eq!(game.escrow_locked(), 160);
+        assert_eq!(
+            game.total_upkeep(),
+            ProcessorState::dual().upkeep_cost + 2 * rush_order(400).upkeep
+        );
+
+        game.state.processors[0].reliability_base = 0.0;
+        game.step_processors(Duration::from_millis(100));
+
+        assert!(!game.state.processors[0].is_functional());
+        assert_eq!(game.escrow_locked(), 0);
+        assert_eq!(game.escrow_forfeited(), 160);
+        assert_eq!(game.state.processors[0].jobs_failed, 2);
+        assert!(
+            game.messages()
+                .any(|message| message.contains("lost when F24-Dual #"))
+        );
     }
 
     #[test]
-    fn cycling_daemon_mode_traverses_states() {
+    fn dual_units_reload_with_both_cores_busy() {
+        let mut game = Game::fresh();
+        game.state.processors = vec![ProcessorState::dual()];
+        game.assign_job_to_processor(rush_order(40), 0, false)
+            .expect("first core");
+        game.assign_job_to_processor(
+            Job {
+                id: 3,
+                ..rush_order(40)
+            },
+            0,
+            false,
+        )
+        .expect("second core");
+
+        let saved = ron::to_string(&game.state).expect("serializes");
+        let game = Game::from_state(ron::from_str(&saved).expect("deserializes"));
+
+        assert!(game.quarantine_notices().is_empty());
+        let dual = &game.state.processors[0];
+        assert_eq!(dual.name, "Model F24-Dual");
+        assert_eq!(dual.cores, 2);
+        assert_eq!(
+            dual.slots().map(|work| work.job.id).collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+    }
+
+    #[test]
+    fn auto_scores_count_the_standby_spin_up() {
+        let job = Job {
+            tag: GENERAL_TAG.to_string(),
+            base_time_ms: 2_000,
+            base_reward: 60,
+            ..Job::default()
+        };
+        let awake = ProcessorState::starter();
+        let mut asleep = ProcessorState::starter();
+        asleep.idle_ms = asleep.standby_after_ms.expect("standby on");
+        assert!(asleep.in_standby());
+
+        let awake_score = daemon_job_score(&awake, &job, 0, 0.0).expect("scores");
+        let asleep_score = daemon_job_score(&asleep, &job, 0, 0.0).expect("scores");
+        assert!(asleep_score < awake_score);
+
+        let mut game = Game::fresh();
+        game.state.processors[0].idle_ms = asleep.idle_ms;
+        game.cycle_standby(0).expect("cycles");
+        game.cycle_standby(0).expect("cycles");
+        assert_eq!(game.state.processors[0].standby_after_ms, None);
+        assert!(!game.state.processors[0].in_standby());
+    }
+
+    #[test]
+    fn fleet_daemon_mode_skips_locked_and_offline_units() {
         let mut game = Game::fresh();
         game.state.daemon_unlocked = true;
-        let processor = &mut game.state.processors[0];
-        processor.daemon_unlocked = true;
+        game.state.processors = vec![ProcessorState::starter(); 4];
+        for processor in &mut game.state.processors[..3] {
+            processor.daemon_firmware_level = 1;
+        }
+        game.state.processors[2].status = ProcessorStatus::BurntOut;
 
-        assert_eq!(processor.daemon_mode, DaemonMode::Off);
-        game.cycle_daemon_mode(0);
-        assert_eq!(game.state.processors[0].daemon_mode, DaemonMode::Assist);
-        game.cycle_daemon_mode(0);
+        assert_eq!(game.cycle_fleet_daemon_mode(), 2);
+        assert_eq!(game.state.fleet_daemon_mode, DaemonMode::Assist);
+        let modes: Vec<DaemonMode> = game
+            .state
+            .processors
+            .iter()
+            .map(|processor| processor.daemon_mode)
+            .collect();
+        assert_eq!(
+            modes,
+            [
+                DaemonMode::Assist,
+                DaemonMode::Assist,
+                DaemonMode::Off,
+                DaemonMode::Off
+            ]
+        );
+        assert!(
+            game.messages()
+                .any(|message| message == "2 processors set to Assist, 2 skipped: locked/offline.")
+        );
+
+        assert_eq!(game.set_all_daemon_modes(DaemonMode::Auto), 2);
+        game.state.daemon_unlocked = false;
+        assert_eq!(game.cycle_fleet_daemon_mode(), 0);
         assert_eq!(game.state.processors[0].daemon_mode, DaemonMode::Auto);
-        game.cycle_daemon_mode(0);
-        assert_eq!(game.state.processors[0].daemon_mode, DaemonMode::Off);
     }
 
     #[test]
-    fn cooling_upgrade_respects_cap() {
+    fn salvage_strips_only_offline_units_and_drops_their_upkeep() {
         let mut game = Game::fresh();
-        game.state.credits = 1_000;
-        let processor_index = 0;
-        let cooling_idx = STORE_ITEMS
-            .iter()
-            .position(|item| item.action == StoreAction::UpgradeCooling)
-            .expect("cooling kit present");
+        game.state.processors = vec![ProcessorState::starter(), ProcessorState::rugged()];
+        game.state.credits = 0;
+        game.ledger = Ledger::new(0);
+        assert!(matches!(
+            game.salvage_processor(1),
+            Err(RetireError::StillOperational)
+        ));
+        assert_eq!(game.state.processors.len(), 2);
 
-        game.purchase_item(cooling_idx, Some(processor_index))
-            .expect("upgrade should succeed");
-        assert_eq!(game.state.processors[processor_index].cooling_level, 1);
+        game.state.processors[1].status = ProcessorStatus::BurntOut;
+        let upkeep = game.total_upkeep();
+        let value = game.salvage_processor(1).expect("salvages");
+        assert_eq!(value, 18);
+        assert_eq!(game.state.credits, 18);
+        assert_eq!(
+            game.total_upkeep(),
+            upkeep - ProcessorState::rugged().upkeep_cost
+        );
+        assert_eq!(game.state.processors.len(), 1);
 
-        // Bump to cap
-        game.purchase_item(cooling_idx, Some(processor_index))
-            .expect("second upgrade should succeed");
-        game.purchase_item(cooling_idx, Some(processor_index))
-            .expect("third upgrade should succeed");
+        game.state.processors[0].status = ProcessorStatus::Destroyed;
+        assert!(matches!(
+            game.salvage_processor(0),
+            Err(RetireError::LastProcessor)
+        ));
+    }
+
+    #[test]
+    fn contract_deposit_is_forfeited_on_burnout() {
+        let mut game = Game::fresh();
+        game.state.credits = 100;
+        game.ledger = Ledger::new(100);
+        game.assign_job_to_processor(rush_order(400), 0, false)
+            .expect("assigns");
+        let ProcessorStatus::Working(work) = game.state.processors[0].status.clone() else {
+            panic!("processor should be working");
+        };
+        game.state.processors[0].status = ProcessorStatus::BurntOut;
+        game.handle_burnout(0, work.job);
+
+        assert_eq!(game.state.credits, 20);
+        assert_eq!(game.escrow_locked(), 0);
+        assert_eq!(game.escrow_forfeited(), 80);
+        assert!(
+            game.events
+                .iter()
+                .any(|event| matches!(event, GameEvent::EscrowForfeited { amount: 80, .. }))
+        );
+        assert_eq!(game.ledger.expected_balance(), Some(game.state.credits));
+    }
+
+    #[test]
+    fn contract_needs_credits_for_the_deposit() {
+        let mut game = Game::fresh();
+        game.state.credits = 79;
+        let result = game.assign_job_to_processor(rush_order(400), 0, false);
 
-        assert_eq!(game.state.processors[processor_index].cooling_level, 3);
         assert!(matches!(
-            game.purchase_item(cooling_idx, Some(processor_index)),
-            Err(PurchaseError::UpgradeAtCap)
+            result,
+            Err(AssignmentError::InsufficientEscrow { deposit: 80 })
         ));
+        assert!(game.state.processors[0].is_idle());
+        assert_eq!(game.state.credits, 79);
     }
 
     #[test]
-    fn assist_mode_assigns_suggested_job() {
+    fn microcode_installs_on_one_unit_and_loads_leave_fleets_alone() {
         let mut game = Game::fresh();
-        game.state.daemon_unlocked = true;
-        let processor = &mut game.state.processors[0];
-        processor.daemon_unlocked = true;
-        processor.daemon_mode = DaemonMode::Assist;
+        game.state.processors = vec![ProcessorState::starter(); 2];
+        game.state.credits = 1_000;
+        game.unlock_instruction_tag(SIMD_TAG);
+        let install = Game::store_index_for(StoreAction::InstallInstruction { tag: SIMD_TAG })
+            .expect("listed");
+        assert_eq!(game.item_cost(install, None), None);
+        game.purchase_item(install, Some(1)).expect("installs");
 
-        game.state.jobs.push(Job {
-            id: 42,
-            name: "Assist Contract".to_string(),
+        let supports: Vec<bool> = game
+            .state
+            .processors
+            .iter()
+            .map(|processor| processor.supports(SIMD_TAG))
+            .collect();
+        assert_eq!(supports, [false, true]);
+
+        let reloaded = Game::from_state(game.state.clone());
+        assert!(!reloaded.state.processors[0].supports(SIMD_TAG));
+        assert!(reloaded.state.processors[1].supports(SIMD_TAG));
+    }
+
+    #[test]
+    fn mixed_tag_days_boost_the_next_dividend() {
+        let mut game = Game::fresh();
+        game.unlock_instruction_tag(jobs::SIMD_TAG);
+        game.state.storage.stored = 200;
+        game.state.statistics.record_completion(jobs::GENERAL_TAG);
+        game.state.statistics.record_completion(jobs::SIMD_TAG);
+        assert_eq!(game.versatility_progress(), (2, 3));
+
+        // Two tags never qualify, even when only two are unlocked.
+        game.apply_daily_cycle();
+        assert!(!game.state.statistics.versatility_active);
+
+        game.unlock_instruction_tag(jobs::RADIATION_TAG);
+        for tag in [jobs::GENERAL_TAG, jobs::SIMD_TAG, jobs::RADIATION_TAG] {
+            game.state.statistics.record_completion(tag);
+        }
+        game.apply_daily_cycle();
+        assert!(game.state.statistics.versatility_active);
+        assert_eq!(game.versatility_progress(), (0, 3));
+
+        game.apply_daily_cycle();
+        assert!(!game.state.statistics.versatility_active);
+        assert!(
+            game.messages()
+                .any(|message| message.contains("+12 credits (versatility x1.15)"))
+        );
+    }
+
+    #[test]
+    fn weather_drifts_within_its_band_and_heat_waves_run_out() {
+        let mut game = Game::seeded(GameState::default(), 11);
+        let (low, high) = TUNING.ambient_range;
+        let (mut started, mut broke) = (false, false);
+        for _ in 0..200 {
+            let before = game.state.heat_wave_days;
+            game.apply_daily_cycle();
+            game.process_events();
+            let ambient = game.state.ambient_temperature;
+            assert!((low..=high).contains(&ambient));
+            if before > 0 {
+                assert_eq!(game.state.heat_wave_days, before - 1);
+                broke |= game.state.heat_wave_days == 0
+                    && game
+                        .messages()
+                        .any(|message| message.contains("heat wave has broken"));
+            } else if game.state.heat_wave_days > 0 {
+                started |= game
+                    .messages()
+                    .any(|message| message.starts_with("Heat wave:"));
+            }
+        }
+        assert!(started && broke);
+    }
+
+    #[test]
+    fn heat_waves_make_jobs_hotter_and_cooling_claws_it_back() {
+        let mut game = Game::fresh();
+        let job = rush_order(100);
+        let evaluate = |game: &Game| {
+            game.state.processors[0].evaluate_job(&job, 0, game.ambient_temperature())
+        };
+        let mild = evaluate(&game);
+        game.state.heat_wave_days = 3;
+        let wave = evaluate(&game);
+        assert!(wave.heat > mild.heat);
+        assert!(wave.reliability < mild.reliability);
+
+        game.state.processors[0].cooling_level = 3;
+        let cooled = evaluate(&game);
+        let cooled_mild = game.state.processors[0].evaluate_job(&job, 0, 0.0);
+        assert!(cooled.heat - cooled_mild.heat < wave.heat - mild.heat);
+    }
+
+    /// Runs a scripted 3s job on a unit that cannot burn out, feeding the
+    /// game `frames` of game time, and notes when the unit went idle.
+    fn run_scripted_job(seed: u64, frames: &[u64]) -> (Game, Option<u64>) {
+        let mut game = Game::seeded(GameState::default(), seed);
+        let job = Job {
+            id: 900,
+            name: "Spiky Render".to_string(),
             tag: GENERAL_TAG.to_string(),
-            base_time_ms: 5_000,
-            base_reward: 150,
-            quality_target: 60,
-            data_output: 30,
-        });
+            base_time_ms: 3_000,
+            base_reward: 100,
+            quality_target: 50,
+            script: vec![
+                jobs::ScriptStep {
+                    at: 0.33,
+                    effect: ScriptEffect::HeatSpike { heat: 0.8 },
+                },
+                jobs::ScriptStep {
+                    at: 0.66,
+                    effect: ScriptEffect::QualityCheckpoint {
+                        min_reliability: 0.5,
+                    },
+                },
+            ],
+            ..Job::default()
+        };
+        game.state.processors[0].reliability_base = 2.0;
+        game.state.processors[0].assign(job, 3_000, None);
+        let mut elapsed = 0;
+        let mut completed_at = None;
+        for &frame in frames {
+            game.update(Duration::from_millis(frame));
+            elapsed += frame;
+            if completed_at.is_none() && game.state.processors[0].is_idle() {
+                completed_at = Some(elapsed);
+            }
+        }
+        (game, completed_at)
+    }
 
-        assert!(game.accept_assist_suggestion(0));
-        assert!(game.state.jobs.is_empty());
+    #[test]
+    fn long_frames_play_out_like_many_short_ones() {
+        for seed in 0..8 {
+            let (stepped, completed_at) = run_scripted_job(seed, &[10; 500]);
+            let (lumped, _) = run_scripted_job(seed, &[5_000]);
+            let completed_at = completed_at.expect("job completes");
+            assert_eq!(completed_at % TUNING.max_substep_ms, 0);
+
+            let stepped_unit = &stepped.state.processors[0];
+            let lumped_unit = &lumped.state.processors[0];
+            assert_eq!(stepped_unit.wear, lumped_unit.wear, "seed {seed}");
+            assert_eq!(stepped.state.credits, lumped.state.credits);
+            let stepped_log: Vec<_> = stepped.messages().collect();
+            let lumped_log: Vec<_> = lumped.messages().collect();
+            assert_eq!(stepped_log, lumped_log);
+            assert!(
+                stepped_log
+                    .iter()
+                    .any(|message| message.contains("quality checkpoint passed"))
+            );
+
+            // One step short of the completion time the job is still running.
+            let (early, _) = run_scripted_job(seed, &[completed_at - 1]);
+            assert!(!early.state.processors[0].is_idle());
+            let (exact, _) = run_scripted_job(seed, &[completed_at]);
+            assert!(exact.state.processors[0].is_idle());
+        }
+    }
+
+    #[test]
+    fn stacked_mutators_reshape_payouts_data_power_and_automation() {
+        let run = |mutators: Vec<Mutator>| {
+            let mut game = Game::seeded(
+                GameState {
+                    mutators,
+                    ..GameState::default()
+                },
+                11,
+            );
+            let job = Job {
+                id: 5,
+                name: "Census Batch".to_string(),
+                tag: GENERAL_TAG.to_string(),
+                base_time_ms: 5_000,
+                base_reward: 200,
+                quality_target: 60,
+                data_output: 40,
+                ..Job::default()
+            };
+            let start = game.state.credits;
+            game.resolve_completed_job(
+                0,
+                CompletedJob {
+                    job,
+                    daemon_penalty: None,
+                    forced: false,
+                },
+            );
+            let payout = game.state.credits - start;
+            (game, payout)
+        };
+        let (mut plain, plain_payout) = run(Vec::new());
+        let (mut stacked, stacked_payout) = run(Mutator::ALL.to_vec());
+
+        assert_eq!(stacked_payout, (plain_payout as f64 * 1.2).round() as u64);
+        assert_eq!(plain.state.storage.stored, 40);
+        assert_eq!(stacked.state.storage.stored, 20);
+        assert_eq!(stacked.electricity_rate(), plain.electricity_rate() * 2.0);
+
+        let job = jobs::boss_contract(9);
+        let plain_eval = plain.state.processors[0].evaluate_job(&job, 0, 0.0);
+        let stacked_eval = stacked.state.processors[0].evaluate_job(&job, 0, 0.0);
+        let expected = plain_eval.reliability - 0.02 * plain_eval.heat;
+        assert!((stacked_eval.reliability - expected).abs() < 1e-9);
+
+        for game in [&mut plain, &mut stacked] {
+            game.state.credits = DAEMON_UNLOCK_CREDITS;
+            game.ledger = Ledger::new(DAEMON_UNLOCK_CREDITS);
+            game.update(Duration::from_millis(1));
+        }
+        assert!(plain.state.daemon_unlocked);
+        assert!(!stacked.state.daemon_unlocked);
         assert!(matches!(
-            game.state.processors[0].status,
-            ProcessorStatus::Working(_)
+            stacked.start_daemon_trial(0),
+            Err(TrialError::Disabled)
         ));
     }
 }
@@ -0,0 +1,55 @@
+//! Headless runs: advances a seeded game tick by tick under an optional
+//! scripted policy and reports the outcome.
+
+use super::game::{DAY_DURATION, Game, GameState};
+use super::policy::Policy;
+use crate::TICK_RATE;
+use serde::{Deserialize, Serialize};
+
+/// Headline metrics of a finished headless run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunReport {
+    pub days: u64,
+    pub credits: u64,
+    pub completions: u64,
+    pub burnouts: u64,
+}
+
+impl RunReport {
+    pub fn of(game: &Game) -> Self {
+        let statistics = &game.state.statistics;
+        Self {
+            days: statistics.days_elapsed,
+            credits: game.state.credits,
+            completions: statistics.completions_by_tag.values().sum(),
+            burnouts: statistics.burnouts,
+        }
+    }
+}
+
+/// Seed the `--demo` attract mode always starts from.
+pub const DEMO_SEED: u64 = 1234;
+
+/// A new run whose starter board and every later roll derive from `seed`.
+pub fn seeded_run(seed: u64) -> Game {
+    let mut state = GameState {
+        run_seed: seed,
+        ..GameState::default()
+    };
+    state.seed_starter_board(seed);
+    Game::seeded(state, seed)
+}
+
+/// Starts a new run from `seed` and plays `days` full days, letting
+/// `policy` act before every tick.
+pub fn simulate(days: u64, seed: u64, policy: Option<Policy>) -> Game {
+    let mut game = seeded_run(seed);
+    let ticks = days * (DAY_DURATION.as_millis() / TICK_RATE.as_millis()) as u64;
+    for _ in 0..ticks {
+        if let Some(policy) = policy {
+            policy.act(&mut game);
+        }
+        game.update(TICK_RATE);
+    }
+    game
+}
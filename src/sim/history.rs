@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Completed jobs kept for advice and summaries.
+pub const MAX_JOB_HISTORY: usize = 40;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobRecord {
+    pub processor_id: u64,
+    pub tag: String,
+    pub duration_ms: u64,
+    pub quality: u8,
+    pub payout: u64,
+}
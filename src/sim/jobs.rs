@@ -1,10 +1,30 @@
+use super::reputation::ReputationTier;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+use thiserror::Error;
 
 pub const GENERAL_TAG: &str = "GENERAL";
 pub const SIMD_TAG: &str = "SIMD";
+pub const RADIATION_TAG: &str = "RADIATION";
+pub const ANGEL_TAG: &str = "ANGEL";
+pub const SURVEILLANCE_TAG: &str = "SURVEILLANCE";
+/// Every instruction tag this build can generate jobs for.
+pub const KNOWN_TAGS: [&str; 5] = [
+    GENERAL_TAG,
+    SIMD_TAG,
+    RADIATION_TAG,
+    ANGEL_TAG,
+    SURVEILLANCE_TAG,
+];
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+pub const FOLLOW_UP_REWARD_MULTIPLIER: f64 = 1.35;
+/// Posted jobs wait on the board this many times their base run time.
+pub const EXPIRY_FACTOR: u64 = 12;
+/// Share of the posting window under which a deadline counts as close.
+pub const EXPIRY_WARNING_SHARE: f64 = 0.2;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Job {
     pub id: u64,
     pub name: String,
@@ -13,43 +33,372 @@ pub struct Job {
     pub base_reward: u64,
     pub quality_target: u8,
     pub data_output: u64,
+    #[serde(default)]
+    pub follow_up_of: Option<u64>,
+    #[serde(default)]
+    pub preferred_processor_id: Option<u64>,
+    /// Model whose burnout this zero-reward job investigates.
+    #[serde(default)]
+    pub diagnostic_for: Option<String>,
+    /// Deposit held while the job is running; 0 when nothing is escrowed.
+    #[serde(default)]
+    pub escrow: u64,
+    /// Archive space held while the job is running; 0 when none is.
+    #[serde(default)]
+    pub storage_reserved: u64,
+    /// Extra daily upkeep charged while a unit is running the job.
+    #[serde(default)]
+    pub upkeep: u64,
+    /// Mid-job events, ordered by the progress at which they fire.
+    #[serde(default)]
+    pub script: Vec<ScriptStep>,
+    /// Board time left before the posting is withdrawn; frozen while a unit
+    /// holds the job. None never expires.
+    #[serde(default)]
+    pub expires_in_ms: Option<u64>,
+    /// Contract this job is a stage of.
+    #[serde(default)]
+    pub contract_id: Option<u64>,
+    /// Which stage of its contract, from 1; 0 for standalone jobs.
+    #[serde(default)]
+    pub stage: u8,
+}
+
+/// A scripted event that fires once the job passes `at` progress (0..1).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScriptStep {
+    pub at: f64,
+    pub effect: ScriptEffect,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ScriptEffect {
+    /// Adds heat for the rest of the job; cooling still reduces it.
+    HeatSpike { heat: f64 },
+    /// Fails the job unless the unit's reliability is at least the minimum.
+    QualityCheckpoint { min_reliability: f64 },
+}
+
+impl Job {
+    /// Utility jobs pay nothing directly; the daemon skips them unless a
+    /// unit opts in.
+    pub fn is_utility(&self) -> bool {
+        self.diagnostic_for.is_some()
+    }
+
+    pub fn is_boss(&self) -> bool {
+        !self.script.is_empty()
+    }
+
+    /// Free archive space the job needs to start. SURVEILLANCE work holds
+    /// its whole data output instead of producing it.
+    pub fn storage_demand(&self) -> u64 {
+        if self.tag == SURVEILLANCE_TAG {
+            self.data_output
+        } else {
+            0
+        }
+    }
+
+    /// How long a fresh posting of this job stays on the board.
+    pub fn expiry_window_ms(&self) -> u64 {
+        self.base_time_ms.saturating_mul(EXPIRY_FACTOR)
+    }
+
+    /// Share of the posting window left, or None when the job never expires.
+    pub fn expiry_share_left(&self) -> Option<f64> {
+        let left = self.expires_in_ms?;
+        Some((left as f64 / self.expiry_window_ms().max(1) as f64).clamp(0.0, 1.0))
+    }
+
+    fn posted(mut self) -> Self {
+        self.expires_in_ms = Some(self.expiry_window_ms());
+        self
+    }
+}
+
+/// The shipped templates, used when no override is installed.
+pub const DEFAULT_TEMPLATES: &str = include_str!("jobs.ron");
+
+static TEMPLATES: OnceLock<JobTemplates> = OnceLock::new();
+
+#[derive(Debug, Error)]
+pub enum TemplateError {
+    #[error("job templates do not parse: {0}")]
+    Parse(#[from] ron::error::SpannedError),
+    #[error("job template {entry}: {problem}")]
+    Invalid { entry: String, problem: String },
+    #[error("no job template for {tag}")]
+    Missing { tag: &'static str },
+}
+
+/// Names and stat ranges for the jobs posted under one tag. Ranges exclude
+/// their upper bound.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JobTemplate {
+    pub tag: String,
+    pub names: Vec<String>,
+    pub time_ms: (u64, u64),
+    pub reward: (u64, u64),
+    pub quality: (u8, u8),
+    pub data: (u64, u64),
+    /// Slots the tag takes in the spawn draw; 0 never spawns it at random.
+    #[serde(default)]
+    pub weight: u32,
+}
+
+impl JobTemplate {
+    fn generate(&self, id: u64, rng: &mut impl Rng) -> Job {
+        let base_time_ms = rng.gen_range(self.time_ms.0..self.time_ms.1);
+        let base_reward = rng.gen_range(self.reward.0..self.reward.1);
+        let quality_target = rng.gen_range(self.quality.0..self.quality.1);
+        let data_output = rng.gen_range(self.data.0..self.data.1);
+        let name = match self.names.as_slice() {
+            [only] => only,
+            names => &names[rng.gen_range(0..names.len())],
+        };
+        Job {
+            id,
+            name: format!("{name} #{id}"),
+            tag: self.tag.clone(),
+            base_time_ms,
+            base_reward,
+            quality_target,
+            data_output,
+            ..Job::default()
+        }
+        .posted()
+    }
+
+    fn validate(&self, entry: &str) -> Result<(), TemplateError> {
+        let invalid = |problem: String| TemplateError::Invalid {
+            entry: entry.to_string(),
+            problem,
+        };
+        if self.names.is_empty() || self.names.iter().any(|name| name.trim().is_empty()) {
+            return Err(invalid("needs at least one non-blank name".to_string()));
+        }
+        for (field, (min, max)) in [
+            ("time_ms", self.time_ms),
+            ("reward", self.reward),
+            ("data", self.data),
+            ("quality", (self.quality.0 as u64, self.quality.1 as u64)),
+        ] {
+            if min >= max {
+                return Err(invalid(format!("{field} range ({min}, {max}) is empty")));
+            }
+        }
+        if self.quality.1 > 101 {
+            return Err(invalid(format!(
+                "quality range reaches {}, past 100",
+                self.quality.1 - 1
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Every job generator's names, ranges and spawn weights, read from RON.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JobTemplates {
+    pub starter: JobTemplate,
+    pub tags: Vec<JobTemplate>,
+}
+
+impl JobTemplates {
+    /// Parses and checks templates, naming the entry at fault: `starter`
+    /// or the tag's own name.
+    pub fn from_ron(text: &str) -> Result<Self, TemplateError> {
+        let templates: Self = ron::from_str(text)?;
+        templates.starter.validate("starter")?;
+        for (index, template) in templates.tags.iter().enumerate() {
+            template.validate(&template.tag)?;
+            if !KNOWN_TAGS.contains(&template.tag.as_str()) {
+                return Err(TemplateError::Invalid {
+                    entry: template.tag.clone(),
+                    problem: format!("unknown tag; expected one of {}", KNOWN_TAGS.join(", ")),
+                });
+            }
+            if templates.tags[..index]
+                .iter()
+                .any(|earlier| earlier.tag == template.tag)
+            {
+                return Err(TemplateError::Invalid {
+                    entry: template.tag.clone(),
+                    problem: "listed more than once".to_string(),
+                });
+            }
+        }
+        if let Some(tag) = KNOWN_TAGS
+            .into_iter()
+            .find(|tag| templates.get(tag).is_none())
+        {
+            return Err(TemplateError::Missing { tag });
+        }
+        Ok(templates)
+    }
+
+    pub fn get(&self, tag: &str) -> Option<&JobTemplate> {
+        self.tags.iter().find(|template| template.tag == tag)
+    }
+
+    pub fn weight(&self, tag: &str) -> u32 {
+        self.get(tag).map_or(0, |template| template.weight)
+    }
+}
+
+/// Makes `templates` the ones every generator uses. Only the first call
+/// takes effect; returns whether this one did.
+pub fn install_templates(templates: JobTemplates) -> bool {
+    TEMPLATES.set(templates).is_ok()
+}
+
+/// The installed templates, or the shipped defaults.
+pub fn templates() -> &'static JobTemplates {
+    TEMPLATES.get_or_init(|| {
+        JobTemplates::from_ron(DEFAULT_TEMPLATES).expect("shipped job templates are valid")
+    })
+}
+
+/// A short, forgiving GENERAL job that any starter unit can finish.
+pub fn generate_starter_job(id: u64, rng: &mut impl Rng) -> Job {
+    templates().starter.generate(id, rng)
+}
+
+/// Posts a job for `tag` from its template; its reward range widens with
+/// the client reputation tier. Unknown tags post GENERAL work.
+pub fn generate_job_with_tag(id: u64, tag: &str, reputation: i32, rng: &mut impl Rng) -> Job {
+    let templates = templates();
+    let template = templates
+        .get(tag)
+        .or_else(|| templates.get(GENERAL_TAG))
+        .expect("templates cover every known tag");
+    let mut job = template.generate(id, rng);
+    let scale = ReputationTier::for_reputation(reputation).reward_multiplier();
+    job.base_reward = (job.base_reward as f64 * scale).round() as u64;
+    job
+}
+
+/// Builds a repeat posting from the client behind `original`, addressed to the
+/// processor that delivered it.
+pub fn generate_follow_up_job(
+    id: u64,
+    original: &Job,
+    processor_id: u64,
+    reputation: i32,
+    rng: &mut impl Rng,
+) -> Job {
+    let mut job = generate_job_with_tag(id, &original.tag, reputation, rng);
+    job.name = format!("Follow-up #{id} (re #{})", original.id);
+    job.base_reward = ((job.base_reward.max(original.base_reward) as f64)
+        * FOLLOW_UP_REWARD_MULTIPLIER)
+        .round() as u64;
+    job.follow_up_of = Some(original.id);
+    job.preferred_processor_id = Some(processor_id);
+    job
 }
 
-pub fn generate_general_job(id: u64, rng: &mut impl Rng) -> Job {
-    let base_time_ms = rng.gen_range(4_000..9_000);
-    let base_reward = rng.gen_range(70..140);
-    let quality_target = rng.gen_range(55..85);
-    let data_output = rng.gen_range(12..32);
+/// The handcrafted boss contract: a multi-day render that spikes hot halfway
+/// and is inspected at three quarters.
+pub fn boss_contract(id: u64) -> Job {
     Job {
         id,
-        name: format!("General Task #{id}"),
+        name: format!("Leviathan Render #{id}"),
         tag: GENERAL_TAG.to_string(),
-        base_time_ms,
-        base_reward,
-        quality_target,
-        data_output,
+        base_time_ms: 75_000,
+        base_reward: 2_400,
+        quality_target: 70,
+        data_output: 120,
+        upkeep: 25,
+        script: vec![
+            ScriptStep {
+                at: 0.5,
+                effect: ScriptEffect::HeatSpike { heat: 1.5 },
+            },
+            ScriptStep {
+                at: 0.75,
+                effect: ScriptEffect::QualityCheckpoint {
+                    min_reliability: 0.75,
+                },
+            },
+        ],
+        ..Job::default()
     }
 }
 
-pub fn generate_simd_job(id: u64, rng: &mut impl Rng) -> Job {
-    let base_time_ms = rng.gen_range(6_000..13_000);
-    let base_reward = rng.gen_range(160..260);
-    let quality_target = rng.gen_range(65..95);
-    let data_output = rng.gen_range(36..72);
+/// Free inspection of a burnt-out model; completing it discounts that model's
+/// next replacement.
+pub fn generate_diagnostics_job(id: u64, model: &str, rng: &mut impl Rng) -> Job {
     Job {
         id,
-        name: format!("SIMD Workload #{id}"),
-        tag: SIMD_TAG.to_string(),
-        base_time_ms,
-        base_reward,
-        quality_target,
-        data_output,
+        name: format!("Diagnostics #{id}: {model}"),
+        tag: GENERAL_TAG.to_string(),
+        base_time_ms: rng.gen_range(8_000..12_000),
+        base_reward: 0,
+        quality_target: 50,
+        data_output: 0,
+        diagnostic_for: Some(model.to_string()),
+        ..Job::default()
     }
 }
 
-pub fn generate_job_with_tag(id: u64, tag: &str, rng: &mut impl Rng) -> Job {
-    match tag {
-        SIMD_TAG => generate_simd_job(id, rng),
-        _ => generate_general_job(id, rng),
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn shipped_templates_post_jobs_within_their_ranges() {
+        let templates = JobTemplates::from_ron(DEFAULT_TEMPLATES).expect("valid");
+        let mut rng = StdRng::seed_from_u64(3);
+        for template in templates.tags.iter().chain([&templates.starter]) {
+            for id in 0..200 {
+                let job = template.generate(id, &mut rng);
+                let within = |(min, max): (u64, u64), value: u64| (min..max).contains(&value);
+                assert!(within(template.time_ms, job.base_time_ms), "{job:?}");
+                assert!(within(template.reward, job.base_reward), "{job:?}");
+                assert!(within(template.data, job.data_output), "{job:?}");
+                assert!((template.quality.0..template.quality.1).contains(&job.quality_target));
+                assert!(job.quality_target <= 100);
+                assert!(
+                    template
+                        .names
+                        .iter()
+                        .any(|name| job.name == format!("{name} #{id}"))
+                );
+                assert_eq!(job.tag, template.tag);
+                assert_eq!(job.expires_in_ms, Some(job.expiry_window_ms()));
+            }
+        }
+        assert_eq!(templates.weight(GENERAL_TAG), 4);
+        assert_eq!(templates.weight("QUANTUM"), 0);
+    }
+
+    #[test]
+    fn malformed_templates_name_the_offending_entry() {
+        let broken = DEFAULT_TEMPLATES.replace("reward: (160, 260)", "reward: (260, 160)");
+        let err = JobTemplates::from_ron(&broken).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "job template SIMD: reward range (260, 160) is empty"
+        );
+
+        let broken = DEFAULT_TEMPLATES.replace("\"Starter Task\", ", "\" \", ");
+        assert!(matches!(
+            JobTemplates::from_ron(&broken),
+            Err(TemplateError::Invalid { entry, .. }) if entry == "starter"
+        ));
+
+        let broken = DEFAULT_TEMPLATES.replace("tag: \"SURVEILLANCE\"", "tag: \"SURVEYING\"");
+        assert!(matches!(
+            JobTemplates::from_ron(&broken),
+            Err(TemplateError::Invalid { entry, .. }) if entry == "SURVEYING"
+        ));
+
+        assert!(matches!(
+            JobTemplates::from_ron("(starter: ("),
+            Err(TemplateError::Parse(_))
+        ));
     }
 }
@@ -0,0 +1,64 @@
+/// Runtime record of every credit movement since the game was loaded, used to
+/// catch arithmetic that would have underflowed the treasury.
+#[derive(Debug, Clone, Default)]
+pub struct Ledger {
+    opening_balance: u64,
+    income: u64,
+    expenses: u64,
+    escrow_posted: u64,
+    escrow_returned: u64,
+    escrow_forfeited: u64,
+    underflows: Vec<(u64, u64)>,
+}
+
+impl Ledger {
+    pub fn new(opening_balance: u64) -> Self {
+        Self {
+            opening_balance,
+            ..Self::default()
+        }
+    }
+
+    pub fn record_income(&mut self, amount: u64) {
+        self.income = self.income.saturating_add(amount);
+    }
+
+    pub fn record_expense(&mut self, amount: u64) {
+        self.expenses = self.expenses.saturating_add(amount);
+    }
+
+    /// Credits moved out of the treasury into a contract deposit.
+    pub fn record_escrow(&mut self, amount: u64) {
+        self.escrow_posted = self.escrow_posted.saturating_add(amount);
+    }
+
+    pub fn record_escrow_return(&mut self, amount: u64) {
+        self.escrow_returned = self.escrow_returned.saturating_add(amount);
+    }
+
+    /// Deposit kept on failure; it already left the treasury when posted.
+    pub fn record_escrow_forfeit(&mut self, amount: u64) {
+        self.escrow_forfeited = self.escrow_forfeited.saturating_add(amount);
+    }
+
+    pub fn escrow_forfeited(&self) -> u64 {
+        self.escrow_forfeited
+    }
+
+    pub fn record_underflow(&mut self, attempted: u64, available: u64) {
+        self.underflows.push((attempted, available));
+    }
+
+    /// Attempted debits that exceeded the balance, as `(attempted, available)`.
+    pub fn underflows(&self) -> &[(u64, u64)] {
+        &self.underflows
+    }
+
+    pub fn expected_balance(&self) -> Option<u64> {
+        self.opening_balance
+            .checked_add(self.income)?
+            .checked_add(self.escrow_returned)?
+            .checked_sub(self.expenses)?
+            .checked_sub(self.escrow_posted)
+    }
+}
@@ -0,0 +1,93 @@
+use super::data_storage::DataStorage;
+use super::game::GameState;
+use super::processors::ProcessorState;
+use serde::{Deserialize, Serialize};
+
+/// Opening fleet chosen on the new-game screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Loadout {
+    #[default]
+    Balanced,
+    TwinBudget,
+    Hardened,
+}
+
+impl Loadout {
+    pub const ALL: [Loadout; 3] = [Loadout::Balanced, Loadout::TwinBudget, Loadout::Hardened];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Loadout::Balanced => "Balanced",
+            Loadout::TwinBudget => "Twin Budget",
+            Loadout::Hardened => "Hardened Vault",
+        }
+    }
+
+    pub fn summary(self) -> &'static str {
+        match self {
+            Loadout::Balanced => "One Model F12-Scalar, 120 cr, 120 storage.",
+            Loadout::TwinBudget => "Two cheap, fragile F9-Budget units, 60 cr, 120 storage.",
+            Loadout::Hardened => "One slow, hardened F12-Rugged unit, 90 cr, 200 storage.",
+        }
+    }
+
+    /// Opening advice tailored to the loadout's quirks.
+    pub fn tip(self) -> &'static str {
+        match self {
+            Loadout::Balanced => {
+                "Tip: your F12-Scalar has no quirks — take GENERAL jobs and save for upgrades."
+            }
+            Loadout::TwinBudget => {
+                "Tip: the F9-Budget units lose reliability fast when hot — keep both busy but buy cooling early."
+            }
+            Loadout::Hardened => {
+                "Tip: the F12-Rugged shrugs off risky jobs but runs slowly — favor high-reward work and use the spare storage."
+            }
+        }
+    }
+
+    pub fn state(self) -> GameState {
+        match self {
+            Loadout::Balanced => GameState::default(),
+            Loadout::TwinBudget => GameState {
+                credits: 60,
+                processors: vec![ProcessorState::budget(), ProcessorState::budget()],
+                loadout: self,
+                ..GameState::default()
+            },
+            Loadout::Hardened => GameState {
+                credits: 90,
+                processors: vec![ProcessorState::rugged()],
+                storage: DataStorage::new(200),
+                loadout: self,
+                ..GameState::default()
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::game::Game;
+
+    #[test]
+    fn every_loadout_builds_a_playable_state() {
+        for loadout in Loadout::ALL {
+            let game = Game::from_state(loadout.state());
+            assert_eq!(game.state.loadout, loadout);
+            assert!(!game.state.processors.is_empty());
+            let mut ids: Vec<u64> = game.state.processors.iter().map(|p| p.id).collect();
+            ids.sort_unstable();
+            ids.dedup();
+            assert_eq!(
+                ids.len(),
+                game.state.processors.len(),
+                "{}",
+                loadout.label()
+            );
+        }
+        assert_eq!(Loadout::TwinBudget.state().processors.len(), 2);
+        assert_eq!(Loadout::Hardened.state().storage.capacity, 200);
+    }
+}
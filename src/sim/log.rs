@@ -0,0 +1,239 @@
+use super::ring::RingBuffer;
+use super::settings::MessageCategory;
+
+/// Entries the event log keeps before evicting the oldest.
+pub const MAX_LOG_ENTRIES: usize = 500;
+
+/// Entity an entry is about, by stable id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogSubject {
+    Processor(u64),
+    Job(u64),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    /// Position in the log since the game started; never reused.
+    pub seq: u64,
+    /// Routed notices carry their category; plain messages have none.
+    pub category: Option<MessageCategory>,
+    pub subject: Option<LogSubject>,
+    pub text: String,
+}
+
+impl LogEntry {
+    /// ASCII case-insensitive match on the text or category label; `needle`
+    /// must already be lowercase.
+    fn matches(&self, needle: &str) -> bool {
+        self.text.to_ascii_lowercase().contains(needle)
+            || self
+                .category
+                .is_some_and(|category| category.label().contains(needle))
+    }
+}
+
+/// Event log with sequence numbers, so a reference to an entry stays valid
+/// (or detectably stale) as old entries are evicted.
+#[derive(Debug, Clone)]
+pub struct EventLog {
+    entries: RingBuffer<LogEntry>,
+    next_seq: u64,
+}
+
+impl EventLog {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: RingBuffer::new(capacity),
+            next_seq: 0,
+        }
+    }
+
+    pub fn push(
+        &mut self,
+        category: Option<MessageCategory>,
+        subject: Option<LogSubject>,
+        text: String,
+    ) -> u64 {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.entries.push(LogEntry {
+            seq,
+            category,
+            subject,
+            text,
+        });
+        seq
+    }
+
+    /// Oldest to newest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &LogEntry> + ExactSizeIterator {
+        self.entries.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Sequence number of the oldest entry still held.
+    pub fn first_seq(&self) -> u64 {
+        self.next_seq - self.entries.len() as u64
+    }
+
+    pub fn last_seq(&self) -> Option<u64> {
+        self.next_seq.checked_sub(1).filter(|_| !self.is_empty())
+    }
+
+    pub fn get(&self, seq: u64) -> Option<&LogEntry> {
+        let offset = seq.checked_sub(self.first_seq())?;
+        self.entries.iter().nth(offset as usize)
+    }
+
+    /// Position of `seq` in `iter()` order, if it hasn't been evicted.
+    pub fn position(&self, seq: u64) -> Option<usize> {
+        let offset = seq.checked_sub(self.first_seq())? as usize;
+        (offset < self.len()).then_some(offset)
+    }
+}
+
+/// Incremental search over an `EventLog`. Matches are kept by sequence
+/// number; `sync` indexes new entries and drops evicted ones, so the log can
+/// keep growing while a search is open.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogSearch {
+    query: String,
+    /// Matching sequence numbers, ascending.
+    matches: Vec<u64>,
+    /// First sequence number not yet looked at.
+    indexed_to: u64,
+}
+
+impl LogSearch {
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn set_query(&mut self, query: String, log: &EventLog) {
+        self.query = query;
+        self.matches.clear();
+        self.indexed_to = 0;
+        self.sync(log);
+    }
+
+    pub fn sync(&mut self, log: &EventLog) {
+        let first = log.first_seq();
+        self.matches.retain(|&seq| seq >= first);
+        if self.query.is_empty() {
+            self.indexed_to = first + log.len() as u64;
+            return;
+        }
+        let needle = self.query.to_ascii_lowercase();
+        let start = self.indexed_to.max(first);
+        self.matches.extend(
+            log.iter()
+                .skip((start - first) as usize)
+                .filter(|entry| entry.matches(&needle))
+                .map(|entry| entry.seq),
+        );
+        self.indexed_to = first + log.len() as u64;
+    }
+
+    pub fn matches(&self) -> &[u64] {
+        &self.matches
+    }
+
+    pub fn is_match(&self, seq: u64) -> bool {
+        self.matches.binary_search(&seq).is_ok()
+    }
+
+    /// First match after `from`, wrapping to the oldest.
+    pub fn next(&self, from: Option<u64>) -> Option<u64> {
+        let after = from.map_or(0, |seq| self.matches.partition_point(|&m| m <= seq));
+        self.matches
+            .get(after)
+            .or_else(|| self.matches.first())
+            .copied()
+    }
+
+    /// Last match before `from`, wrapping to the newest.
+    pub fn previous(&self, from: Option<u64>) -> Option<u64> {
+        let before = from.map_or(self.matches.len(), |seq| {
+            self.matches.partition_point(|&m| m < seq)
+        });
+        before
+            .checked_sub(1)
+            .and_then(|index| self.matches.get(index))
+            .or_else(|| self.matches.last())
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log_of(capacity: usize, texts: &[&str]) -> EventLog {
+        let mut log = EventLog::new(capacity);
+        for text in texts {
+            log.push(None, None, text.to_string());
+        }
+        log
+    }
+
+    #[test]
+    fn search_index_tracks_evictions_and_new_entries() {
+        let mut log = log_of(
+            4,
+            &["Unit 2 burnt out", "Job posted", "unit 2 BURNT OUT again"],
+        );
+        let mut search = LogSearch::default();
+        search.set_query("burnt".to_string(), &log);
+        assert_eq!(search.matches(), &[0, 2]);
+
+        log.push(None, None, "Purchased cooling".to_string());
+        log.push(None, None, "Unit 3 burnt out".to_string());
+        search.sync(&log);
+        assert_eq!(log.first_seq(), 1);
+        assert_eq!(search.matches(), &[2, 4]);
+        assert!(!search.is_match(0));
+
+        for _ in 0..4 {
+            log.push(None, None, "quiet".to_string());
+        }
+        search.sync(&log);
+        assert!(search.matches().is_empty());
+        assert_eq!(search.next(Some(4)), None);
+
+        log.push(None, None, "burnt again".to_string());
+        search.sync(&log);
+        assert_eq!(search.matches(), &[9]);
+        assert_eq!(
+            log.get(9).map(|entry| entry.text.as_str()),
+            Some("burnt again")
+        );
+        assert_eq!(log.position(9), Some(3));
+        assert_eq!(log.position(4), None);
+    }
+
+    #[test]
+    fn next_and_previous_wrap_and_categories_match() {
+        let mut log = log_of(10, &["a", "b"]);
+        log.push(
+            Some(MessageCategory::Hardware),
+            None,
+            "fan noise".to_string(),
+        );
+        log.push(None, None, "hardware sale".to_string());
+        let mut search = LogSearch::default();
+        search.set_query("HARDWARE".to_string(), &log);
+        assert_eq!(search.matches(), &[2, 3]);
+        assert_eq!(search.next(None), Some(2));
+        assert_eq!(search.next(Some(2)), Some(3));
+        assert_eq!(search.next(Some(3)), Some(2));
+        assert_eq!(search.previous(Some(2)), Some(3));
+        assert_eq!(search.previous(None), Some(3));
+    }
+}
@@ -0,0 +1,35 @@
+use super::economy::ELECTRICITY_RATE;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// Days of market conditions rolled ahead of time; the deepest forecast tier
+/// can see this far.
+pub const OUTLOOK_DAYS: usize = 2;
+
+/// Market conditions for a single day, rolled before the day begins so that
+/// forecasts only ever display already-determined values.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DailyOutlook {
+    /// Credits charged per kWh drawn.
+    pub electricity_rate: f64,
+    /// Multiplier applied to the reward of newly posted jobs.
+    pub demand: f64,
+}
+
+impl Default for DailyOutlook {
+    fn default() -> Self {
+        Self {
+            electricity_rate: ELECTRICITY_RATE,
+            demand: 1.0,
+        }
+    }
+}
+
+impl DailyOutlook {
+    pub fn roll(rng: &mut impl Rng) -> Self {
+        Self {
+            electricity_rate: ELECTRICITY_RATE * rng.gen_range(0.7..1.3),
+            demand: rng.gen_range(0.8..1.25),
+        }
+    }
+}
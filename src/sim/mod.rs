@@ -1,5 +1,29 @@
+pub mod advisor;
+pub mod audit;
+pub mod care_package;
+pub mod carry_over;
+pub mod command;
+pub mod contracts;
 pub mod data_storage;
 pub mod economy;
+pub mod events;
 pub mod game;
+pub mod harness;
+pub mod history;
 pub mod jobs;
+pub mod ledger;
+pub mod loadout;
+pub mod log;
+pub mod market;
+pub mod museum;
+pub mod mutators;
+pub mod policy;
 pub mod processors;
+pub mod quarantine;
+pub mod racks;
+pub mod reputation;
+pub mod ring;
+pub mod settings;
+pub mod stats;
+pub mod timeline;
+pub mod tuning;
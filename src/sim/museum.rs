@@ -0,0 +1,51 @@
+use super::mutators::Mutator;
+use super::processors::ProcessorState;
+use serde::{Deserialize, Serialize};
+
+/// Completions a processor needs before retiring earns it a museum plaque.
+pub const MUSEUM_MIN_COMPLETIONS: u64 = 50;
+/// Whole quality points each exhibit adds to every delivery.
+pub const MUSEUM_QUALITY_PER_ENTRY: u8 = 1;
+pub const MUSEUM_BONUS_MAX_ENTRIES: usize = 5;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MuseumEntry {
+    pub processor_id: u64,
+    pub name: String,
+    pub jobs_completed: u64,
+    pub best_quality: u8,
+    pub speed: f64,
+    pub quality_bias: i8,
+    pub cooling_level: u8,
+    pub hardening_level: u8,
+    pub wear: f64,
+    /// Mutators of the run the unit retired from.
+    #[serde(default)]
+    pub mutators: Vec<Mutator>,
+}
+
+impl MuseumEntry {
+    pub fn from_processor(processor: &ProcessorState, mutators: &[Mutator]) -> Self {
+        Self {
+            processor_id: processor.id,
+            name: processor.name.clone(),
+            jobs_completed: processor.jobs_completed,
+            best_quality: processor.best_quality,
+            speed: processor.speed,
+            quality_bias: processor.quality_bias,
+            cooling_level: processor.cooling_level,
+            hardening_level: processor.hardening_level,
+            wear: processor.wear,
+            mutators: mutators.to_vec(),
+        }
+    }
+}
+
+pub fn qualifies(processor: &ProcessorState) -> bool {
+    processor.jobs_completed >= MUSEUM_MIN_COMPLETIONS
+}
+
+/// Quality points granted to the whole fleet by the museum.
+pub fn fleet_quality_bonus(museum: &[MuseumEntry]) -> u8 {
+    museum.len().min(MUSEUM_BONUS_MAX_ENTRIES) as u8 * MUSEUM_QUALITY_PER_ENTRY
+}
@@ -0,0 +1,118 @@
+use super::tuning::Tuning;
+use serde::{Deserialize, Serialize};
+
+/// Optional rule changes picked on the new-game screen, fixed for the run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Mutator {
+    FragileSilicon,
+    BlackoutProtocol,
+    SilentDaemon,
+    DataFamine,
+}
+
+impl Mutator {
+    pub const ALL: [Mutator; 4] = [
+        Mutator::FragileSilicon,
+        Mutator::BlackoutProtocol,
+        Mutator::SilentDaemon,
+        Mutator::DataFamine,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Mutator::FragileSilicon => "Fragile Silicon",
+            Mutator::BlackoutProtocol => "Blackout Protocol",
+            Mutator::SilentDaemon => "Silent Daemon",
+            Mutator::DataFamine => "Data Famine",
+        }
+    }
+
+    /// Short tag for the header.
+    pub fn badge(self) -> &'static str {
+        match self {
+            Mutator::FragileSilicon => "FRAGILE",
+            Mutator::BlackoutProtocol => "BLACKOUT",
+            Mutator::SilentDaemon => "SILENT",
+            Mutator::DataFamine => "FAMINE",
+        }
+    }
+
+    pub fn summary(self) -> &'static str {
+        match self {
+            Mutator::FragileSilicon => "+0.02 fragility on every unit, +20% rewards.",
+            Mutator::BlackoutProtocol => "Electricity x2, passive income x2.",
+            Mutator::SilentDaemon => "Automation never unlocks.",
+            Mutator::DataFamine => "Jobs output half the data.",
+        }
+    }
+
+    /// Layers this mutator onto `tuning`: additive terms add, multipliers
+    /// multiply, so mutators compose in any order.
+    pub fn apply(self, tuning: &mut Tuning) {
+        match self {
+            Mutator::FragileSilicon => {
+                tuning.extra_fragility += 0.02;
+                tuning.reward_multiplier *= 1.2;
+            }
+            Mutator::BlackoutProtocol => {
+                tuning.electricity_multiplier *= 2.0;
+                tuning.passive_income_multiplier *= 2.0;
+            }
+            Mutator::SilentDaemon => tuning.daemon_unlockable = false,
+            Mutator::DataFamine => tuning.data_output_multiplier *= 0.5,
+        }
+    }
+}
+
+/// Adds `mutator` if absent, removes it otherwise, keeping the list sorted.
+pub fn toggle(mutators: &mut Vec<Mutator>, mutator: Mutator) {
+    match mutators.binary_search(&mutator) {
+        Ok(index) => {
+            mutators.remove(index);
+        }
+        Err(index) => mutators.insert(index, mutator),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::tuning::TUNING;
+
+    #[test]
+    fn mutators_transform_tuning_and_compose() {
+        let base = Tuning::with_mutators(&[]);
+        assert_eq!(base.reward_multiplier, 1.0);
+        assert!(base.daemon_unlockable);
+
+        let fragile = Tuning::with_mutators(&[Mutator::FragileSilicon]);
+        assert_eq!(fragile.extra_fragility, 0.02);
+        assert!((fragile.reward_multiplier - 1.2).abs() < 1e-9);
+        assert_eq!(
+            fragile.heat_failure_multiplier,
+            TUNING.heat_failure_multiplier
+        );
+
+        let all = Tuning::with_mutators(&Mutator::ALL);
+        let reversed: Vec<Mutator> = Mutator::ALL.into_iter().rev().collect();
+        let all_reversed = Tuning::with_mutators(&reversed);
+        for tuning in [all, all_reversed] {
+            assert_eq!(tuning.extra_fragility, 0.02);
+            assert!((tuning.reward_multiplier - 1.2).abs() < 1e-9);
+            assert_eq!(tuning.electricity_multiplier, 2.0);
+            assert_eq!(tuning.passive_income_multiplier, 2.0);
+            assert_eq!(tuning.data_output_multiplier, 0.5);
+            assert!(!tuning.daemon_unlockable);
+        }
+    }
+
+    #[test]
+    fn toggle_keeps_the_list_sorted_and_unique() {
+        let mut mutators = Vec::new();
+        toggle(&mut mutators, Mutator::DataFamine);
+        toggle(&mut mutators, Mutator::FragileSilicon);
+        assert_eq!(mutators, vec![Mutator::FragileSilicon, Mutator::DataFamine]);
+        toggle(&mut mutators, Mutator::DataFamine);
+        assert_eq!(mutators, vec![Mutator::FragileSilicon]);
+    }
+}
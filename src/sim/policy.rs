@@ -0,0 +1,117 @@
+//! Scripted players for headless runs: the golden regression test and the
+//! `simulate` subcommand drive the game through these.
+
+use super::game::{Game, StoreAction};
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    /// Puts the best-paying job on the fastest idle unit and buys cooling
+    /// whenever it can pay cash for it.
+    Conservative,
+}
+
+impl Policy {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "conservative" => Some(Policy::Conservative),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Policy::Conservative => "conservative",
+        }
+    }
+
+    /// Takes this tick's decisions.
+    pub fn act(self, game: &mut Game) {
+        match self {
+            Policy::Conservative => {
+                assign_best_jobs(game);
+                buy_cooling(game);
+            }
+        }
+    }
+}
+
+fn assign_best_jobs(game: &mut Game) {
+    let mut idle: Vec<usize> = game
+        .state
+        .processors
+        .iter()
+        .enumerate()
+        .filter(|(_, processor)| processor.is_idle() && processor.is_functional())
+        .map(|(index, _)| index)
+        .collect();
+    idle.sort_by(|a, b| {
+        let (pa, pb) = (&game.state.processors[*a], &game.state.processors[*b]);
+        pb.effective_speed()
+            .partial_cmp(&pa.effective_speed())
+            .unwrap_or(Ordering::Equal)
+    });
+    for processor_index in idle {
+        let processor = &game.state.processors[processor_index];
+        let mut candidates: Vec<usize> = (0..game.state.jobs.len())
+            .filter(|&index| processor.supports(&game.state.jobs[index].tag))
+            .collect();
+        candidates.sort_by_key(|&index| std::cmp::Reverse(game.state.jobs[index].base_reward));
+        // Contracts the treasury cannot cover are skipped for the next best.
+        for job_index in candidates {
+            if game.assign_board_job(job_index, processor_index).is_ok() {
+                break;
+            }
+        }
+    }
+}
+
+fn buy_cooling(game: &mut Game) {
+    let Some(store_index) = Game::store_index_for(StoreAction::UpgradeCooling) else {
+        return;
+    };
+    for processor_index in 0..game.state.processors.len() {
+        let Some(cost) = game.item_cost(store_index, Some(processor_index)) else {
+            continue;
+        };
+        if cost <= game.state.credits {
+            let _ = game.purchase_item(store_index, Some(processor_index));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::jobs::{GENERAL_TAG, Job};
+    use crate::sim::processors::ProcessorStatus;
+
+    fn job(id: u64, base_reward: u64) -> Job {
+        Job {
+            id,
+            name: format!("Policy Job #{id}"),
+            tag: GENERAL_TAG.to_string(),
+            base_time_ms: 5_000,
+            base_reward,
+            quality_target: 60,
+            ..Job::default()
+        }
+    }
+
+    #[test]
+    fn conservative_takes_the_best_paying_job() {
+        let mut game = Game::fresh();
+        game.state.jobs = vec![job(1, 40), job(2, 90), job(3, 60)];
+
+        Policy::Conservative.act(&mut game);
+
+        let ProcessorStatus::Working(work) = &game.state.processors[0].status else {
+            panic!("idle unit should be put to work");
+        };
+        assert_eq!(work.job.id, 2);
+        assert_eq!(
+            Policy::from_name("conservative"),
+            Some(Policy::Conservative)
+        );
+    }
+}
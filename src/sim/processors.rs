@@ -1,6 +1,9 @@
-use crate::sim::jobs::Job;
+use crate::sim::jobs::{self, Job, ScriptEffect};
+use crate::sim::ring::RingBuffer;
+use crate::sim::tuning::TUNING;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use thiserror::Error;
 
@@ -10,8 +13,107 @@ const DEFAULT_REPLACE_RATIO: f64 = 0.35;
 const DEFAULT_POWER_DRAW: f64 = 4.2;
 const DEFAULT_HEAT_OUTPUT: f64 = 1.0;
 const DEFAULT_PURCHASE_COST: u64 = 180;
-const HEAT_FAILURE_MULTIPLIER: f64 = 0.12;
-const ELECTRIC_COOLING_FACTOR: f64 = 0.05;
+const DEFAULT_PREEMPT_FACTOR: f64 = 1.5;
+/// `daemon_priority` is kept within ±this many steps.
+pub const MAX_DAEMON_PRIORITY: i32 = 5;
+/// Highest hardening cap chassis reinforcement can reach.
+pub const MAX_HARDENING_CAP: u8 = 6;
+const DEFAULT_HARDENING_CAP: u8 = 3;
+/// Highest daemon firmware level the store sells.
+pub const MAX_DAEMON_FIRMWARE_LEVEL: u8 = 4;
+/// Daemon penalty (quality, time multiplier) at each firmware level; the
+/// first entry is the stock penalty of a unit without firmware.
+const FIRMWARE_PENALTY_CURVE: [(i8, f64); MAX_DAEMON_FIRMWARE_LEVEL as usize + 1] =
+    [(-5, 1.10), (-3, 1.08), (-2, 1.05), (-1, 1.03), (0, 1.01)];
+/// Daemon affinity per tag is kept within ±this much.
+pub const MAX_DAEMON_AFFINITY: f64 = 2.0;
+/// Share of the idle draw a paused unit still pulls.
+const PAUSED_POWER_FACTOR: f64 = 0.25;
+/// Readouts kept for a unit's trend line: a minute at one per second.
+pub const READOUT_HISTORY: usize = 60;
+const READOUT_INTERVAL_MS: u64 = 1_000;
+/// Share of the idle draw a unit in standby still pulls.
+const STANDBY_POWER_FACTOR: f64 = 0.05;
+/// Idle time before standby, in the order the player cycles through them.
+pub const STANDBY_DELAYS_MS: [u64; 3] = [2_000, 5_000, 10_000];
+/// Added to a job started on a unit in standby while it wakes.
+pub const STANDBY_SPIN_UP_MS: u64 = 1_500;
+/// Ticks without progress before the watchdog force-completes a job.
+pub const STALL_TICK_LIMIT: u32 = 50;
+
+/// Stat multipliers a unit suffers once its wear crosses a threshold.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WearModifier {
+    pub speed: f64,
+    pub reliability: f64,
+}
+
+impl WearModifier {
+    pub const NONE: WearModifier = WearModifier {
+        speed: 1.0,
+        reliability: 1.0,
+    };
+
+    /// Wear thresholds, most severe first.
+    pub const TIERS: [(f64, WearModifier); 3] = [
+        (
+            0.9,
+            WearModifier {
+                speed: 0.85,
+                reliability: 0.94,
+            },
+        ),
+        (
+            0.75,
+            WearModifier {
+                speed: 0.92,
+                reliability: 0.97,
+            },
+        ),
+        (
+            0.5,
+            WearModifier {
+                speed: 0.97,
+                reliability: 0.99,
+            },
+        ),
+    ];
+
+    pub fn for_wear(wear: f64) -> Self {
+        Self::TIERS
+            .iter()
+            .find(|(threshold, _)| wear >= *threshold)
+            .map(|(_, modifier)| *modifier)
+            .unwrap_or(Self::NONE)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Condition {
+    Good,
+    Worn,
+    Failing,
+}
+
+impl Condition {
+    pub fn for_wear(wear: f64) -> Self {
+        if wear >= 0.9 {
+            Condition::Failing
+        } else if wear >= 0.5 {
+            Condition::Worn
+        } else {
+            Condition::Good
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Condition::Good => "Good",
+            Condition::Worn => "Worn",
+            Condition::Failing => "Failing",
+        }
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DaemonPenalty {
@@ -21,23 +123,105 @@ pub struct DaemonPenalty {
 
 impl Default for DaemonPenalty {
     fn default() -> Self {
+        Self::for_firmware(0)
+    }
+}
+
+impl DaemonPenalty {
+    /// The penalty firmware at `level` brings a unit down to.
+    pub fn for_firmware(level: u8) -> Self {
+        let (quality, time_multiplier) =
+            FIRMWARE_PENALTY_CURVE[level.min(MAX_DAEMON_FIRMWARE_LEVEL) as usize];
         Self {
-            quality: -5,
-            time_multiplier: 1.10,
+            quality,
+            time_multiplier,
+        }
+    }
+}
+
+/// Clock profile a unit runs at, cycled from the Processors panel.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum ClockMode {
+    #[default]
+    Stock,
+    /// Undervolted: slower, cooler, and cheaper to run.
+    Eco,
+    /// Faster, hotter, hungrier, and harder on the hardware.
+    Overclock,
+}
+
+impl ClockMode {
+    pub fn next(self) -> Self {
+        match self {
+            ClockMode::Stock => ClockMode::Eco,
+            ClockMode::Eco => ClockMode::Overclock,
+            ClockMode::Overclock => ClockMode::Stock,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ClockMode::Stock => "Stock",
+            ClockMode::Eco => "Eco",
+            ClockMode::Overclock => "Overclock",
+        }
+    }
+
+    pub fn speed(self) -> f64 {
+        match self {
+            ClockMode::Stock => 1.0,
+            ClockMode::Eco => TUNING.eco_speed,
+            ClockMode::Overclock => TUNING.overclock_speed,
+        }
+    }
+
+    pub fn heat(self) -> f64 {
+        match self {
+            ClockMode::Stock => 1.0,
+            ClockMode::Eco => TUNING.eco_heat,
+            ClockMode::Overclock => TUNING.overclock_heat,
+        }
+    }
+
+    pub fn power(self) -> f64 {
+        match self {
+            ClockMode::Stock => 1.0,
+            ClockMode::Eco => TUNING.eco_power,
+            ClockMode::Overclock => TUNING.overclock_power,
+        }
+    }
+
+    pub fn wear(self) -> f64 {
+        match self {
+            ClockMode::Stock | ClockMode::Eco => 1.0,
+            ClockMode::Overclock => TUNING.overclock_wear,
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 pub enum DaemonMode {
+    #[default]
     Off,
     Assist,
     Auto,
 }
 
-impl Default for DaemonMode {
-    fn default() -> Self {
-        DaemonMode::Off
+impl DaemonMode {
+    pub fn next(self) -> Self {
+        match self {
+            DaemonMode::Off => DaemonMode::Assist,
+            DaemonMode::Assist => DaemonMode::Auto,
+            DaemonMode::Auto => DaemonMode::Off,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DaemonMode::Off => "Off",
+            DaemonMode::Assist => "Assist",
+            DaemonMode::Auto => "Auto",
+        }
     }
 }
 
@@ -49,24 +233,78 @@ pub struct ProcessorWork {
     pub daemon_penalty: Option<DaemonPenalty>,
     #[serde(default)]
     pub overheating: bool,
+    /// Set while a tripped breaker holds this unit off the panel.
+    #[serde(default)]
+    pub paused: bool,
+    /// Consecutive ticks in which `remaining_ms` failed to move.
+    #[serde(default)]
+    pub stalled_ticks: u32,
+    /// Script steps of the job that have already fired.
+    #[serde(default)]
+    pub script_step: usize,
+    /// Heat added by scripted spikes, before cooling.
+    #[serde(default)]
+    pub heat_spike: f64,
+    /// Share of progress held back while the unit protects itself from
+    /// heat; 0.0 runs at full rate.
+    #[serde(default)]
+    pub throttle: f64,
+    /// Consecutive ticks spent overheating or failing reliability rolls.
+    #[serde(default)]
+    pub strained_ticks: u32,
+    /// The log has already been warned this job throttled.
+    #[serde(default)]
+    pub throttle_warned: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl ProcessorWork {
+    pub fn progress(&self) -> f64 {
+        if self.total_ms == 0 {
+            1.0
+        } else {
+            1.0 - self.remaining_ms as f64 / self.total_ms as f64
+        }
+    }
+
+    pub fn throttled(&self) -> bool {
+        self.throttle > 0.0
+    }
+
+    /// Counts one tick of strain up, or an easy tick down. Strain that runs
+    /// on throttles the job until it has eased off entirely; true once it
+    /// has built up enough to burn the unit out.
+    fn strain(&mut self, strained: bool) -> bool {
+        if strained {
+            self.strained_ticks += 1;
+        } else {
+            self.strained_ticks = self.strained_ticks.saturating_sub(1);
+        }
+        if self.strained_ticks >= TUNING.throttle_after_ticks {
+            self.throttle = TUNING.throttle_slowdown;
+        } else if self.strained_ticks == 0 {
+            self.throttle = 0.0;
+        }
+        self.strained_ticks >= TUNING.throttle_burnout_ticks
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub enum ProcessorStatus {
+    #[default]
     Idle,
     Working(Box<ProcessorWork>),
+    /// Down for scheduled maintenance; wear comes off until it's back.
+    Servicing {
+        remaining_ms: u64,
+    },
     BurntOut,
     Destroyed,
 }
 
-impl Default for ProcessorStatus {
-    fn default() -> Self {
-        ProcessorStatus::Idle
-    }
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProcessorState {
+    #[serde(default)]
+    pub id: u64,
     pub name: String,
     pub speed: f64,
     pub quality_bias: i8,
@@ -74,6 +312,13 @@ pub struct ProcessorState {
     pub upkeep_cost: u64,
     #[serde(default)]
     pub status: ProcessorStatus,
+    /// Jobs the unit can run at once.
+    #[serde(default = "default_cores")]
+    pub cores: u8,
+    /// Jobs on cores beyond the first; the first lives in `status`, and the
+    /// oldest of these moves up when it finishes.
+    #[serde(default)]
+    pub extra_slots: Vec<ProcessorWork>,
     #[serde(default = "default_reliability_base")]
     pub reliability_base: f64,
     #[serde(default)]
@@ -84,6 +329,9 @@ pub struct ProcessorState {
     pub cooling_cap: u8,
     #[serde(default)]
     pub hardening_level: u8,
+    /// Highest hardening level the chassis takes; reinforcement raises it.
+    #[serde(default = "default_hardening_cap")]
+    pub hardening_cap: u8,
     #[serde(default)]
     pub requires_cooling_min: u8,
     #[serde(default)]
@@ -106,16 +354,95 @@ pub struct ProcessorState {
     pub purchase_cost: u64,
     #[serde(default)]
     pub daemon_mode: DaemonMode,
+    /// Daemon firmware installed; 0 means none. Each level eases the penalty.
+    #[serde(default)]
+    pub daemon_firmware_level: u8,
+    /// Version 3's firmware switch, read only by the migration to
+    /// `daemon_firmware_level`.
     #[serde(default)]
     pub daemon_unlocked: bool,
+    /// Firmware is on loan from a daemon trial and reverts at day's end.
+    #[serde(default)]
+    pub daemon_trial: bool,
     #[serde(default)]
     pub daemon_affinity: HashMap<String, f64>,
+    /// Tags Auto and Assist never hand this unit; manual assignment still
+    /// may. Sorted.
+    #[serde(default)]
+    pub daemon_blocked_tags: Vec<String>,
     #[serde(default)]
     pub daemon_priority: i32,
     #[serde(default = "default_honor_cooling")]
     pub honor_cooling_mins: bool,
     #[serde(default)]
+    pub daemon_preempt: bool,
+    #[serde(default = "default_preempt_factor")]
+    pub preempt_factor: f64,
+    #[serde(default)]
     pub daemon_penalty: DaemonPenalty,
+    #[serde(default)]
+    pub allow_utility: bool,
+    /// Benched by the player: takes no work and idles at reduced draw.
+    #[serde(default)]
+    pub paused: bool,
+    #[serde(default)]
+    pub clock: ClockMode,
+    /// Version 2's overclock switch, read only by the migration to `clock`.
+    #[serde(default)]
+    pub overclocked: bool,
+    #[serde(default)]
+    pub jobs_completed: u64,
+    #[serde(default)]
+    pub best_quality: u8,
+    /// Lifetime record for this rack slot; replacement keeps it.
+    #[serde(default)]
+    pub jobs_failed: u64,
+    #[serde(default)]
+    pub credits_earned: u64,
+    #[serde(default)]
+    pub total_work_ms: u64,
+    /// Energy metered since the last daily bill, in day-kWh: a unit drawing
+    /// 4 kWh for the whole day accumulates 4.0.
+    #[serde(default)]
+    pub energy_today: f64,
+    /// Heat soaked into the unit. It climbs while working and bleeds off
+    /// through cooling, so a unit run back to back starts each job warm.
+    #[serde(default)]
+    pub temperature: f64,
+    /// Index into `GameState::racks`; unracked units share nothing.
+    #[serde(default)]
+    pub rack_id: Option<u8>,
+    /// Go into maintenance as soon as the current job is done.
+    #[serde(default)]
+    pub maintenance_scheduled: bool,
+    /// Day-cycles left in which a burnout or destruction is replaced free.
+    /// The clock stops while the unit is offline awaiting that replacement.
+    #[serde(default)]
+    pub warranty_days_remaining: u8,
+    /// Covered by an insurance contract, billed daily with upkeep.
+    #[serde(default)]
+    pub insured: bool,
+    /// Shields the unit from ANGEL backlash; a failed ANGEL run can't
+    /// destroy it.
+    #[serde(default)]
+    pub faraday_shroud: bool,
+    /// Buys a replacement chassis on its own when the unit burns out or is
+    /// destroyed, if the treasury stays above the auto-replace floor.
+    #[serde(default)]
+    pub auto_replace: bool,
+    /// Idle time before the unit drops into standby; None keeps it ready.
+    #[serde(default = "default_standby_after")]
+    pub standby_after_ms: Option<u64>,
+    /// How long the unit has sat idle.
+    #[serde(default)]
+    pub idle_ms: u64,
+    /// Heat times seconds spent working since the last day cycle; the more
+    /// of it, the likelier the cooling wears down overnight.
+    #[serde(default)]
+    pub heat_seconds_today: f64,
+    /// Cooling levels lost to wear; buying them back is a cheaper recharge.
+    #[serde(default)]
+    pub cooling_worn: u8,
     #[serde(skip)]
     pub last_reliability: f64,
     #[serde(skip)]
@@ -124,6 +451,65 @@ pub struct ProcessorState {
     pub last_power_draw: f64,
     #[serde(skip)]
     pub last_effective_cooling: u8,
+    /// Heat from rack neighbors, refreshed every tick; negative when they
+    /// sit cold.
+    #[serde(skip)]
+    pub ambient_heat: f64,
+    /// Run-wide fragility from mutators, set by the game on load.
+    #[serde(skip)]
+    pub extra_fragility: f64,
+    /// Recent reliability and heat; a loaded save starts a fresh trend.
+    #[serde(skip)]
+    pub readouts: ReadoutHistory,
+}
+
+/// One sample of the unit's live readouts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Readout {
+    pub reliability: f64,
+    pub heat: f64,
+}
+
+/// The last `READOUT_HISTORY` readouts, sampled once a second of game time.
+#[derive(Debug, Clone)]
+pub struct ReadoutHistory {
+    samples: RingBuffer<Readout>,
+    since_sample_ms: u64,
+}
+
+impl Default for ReadoutHistory {
+    fn default() -> Self {
+        Self {
+            samples: RingBuffer::new(READOUT_HISTORY),
+            since_sample_ms: 0,
+        }
+    }
+}
+
+impl ReadoutHistory {
+    /// Counts `delta_ms` toward the next sample, recording `readout` once for
+    /// every interval that elapsed.
+    fn record(&mut self, delta_ms: u64, readout: Readout) {
+        self.since_sample_ms += delta_ms;
+        let due = self.since_sample_ms / READOUT_INTERVAL_MS;
+        self.since_sample_ms %= READOUT_INTERVAL_MS;
+        for _ in 0..due.min(READOUT_HISTORY as u64) {
+            self.samples.push(readout);
+        }
+    }
+
+    /// Oldest to newest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Readout> + ExactSizeIterator {
+        self.samples.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
 }
 
 fn default_reliability_base() -> f64 {
@@ -154,20 +540,40 @@ fn default_honor_cooling() -> bool {
     true
 }
 
+fn default_standby_after() -> Option<u64> {
+    Some(STANDBY_DELAYS_MS[1])
+}
+
+fn default_preempt_factor() -> f64 {
+    DEFAULT_PREEMPT_FACTOR
+}
+
+fn default_hardening_cap() -> u8 {
+    DEFAULT_HARDENING_CAP
+}
+
+fn default_cores() -> u8 {
+    1
+}
+
 impl ProcessorState {
     pub fn starter() -> Self {
         let mut processor = Self {
+            id: 0,
             name: "Model F12-Scalar".to_string(),
             speed: 1.0,
             quality_bias: 0,
             instruction_set: vec!["GENERAL".to_string()],
             upkeep_cost: 8,
             status: ProcessorStatus::Idle,
+            cores: 1,
+            extra_slots: Vec::new(),
             reliability_base: DEFAULT_RELIABILITY,
             cooling_required: false,
             cooling_level: 0,
             cooling_cap: DEFAULT_COOLING_CAP,
             hardening_level: 0,
+            hardening_cap: DEFAULT_HARDENING_CAP,
             requires_cooling_min: 0,
             finite_lifespan: false,
             mttf_ticks: 0,
@@ -179,24 +585,134 @@ impl ProcessorState {
             heat_output_base: DEFAULT_HEAT_OUTPUT,
             purchase_cost: DEFAULT_PURCHASE_COST,
             daemon_mode: DaemonMode::Off,
+            daemon_firmware_level: 0,
             daemon_unlocked: false,
+            daemon_trial: false,
             daemon_affinity: HashMap::new(),
+            daemon_blocked_tags: Vec::new(),
             daemon_priority: 0,
             honor_cooling_mins: true,
+            daemon_preempt: false,
+            preempt_factor: DEFAULT_PREEMPT_FACTOR,
             daemon_penalty: DaemonPenalty::default(),
+            allow_utility: false,
+            paused: false,
+            clock: ClockMode::Stock,
+            overclocked: false,
+            jobs_completed: 0,
+            best_quality: 0,
+            jobs_failed: 0,
+            credits_earned: 0,
+            total_work_ms: 0,
+            energy_today: 0.0,
+            temperature: 0.0,
+            rack_id: None,
+            maintenance_scheduled: false,
+            warranty_days_remaining: 0,
+            insured: false,
+            faraday_shroud: false,
+            auto_replace: false,
+            standby_after_ms: default_standby_after(),
+            idle_ms: 0,
+            heat_seconds_today: 0.0,
+            cooling_worn: 0,
             last_reliability: DEFAULT_RELIABILITY,
             last_heat: 0.0,
             last_power_draw: DEFAULT_POWER_DRAW,
             last_effective_cooling: 0,
+            ambient_heat: 0.0,
+            extra_fragility: 0.0,
+            readouts: ReadoutHistory::default(),
         };
         processor.ensure_runtime_defaults();
         processor
     }
 
+    /// Cheap, fast-wearing unit; two of them open the Twin Budget loadout.
+    pub fn budget() -> Self {
+        let mut processor = Self::starter();
+        processor.name = "Model F9-Budget".to_string();
+        processor.speed = 0.85;
+        processor.upkeep_cost = 5;
+        processor.fragility = 0.04;
+        processor.purchase_cost /= 2;
+        processor
+    }
+
+    /// Slow unit that ships pre-hardened.
+    pub fn rugged() -> Self {
+        let mut processor = Self::starter();
+        processor.name = "Model F12-Rugged".to_string();
+        processor.speed = 0.75;
+        processor.hardening_level = 2;
+        processor.hardening_cap = 5;
+        processor
+    }
+
+    /// High-end unit with two cores; each busy core adds its own heat and draw.
+    pub fn dual() -> Self {
+        let mut processor = Self::starter();
+        processor.name = "Model F24-Dual".to_string();
+        processor.cores = 2;
+        processor.upkeep_cost = 14;
+        processor.purchase_cost = DEFAULT_PURCHASE_COST * 5 / 2;
+        processor
+    }
+
+    /// Experimental overclocked part: very fast, but it wears out for good
+    /// within a few days of steady work.
+    pub fn prototype() -> Self {
+        let mut processor = Self::starter();
+        processor.name = "Model X1-Prototype".to_string();
+        processor.speed = 1.6;
+        processor.heat_output_base = 1.2;
+        processor.finite_lifespan = true;
+        processor.mttf_ticks = 6_000;
+        processor.fragility = 0.005;
+        processor.upkeep_cost = 9;
+        processor.purchase_cost = 260;
+        processor.replace_cost_ratio = 0.6;
+        processor
+    }
+
+    /// Experimental vector part: ships with SIMD microcode and a quality
+    /// edge, on a lifespan a little longer than the X1's.
+    pub fn vector() -> Self {
+        let mut processor = Self::starter();
+        processor.name = "Model X7-Vector".to_string();
+        processor.speed = 1.15;
+        processor.quality_bias = 4;
+        processor.instruction_set.push(jobs::SIMD_TAG.to_string());
+        processor.finite_lifespan = true;
+        processor.mttf_ticks = 10_000;
+        processor.fragility = 0.004;
+        processor.upkeep_cost = 10;
+        processor.purchase_cost = 320;
+        processor.replace_cost_ratio = 0.55;
+        processor
+    }
+
+    /// A factory-fresh unit of the named model, if the build knows it.
+    pub fn of_model(name: &str) -> Option<Self> {
+        [
+            Self::starter(),
+            Self::budget(),
+            Self::rugged(),
+            Self::dual(),
+            Self::prototype(),
+            Self::vector(),
+        ]
+        .into_iter()
+        .find(|processor| processor.name == name)
+    }
+
     pub fn ensure_runtime_defaults(&mut self) {
         if self.cooling_cap == 0 {
             self.cooling_cap = DEFAULT_COOLING_CAP;
         }
+        if self.hardening_cap == 0 {
+            self.hardening_cap = DEFAULT_HARDENING_CAP;
+        }
         if self.replace_cost_ratio == 0.0 {
             self.replace_cost_ratio = DEFAULT_REPLACE_RATIO;
         }
@@ -212,6 +728,7 @@ impl ProcessorState {
         if self.purchase_cost == 0 {
             self.purchase_cost = DEFAULT_PURCHASE_COST;
         }
+        self.cores = self.cores.max(1);
         self.last_reliability = self.reliability_base;
         self.last_heat = 0.0;
         self.last_effective_cooling = self.cooling_level;
@@ -219,12 +736,113 @@ impl ProcessorState {
     }
 
     pub fn idle_power_draw(&self) -> f64 {
-        let cooling_factor = 1.0 + ELECTRIC_COOLING_FACTOR * self.cooling_level as f64;
-        (self.power_draw_base * cooling_factor).max(0.0)
+        let cooling_factor = 1.0 + TUNING.cooling_power_factor * self.cooling_level as f64;
+        let pause_factor = if self.paused {
+            PAUSED_POWER_FACTOR
+        } else {
+            1.0
+        };
+        let standby_factor = if self.in_standby() {
+            STANDBY_POWER_FACTOR
+        } else {
+            1.0
+        };
+        (self.power_draw_base * self.clock.power() * cooling_factor * pause_factor * standby_factor)
+            .max(0.0)
     }
 
-    pub fn is_idle(&self) -> bool {
+    /// Idle long enough to have powered down; the next job pays a spin-up.
+    pub fn in_standby(&self) -> bool {
         matches!(self.status, ProcessorStatus::Idle)
+            && !self.paused
+            && !self.maintenance_scheduled
+            && self
+                .standby_after_ms
+                .is_some_and(|after| self.idle_ms >= after)
+    }
+
+    /// Steps the standby delay through `STANDBY_DELAYS_MS`, then off.
+    pub fn cycle_standby(&mut self) {
+        self.standby_after_ms = match self.standby_after_ms {
+            None => Some(STANDBY_DELAYS_MS[0]),
+            Some(current) => STANDBY_DELAYS_MS
+                .iter()
+                .copied()
+                .find(|&delay| delay > current),
+        };
+    }
+
+    /// Whether the unit has a free core: idle, or working with one to spare.
+    pub fn is_idle(&self) -> bool {
+        match &self.status {
+            ProcessorStatus::Idle => true,
+            ProcessorStatus::Working(work) => {
+                !work.paused
+                    && !self.maintenance_scheduled
+                    && self.active_slots() < self.cores as usize
+            }
+            _ => false,
+        }
+    }
+
+    /// Every running job, first core first.
+    pub fn slots(&self) -> impl Iterator<Item = &ProcessorWork> {
+        let primary = match &self.status {
+            ProcessorStatus::Working(work) => Some(work.as_ref()),
+            _ => None,
+        };
+        primary.into_iter().chain(&self.extra_slots)
+    }
+
+    pub fn slots_mut(&mut self) -> impl Iterator<Item = &mut ProcessorWork> {
+        let primary = match &mut self.status {
+            ProcessorStatus::Working(work) => Some(work.as_mut()),
+            _ => None,
+        };
+        primary.into_iter().chain(&mut self.extra_slots)
+    }
+
+    pub fn active_slots(&self) -> usize {
+        self.slots().count()
+    }
+
+    /// Cores busy once `job` runs here: one more than now if it would take a
+    /// free core, otherwise what is already loaded.
+    fn load_for(&self, job: &Job) -> usize {
+        let active = self.active_slots();
+        if self.slots().any(|work| work.job.id == job.id) {
+            active
+        } else {
+            (active + 1).min(self.cores.max(1) as usize)
+        }
+    }
+
+    pub fn is_servicing(&self) -> bool {
+        matches!(self.status, ProcessorStatus::Servicing { .. })
+    }
+
+    /// Where a unit goes when its first core's job ends: on to the next
+    /// running job if another core has one, then maintenance if it's booked,
+    /// otherwise idle.
+    fn status_after_job(&mut self) -> ProcessorStatus {
+        if !self.extra_slots.is_empty() {
+            return ProcessorStatus::Working(Box::new(self.extra_slots.remove(0)));
+        }
+        if std::mem::take(&mut self.maintenance_scheduled) {
+            self.temperature = 0.0;
+            ProcessorStatus::Servicing {
+                remaining_ms: TUNING.maintenance_ms,
+            }
+        } else {
+            ProcessorStatus::Idle
+        }
+    }
+
+    /// Model plus id, e.g. "F12-Scalar #3", so messages tell identical
+    /// units apart.
+    pub fn label(&self) -> String {
+        let model = self.name.strip_prefix("Model ").unwrap_or(&self.name);
+        format!("{model} #{}", self.id)
     }
 
     pub fn is_functional(&self) -> bool {
@@ -234,80 +852,288 @@ impl ProcessorState {
         )
     }
 
+    /// Whether the unit has daemon firmware, loaned or bought.
+    pub fn daemon_unlocked(&self) -> bool {
+        self.daemon_firmware_level >= 1
+    }
+
+    /// Whether a replacement right now would be covered by the warranty.
+    pub fn under_warranty(&self) -> bool {
+        self.warranty_days_remaining > 0 && !self.is_functional()
+    }
+
     pub fn supports(&self, tag: &str) -> bool {
         self.instruction_set.iter().any(|known| known == tag)
     }
 
+    /// Whether automation is barred from giving this unit `tag` jobs.
+    pub fn daemon_blocks(&self, tag: &str) -> bool {
+        self.daemon_blocked_tags
+            .iter()
+            .any(|blocked| blocked == tag)
+    }
+
+    /// Starts `job` on a free core, or on the whole unit if it is idle.
     pub fn assign(&mut self, job: Job, total_ms: u64, daemon_penalty: Option<DaemonPenalty>) {
-        self.status = ProcessorStatus::Working(Box::new(ProcessorWork {
+        let work = ProcessorWork {
             job,
             remaining_ms: total_ms,
             total_ms,
             daemon_penalty,
             overheating: false,
-        }));
+            paused: false,
+            stalled_ticks: 0,
+            script_step: 0,
+            heat_spike: 0.0,
+            throttle: 0.0,
+            strained_ticks: 0,
+            throttle_warned: false,
+        };
+        if matches!(self.status, ProcessorStatus::Working(_))
+            && self.active_slots() < self.cores as usize
+        {
+            self.extra_slots.push(work);
+            return;
+        }
+        self.status = ProcessorStatus::Working(Box::new(work));
+        self.idle_ms = 0;
         self.last_power_draw = self.idle_power_draw();
     }
 
+    /// Advances the unit by `delta_ms`, returning what happened on each core.
     pub fn tick(
         &mut self,
         delta_ms: u64,
         rng: &mut impl Rng,
         cooling_bonus_levels: u8,
-    ) -> Option<ProcessorEvent> {
-        let evaluation_snapshot = match &self.status {
-            ProcessorStatus::Working(work) => {
-                Some(self.evaluate_job(&work.job, cooling_bonus_levels))
-            }
-            _ => None,
+        ambient_temperature: f64,
+    ) -> Vec<ProcessorEvent> {
+        let load = self.active_slots();
+        let evaluations: Vec<JobEvaluation> = self
+            .slots()
+            .map(|work| {
+                self.evaluate_with_heat(
+                    &work.job,
+                    cooling_bonus_levels,
+                    ambient_temperature,
+                    work.heat_spike,
+                    load,
+                )
+            })
+            .collect();
+        let wear_rate = self.clock.wear();
+        let idle_draw = self.idle_power_draw();
+        let secs = delta_ms as f64 / 1000.0;
+        let temperature = self.temperature;
+        let heat_in = match &self.status {
+            ProcessorStatus::Working(work) if !work.paused => evaluations
+                .iter()
+                .map(|evaluation| evaluation.heat.max(0.0))
+                .fold(0.0, f64::max),
+            _ => 0.0,
         };
-        match &mut self.status {
+        let cooling =
+            effective_cooling_level(self.cooling_level, self.cooling_cap, cooling_bonus_levels);
+        self.temperature = next_temperature(temperature, heat_in, cooling, secs);
+        if !matches!(self.status, ProcessorStatus::Idle) {
+            self.idle_ms = 0;
+        }
+        let events = match &mut self.status {
             ProcessorStatus::Idle => {
+                if self.maintenance_scheduled {
+                    self.status = self.status_after_job();
+                }
+                self.idle_ms = if matches!(self.status, ProcessorStatus::Idle) {
+                    self.idle_ms.saturating_add(delta_ms)
+                } else {
+                    0
+                };
                 self.last_power_draw = self.idle_power_draw();
-                None
+                Vec::new()
             }
-            ProcessorStatus::BurntOut | ProcessorStatus::Destroyed => None,
-            ProcessorStatus::Working(work) => {
-                let evaluation = evaluation_snapshot.expect("evaluation missing");
-                self.last_reliability = evaluation.reliability;
-                self.last_heat = evaluation.heat;
-                self.last_effective_cooling = evaluation.effective_cooling;
-                self.last_power_draw = evaluation.power_draw;
-
-                if evaluation.reliability <= 0.0 || rng.gen_range(0.0..1.0) > evaluation.reliability
-                {
-                    let job = work.job.clone();
-                    self.status = ProcessorStatus::BurntOut;
-                    return Some(ProcessorEvent::BurntOut { job });
+            ProcessorStatus::Servicing { remaining_ms } => {
+                self.last_power_draw = idle_draw;
+                self.last_heat = 0.0;
+                let step = delta_ms.min(*remaining_ms);
+                *remaining_ms -= step;
+                let done = *remaining_ms == 0;
+                self.wear = (self.wear
+                    - TUNING.maintenance_wear_relief * step as f64 / TUNING.maintenance_ms as f64)
+                    .max(0.0);
+                if done {
+                    self.status = ProcessorStatus::Idle;
+                    vec![ProcessorEvent::ServicingDone]
+                } else {
+                    Vec::new()
                 }
+            }
+            ProcessorStatus::BurntOut | ProcessorStatus::Destroyed => Vec::new(),
+            ProcessorStatus::Working(work) if work.paused => {
+                self.last_power_draw = 0.0;
+                self.last_heat = 0.0;
+                Vec::new()
+            }
+            ProcessorStatus::Working(_) => {
+                self.work_slots(delta_ms, rng, &evaluations, temperature, wear_rate)
+            }
+        };
+        if self.is_functional() {
+            let readout = Readout {
+                reliability: self.reliability_display(),
+                heat: self.heat_display(),
+            };
+            self.readouts.record(delta_ms, readout);
+        }
+        events
+    }
 
-                if self.finite_lifespan && self.mttf_ticks > 0 {
-                    let base_wear = delta_ms as f64 / self.mttf_ticks as f64;
-                    let heat_wear = evaluation.heat.max(0.0) * 0.0005 * (delta_ms as f64 / 1000.0);
-                    let hazard_wear = evaluation.hazard_penalty * 0.05;
-                    self.wear += base_wear + heat_wear + hazard_wear;
-                    if self.wear >= 1.0 {
-                        let job = work.job.clone();
-                        self.status = ProcessorStatus::Destroyed;
-                        return Some(ProcessorEvent::Destroyed { job });
-                    }
-                }
+    /// One working step: the unit's readouts, failure roll, and wear follow
+    /// its riskiest core, then every core moves its own job along.
+    fn work_slots(
+        &mut self,
+        delta_ms: u64,
+        rng: &mut impl Rng,
+        evaluations: &[JobEvaluation],
+        temperature: f64,
+        wear_rate: f64,
+    ) -> Vec<ProcessorEvent> {
+        let Some(worst) = evaluations.iter().min_by(|a, b| {
+            a.reliability
+                .partial_cmp(&b.reliability)
+                .unwrap_or(Ordering::Equal)
+        }) else {
+            return Vec::new();
+        };
+        let heat = evaluations
+            .iter()
+            .map(|evaluation| evaluation.heat)
+            .fold(f64::MIN, f64::max);
+        let secs = delta_ms as f64 / 1000.0;
+        self.total_work_ms += delta_ms;
+        self.last_reliability = worst.reliability;
+        self.last_heat = heat;
+        self.last_effective_cooling = worst.effective_cooling;
+        self.last_power_draw = evaluations
+            .iter()
+            .map(|evaluation| evaluation.power_draw)
+            .fold(0.0, f64::max);
 
-                if work.remaining_ms > delta_ms {
-                    work.remaining_ms -= delta_ms;
-                    work.overheating = evaluation.heat > 1.0
-                        || self.requires_cooling_min > evaluation.effective_cooling;
-                    None
-                } else {
-                    let completed_job = CompletedJob {
-                        job: work.job.clone(),
-                        daemon_penalty: work.daemon_penalty.clone(),
-                    };
-                    self.status = ProcessorStatus::Idle;
-                    Some(ProcessorEvent::Completed(completed_job))
-                }
+        // A failed roll only burns out a unit already near failure; otherwise
+        // it strains the unit like overheating does, and strain that runs on
+        // throttles it before burning it out.
+        let roll_failed = worst.reliability <= 0.0 || rng.gen_range(0.0..1.0) > worst.reliability;
+        if roll_failed && worst.reliability < TUNING.critical_reliability {
+            return self.go_down(ProcessorStatus::BurntOut);
+        }
+        let mut events = Vec::new();
+        let mut burnt_out = false;
+        for (work, evaluation) in self.slots_mut().zip(evaluations) {
+            // A throttled core runs cooler by the share of work it gives up,
+            // so only heat that throttling can't tame builds toward burnout.
+            let heat = evaluation.heat * (1.0 - work.throttle);
+            burnt_out |= work.strain(roll_failed || heat > TUNING.overheat_threshold);
+            if work.throttled() && !work.throttle_warned {
+                work.throttle_warned = true;
+                events.push(ProcessorEvent::Throttled {
+                    job_name: work.job.name.clone(),
+                    heat: evaluation.heat,
+                });
+            }
+        }
+        if burnt_out {
+            return self.go_down(ProcessorStatus::BurntOut);
+        }
+
+        let heat_wear = (heat.max(0.0) * TUNING.heat_wear_per_sec
+            + temperature * TUNING.temperature_wear_per_sec)
+            * secs
+            * wear_rate;
+        let hazard = evaluations
+            .iter()
+            .map(|evaluation| evaluation.hazard_penalty)
+            .fold(0.0, f64::max);
+        let hazard_wear = hazard * TUNING.hazard_wear * wear_rate;
+        if self.finite_lifespan && self.mttf_ticks > 0 {
+            let base_wear = delta_ms as f64 / self.mttf_ticks as f64 * wear_rate;
+            self.wear += base_wear + heat_wear + hazard_wear;
+            if self.wear >= 1.0 {
+                return self.go_down(ProcessorStatus::Destroyed);
+            }
+        } else {
+            // Without a lifespan wear only degrades stats, so it tops out.
+            let duty_wear = TUNING.duty_wear_per_sec * secs * wear_rate;
+            self.wear = (self.wear + duty_wear + heat_wear + hazard_wear).min(1.0);
+        }
+
+        let requires_cooling_min = self.requires_cooling_min;
+        let mut finished = Vec::new();
+        for (slot, (work, evaluation)) in self.slots_mut().zip(evaluations).enumerate() {
+            let (event, done) = advance_slot(work, evaluation, delta_ms, requires_cooling_min);
+            events.extend(event);
+            if done {
+                finished.push(slot);
+            }
+        }
+        // Extra cores first, so the first core's successor is still queued.
+        for slot in finished.into_iter().rev() {
+            if slot == 0 {
+                self.status = self.status_after_job();
+            } else {
+                self.extra_slots.remove(slot - 1);
             }
         }
+        events
+    }
+
+    /// Takes a working unit offline; the first core's job goes down with it
+    /// and any on other cores are dropped.
+    fn go_down(&mut self, status: ProcessorStatus) -> Vec<ProcessorEvent> {
+        let ProcessorStatus::Working(work) = std::mem::replace(&mut self.status, status) else {
+            return Vec::new();
+        };
+        let job = work.job;
+        let mut events = vec![if matches!(self.status, ProcessorStatus::Destroyed) {
+            ProcessorEvent::Destroyed { job }
+        } else {
+            ProcessorEvent::BurntOut { job }
+        }];
+        events.extend(
+            self.extra_slots
+                .drain(..)
+                .map(|work| ProcessorEvent::Dropped { job: work.job }),
+        );
+        events
+    }
+
+    /// Destroys the unit outright, whatever it is doing, returning the jobs
+    /// it still had running.
+    pub fn destroy(&mut self) -> Vec<Job> {
+        let mut lost = Vec::new();
+        if let ProcessorStatus::Working(work) =
+            std::mem::replace(&mut self.status, ProcessorStatus::Destroyed)
+        {
+            lost.push(work.job);
+        }
+        lost.extend(self.extra_slots.drain(..).map(|work| work.job));
+        self.last_heat = 0.0;
+        lost
+    }
+
+    /// Stops the current job without completing it, returning the job.
+    pub fn abort(&mut self) -> Option<Job> {
+        if !matches!(self.status, ProcessorStatus::Working(_)) {
+            return None;
+        }
+        let ProcessorStatus::Working(work) = std::mem::take(&mut self.status) else {
+            return None;
+        };
+        if !self.extra_slots.is_empty() {
+            self.status = ProcessorStatus::Working(Box::new(self.extra_slots.remove(0)));
+            return Some(work.job);
+        }
+        self.last_heat = 0.0;
+        self.last_power_draw = self.idle_power_draw();
+        Some(work.job)
     }
 
     pub fn remaining_and_total(&self) -> Option<(u64, u64)> {
@@ -317,15 +1143,69 @@ impl ProcessorState {
         }
     }
 
+    pub fn wear_modifier(&self) -> WearModifier {
+        WearModifier::for_wear(self.wear)
+    }
+
+    pub fn condition(&self) -> Condition {
+        Condition::for_wear(self.wear)
+    }
+
+    /// Speed after wear; what job durations are computed from.
+    pub fn effective_speed(&self) -> f64 {
+        self.speed * self.wear_modifier().speed
+    }
+
+    /// Working time left before a finite-lifespan unit wears out, at its
+    /// current clock, heat, and temperature. None for units that can't die
+    /// of wear or are already offline.
+    pub fn estimated_lifespan_ms(&self) -> Option<u64> {
+        if !self.finite_lifespan || self.mttf_ticks == 0 || !self.is_functional() {
+            return None;
+        }
+        let per_ms = (1.0 / self.mttf_ticks as f64
+            + (self.last_heat.max(0.0) * TUNING.heat_wear_per_sec
+                + self.temperature * TUNING.temperature_wear_per_sec)
+                / 1000.0)
+            * self.clock.wear();
+        Some(((1.0 - self.wear).max(0.0) / per_ms).round() as u64)
+    }
+
+    /// Whether the unit is expected to wear out within the warning window.
+    pub fn near_end_of_life(&self) -> bool {
+        self.estimated_lifespan_ms()
+            .is_some_and(|left| left < TUNING.lifespan_warning_ms)
+    }
+
+    /// Clears accumulated wear without touching the unit's status.
+    pub fn service(&mut self) {
+        self.wear = 0.0;
+    }
+
+    /// Takes up to `amount` of wear off, returning how much came off.
+    pub fn repair(&mut self, amount: f64) -> f64 {
+        let removed = self.wear.clamp(0.0, amount.max(0.0));
+        self.wear -= removed;
+        removed
+    }
+
     pub fn replace(&mut self) {
         self.status = ProcessorStatus::Idle;
+        self.extra_slots.clear();
+        self.maintenance_scheduled = false;
         self.wear = 0.0;
+        self.temperature = 0.0;
         self.last_heat = 0.0;
         self.last_reliability = self.reliability_base;
         self.last_effective_cooling = self.cooling_level;
         self.last_power_draw = self.idle_power_draw();
     }
 
+    /// The unit's own fragility plus any the run's mutators add.
+    pub fn total_fragility(&self) -> f64 {
+        self.fragility + self.extra_fragility
+    }
+
     pub fn reliability_display(&self) -> f64 {
         self.last_reliability.max(0.0)
     }
@@ -342,36 +1222,110 @@ impl ProcessorState {
         self.last_power_draw
     }
 
-    pub fn evaluate_job(&self, job: &Job, cooling_bonus_levels: u8) -> JobEvaluation {
+    /// Heat this unit pushes onto each rack neighbor.
+    pub fn neighbor_heat(&self) -> f64 {
+        match &self.status {
+            ProcessorStatus::Working(work) if !work.paused => {
+                if self.last_heat > TUNING.overheat_threshold {
+                    self.last_heat * TUNING.neighbor_heat_share
+                } else {
+                    0.0
+                }
+            }
+            _ => -TUNING.neighbor_idle_cooling,
+        }
+    }
+
+    /// Adds the current draw held for `day_fraction` of a day to the meter.
+    pub fn meter_energy(&mut self, day_fraction: f64) {
+        self.energy_today += self.last_power_draw * day_fraction.max(0.0);
+    }
+
+    /// Adds the heat of `secs` spent working to the day's tally.
+    pub fn meter_heat(&mut self, secs: f64) {
+        if matches!(&self.status, ProcessorStatus::Working(work) if !work.paused) {
+            self.heat_seconds_today += self.last_heat.max(0.0) * secs.max(0.0);
+        }
+    }
+
+    /// Loses one installed cooling level to wear; false when none is left.
+    pub fn wear_cooling(&mut self) -> bool {
+        if self.cooling_level == 0 {
+            return false;
+        }
+        self.cooling_level -= 1;
+        self.cooling_worn += 1;
+        true
+    }
+
+    /// `ambient_temperature` is the weather's share of heat, added ahead of
+    /// cooling; it dips below zero on cool days.
+    pub fn evaluate_job(
+        &self,
+        job: &Job,
+        cooling_bonus_levels: u8,
+        ambient_temperature: f64,
+    ) -> JobEvaluation {
+        self.evaluate_with_heat(
+            job,
+            cooling_bonus_levels,
+            ambient_temperature,
+            0.0,
+            self.load_for(job),
+        )
+    }
+
+    /// Evaluates the job with scripted heat added ahead of cooling, with
+    /// `load` cores busy; each one adds its share of heat and draw.
+    fn evaluate_with_heat(
+        &self,
+        job: &Job,
+        cooling_bonus_levels: u8,
+        ambient_temperature: f64,
+        extra_heat: f64,
+        load: usize,
+    ) -> JobEvaluation {
+        let load = load.max(1) as f64;
         let effective_cooling =
             effective_cooling_level(self.cooling_level, self.cooling_cap, cooling_bonus_levels);
-        let cooling_reduction = cooling_reduction(effective_cooling);
-        let mut heat =
-            self.heat_output_base * (1.0 + load_modifier(&self.power_draw_mod, &job.tag));
+        let cooling_reduction = TUNING.cooling_heat_reduction(effective_cooling);
+        let mut heat = (self.heat_output_base
+            * self.clock.heat()
+            * (1.0 + load_modifier(&self.power_draw_mod, &job.tag))
+            * load
+            + extra_heat.max(0.0)
+            + self.ambient_heat
+            + ambient_temperature)
+            .max(0.0);
         heat *= 1.0 - cooling_reduction;
         if self.cooling_required && effective_cooling == 0 {
-            heat += 1.2;
+            heat += TUNING.uncooled_heat;
         }
         if self.requires_cooling_min > effective_cooling {
-            heat += 0.8 * (self.requires_cooling_min - effective_cooling) as f64;
+            heat += TUNING.cooling_shortfall_heat
+                * (self.requires_cooling_min - effective_cooling) as f64;
         }
-        let hazard = tag_hazard(&job.tag);
-        let hazard_penalty = hazard * hardening_multiplier(self.hardening_level, &job.tag);
-        let mut reliability = self.reliability_base;
-        reliability -= heat.max(0.0) * HEAT_FAILURE_MULTIPLIER;
+        let hazard = TUNING.tag_hazard(&job.tag);
+        let hazard_penalty = hazard * TUNING.hardening_multiplier(self.hardening_level, &job.tag);
+        let mut reliability = self.reliability_base * self.wear_modifier().reliability;
+        reliability -= heat.max(0.0) * TUNING.heat_failure_multiplier;
         reliability -= hazard_penalty;
-        reliability += cooling_reliability_bonus(effective_cooling);
+        reliability += TUNING.cooling_reliability_bonus(effective_cooling);
         if self.cooling_required && effective_cooling == 0 {
-            reliability -= 0.25;
+            reliability -= TUNING.uncooled_reliability;
         }
         if self.requires_cooling_min > effective_cooling {
-            reliability -= 0.15 * (self.requires_cooling_min - effective_cooling) as f64;
+            reliability -= TUNING.cooling_shortfall_reliability
+                * (self.requires_cooling_min - effective_cooling) as f64;
         }
-        reliability -= self.fragility * heat.max(0.0);
+        reliability -= self.total_fragility() * heat.max(0.0);
+        reliability -= self.temperature * TUNING.temperature_failure_multiplier;
         reliability = reliability.clamp(0.0, 0.999);
-        let cooling_factor = 1.0 + ELECTRIC_COOLING_FACTOR * effective_cooling as f64;
-        let mut power_draw =
-            self.power_draw_base * (1.0 + load_modifier(&self.power_draw_mod, &job.tag));
+        let cooling_factor = 1.0 + TUNING.cooling_power_factor * effective_cooling as f64;
+        let mut power_draw = self.power_draw_base
+            * self.clock.power()
+            * (1.0 + load_modifier(&self.power_draw_mod, &job.tag))
+            * load;
         if power_draw < 0.0 {
             power_draw = 0.0;
         }
@@ -395,48 +1349,75 @@ pub struct JobEvaluation {
     pub power_draw: f64,
 }
 
-fn effective_cooling_level(level: u8, cap: u8, bonus: u8) -> u8 {
-    let effective = level as u16 + bonus as u16;
-    let max_allowed = cap as u16 + bonus as u16;
-    effective.min(max_allowed) as u8
+/// Steps a unit's temperature toward the heat it's producing; cooling
+/// raises how fast it sheds what it has soaked up.
+fn next_temperature(temperature: f64, heat: f64, cooling: u8, secs: f64) -> f64 {
+    let dissipation = 1.0 + TUNING.temperature_cooling_step * cooling as f64;
+    let rate = (heat - temperature * dissipation) / TUNING.temperature_time_constant_secs;
+    (temperature + rate * secs).clamp(0.0, TUNING.max_temperature)
 }
 
-fn cooling_reduction(level: u8) -> f64 {
-    match level {
-        0 => 0.0,
-        1 => 0.25,
-        2 => 0.45,
-        3 => 0.60,
-        other => 0.60 + 0.05 * (other.saturating_sub(3)) as f64,
+/// Moves one core's job along; returns the event it raised and whether the
+/// job has left the core.
+fn advance_slot(
+    work: &mut ProcessorWork,
+    evaluation: &JobEvaluation,
+    delta_ms: u64,
+    requires_cooling_min: u8,
+) -> (Option<ProcessorEvent>, bool) {
+    let before = work.remaining_ms;
+    let step = (delta_ms as f64 * (1.0 - work.throttle)).round() as u64;
+    let finished = before <= step;
+    if !finished {
+        work.remaining_ms -= step;
+        work.overheating = evaluation.heat > TUNING.overheat_threshold
+            || requires_cooling_min > evaluation.effective_cooling;
     }
-}
-
-fn cooling_reliability_bonus(level: u8) -> f64 {
-    match level {
-        0 => 0.0,
-        1 => 0.01,
-        2 => 0.02,
-        3 => 0.03,
-        other => 0.03 + 0.005 * (other.saturating_sub(3)) as f64,
+    if !finished && work.remaining_ms == before {
+        work.stalled_ticks += 1;
+    } else {
+        work.stalled_ticks = 0;
     }
-}
-
-fn tag_hazard(tag: &str) -> f64 {
-    match tag {
-        "RADIATION" => 0.02,
-        "ANGEL" => 0.03,
-        "SURVEILLANCE" => 0.01,
-        "SIMD" => 0.015,
-        _ => 0.0,
+    let forced = work.stalled_ticks >= STALL_TICK_LIMIT;
+    if !finished
+        && !forced
+        && let Some(step) = work.job.script.get(work.script_step).copied()
+        && work.progress() >= step.at
+    {
+        work.script_step += 1;
+        if let ScriptEffect::HeatSpike { heat } = step.effect {
+            work.heat_spike += heat;
+        }
+        if let ScriptEffect::QualityCheckpoint { min_reliability } = step.effect
+            && evaluation.reliability < min_reliability
+        {
+            let event = ProcessorEvent::CheckpointFailed {
+                job: work.job.clone(),
+                reliability: evaluation.reliability,
+            };
+            return (Some(event), true);
+        }
+        let event = ProcessorEvent::Scripted {
+            job_name: work.job.name.clone(),
+            effect: step.effect,
+        };
+        return (Some(event), false);
+    }
+    if !finished && !forced {
+        return (None, false);
     }
+    let completed_job = CompletedJob {
+        job: work.job.clone(),
+        daemon_penalty: work.daemon_penalty.clone(),
+        forced,
+    };
+    (Some(ProcessorEvent::Completed(completed_job)), true)
 }
 
-fn hardening_multiplier(level: u8, tag: &str) -> f64 {
-    if tag == "RADIATION" || tag == "ANGEL" || tag == "SURVEILLANCE" {
-        (1.0 - 0.2 * level as f64).max(0.2)
-    } else {
-        (1.0 - 0.05 * level as f64).max(0.5)
-    }
+fn effective_cooling_level(level: u8, cap: u8, bonus: u8) -> u8 {
+    let effective = level as u16 + bonus as u16;
+    let max_allowed = cap as u16 + bonus as u16;
+    effective.min(max_allowed) as u8
 }
 
 fn load_modifier(mods: &HashMap<String, f64>, tag: &str) -> f64 {
@@ -446,24 +1427,553 @@ fn load_modifier(mods: &HashMap<String, f64>, tag: &str) -> f64 {
 #[derive(Debug)]
 pub enum ProcessorEvent {
     Completed(CompletedJob),
-    BurntOut { job: Job },
-    Destroyed { job: Job },
+    BurntOut {
+        job: Job,
+    },
+    Destroyed {
+        job: Job,
+    },
+    /// A script step fired and the job carries on.
+    Scripted {
+        job_name: String,
+        effect: ScriptEffect,
+    },
+    /// A quality checkpoint found the unit too unreliable; the job is lost.
+    CheckpointFailed {
+        job: Job,
+        reliability: f64,
+    },
+    /// Scheduled maintenance finished and the unit is idle again.
+    ServicingDone,
+    /// Strain on a core has run on long enough to throttle its job.
+    Throttled {
+        job_name: String,
+        heat: f64,
+    },
+    /// A job on another core, lost when the unit went down.
+    Dropped {
+        job: Job,
+    },
 }
 
 #[derive(Debug, Clone)]
 pub struct CompletedJob {
     pub job: Job,
     pub daemon_penalty: Option<DaemonPenalty>,
+    /// Finished by the stall watchdog rather than by running down the clock.
+    pub forced: bool,
 }
 
 #[derive(Debug, Error)]
 pub enum AssignmentError {
     #[error("invalid processor index")]
     InvalidProcessor,
+    #[error("job is no longer on the board")]
+    InvalidJob,
     #[error("processor is busy")]
     ProcessorBusy,
     #[error("processor lacks instruction {0}")]
     IncompatibleInstruction(String),
     #[error("processor is not operational")]
     ProcessorInoperative,
+    #[error("processor is paused")]
+    ProcessorPaused,
+    #[error("processor is down for maintenance")]
+    ProcessorServicing,
+    #[error("processor has no job to cancel")]
+    NotWorking,
+    #[error("contract deposit of {deposit} cr exceeds available credits")]
+    InsufficientEscrow { deposit: u64 },
+    #[error("job needs {needed} free archive units; only {free} are free")]
+    InsufficientStorage { needed: u64, free: u64 },
+    #[error("no job is awaiting assignment")]
+    NothingPending,
+    #[error("job would push draw to {draw:.1} kWh, over the {capacity:.1} kWh panel")]
+    PowerBudgetExceeded { draw: f64, capacity: f64 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::{economy, jobs};
+    use rand::rngs::mock::StepRng;
+
+    #[test]
+    fn wear_modifier_steps_at_each_threshold() {
+        assert_eq!(WearModifier::for_wear(0.0), WearModifier::NONE);
+        assert_eq!(WearModifier::for_wear(0.499), WearModifier::NONE);
+        for (threshold, modifier) in WearModifier::TIERS {
+            assert_eq!(WearModifier::for_wear(threshold), modifier);
+        }
+        assert_eq!(WearModifier::for_wear(0.749).speed, 0.97);
+        assert_eq!(WearModifier::for_wear(0.899).speed, 0.92);
+        assert_eq!(WearModifier::for_wear(1.0).speed, 0.85);
+
+        assert_eq!(Condition::for_wear(0.49), Condition::Good);
+        assert_eq!(Condition::for_wear(0.5), Condition::Worn);
+        assert_eq!(Condition::for_wear(0.89), Condition::Worn);
+        assert_eq!(Condition::for_wear(0.9), Condition::Failing);
+    }
+
+    #[test]
+    fn worn_units_evaluate_slower_and_less_reliable_until_serviced() {
+        let job = Job {
+            base_time_ms: 10_000,
+            ..Job::default()
+        };
+        let mut unit = ProcessorState::starter();
+        let fresh = unit.evaluate_job(&job, 0, 0.0).reliability;
+        let fresh_speed = unit.effective_speed();
+
+        unit.wear = 0.8;
+        assert!(unit.evaluate_job(&job, 0, 0.0).reliability < fresh);
+        assert!((unit.effective_speed() - fresh_speed * 0.92).abs() < 1e-9);
+        assert_eq!(unit.condition(), Condition::Worn);
+
+        unit.service();
+        assert_eq!(unit.evaluate_job(&job, 0, 0.0).reliability, fresh);
+        assert_eq!(unit.condition(), Condition::Good);
+    }
+
+    #[test]
+    fn overclocking_shortens_jobs_but_costs_reliability_power_and_wear() {
+        let job = Job {
+            base_time_ms: 13_000,
+            ..Job::default()
+        };
+        let stock = ProcessorState::starter();
+        let mut overclocked = stock.clone();
+        overclocked.clock = ClockMode::Overclock;
+
+        let stock_ms = economy::assignment_duration_ms(&job, &stock, None);
+        let overclocked_ms = economy::assignment_duration_ms(&job, &overclocked, None);
+        assert_eq!(stock_ms, 13_000);
+        assert_eq!(overclocked_ms, 10_000);
+        let (stock_eval, overclocked_eval) = (
+            stock.evaluate_job(&job, 0, 0.0),
+            overclocked.evaluate_job(&job, 0, 0.0),
+        );
+        assert!(overclocked_eval.reliability < stock_eval.reliability);
+        assert!(overclocked_eval.power_draw > stock_eval.power_draw);
+
+        let mut rng = StepRng::new(0, 0);
+        let mut worn = Vec::new();
+        for mut unit in [stock, overclocked] {
+            unit.assign(job.clone(), 13_000, None);
+            unit.tick(1_000, &mut rng, 0, 0.0);
+            worn.push(unit.wear);
+        }
+        assert!(worn[1] > worn[0] * 2.0);
+    }
+
+    #[test]
+    fn hardening_takes_the_sting_out_of_radiation_jobs() {
+        let job = jobs::generate_job_with_tag(1, jobs::RADIATION_TAG, 0, &mut StepRng::new(0, 1));
+        let mut unit = ProcessorState::starter();
+        unit.instruction_set.push(jobs::RADIATION_TAG.to_string());
+        let exposed = unit.evaluate_job(&job, 0, 0.0);
+        unit.hardening_level = 3;
+        let hardened = unit.evaluate_job(&job, 0, 0.0);
+
+        assert!(exposed.reliability < 0.9, "{}", exposed.reliability);
+        assert!(hardened.hazard_penalty <= exposed.hazard_penalty * 0.5);
+        assert!(hardened.reliability - exposed.reliability >= 0.05);
+    }
+
+    #[test]
+    fn hardening_past_level_three_still_helps_but_less() {
+        let reductions: Vec<f64> = (0..=MAX_HARDENING_CAP)
+            .map(|level| TUNING.hardening_multiplier(level, "RADIATION"))
+            .collect::<Vec<_>>()
+            .windows(2)
+            .map(|pair| pair[0] - pair[1])
+            .collect();
+        assert!((reductions[2] - TUNING.hardening_exotic_step).abs() < 1e-9);
+        for pair in reductions[2..].windows(2) {
+            assert!(pair[1] > 0.0 && pair[1] < pair[0], "{reductions:?}");
+        }
+    }
+
+    #[test]
+    fn dual_core_runs_two_jobs_at_double_heat_and_draw() {
+        let short = Job {
+            id: 1,
+            ..Job::default()
+        };
+        let long = Job {
+            id: 2,
+            ..Job::default()
+        };
+        let mut unit = ProcessorState::dual();
+        let single = unit.evaluate_job(&short, 0, 0.0);
+        unit.assign(short, 1_000, None);
+        assert!(unit.is_idle());
+        let doubled = unit.evaluate_job(&long, 0, 0.0);
+        assert!((doubled.power_draw - 2.0 * single.power_draw).abs() < 1e-9);
+        assert!(doubled.heat > single.heat);
+
+        unit.assign(long, 3_000, None);
+        assert_eq!(unit.active_slots(), 2);
+        assert!(!unit.is_idle());
+
+        // Cooled so the doubled heat stays short of throttling.
+        let mut rng = StepRng::new(0, 0);
+        let mut events = Vec::new();
+        for _ in 0..10 {
+            events.extend(unit.tick(100, &mut rng, 3, 0.0));
+        }
+        assert!(matches!(events.as_slice(), [ProcessorEvent::Completed(done)] if done.job.id == 1));
+        assert_eq!(unit.active_slots(), 1);
+        assert_eq!(unit.remaining_and_total(), Some((2_000, 3_000)));
+        assert!(unit.is_idle());
+    }
+
+    #[test]
+    fn readouts_sample_once_a_second_and_are_never_saved() {
+        let mut unit = ProcessorState::starter();
+        unit.assign(
+            Job {
+                base_time_ms: 600_000,
+                ..Job::default()
+            },
+            600_000,
+            None,
+        );
+        let mut rng = StepRng::new(0, 0);
+        for _ in 0..20 {
+            unit.tick(100, &mut rng, 3, 0.0);
+        }
+        assert_eq!(unit.readouts.len(), 2);
+        let sample = *unit.readouts.iter().next_back().expect("sampled");
+        assert_eq!(sample.reliability, unit.reliability_display());
+        assert_eq!(sample.heat, unit.heat_display());
+
+        unit.tick(120_000, &mut rng, 3, 0.0);
+        assert_eq!(unit.readouts.len(), READOUT_HISTORY);
+
+        let saved = ron::to_string(&unit).expect("serializes");
+        let loaded: ProcessorState = ron::from_str(&saved).expect("loads");
+        assert!(loaded.readouts.is_empty());
+    }
+
+    #[test]
+    fn burning_out_drops_the_job_on_the_other_core() {
+        let mut unit = ProcessorState::dual();
+        unit.assign(
+            Job {
+                id: 1,
+                ..Job::default()
+            },
+            5_000,
+            None,
+        );
+        unit.assign(
+            Job {
+                id: 2,
+                ..Job::default()
+            },
+            5_000,
+            None,
+        );
+        unit.reliability_base = 0.0;
+
+        let events = unit.tick(100, &mut StepRng::new(0, 0), 0, 0.0);
+        assert!(matches!(
+            events.as_slice(),
+            [ProcessorEvent::BurntOut { job }, ProcessorEvent::Dropped { job: dropped }]
+                if job.id == 1 && dropped.id == 2
+        ));
+        assert!(unit.extra_slots.is_empty());
+        assert_eq!(unit.active_slots(), 0);
+    }
+
+    /// Ticks a unit through the boss contract in one-second steps, returning
+    /// every event along the way.
+    fn run_boss(unit: &mut ProcessorState, cooling_after_spike: u8) -> Vec<ProcessorEvent> {
+        let mut rng = StepRng::new(0, 0);
+        unit.assign(jobs::boss_contract(1), 75_000, None);
+        let mut events = Vec::new();
+        let mut cooling = 0;
+        for _ in 0..120 {
+            let Some(event) = unit.tick(1_000, &mut rng, cooling, 0.0).pop() else {
+                continue;
+            };
+            if matches!(event, ProcessorEvent::Throttled { .. }) {
+                continue;
+            }
+            if matches!(event, ProcessorEvent::Scripted { .. }) {
+                cooling = cooling_after_spike;
+            }
+            let done = !matches!(event, ProcessorEvent::Scripted { .. });
+            events.push(event);
+            if done {
+                break;
+            }
+        }
+        events
+    }
+
+    #[test]
+    fn unmitigated_heat_spike_fails_the_boss_checkpoint() {
+        let mut unit = ProcessorState::starter();
+        let events = run_boss(&mut unit, 0);
+
+        assert!(matches!(
+            events[0],
+            ProcessorEvent::Scripted {
+                effect: ScriptEffect::HeatSpike { .. },
+                ..
+            }
+        ));
+        let ProcessorEvent::CheckpointFailed { reliability, .. } = &events[1] else {
+            panic!("checkpoint should fail: {:?}", events[1]);
+        };
+        assert!(*reliability < 0.75);
+        assert!(unit.is_idle());
+    }
+
+    #[test]
+    fn thermal_paste_carries_the_boss_through_its_script() {
+        let mut unit = ProcessorState::starter();
+        let events = run_boss(&mut unit, 1);
+
+        assert_eq!(events.len(), 3, "{events:?}");
+        assert!(matches!(
+            events[1],
+            ProcessorEvent::Scripted {
+                effect: ScriptEffect::QualityCheckpoint { .. },
+                ..
+            }
+        ));
+        assert!(matches!(events[2], ProcessorEvent::Completed(_)));
+    }
+
+    #[test]
+    fn back_to_back_hot_jobs_run_hotter_and_riskier_than_spaced_ones() {
+        let job = Job {
+            base_time_ms: 60_000,
+            ..Job::default()
+        };
+        let run = |unit: &mut ProcessorState, ms: u64| {
+            let mut rng = StepRng::new(0, 0);
+            for _ in 0..ms / 100 {
+                unit.tick(100, &mut rng, 0, 0.0);
+            }
+        };
+        let mut rushed = ProcessorState::starter();
+        let mut spaced = ProcessorState::starter();
+        for unit in [&mut rushed, &mut spaced] {
+            unit.assign(job.clone(), 60_000, None);
+            run(unit, 60_000);
+            assert!(unit.is_idle());
+        }
+        run(&mut spaced, 180_000);
+        assert!(spaced.temperature < rushed.temperature / 5.0);
+
+        let rushed_start = rushed.evaluate_job(&job, 0, 0.0).reliability;
+        let spaced_start = spaced.evaluate_job(&job, 0, 0.0).reliability;
+        assert!(rushed_start < spaced_start);
+
+        let (rushed_wear, spaced_wear) = (rushed.wear, spaced.wear);
+        for unit in [&mut rushed, &mut spaced] {
+            unit.assign(job.clone(), 60_000, None);
+            run(unit, 60_000);
+        }
+        assert!(rushed.wear - rushed_wear > spaced.wear - spaced_wear);
+        assert!(rushed.temperature > spaced.temperature);
+    }
+
+    #[test]
+    fn cooling_sheds_temperature_faster() {
+        let mut bare = ProcessorState {
+            temperature: 2.0,
+            ..ProcessorState::starter()
+        };
+        let mut cooled = ProcessorState {
+            temperature: 2.0,
+            cooling_level: 3,
+            ..ProcessorState::starter()
+        };
+        let mut rng = StepRng::new(0, 0);
+        for _ in 0..100 {
+            bare.tick(100, &mut rng, 0, 0.0);
+            cooled.tick(100, &mut rng, 0, 0.0);
+        }
+        assert!(cooled.temperature < bare.temperature);
+        assert!(bare.temperature < 2.0);
+    }
+
+    #[test]
+    fn booked_maintenance_follows_the_job_then_returns_to_idle() {
+        let job = Job {
+            base_time_ms: 2_000,
+            ..Job::default()
+        };
+        let mut unit = ProcessorState {
+            wear: 0.5,
+            maintenance_scheduled: true,
+            ..ProcessorState::starter()
+        };
+        let mut rng = StepRng::new(0, 0);
+        unit.assign(job, 2_000, None);
+        let mut events = Vec::new();
+        for _ in 0..20 {
+            events.extend(unit.tick(100, &mut rng, 0, 0.0));
+        }
+        assert!(matches!(events.last(), Some(ProcessorEvent::Completed(_))));
+        assert!(unit.is_servicing());
+        assert!(!unit.maintenance_scheduled);
+        assert_eq!(unit.temperature, 0.0);
+        let worn = unit.wear;
+
+        let ticks = TUNING.maintenance_ms / 100;
+        for _ in 1..ticks {
+            assert!(unit.tick(100, &mut rng, 0, 0.0).is_empty());
+        }
+        assert!(unit.is_servicing());
+        assert!(matches!(
+            unit.tick(100, &mut rng, 0, 0.0).pop(),
+            Some(ProcessorEvent::ServicingDone)
+        ));
+        assert!(unit.is_idle());
+        assert!((worn - unit.wear - TUNING.maintenance_wear_relief).abs() < 1e-9);
+    }
+
+    /// Ticks a fresh starter through a 10s job under `ambient` heat,
+    /// returning how many 100ms ticks it took and what it raised.
+    fn run_hot(ambient: f64) -> (u32, Vec<ProcessorEvent>) {
+        let mut unit = ProcessorState::starter();
+        let mut rng = StepRng::new(0, 0);
+        unit.assign(Job::default(), 10_000, None);
+        let mut events = Vec::new();
+        let mut ticks = 0;
+        while !unit.is_idle() && unit.is_functional() && ticks < 1_000 {
+            events.extend(unit.tick(100, &mut rng, 0, ambient));
+            ticks += 1;
+        }
+        (ticks, events)
+    }
+
+    #[test]
+    fn overheating_throttles_the_job_before_it_burns_the_unit_out() {
+        let base = ProcessorState::starter()
+            .evaluate_job(&Job::default(), 0, 0.0)
+            .heat;
+
+        let (cool_ticks, cool_events) = run_hot(0.5 - base);
+        assert_eq!(cool_ticks, 100);
+        assert!(matches!(
+            cool_events.as_slice(),
+            [ProcessorEvent::Completed(_)]
+        ));
+
+        // Too hot to run flat out, cool enough once throttled: it throttles
+        // on and off, landing between full and half speed, and the log hears
+        // about it once.
+        let (hot_ticks, hot_events) = run_hot(1.5 - base);
+        assert!(hot_ticks > cool_ticks * 6 / 5, "{hot_ticks}");
+        assert!(hot_ticks < cool_ticks * 2, "{hot_ticks}");
+        assert!(matches!(
+            hot_events.as_slice(),
+            [
+                ProcessorEvent::Throttled { .. },
+                ProcessorEvent::Completed(_)
+            ]
+        ));
+
+        // Still overheating at half speed: the strain builds until it burns.
+        let (burn_ticks, burn_events) = run_hot(2.5 - base);
+        assert_eq!(burn_ticks, TUNING.throttle_burnout_ticks);
+        assert!(matches!(
+            burn_events.as_slice(),
+            [
+                ProcessorEvent::Throttled { .. },
+                ProcessorEvent::BurntOut { .. }
+            ]
+        ));
+    }
+
+    #[test]
+    fn experimental_units_are_destroyed_once_worn_through() {
+        for mut unit in [ProcessorState::prototype(), ProcessorState::vector()] {
+            assert!(unit.finite_lifespan && unit.fragility > 0.0);
+            let mut rng = StepRng::new(0, 0);
+            unit.assign(Job::default(), u64::MAX, None);
+            let mut events = Vec::new();
+            for _ in 0..unit.mttf_ticks / 100 {
+                events.extend(unit.tick(100, &mut rng, 2, 0.0));
+            }
+            assert!(matches!(unit.status, ProcessorStatus::Destroyed));
+            assert!(matches!(
+                events.as_slice(),
+                [ProcessorEvent::Destroyed { .. }]
+            ));
+        }
+    }
+
+    #[test]
+    fn idle_units_sleep_cheaply_and_wake_slowly_unless_told_not_to() {
+        let job = Job {
+            base_time_ms: 2_000,
+            ..Job::default()
+        };
+        let mut unit = ProcessorState::starter();
+        let mut rng = StepRng::new(0, 0);
+        let awake_draw = unit.idle_power_draw();
+        let awake_ms = economy::assignment_duration_ms(&job, &unit, None);
+        let delay = unit.standby_after_ms.expect("standby is on by default");
+        for _ in 0..delay / 100 {
+            unit.tick(100, &mut rng, 0, 0.0);
+        }
+        assert!(unit.in_standby());
+        assert!(unit.last_power_draw() < awake_draw * 0.1);
+        assert_eq!(
+            economy::assignment_duration_ms(&job, &unit, None),
+            awake_ms + STANDBY_SPIN_UP_MS
+        );
+
+        unit.assign(job.clone(), awake_ms, None);
+        assert!(!unit.in_standby());
+        assert_eq!(unit.idle_ms, 0);
+
+        let mut always_ready = ProcessorState::starter();
+        while always_ready.standby_after_ms.is_some() {
+            always_ready.cycle_standby();
+        }
+        for _ in 0..STANDBY_DELAYS_MS[2] / 100 {
+            always_ready.tick(100, &mut rng, 0, 0.0);
+        }
+        assert!(!always_ready.in_standby());
+        assert_eq!(always_ready.last_power_draw(), awake_draw);
+        assert_eq!(
+            economy::assignment_duration_ms(&job, &always_ready, None),
+            awake_ms
+        );
+    }
+
+    #[test]
+    fn lifespan_estimate_shrinks_with_wear_heat_and_overclocking() {
+        let immortal = ProcessorState::starter();
+        assert_eq!(immortal.estimated_lifespan_ms(), None);
+
+        let mut unit = ProcessorState {
+            finite_lifespan: true,
+            mttf_ticks: 100_000,
+            wear: 0.5,
+            ..ProcessorState::starter()
+        };
+        assert_eq!(unit.estimated_lifespan_ms(), Some(50_000));
+        assert!(!unit.near_end_of_life());
+
+        unit.last_heat = 2.0;
+        let hot = unit.estimated_lifespan_ms().expect("finite");
+        assert!(hot < 50_000);
+        unit.clock = ClockMode::Overclock;
+        assert!(unit.estimated_lifespan_ms().expect("finite") < hot);
+
+        unit.wear = 0.95;
+        assert!(unit.near_end_of_life());
+        unit.status = ProcessorStatus::Destroyed;
+        assert_eq!(unit.estimated_lifespan_ms(), None);
+    }
 }
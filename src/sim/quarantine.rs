@@ -0,0 +1,183 @@
+use super::game::GameState;
+use super::jobs::{self, Job};
+use super::processors::ProcessorState;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Save content this build does not recognize, detached on load and carried
+/// through every later save so a newer build can pick it back up.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Quarantine {
+    /// Purchase counts for catalog positions past the end of this build's store.
+    pub store_purchases: BTreeMap<usize, u32>,
+    pub unlocked_tags: Vec<String>,
+    /// Unknown instruction tags stripped from processors, by processor id.
+    pub instruction_tags: BTreeMap<u64, Vec<String>>,
+    /// Original tags of jobs that were retagged as GENERAL, by job id.
+    pub job_tags: BTreeMap<u64, String>,
+    /// Units of unknown models, as saved; each was replaced by a starter unit.
+    pub processors: Vec<ProcessorState>,
+}
+
+fn is_known_tag(tag: &str) -> bool {
+    jobs::KNOWN_TAGS.contains(&tag)
+}
+
+/// Moves unrecognized content from `state` into `state.quarantine`, putting
+/// safe defaults in its place. Returns one line per item detached this pass.
+pub fn run(state: &mut GameState, catalog_len: usize) -> Vec<String> {
+    let mut notices = Vec::new();
+
+    if state.store_purchases.len() > catalog_len {
+        for (index, count) in state.store_purchases.drain(catalog_len..).enumerate() {
+            if count > 0 {
+                let index = catalog_len + index;
+                state.quarantine.store_purchases.insert(index, count);
+                notices.push(format!(
+                    "Store item #{} ({count} purchased) is not in this catalog.",
+                    index + 1
+                ));
+            }
+        }
+    }
+
+    for slot in &mut state.processors {
+        if ProcessorState::of_model(&slot.name).is_some() {
+            continue;
+        }
+        let mut substitute = ProcessorState::starter();
+        substitute.id = slot.id;
+        let original = std::mem::replace(slot, substitute);
+        notices.push(format!(
+            "Unknown model {} (unit #{}) swapped for a {}.",
+            original.name, original.id, slot.name
+        ));
+        state.quarantine.processors.push(original);
+    }
+
+    let (known, unknown): (Vec<String>, Vec<String>) = std::mem::take(&mut state.unlocked_tags)
+        .into_iter()
+        .partition(|tag| is_known_tag(tag));
+    state.unlocked_tags = known;
+    for tag in unknown {
+        notices.push(format!("Unlocked instruction set {tag} is not recognized."));
+        state.quarantine.unlocked_tags.push(tag);
+    }
+
+    for processor in &mut state.processors {
+        let (known, unknown): (Vec<String>, Vec<String>) =
+            std::mem::take(&mut processor.instruction_set)
+                .into_iter()
+                .partition(|tag| is_known_tag(tag));
+        processor.instruction_set = known;
+        if !unknown.is_empty() {
+            notices.push(format!(
+                "{} (unit #{}) lost unknown tags: {}.",
+                processor.name,
+                processor.id,
+                unknown.join(", ")
+            ));
+            state
+                .quarantine
+                .instruction_tags
+                .entry(processor.id)
+                .or_default()
+                .extend(unknown);
+        }
+    }
+
+    let mut retag = |job: &mut Job| {
+        if is_known_tag(&job.tag) {
+            return;
+        }
+        let original = std::mem::replace(&mut job.tag, jobs::GENERAL_TAG.to_string());
+        notices.push(format!(
+            "{} was tagged {original}; running it as {}.",
+            job.name,
+            jobs::GENERAL_TAG
+        ));
+        state.quarantine.job_tags.insert(job.id, original);
+    };
+    for job in state.jobs.iter_mut().chain(&mut state.pending_jobs) {
+        retag(job);
+    }
+    for scheduled in &mut state.scheduled_jobs {
+        retag(&mut scheduled.job);
+    }
+    for processor in &mut state.processors {
+        for work in processor.slots_mut() {
+            retag(&mut work.job);
+        }
+    }
+
+    notices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn modded_state() -> GameState {
+        let mut state = GameState::default();
+        let catalog_len = state.store_purchases.len();
+        state.store_purchases.extend([0, 3]);
+        state.unlocked_tags.push("VECTOR".to_string());
+        state.processors[0]
+            .instruction_set
+            .push("VECTOR".to_string());
+        let mut modded = ProcessorState::starter();
+        modded.id = 7;
+        modded.name = "Model Z9-Quantum".to_string();
+        state.processors.push(modded);
+        state.jobs.push(Job {
+            id: 4,
+            name: "Tensor Sweep".to_string(),
+            tag: "VECTOR".to_string(),
+            ..Job::default()
+        });
+        assert_eq!(state.store_purchases.len(), catalog_len + 2);
+        state
+    }
+
+    #[test]
+    fn unknown_content_is_detached_and_replaced() {
+        let mut state = modded_state();
+        let catalog_len = GameState::default().store_purchases.len();
+
+        let notices = run(&mut state, catalog_len);
+
+        assert_eq!(notices.len(), 5);
+        assert_eq!(state.store_purchases.len(), catalog_len);
+        assert_eq!(
+            state.quarantine.store_purchases,
+            BTreeMap::from([(catalog_len + 1, 3)])
+        );
+        assert_eq!(state.quarantine.unlocked_tags, vec!["VECTOR".to_string()]);
+        assert!(!state.processors[0].supports("VECTOR"));
+        assert_eq!(state.processors[1].name, ProcessorState::starter().name);
+        assert_eq!(state.processors[1].id, 7);
+        assert_eq!(state.quarantine.processors[0].name, "Model Z9-Quantum");
+        assert_eq!(state.jobs[0].tag, jobs::GENERAL_TAG);
+        assert_eq!(state.quarantine.job_tags[&4], "VECTOR");
+    }
+
+    #[test]
+    fn quarantine_survives_save_round_trips() {
+        let mut state = modded_state();
+        let catalog_len = GameState::default().store_purchases.len();
+        run(&mut state, catalog_len);
+        let first = ron::to_string(&state.quarantine).expect("serializes");
+
+        let saved = ron::to_string(&state).expect("serializes");
+        let mut reloaded: GameState = ron::from_str(&saved).expect("deserializes");
+        let notices = run(&mut reloaded, catalog_len);
+
+        assert!(notices.is_empty());
+        assert_eq!(
+            ron::to_string(&reloaded.quarantine).expect("serializes"),
+            first
+        );
+        assert_eq!(ron::to_string(&reloaded).expect("serializes"), saved);
+    }
+}
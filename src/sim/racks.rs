@@ -0,0 +1,71 @@
+use super::processors::ProcessorState;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Units one rack holds.
+pub const RACK_CAPACITY: usize = 4;
+/// Highest shared cooling level a rack can be fitted with.
+pub const MAX_RACK_COOLING: u8 = 2;
+
+/// A rack of processors sharing one cooling plant. Members are the units
+/// whose `rack_id` is this rack's index in `GameState::racks`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RackState {
+    #[serde(default)]
+    pub cooling_level: u8,
+}
+
+#[derive(Debug, Error)]
+pub enum RackError {
+    #[error("invalid processor index")]
+    InvalidProcessor,
+    #[error("no racks installed; buy a Server Rack in the store")]
+    NoRacks,
+}
+
+/// Cooling levels the unit's rack adds on top of its own.
+pub fn cooling_for(racks: &[RackState], processor: &ProcessorState) -> u8 {
+    processor
+        .rack_id
+        .and_then(|rack| racks.get(rack as usize))
+        .map_or(0, |rack| rack.cooling_level)
+}
+
+/// Members of rack `rack` in fleet order.
+pub fn members(processors: &[ProcessorState], rack: u8) -> impl Iterator<Item = usize> + '_ {
+    processors
+        .iter()
+        .enumerate()
+        .filter(move |(_, processor)| processor.rack_id == Some(rack))
+        .map(|(index, _)| index)
+}
+
+/// The rack after `current` with room for another unit, or None once the
+/// unit has been walked past the last rack.
+pub fn next_rack_with_room(
+    racks: &[RackState],
+    processors: &[ProcessorState],
+    current: Option<u8>,
+) -> Option<u8> {
+    let start = current.map_or(0, |rack| rack as usize + 1);
+    (start..racks.len())
+        .map(|rack| rack as u8)
+        .find(|&rack| members(processors, rack).count() < RACK_CAPACITY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_racks_are_skipped_when_mounting() {
+        let racks = vec![RackState::default(); 2];
+        let mut processors = vec![ProcessorState::starter(); RACK_CAPACITY + 1];
+        for processor in processors.iter_mut().take(RACK_CAPACITY) {
+            processor.rack_id = Some(0);
+        }
+        assert_eq!(members(&processors, 0).count(), RACK_CAPACITY);
+        assert_eq!(next_rack_with_room(&racks, &processors, None), Some(1));
+        assert_eq!(next_rack_with_room(&racks, &processors, Some(1)), None);
+    }
+}
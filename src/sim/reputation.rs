@@ -0,0 +1,97 @@
+/// Reputation never drops below this, however many jobs are lost.
+pub const MIN_REPUTATION: i32 = -100;
+/// Reputation never rises above this.
+pub const MAX_REPUTATION: i32 = 100;
+/// Gained for a completion that meets the job's quality target.
+pub const ON_TARGET_GAIN: i32 = 2;
+/// Lost when a claimed job fails: a missed checkpoint, a drop, a burnout or
+/// a destroyed unit.
+pub const FAILURE_LOSS: i32 = 5;
+/// Lost when a posting expires unclaimed on the board.
+pub const EXPIRY_LOSS: i32 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ReputationTier {
+    Unknown,
+    Trusted,
+    Renowned,
+}
+
+impl ReputationTier {
+    const LADDER: [ReputationTier; 2] = [ReputationTier::Renowned, ReputationTier::Trusted];
+
+    pub fn threshold(self) -> i32 {
+        match self {
+            ReputationTier::Unknown => MIN_REPUTATION,
+            ReputationTier::Trusted => 25,
+            ReputationTier::Renowned => 60,
+        }
+    }
+
+    pub fn for_reputation(reputation: i32) -> Self {
+        Self::LADDER
+            .into_iter()
+            .find(|tier| reputation >= tier.threshold())
+            .unwrap_or(ReputationTier::Unknown)
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ReputationTier::Unknown => "Unknown",
+            ReputationTier::Trusted => "Trusted",
+            ReputationTier::Renowned => "Renowned",
+        }
+    }
+
+    /// Scale applied to the reward range of freshly posted jobs.
+    pub fn reward_multiplier(self) -> f64 {
+        match self {
+            ReputationTier::Unknown => 1.0,
+            ReputationTier::Trusted => 1.15,
+            ReputationTier::Renowned => 1.3,
+        }
+    }
+}
+
+/// Applies `delta` to `reputation`, keeping it within bounds.
+pub fn adjust(reputation: i32, delta: i32) -> i32 {
+    reputation
+        .saturating_add(delta)
+        .clamp(MIN_REPUTATION, MAX_REPUTATION)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::jobs;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn tiers_start_at_their_thresholds_and_adjustments_clamp() {
+        assert_eq!(ReputationTier::for_reputation(-40), ReputationTier::Unknown);
+        assert_eq!(ReputationTier::for_reputation(24), ReputationTier::Unknown);
+        assert_eq!(ReputationTier::for_reputation(25), ReputationTier::Trusted);
+        assert_eq!(ReputationTier::for_reputation(59), ReputationTier::Trusted);
+        assert_eq!(ReputationTier::for_reputation(60), ReputationTier::Renowned);
+        assert_eq!(adjust(99, ON_TARGET_GAIN), MAX_REPUTATION);
+        assert_eq!(adjust(-98, -FAILURE_LOSS), MIN_REPUTATION);
+        assert_eq!(adjust(10, -EXPIRY_LOSS), 8);
+    }
+
+    #[test]
+    fn postings_pay_more_from_each_tier_threshold() {
+        let reward = |reputation: i32, tag: &str| {
+            jobs::generate_job_with_tag(1, tag, reputation, &mut StdRng::seed_from_u64(7))
+                .base_reward
+        };
+        for tag in jobs::KNOWN_TAGS {
+            let base = reward(0, tag);
+            assert_eq!(reward(-100, tag), base);
+            assert_eq!(reward(24, tag), base);
+            assert_eq!(reward(25, tag), (base as f64 * 1.15).round() as u64);
+            assert_eq!(reward(59, tag), reward(25, tag));
+            assert_eq!(reward(60, tag), (base as f64 * 1.3).round() as u64);
+        }
+    }
+}
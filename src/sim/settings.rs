@@ -0,0 +1,188 @@
+use serde::{Deserialize, Serialize};
+
+/// How large numbers are written in the UI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum NumberStyle {
+    /// Abbreviates big values ("12.5k").
+    #[default]
+    Compact,
+    /// Always prints every digit, grouped by the separator.
+    Full,
+}
+
+impl NumberStyle {
+    pub fn next(self) -> Self {
+        match self {
+            NumberStyle::Compact => NumberStyle::Full,
+            NumberStyle::Full => NumberStyle::Compact,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            NumberStyle::Compact => "compact",
+            NumberStyle::Full => "full",
+        }
+    }
+}
+
+/// Character placed between groups of thousands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Separator {
+    #[default]
+    Comma,
+    Period,
+    Space,
+    None,
+}
+
+impl Separator {
+    pub fn next(self) -> Self {
+        match self {
+            Separator::Comma => Separator::Period,
+            Separator::Period => Separator::Space,
+            Separator::Space => Separator::None,
+            Separator::None => Separator::Comma,
+        }
+    }
+
+    pub fn as_char(self) -> Option<char> {
+        match self {
+            Separator::Comma => Some(','),
+            Separator::Period => Some('.'),
+            Separator::Space => Some(' '),
+            Separator::None => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Separator::Comma => "comma",
+            Separator::Period => "period",
+            Separator::Space => "space",
+            Separator::None => "none",
+        }
+    }
+}
+
+/// Player display preferences, saved alongside the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplaySettings {
+    pub number_style: NumberStyle,
+    pub separator: Separator,
+    pub routing: MessageRouting,
+}
+
+/// Kinds of notice the game raises, each routed separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MessageCategory {
+    Jobs,
+    Economy,
+    Hardware,
+    Store,
+    Automation,
+}
+
+impl MessageCategory {
+    pub const ALL: [MessageCategory; 5] = [
+        MessageCategory::Jobs,
+        MessageCategory::Economy,
+        MessageCategory::Hardware,
+        MessageCategory::Store,
+        MessageCategory::Automation,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MessageCategory::Jobs => "job lifecycle",
+            MessageCategory::Economy => "economy",
+            MessageCategory::Hardware => "hardware failure",
+            MessageCategory::Store => "store",
+            MessageCategory::Automation => "automation",
+        }
+    }
+}
+
+/// Where a notice surfaces. Every route also writes to the event log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MessageRoute {
+    Log,
+    Ticker,
+    Toast,
+    ToastBell,
+}
+
+impl MessageRoute {
+    pub fn next(self) -> Self {
+        match self {
+            MessageRoute::Log => MessageRoute::Ticker,
+            MessageRoute::Ticker => MessageRoute::Toast,
+            MessageRoute::Toast => MessageRoute::ToastBell,
+            MessageRoute::ToastBell => MessageRoute::Log,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MessageRoute::Log => "log only",
+            MessageRoute::Ticker => "ticker",
+            MessageRoute::Toast => "toast",
+            MessageRoute::ToastBell => "toast + bell",
+        }
+    }
+}
+
+/// Route chosen for each message category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MessageRouting {
+    pub jobs: MessageRoute,
+    pub economy: MessageRoute,
+    pub hardware: MessageRoute,
+    pub store: MessageRoute,
+    pub automation: MessageRoute,
+}
+
+impl Default for MessageRouting {
+    fn default() -> Self {
+        Self {
+            jobs: MessageRoute::Toast,
+            economy: MessageRoute::Toast,
+            hardware: MessageRoute::ToastBell,
+            store: MessageRoute::Log,
+            automation: MessageRoute::Ticker,
+        }
+    }
+}
+
+impl MessageRouting {
+    pub fn route(&self, category: MessageCategory) -> MessageRoute {
+        *self.slot(category)
+    }
+
+    pub fn cycle(&mut self, category: MessageCategory) {
+        let slot = self.slot_mut(category);
+        *slot = slot.next();
+    }
+
+    fn slot(&self, category: MessageCategory) -> &MessageRoute {
+        match category {
+            MessageCategory::Jobs => &self.jobs,
+            MessageCategory::Economy => &self.economy,
+            MessageCategory::Hardware => &self.hardware,
+            MessageCategory::Store => &self.store,
+            MessageCategory::Automation => &self.automation,
+        }
+    }
+
+    fn slot_mut(&mut self, category: MessageCategory) -> &mut MessageRoute {
+        match category {
+            MessageCategory::Jobs => &mut self.jobs,
+            MessageCategory::Economy => &mut self.economy,
+            MessageCategory::Hardware => &mut self.hardware,
+            MessageCategory::Store => &mut self.store,
+            MessageCategory::Automation => &mut self.automation,
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use super::carry_over::ImportRecord;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Statistics {
+    #[serde(default)]
+    pub completions_by_tag: BTreeMap<String, u64>,
+    #[serde(default)]
+    pub daemon_assignments: u64,
+    #[serde(default)]
+    pub daemon_preemptions: u64,
+    #[serde(default)]
+    pub days_elapsed: u64,
+    /// Game time played across all sessions; sleep gaps don't count.
+    #[serde(default)]
+    pub play_time_ms: u64,
+    /// Distinct tags completed since the last day close.
+    #[serde(default)]
+    pub tags_today: BTreeSet<String>,
+    /// Versatility earned yesterday, boosting today's passive income.
+    #[serde(default)]
+    pub versatility_active: bool,
+    /// Processors carried over from earlier runs.
+    #[serde(default)]
+    pub imports: Vec<ImportRecord>,
+    #[serde(default)]
+    pub burnouts: u64,
+    /// Every credit taken in over the run, before any spending.
+    #[serde(default)]
+    pub credits_earned: u64,
+}
+
+impl Statistics {
+    /// The day in progress, counting from 1.
+    pub fn day(&self) -> u64 {
+        self.days_elapsed + 1
+    }
+
+    pub fn completions(&self, tag: &str) -> u64 {
+        self.completions_by_tag.get(tag).copied().unwrap_or(0)
+    }
+
+    pub fn tag_rank(&self, tag: &str) -> Option<TagRank> {
+        TagRank::for_completions(self.completions(tag))
+    }
+
+    /// Records a completion, returning the rank newly reached, if any.
+    pub fn record_completion(&mut self, tag: &str) -> Option<TagRank> {
+        self.tags_today.insert(tag.to_string());
+        let before = self.tag_rank(tag);
+        *self.completions_by_tag.entry(tag.to_string()).or_insert(0) += 1;
+        let after = self.tag_rank(tag);
+        if after > before { after } else { None }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TagRank {
+    Apprentice,
+    Journeyman,
+    Master,
+}
+
+impl TagRank {
+    const LADDER: [TagRank; 3] = [TagRank::Master, TagRank::Journeyman, TagRank::Apprentice];
+
+    pub fn threshold(self) -> u64 {
+        match self {
+            TagRank::Apprentice => 10,
+            TagRank::Journeyman => 50,
+            TagRank::Master => 200,
+        }
+    }
+
+    pub fn for_completions(count: u64) -> Option<Self> {
+        Self::LADDER
+            .into_iter()
+            .find(|rank| count >= rank.threshold())
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TagRank::Apprentice => "Apprentice",
+            TagRank::Journeyman => "Journeyman",
+            TagRank::Master => "Master",
+        }
+    }
+
+    /// Fractional payout bonus for jobs of a tag held at this rank.
+    pub fn payout_bonus(self) -> f64 {
+        match self {
+            TagRank::Apprentice => 0.01,
+            TagRank::Journeyman => 0.02,
+            TagRank::Master => 0.03,
+        }
+    }
+}
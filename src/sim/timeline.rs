@@ -0,0 +1,84 @@
+use super::processors::{ProcessorState, ProcessorStatus};
+use super::ring::RingBuffer;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleState {
+    Idle,
+    Working,
+    Overheating,
+    Offline,
+}
+
+impl SampleState {
+    pub fn of(processor: &ProcessorState) -> Self {
+        match &processor.status {
+            ProcessorStatus::Idle | ProcessorStatus::Servicing { .. } => SampleState::Idle,
+            ProcessorStatus::Working(work) if work.paused => SampleState::Idle,
+            ProcessorStatus::Working(work) if work.overheating => SampleState::Overheating,
+            ProcessorStatus::Working(_) => SampleState::Working,
+            ProcessorStatus::BurntOut | ProcessorStatus::Destroyed => SampleState::Offline,
+        }
+    }
+}
+
+/// Fleet state sampled at a fixed interval, keyed by processor id so rows
+/// survive reordering and retirement.
+#[derive(Debug, Clone)]
+pub struct FleetTimeline {
+    samples: RingBuffer<Vec<(u64, SampleState)>>,
+}
+
+impl FleetTimeline {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            samples: RingBuffer::new(capacity),
+        }
+    }
+
+    pub fn record(&mut self, processors: &[ProcessorState]) {
+        let snapshot = processors
+            .iter()
+            .map(|processor| (processor.id, SampleState::of(processor)))
+            .collect();
+        self.samples.push(snapshot);
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.samples.capacity()
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Fraction of samples in which the processor sat idle.
+    pub fn idle_share(&self, processor_id: u64) -> Option<f64> {
+        let present: Vec<SampleState> = self.row(processor_id).into_iter().flatten().collect();
+        if present.is_empty() {
+            return None;
+        }
+        let idle = present
+            .iter()
+            .filter(|state| **state == SampleState::Idle)
+            .count();
+        Some(idle as f64 / present.len() as f64)
+    }
+
+    /// Oldest-to-newest states for one processor; `None` where it did not
+    /// exist yet.
+    pub fn row(&self, processor_id: u64) -> Vec<Option<SampleState>> {
+        self.samples
+            .iter()
+            .map(|snapshot| {
+                snapshot
+                    .iter()
+                    .find(|(id, _)| *id == processor_id)
+                    .map(|(_, state)| *state)
+            })
+            .collect()
+    }
+}
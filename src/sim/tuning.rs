@@ -0,0 +1,229 @@
+use super::mutators::Mutator;
+
+/// Formula constants for heat, reliability, wear, and quality. The simulation
+/// reads these directly so in-game explanations can quote live values.
+#[derive(Debug, Clone, Copy)]
+pub struct Tuning {
+    /// Fraction of heat removed at cooling levels 0..=3.
+    pub cooling_heat_reduction: [f64; 4],
+    /// Extra heat reduction per cooling level beyond 3.
+    pub cooling_heat_reduction_step: f64,
+    /// Reliability added at cooling levels 0..=3.
+    pub cooling_reliability_bonus: [f64; 4],
+    /// Extra reliability per cooling level beyond 3.
+    pub cooling_reliability_step: f64,
+    /// Extra power draw per effective cooling level.
+    pub cooling_power_factor: f64,
+    /// Reliability lost per unit of heat.
+    pub heat_failure_multiplier: f64,
+    /// Heat above which a unit reports overheating.
+    pub overheat_threshold: f64,
+    /// Built-up strain, in ticks spent overheating or failing reliability
+    /// rolls less easy ones, at which a unit throttles and at which it
+    /// burns out.
+    pub throttle_after_ticks: u32,
+    pub throttle_burnout_ticks: u32,
+    /// Share of job progress a throttled unit gives up.
+    pub throttle_slowdown: f64,
+    /// Below this reliability a single failed roll burns the unit out.
+    pub critical_reliability: f64,
+    /// Heat and reliability penalties for cooling-dependent units running dry.
+    pub uncooled_heat: f64,
+    pub uncooled_reliability: f64,
+    /// Penalties per cooling level short of a unit's minimum.
+    pub cooling_shortfall_heat: f64,
+    pub cooling_shortfall_reliability: f64,
+    /// Reliability lost per tick to each hazardous tag.
+    pub tag_hazards: &'static [(&'static str, f64)],
+    /// Hazard reduction per hardening level on exotic tags, and its floor.
+    pub hardening_exotic_step: f64,
+    pub hardening_exotic_floor: f64,
+    /// Hazard reduction per hardening level on other tags, and its floor.
+    pub hardening_routine_step: f64,
+    pub hardening_routine_floor: f64,
+    /// Hardening levels that earn the full step; each one past them earns
+    /// `hardening_late_falloff` times the one before.
+    pub hardening_full_levels: u8,
+    pub hardening_late_falloff: f64,
+    /// Tags that hardening protects against most.
+    pub exotic_tags: &'static [&'static str],
+    /// Wear per second per unit of heat, and per unit of hazard penalty.
+    pub heat_wear_per_sec: f64,
+    pub hazard_wear: f64,
+    /// Wear per second of work on units without a finite lifespan.
+    pub duty_wear_per_sec: f64,
+    /// Overclocked units run this much faster, hotter, hungrier, and
+    /// wear this much quicker.
+    pub overclock_speed: f64,
+    pub overclock_heat: f64,
+    pub overclock_power: f64,
+    pub overclock_wear: f64,
+    /// Speed, heat, and power of units in Eco mode.
+    pub eco_speed: f64,
+    pub eco_heat: f64,
+    pub eco_power: f64,
+    /// Seconds for a unit's temperature to close most of the gap to the
+    /// heat it's producing, the ceiling it's clamped to, and how much each
+    /// cooling level speeds up shedding it.
+    pub temperature_time_constant_secs: f64,
+    pub max_temperature: f64,
+    pub temperature_cooling_step: f64,
+    /// Reliability lost, and wear per second, per unit of temperature.
+    pub temperature_failure_multiplier: f64,
+    pub temperature_wear_per_sec: f64,
+    /// Most the ambient temperature drifts in a day, and the band it stays in.
+    pub ambient_drift: f64,
+    pub ambient_range: (f64, f64),
+    /// Daily chance of a heat wave when none is running, the heat it adds,
+    /// and how many days it lasts.
+    pub heat_wave_chance: f64,
+    pub heat_wave_heat: f64,
+    pub heat_wave_days: (u32, u32),
+    /// Remaining lifespan under which a unit is flagged as near death.
+    pub lifespan_warning_ms: u64,
+    /// Length of a maintenance window and the wear it takes off in total.
+    pub maintenance_ms: u64,
+    pub maintenance_wear_relief: f64,
+    /// Share of an overheating unit's heat that reaches each rack neighbor.
+    pub neighbor_heat_share: f64,
+    /// Heat an idle or offline neighbor draws off a unit.
+    pub neighbor_idle_cooling: f64,
+    /// Delivered quality varies by up to this many points either way.
+    pub quality_noise: i8,
+    /// Distinct tags to complete in one day for the versatility bonus,
+    /// capped at the number of unlocked tags.
+    pub versatility_min_tags: usize,
+    /// Multiplier on the following day's passive income once earned.
+    pub versatility_multiplier: f64,
+    /// Longest slice of game time processors advance in one step. Longer
+    /// frames run as several steps, so outcomes don't depend on frame size.
+    pub max_substep_ms: u64,
+    /// Run-wide adjustments; neutral here and changed only by mutators.
+    pub extra_fragility: f64,
+    pub reward_multiplier: f64,
+    pub electricity_multiplier: f64,
+    pub passive_income_multiplier: f64,
+    pub data_output_multiplier: f64,
+    pub daemon_unlockable: bool,
+}
+
+pub const TUNING: Tuning = Tuning {
+    cooling_heat_reduction: [0.0, 0.25, 0.45, 0.60],
+    cooling_heat_reduction_step: 0.05,
+    cooling_reliability_bonus: [0.0, 0.01, 0.02, 0.03],
+    cooling_reliability_step: 0.005,
+    cooling_power_factor: 0.05,
+    heat_failure_multiplier: 0.12,
+    overheat_threshold: 1.0,
+    throttle_after_ticks: 3,
+    throttle_burnout_ticks: 40,
+    throttle_slowdown: 0.5,
+    critical_reliability: 0.5,
+    uncooled_heat: 1.2,
+    uncooled_reliability: 0.25,
+    cooling_shortfall_heat: 0.8,
+    cooling_shortfall_reliability: 0.15,
+    tag_hazards: &[
+        ("RADIATION", 0.12),
+        ("ANGEL", 0.03),
+        ("SURVEILLANCE", 0.01),
+        ("SIMD", 0.015),
+    ],
+    hardening_exotic_step: 0.2,
+    hardening_exotic_floor: 0.2,
+    hardening_routine_step: 0.05,
+    hardening_routine_floor: 0.5,
+    hardening_full_levels: 3,
+    hardening_late_falloff: 0.5,
+    exotic_tags: &["RADIATION", "ANGEL", "SURVEILLANCE"],
+    heat_wear_per_sec: 0.0005,
+    hazard_wear: 0.05,
+    duty_wear_per_sec: 0.0003,
+    overclock_speed: 1.3,
+    overclock_heat: 1.4,
+    overclock_power: 1.35,
+    overclock_wear: 2.0,
+    eco_speed: 0.83,
+    eco_heat: 0.8,
+    eco_power: 0.7,
+    temperature_time_constant_secs: 30.0,
+    max_temperature: 5.0,
+    temperature_cooling_step: 0.5,
+    temperature_failure_multiplier: 0.02,
+    temperature_wear_per_sec: 0.0003,
+    ambient_drift: 0.05,
+    ambient_range: (-0.15, 0.2),
+    heat_wave_chance: 0.08,
+    heat_wave_heat: 0.4,
+    heat_wave_days: (2, 4),
+    lifespan_warning_ms: 18_000,
+    maintenance_ms: 6_000,
+    maintenance_wear_relief: 0.3,
+    neighbor_heat_share: 0.2,
+    neighbor_idle_cooling: 0.05,
+    quality_noise: 4,
+    versatility_min_tags: 3,
+    versatility_multiplier: 1.15,
+    max_substep_ms: 100,
+    extra_fragility: 0.0,
+    reward_multiplier: 1.0,
+    electricity_multiplier: 1.0,
+    passive_income_multiplier: 1.0,
+    data_output_multiplier: 1.0,
+    daemon_unlockable: true,
+};
+
+impl Tuning {
+    /// The base tuning with each of a run's mutators applied.
+    pub fn with_mutators(mutators: &[Mutator]) -> Self {
+        let mut tuning = TUNING;
+        for mutator in mutators {
+            mutator.apply(&mut tuning);
+        }
+        tuning
+    }
+
+    pub fn cooling_heat_reduction(&self, level: u8) -> f64 {
+        stepped(
+            &self.cooling_heat_reduction,
+            self.cooling_heat_reduction_step,
+            level,
+        )
+    }
+
+    pub fn cooling_reliability_bonus(&self, level: u8) -> f64 {
+        stepped(
+            &self.cooling_reliability_bonus,
+            self.cooling_reliability_step,
+            level,
+        )
+    }
+
+    pub fn tag_hazard(&self, tag: &str) -> f64 {
+        self.tag_hazards
+            .iter()
+            .find(|(name, _)| *name == tag)
+            .map_or(0.0, |(_, hazard)| *hazard)
+    }
+
+    /// Multiplier applied to a tag's hazard at the given hardening level.
+    pub fn hardening_multiplier(&self, level: u8, tag: &str) -> f64 {
+        let full = level.min(self.hardening_full_levels);
+        let late: f64 = (1..=level - full)
+            .map(|step| self.hardening_late_falloff.powi(step as i32))
+            .sum();
+        let levels = full as f64 + late;
+        if self.exotic_tags.contains(&tag) {
+            (1.0 - self.hardening_exotic_step * levels).max(self.hardening_exotic_floor)
+        } else {
+            (1.0 - self.hardening_routine_step * levels).max(self.hardening_routine_floor)
+        }
+    }
+}
+
+fn stepped(table: &[f64; 4], step: f64, level: u8) -> f64 {
+    match table.get(level as usize) {
+        Some(value) => *value,
+        None => table[3] + step * (level - 3) as f64,
+    }
+}
@@ -0,0 +1,94 @@
+use super::centered_rect;
+use crate::app::AffinityEditor;
+use crate::sim::game::Game;
+use crate::sim::processors::MAX_DAEMON_AFFINITY;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
+
+/// Cells on each side of the zero mark in an affinity bar.
+const BAR_HALF: usize = 10;
+
+pub fn render(frame: &mut Frame, game: &Game, editor: AffinityEditor) {
+    let Some(processor) = game.state.processors.get(editor.processor_index) else {
+        return;
+    };
+    let area = centered_rect(50, 50, frame.size());
+    frame.render_widget(Clear, area);
+    let block = Block::default()
+        .title(format!("Daemon affinity — {}", processor.name))
+        .borders(Borders::ALL);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(3)])
+        .split(inner);
+
+    let items: Vec<ListItem> = game
+        .state
+        .unlocked_tags
+        .iter()
+        .map(|tag| {
+            let value = processor.daemon_affinity.get(tag).copied().unwrap_or(0.0);
+            let blocked = processor.daemon_blocks(tag);
+            let style = if !processor.supports(tag) {
+                Style::default().fg(Color::DarkGray)
+            } else if value > 0.0 {
+                Style::default().fg(Color::LightGreen)
+            } else if value < 0.0 {
+                Style::default().fg(Color::LightRed)
+            } else {
+                Style::default()
+            };
+            let mut spans = vec![
+                Span::styled(
+                    if blocked { "[x] " } else { "[ ] " },
+                    Style::default().fg(Color::LightRed),
+                ),
+                Span::styled(format!("{tag:<14}"), style),
+                Span::styled(format!("{value:+.1} "), style),
+                Span::styled(bar(value), style),
+            ];
+            if !processor.supports(tag) {
+                spans.push(Span::styled(
+                    "  not supported",
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+    let mut state = ListState::default();
+    if !items.is_empty() {
+        state.select(Some(editor.selected.min(items.len() - 1)));
+    }
+    let list = List::new(items)
+        .highlight_style(Style::default().bg(Color::DarkGray))
+        .highlight_symbol("> ");
+    frame.render_stateful_widget(list, layout[0], &mut state);
+
+    frame.render_widget(
+        Paragraph::new(
+            "Auto adds the affinity to a job's score; [x] tags are never automated.  ↑/↓ tag  •  ←/→ -/+0.1  •  Space block  •  Esc close",
+        )
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::TOP)),
+        layout[1],
+    );
+}
+
+/// A centered bar: filled left of `|` when negative, right when positive.
+fn bar(value: f64) -> String {
+    let cells = ((value.abs() / MAX_DAEMON_AFFINITY) * BAR_HALF as f64).round() as usize;
+    let cells = cells.min(BAR_HALF);
+    let (left, right) = if value < 0.0 { (cells, 0) } else { (0, cells) };
+    format!(
+        "{}{}|{}{}",
+        " ".repeat(BAR_HALF - left),
+        "■".repeat(left),
+        "■".repeat(right),
+        " ".repeat(BAR_HALF - right)
+    )
+}
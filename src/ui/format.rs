@@ -0,0 +1,185 @@
+use crate::sim::economy::DaemonTax;
+use crate::sim::game::Game;
+use crate::sim::settings::{DisplaySettings, NumberStyle};
+
+/// Values at or above this are abbreviated in compact style.
+pub const COMPACT_THRESHOLD: u64 = 10_000;
+
+const SUFFIXES: [&str; 6] = ["k", "M", "B", "T", "Qa", "Qi"];
+
+/// Number formatting bound to the player's display settings.
+#[derive(Debug, Clone, Copy)]
+pub struct Formatter {
+    settings: DisplaySettings,
+}
+
+impl Formatter {
+    pub fn new(settings: DisplaySettings) -> Self {
+        Self { settings }
+    }
+
+    pub fn of(game: &Game) -> Self {
+        Self::new(game.state.display)
+    }
+
+    pub fn number(&self, value: u64) -> String {
+        if self.settings.number_style == NumberStyle::Compact && value >= COMPACT_THRESHOLD {
+            compact(value)
+        } else {
+            self.grouped(value)
+        }
+    }
+
+    pub fn credits(&self, value: u64) -> String {
+        format!("{} cr", self.number(value))
+    }
+
+    pub fn daemon_tax(&self, tax: &DaemonTax) -> String {
+        format!(
+            "daemon tax ≈ {}, {}",
+            self.credits(tax.credits),
+            duration_ms(tax.extra_ms)
+        )
+    }
+
+    fn grouped(&self, value: u64) -> String {
+        let digits = value.to_string();
+        let Some(separator) = self.settings.separator.as_char() else {
+            return digits;
+        };
+        let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+        for (index, digit) in digits.chars().enumerate() {
+            if index > 0 && (digits.len() - index).is_multiple_of(3) {
+                out.push(separator);
+            }
+            out.push(digit);
+        }
+        out
+    }
+}
+
+/// One decimal with a magnitude suffix, dropping a trailing ".0".
+fn compact(value: u64) -> String {
+    let mut scaled = value as f64;
+    let mut suffix = "";
+    for next in SUFFIXES {
+        if scaled < 999.95 {
+            break;
+        }
+        scaled /= 1000.0;
+        suffix = next;
+    }
+    let text = format!("{scaled:.1}");
+    let text = text.strip_suffix(".0").unwrap_or(&text);
+    format!("{text}{suffix}")
+}
+
+/// Seconds with one decimal under a minute, then "1m 12s" or "2h 05m".
+pub fn duration_secs(secs: f64) -> String {
+    let secs = secs.max(0.0);
+    if secs < 59.95 {
+        return format!("{secs:.1}s");
+    }
+    let whole = secs.round() as u64;
+    if whole < 3600 {
+        format!("{}m {:02}s", whole / 60, whole % 60)
+    } else {
+        format!("{}h {:02}m", whole / 3600, (whole % 3600) / 60)
+    }
+}
+
+pub fn duration_ms(ms: u64) -> String {
+    duration_secs(ms as f64 / 1000.0)
+}
+
+/// Fraction in `0.0..=1.0` as a whole percentage.
+pub fn percent(fraction: f64) -> String {
+    format!("{:.0}%", fraction * 100.0)
+}
+
+const SPARK_BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// One bar per value, scaled so `max` fills the cell; values at or below
+/// zero show the lowest bar.
+pub fn sparkline(values: impl IntoIterator<Item = f64>, max: f64) -> String {
+    let top = (SPARK_BARS.len() - 1) as f64;
+    values
+        .into_iter()
+        .map(|value| {
+            let level = if max > 0.0 {
+                (value / max).clamp(0.0, 1.0) * top
+            } else {
+                0.0
+            };
+            SPARK_BARS[level.round() as usize]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::settings::Separator;
+
+    fn formatter(number_style: NumberStyle, separator: Separator) -> Formatter {
+        Formatter::new(DisplaySettings {
+            number_style,
+            separator,
+            ..DisplaySettings::default()
+        })
+    }
+
+    #[test]
+    fn compact_numbers_cover_edges() {
+        let fmt = formatter(NumberStyle::Compact, Separator::Comma);
+        assert_eq!(fmt.credits(0), "0 cr");
+        assert_eq!(fmt.number(1_000), "1,000");
+        assert_eq!(fmt.number(9_999), "9,999");
+        assert_eq!(fmt.number(10_000), "10k");
+        assert_eq!(fmt.credits(12_500), "12.5k cr");
+        assert_eq!(fmt.number(999_960), "1M");
+        assert_eq!(fmt.number(u64::MAX), "18.4Qi");
+    }
+
+    #[test]
+    fn full_numbers_group_with_the_chosen_separator() {
+        assert_eq!(
+            formatter(NumberStyle::Full, Separator::Comma).number(0),
+            "0"
+        );
+        assert_eq!(
+            formatter(NumberStyle::Full, Separator::Period).number(1_000),
+            "1.000"
+        );
+        assert_eq!(
+            formatter(NumberStyle::Full, Separator::Space).number(12_500),
+            "12 500"
+        );
+        assert_eq!(
+            formatter(NumberStyle::Full, Separator::None).number(1_000),
+            "1000"
+        );
+        assert_eq!(
+            formatter(NumberStyle::Full, Separator::Comma).number(u64::MAX),
+            "18,446,744,073,709,551,615"
+        );
+    }
+
+    #[test]
+    fn durations_switch_units_past_a_minute() {
+        assert_eq!(duration_secs(0.0), "0.0s");
+        assert_eq!(duration_secs(59.94), "59.9s");
+        assert_eq!(duration_secs(60.0), "1m 00s");
+        assert_eq!(duration_ms(72_000), "1m 12s");
+        assert_eq!(duration_secs(3_725.0), "1h 02m");
+        assert_eq!(percent(0.0), "0%");
+        assert_eq!(percent(0.999), "100%");
+    }
+
+    #[test]
+    fn sparklines_scale_each_value_to_the_max() {
+        assert_eq!(sparkline([0.0, 0.5, 1.0, 2.0, -1.0], 1.0), "▁▅██▁");
+        assert_eq!(sparkline([3.0, 3.0], 0.0), "▁▁");
+        assert_eq!(sparkline(Vec::new(), 1.0), "");
+    }
+}
@@ -0,0 +1,62 @@
+use super::centered_rect;
+use crate::sim::game::Game;
+use crate::ui::format::{self, Formatter};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+
+/// Shown in place of the game once a hardcore run is lost.
+pub fn render(frame: &mut Frame, game: &Game) {
+    let area = centered_rect(60, 50, frame.size());
+    let block = Block::default()
+        .title("Game over")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Red));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(5), Constraint::Length(2)])
+        .split(inner);
+
+    frame.render_widget(
+        Paragraph::new(summary_lines(game)).wrap(Wrap { trim: true }),
+        layout[0],
+    );
+    frame.render_widget(
+        Paragraph::new("Enter / Q quit")
+            .wrap(Wrap { trim: true })
+            .block(Block::default().borders(Borders::TOP)),
+        layout[1],
+    );
+}
+
+fn summary_lines(game: &Game) -> Vec<Line<'static>> {
+    let fmt = Formatter::of(game);
+    let stats = &game.state.statistics;
+    let completed: u64 = stats.completions_by_tag.values().sum();
+    vec![
+        Line::from(Span::styled(
+            "The array has gone dark.",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )),
+        Line::from("No processor can run and there are no credits to replace one."),
+        Line::from(""),
+        Line::from(format!(
+            "Lasted to day {}  •  {} played",
+            stats.day(),
+            format::duration_ms(stats.play_time_ms)
+        )),
+        Line::from(format!(
+            "{} jobs completed  •  {} burnouts  •  {} left",
+            completed,
+            stats.burnouts,
+            fmt.credits(game.state.credits)
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            "This hardcore run's save has been erased.",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ]
+}
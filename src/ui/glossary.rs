@@ -0,0 +1,203 @@
+use crate::sim::processors::DaemonPenalty;
+use crate::sim::tuning::{TUNING, Tuning};
+
+/// One mechanic explained with values read from the live tuning table.
+pub struct GlossaryEntry {
+    pub term: &'static str,
+    pub explain: fn(&Tuning) -> String,
+}
+
+pub const ENTRIES: &[GlossaryEntry] = &[
+    GlossaryEntry {
+        term: "Effective cooling",
+        explain: |t| {
+            let levels = (1..=3u8)
+                .map(|level| {
+                    format!(
+                        "level {level} reduces heat by {:.0}% and adds +{:.3} reliability",
+                        t.cooling_heat_reduction(level) * 100.0,
+                        t.cooling_reliability_bonus(level)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("; ");
+            format!(
+                "Installed cooling plus any thermal paste bonus, capped per unit. {levels}. \
+                 Each level adds {:.0}% power draw.",
+                t.cooling_power_factor * 100.0
+            )
+        },
+    },
+    GlossaryEntry {
+        term: "Heat",
+        explain: |t| {
+            format!(
+                "Produced while working. Each point costs {:.2} reliability; above {:.1} the unit \
+                 is overheating. Units that need cooling gain +{:.1} heat and lose {:.2} \
+                 reliability without it, and +{:.1} heat / -{:.2} reliability per level below \
+                 their minimum.",
+                t.heat_failure_multiplier,
+                t.overheat_threshold,
+                t.uncooled_heat,
+                t.uncooled_reliability,
+                t.cooling_shortfall_heat,
+                t.cooling_shortfall_reliability
+            )
+        },
+    },
+    GlossaryEntry {
+        term: "Reliability",
+        explain: |t| {
+            format!(
+                "Chance each tick that a working unit keeps running. Below {:.2} a failed roll \
+                 burns the unit out and loses the job; above it the unit is only strained.",
+                t.critical_reliability
+            )
+        },
+    },
+    GlossaryEntry {
+        term: "Throttling",
+        explain: |t| {
+            format!(
+                "Overheating or failed rolls for {} ticks running slow a job to {:.0}% speed, \
+                 which also cuts its heat. If even throttled it stays strained, it burns out \
+                 after {} ticks.",
+                t.throttle_after_ticks,
+                (1.0 - t.throttle_slowdown) * 100.0,
+                t.throttle_burnout_ticks
+            )
+        },
+    },
+    GlossaryEntry {
+        term: "Fragility",
+        explain: |_| {
+            "Extra reliability lost per point of heat, on top of the normal heat penalty. \
+             Cheap hardware tends to be fragile."
+                .to_string()
+        },
+    },
+    GlossaryEntry {
+        term: "Hazard",
+        explain: |t| {
+            let tags = t
+                .tag_hazards
+                .iter()
+                .map(|(tag, hazard)| format!("{tag} -{hazard:.3}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("Reliability lost to risky job tags before hardening: {tags}.")
+        },
+    },
+    GlossaryEntry {
+        term: "Hardening",
+        explain: |t| {
+            format!(
+                "Shields against hazards. On {} each level cuts the hazard by {:.0}% (at least \
+                 {:.0}% remains); on other tags by {:.0}% (at least {:.0}% remains).",
+                t.exotic_tags.join("/"),
+                t.hardening_exotic_step * 100.0,
+                t.hardening_exotic_floor * 100.0,
+                t.hardening_routine_step * 100.0,
+                t.hardening_routine_floor * 100.0
+            )
+        },
+    },
+    GlossaryEntry {
+        term: "Wear",
+        explain: |t| {
+            format!(
+                "Work wears units down through heat ({:.4} per heat per second) and hazards \
+                 ({:.2} × hazard penalty per tick); units without a finite lifespan also wear \
+                 {:.4} per second of duty. From 50% (Worn) speed and reliability slip, \
+                 harder past 75% and 90% (Failing). Field Service clears wear; a finite-lifespan \
+                 unit at 100% is destroyed.",
+                t.heat_wear_per_sec, t.hazard_wear, t.duty_wear_per_sec
+            )
+        },
+    },
+    GlossaryEntry {
+        term: "Rack heat",
+        explain: |t| {
+            format!(
+                "Units sit in rack order. An overheating unit passes {:.0}% of its heat to each \
+                 neighbor; an idle or offline neighbor draws {:.2} heat off. Move units with [ and ].",
+                t.neighbor_heat_share * 100.0,
+                t.neighbor_idle_cooling
+            )
+        },
+    },
+    GlossaryEntry {
+        term: "Quality bias",
+        explain: |t| {
+            format!(
+                "Points added to every job's quality target on this unit. Delivered quality also \
+                 varies by ±{} at random.",
+                t.quality_noise
+            )
+        },
+    },
+    GlossaryEntry {
+        term: "Daemon penalty",
+        explain: |_| {
+            let penalty = DaemonPenalty::default();
+            format!(
+                "Jobs started by automation take {:.0}% longer and deliver {:+} quality by default.",
+                (penalty.time_multiplier - 1.0) * 100.0,
+                penalty.quality
+            )
+        },
+    },
+    GlossaryEntry {
+        term: "Versatility",
+        explain: |t| {
+            format!(
+                "Complete {} different tags in one day and the next day's passive dividend \
+                 is multiplied by {:.2}. Runs with fewer tags unlocked can't earn it yet.",
+                t.versatility_min_tags, t.versatility_multiplier
+            )
+        },
+    },
+];
+
+/// Indices of entries whose term or explanation contains `query`,
+/// ignoring case.
+pub fn matching(query: &str) -> Vec<usize> {
+    let needle = query.to_lowercase();
+    ENTRIES
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| {
+            needle.is_empty()
+                || entry.term.to_lowercase().contains(&needle)
+                || (entry.explain)(&TUNING).to_lowercase().contains(&needle)
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explanations_quote_live_tuning_values() {
+        let cooling = ENTRIES
+            .iter()
+            .find(|entry| entry.term == "Effective cooling")
+            .expect("cooling entry");
+        let text = (cooling.explain)(&TUNING);
+        let expected = format!(
+            "level 2 reduces heat by {:.0}%",
+            TUNING.cooling_heat_reduction(2) * 100.0
+        );
+        assert!(text.contains(&expected), "{text}");
+    }
+
+    #[test]
+    fn search_matches_terms_and_bodies_case_insensitively() {
+        assert_eq!(matching("").len(), ENTRIES.len());
+        let hits = matching("HARDEN");
+        assert!(hits.iter().any(|&index| ENTRIES[index].term == "Hardening"));
+        assert!(matching("zzz-no-such-term").is_empty());
+    }
+}
@@ -0,0 +1,71 @@
+use super::centered_rect;
+use super::glossary::{ENTRIES, matching};
+use crate::app::GlossaryState;
+use crate::sim::tuning::TUNING;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
+
+pub fn render(frame: &mut Frame, glossary: &GlossaryState) {
+    let area = centered_rect(70, 70, frame.size());
+    frame.render_widget(Clear, area);
+    let block = Block::default().title("Glossary").borders(Borders::ALL);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(22), Constraint::Min(10)])
+        .split(layout[1]);
+
+    let cursor = if glossary.searching { "_" } else { "" };
+    frame.render_widget(
+        Paragraph::new(format!("Search: {}{cursor}", glossary.query)),
+        layout[0],
+    );
+
+    let matches = matching(&glossary.query);
+    let items: Vec<ListItem> = matches
+        .iter()
+        .map(|&index| ListItem::new(ENTRIES[index].term))
+        .collect();
+    let mut state = ListState::default();
+    if !matches.is_empty() {
+        state.select(Some(glossary.selected.min(matches.len() - 1)));
+    }
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::RIGHT))
+        .highlight_style(Style::default().bg(Color::DarkGray).fg(Color::White))
+        .highlight_symbol("▶ ");
+    frame.render_stateful_widget(list, columns[0], &mut state);
+
+    let detail = match state.selected().map(|position| &ENTRIES[matches[position]]) {
+        Some(entry) => vec![
+            Line::from(Span::styled(
+                entry.term,
+                Style::default()
+                    .fg(Color::LightCyan)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from((entry.explain)(&TUNING)),
+        ],
+        None => vec![Line::from("No matching entries.")],
+    };
+    frame.render_widget(
+        Paragraph::new(detail).wrap(Wrap { trim: true }),
+        columns[1].inner(&Margin::new(1, 0)),
+    );
+
+    frame.render_widget(
+        Paragraph::new("j/k move  •  / search  •  Esc close"),
+        layout[2],
+    );
+}
@@ -0,0 +1,35 @@
+/// Characters used for compact charts, with an ASCII fallback for terminals
+/// or fonts without block-element coverage.
+#[derive(Debug, Clone, Copy)]
+pub struct Glyphs {
+    pub idle: &'static str,
+    pub working: &'static str,
+    pub hot: &'static str,
+    pub offline: &'static str,
+    pub empty: &'static str,
+}
+
+pub const UNICODE: Glyphs = Glyphs {
+    idle: "░",
+    working: "▓",
+    hot: "█",
+    offline: "╳",
+    empty: " ",
+};
+
+pub const ASCII: Glyphs = Glyphs {
+    idle: ".",
+    working: "=",
+    hot: "#",
+    offline: "x",
+    empty: " ",
+};
+
+/// Set `BABEL_ASCII=1` to force the ASCII set.
+pub fn current() -> Glyphs {
+    if std::env::var_os("BABEL_ASCII").is_some_and(|value| value != "0") {
+        ASCII
+    } else {
+        UNICODE
+    }
+}
@@ -0,0 +1,81 @@
+use super::centered_rect;
+use super::glyphs::{self, Glyphs};
+use crate::sim::game::Game;
+use crate::sim::timeline::SampleState;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+const NAME_WIDTH: usize = 18;
+
+pub fn render(frame: &mut Frame, game: &Game) {
+    let area = centered_rect(70, 60, frame.size());
+    frame.render_widget(Clear, area);
+    let block = Block::default()
+        .title("Fleet Heat Map — last day")
+        .borders(Borders::ALL);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(2)])
+        .split(inner);
+
+    let glyphs = glyphs::current();
+    let timeline = game.timeline();
+    let lines: Vec<Line> = game
+        .state
+        .processors
+        .iter()
+        .map(|processor| {
+            let mut spans = vec![Span::raw(format!(
+                "{:<width$} ",
+                truncate(&processor.name, NAME_WIDTH),
+                width = NAME_WIDTH
+            ))];
+            let row = timeline.row(processor.id);
+            let padding = timeline.capacity().saturating_sub(timeline.len());
+            spans.push(Span::raw(glyphs.empty.repeat(padding)));
+            spans.extend(row.into_iter().map(|sample| cell(sample, &glyphs)));
+            Line::from(spans)
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), layout[0]);
+
+    let legend = Line::from(vec![
+        cell(Some(SampleState::Idle), &glyphs),
+        Span::raw(" idle  "),
+        cell(Some(SampleState::Working), &glyphs),
+        Span::raw(" working  "),
+        cell(Some(SampleState::Overheating), &glyphs),
+        Span::raw(" overheating  "),
+        cell(Some(SampleState::Offline), &glyphs),
+        Span::raw(" burnt  •  Esc/H to close"),
+    ]);
+    frame.render_widget(
+        Paragraph::new(legend).block(Block::default().borders(Borders::TOP)),
+        layout[1],
+    );
+}
+
+fn cell(sample: Option<SampleState>, glyphs: &Glyphs) -> Span<'static> {
+    let (symbol, color) = match sample {
+        None => (glyphs.empty, Color::Reset),
+        Some(SampleState::Idle) => (glyphs.idle, Color::DarkGray),
+        Some(SampleState::Working) => (glyphs.working, Color::LightGreen),
+        Some(SampleState::Overheating) => (glyphs.hot, Color::LightRed),
+        Some(SampleState::Offline) => (glyphs.offline, Color::Red),
+    };
+    Span::styled(symbol, Style::default().fg(color))
+}
+
+fn truncate(name: &str, width: usize) -> String {
+    if name.chars().count() <= width {
+        name.to_string()
+    } else {
+        let mut short: String = name.chars().take(width.saturating_sub(1)).collect();
+        short.push('…');
+        short
+    }
+}
@@ -0,0 +1,99 @@
+use super::centered_rect;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+const BINDINGS: &[(&str, &str)] = &[
+    ("Tab", "switch focus between jobs and processors"),
+    ("↑/↓ or j/k", "move selection"),
+    (
+        "Enter",
+        "queue a job (up to 3) / assign the oldest queued one to a processor",
+    ),
+    (
+        "1-3",
+        "take that Assist suggestion for the focused processor",
+    ),
+    ("Ctrl+A", "toggle auto-replace for the focused processor"),
+    ("P / Shift+P", "pick a job for the selected unit / pause it"),
+    ("Esc", "return the most recently queued job"),
+    ("S", "open the store"),
+    ("D / Shift+D", "cycle automation / toggle cooling safety"),
+    ("Ctrl+D", "cycle automation for the whole fleet"),
+    ("C / Shift+C", "cancel job / toggle daemon preemption"),
+    ("U", "let the daemon run utility jobs"),
+    (
+        "+ / -",
+        "raise / lower daemon priority; Auto serves high first",
+    ),
+    ("W", "edit the unit's daemon affinity and blocked tags"),
+    (
+        "T / Shift+T",
+        "free one-day daemon trial on the selected unit / filter the board by tag",
+    ),
+    (
+        "V",
+        "cycle clock: stock / eco (slow, frugal) / overclock (fast, hot)",
+    ),
+    (
+        "Shift+V",
+        "cycle idle time before standby (near-zero draw, slow wake) / off",
+    ),
+    ("I", "jump to the daemon firmware in the store"),
+    (
+        "R / Shift+R",
+        "replace unit / replace every burnt unit of a model",
+    ),
+    ("Ctrl+R", "patch repair: take up to 40% wear off the unit"),
+    ("Y", "book / cancel maintenance after the current job"),
+    ("X", "retire unit (press twice)"),
+    (
+        "Shift+X",
+        "salvage a burnt-out or destroyed unit for parts (press twice)",
+    ),
+    ("[ / ]", "move unit up / down the rack"),
+    ("M", "museum"),
+    (
+        "Shift+M",
+        "mount unit in the next rack with room / unrack it",
+    ),
+    ("H", "fleet heat map"),
+    (
+        "L",
+        "event log: / search, n/N next/previous match, Enter jump",
+    ),
+    ("F", "cycle credit reserve"),
+    ("B", "toggle the facility power budget"),
+    (
+        "N / Shift+N",
+        "compact or full numbers / thousands separator",
+    ),
+    ("G", "glossary of mechanics"),
+    ("O", "message routing settings"),
+    (
+        "E / Shift+E",
+        "share snapshot (summary.txt + clipboard) / power bill per unit",
+    ),
+    ("Ctrl+S", "save now"),
+    ("`", "frame profiler (debug or profiling builds)"),
+    ("Q", "save & quit"),
+];
+
+pub fn render(frame: &mut Frame) {
+    let area = centered_rect(60, 70, frame.size());
+    frame.render_widget(Clear, area);
+    let mut lines: Vec<Line> = BINDINGS
+        .iter()
+        .map(|(keys, action)| {
+            Line::from(vec![
+                Span::styled(format!("{keys:>12}"), Style::default().fg(Color::Yellow)),
+                Span::raw(format!("  {action}")),
+            ])
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from("Press G for the glossary, Esc or ? to close."));
+    let help = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(Block::default().title("Help").borders(Borders::ALL));
+    frame.render_widget(help, area);
+}
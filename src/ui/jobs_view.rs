@@ -1,5 +1,7 @@
 use crate::app::{App, FocusTarget};
-use crate::sim::game::Game;
+use crate::sim::game::{DIAGNOSTIC_DISCOUNT, Game};
+use crate::sim::jobs::EXPIRY_WARNING_SHARE;
+use crate::ui::format::{self, Formatter};
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, List, ListItem, ListState};
 
@@ -11,27 +13,80 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, game: &Game) {
         Style::default()
     };
 
-    let mut items: Vec<ListItem> = game
-        .state
-        .jobs
+    let fmt = Formatter::of(game);
+    let visible = app.visible_jobs(&game.state.jobs);
+    let mut items: Vec<ListItem> = visible
         .iter()
-        .map(|job| {
-            let time_secs = job.base_time_ms as f64 / 1000.0;
+        .map(|&index| {
+            let job = &game.state.jobs[index];
             let hazard_note = hazard_label(&job.tag);
-            let line = Line::from(vec![
+            let mut line = Line::from(vec![
                 Span::styled(job.name.clone(), Style::default().fg(Color::Yellow)),
                 Span::raw(" "),
-                Span::raw(format!("| {} cr", job.base_reward)),
+                Span::raw(format!("| {}", fmt.credits(job.base_reward))),
                 Span::raw(" "),
-                Span::raw(format!("| {:.1}s", time_secs)),
+                Span::raw(format!("| {}", format::duration_ms(job.base_time_ms))),
                 Span::raw(" "),
                 Span::raw(format!("| Q{}", job.quality_target)),
             ]);
+            if let (Some(left), Some(share)) = (job.expires_in_ms, job.expiry_share_left()) {
+                let style = if share < EXPIRY_WARNING_SHARE {
+                    Style::default()
+                        .fg(Color::LightRed)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
+                line.spans.push(Span::styled(
+                    format!(" | expires in {}", format::duration_ms(left)),
+                    style,
+                ));
+            }
             let detail = Line::from(vec![Span::raw(format!(
                 "Tag: {} • {} • Data: {} units",
                 job.tag, hazard_note, job.data_output
             ))]);
-            ListItem::new(vec![line, detail])
+            let mut lines = vec![line, detail];
+            if let Some(model) = &job.diagnostic_for {
+                lines.push(Line::from(vec![Span::styled(
+                    format!(
+                        "↳ Utility: {} off next {model} replacement",
+                        format::percent(DIAGNOSTIC_DISCOUNT)
+                    ),
+                    Style::default().fg(Color::LightBlue),
+                )]));
+            }
+            if let Some(contract) = job.contract_id.and_then(|id| {
+                game.state
+                    .contracts
+                    .iter()
+                    .find(|contract| contract.id == id)
+            }) {
+                let bonus = if contract.is_final_stage() {
+                    format!(" • completes for +{}", fmt.credits(contract.bonus))
+                } else {
+                    String::new()
+                };
+                lines.push(Line::from(vec![Span::styled(
+                    format!(
+                        "↳ Contract stage {}/{}{bonus} • losing it fails the contract",
+                        job.stage, contract.stages
+                    ),
+                    Style::default().fg(Color::LightMagenta),
+                )]));
+            }
+            if let Some(original) = job.follow_up_of {
+                let preferred = job
+                    .preferred_processor_id
+                    .and_then(|id| game.processor_index_by_id(id))
+                    .map(|index| game.state.processors[index].name.as_str())
+                    .unwrap_or("any unit");
+                lines.push(Line::from(vec![Span::styled(
+                    format!("↳ Follow-up of #{original} • bonus on {preferred}"),
+                    Style::default().fg(Color::LightMagenta),
+                )]));
+            }
+            ListItem::new(lines)
         })
         .collect();
 
@@ -41,10 +96,14 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, game: &Game) {
         )])));
     }
 
+    let title = match &app.job_filter {
+        Some(tag) => format!("Job Board [{tag}]"),
+        None => "Job Board".to_string(),
+    };
     let list = List::new(items)
         .block(
             Block::default()
-                .title("Job Board")
+                .title(title)
                 .borders(Borders::ALL)
                 .border_style(border_style),
         )
@@ -52,8 +111,8 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, game: &Game) {
         .highlight_symbol("▶ ");
 
     let mut state = ListState::default();
-    if !game.state.jobs.is_empty() {
-        let selection = app.selected_job.min(game.state.jobs.len() - 1);
+    if !visible.is_empty() {
+        let selection = app.selected_job.min(visible.len() - 1);
         state.select(Some(selection));
     }
     frame.render_stateful_widget(list, area, &mut state);
@@ -62,8 +121,8 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, game: &Game) {
 fn hazard_label(tag: &str) -> &'static str {
     match tag {
         crate::sim::jobs::SIMD_TAG => "High load",
-        "RADIATION" => "Radiation hazard",
-        "ANGEL" => "ANGEL exposure",
+        crate::sim::jobs::RADIATION_TAG => "Radiation hazard",
+        crate::sim::jobs::ANGEL_TAG => "ANGEL exposure: a miss can destroy the unit",
         "SURVEILLANCE" => "Surveillance risk",
         _ => "Routine",
     }
@@ -0,0 +1,88 @@
+use super::centered_rect;
+use crate::app::LoadChoice;
+use crate::persist::SaveMeta;
+use crate::sim::settings::DisplaySettings;
+use crate::ui::format::{self, Formatter};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Pre-game screen: the save's summary and whether to continue it.
+/// `status` reports the last care package sent or received.
+pub fn render(frame: &mut Frame, meta: &SaveMeta, selected: usize, status: Option<&str>) {
+    let area = centered_rect(60, 60, frame.size());
+    let block = Block::default().title("Saved game").borders(Borders::ALL);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(5),
+            Constraint::Min(4),
+            Constraint::Length(1),
+            Constraint::Length(2),
+        ])
+        .split(inner);
+
+    frame.render_widget(
+        Paragraph::new(summary_lines(meta)).wrap(Wrap { trim: true }),
+        layout[0],
+    );
+
+    let items: Vec<ListItem> = LoadChoice::MENU
+        .iter()
+        .map(|choice| ListItem::new(choice.label()))
+        .collect();
+    let mut state = ListState::default();
+    state.select(Some(selected.min(LoadChoice::MENU.len() - 1)));
+    let list = List::new(items)
+        .highlight_style(Style::default().bg(Color::DarkGray))
+        .highlight_symbol("> ");
+    frame.render_stateful_widget(list, layout[1], &mut state);
+
+    if let Some(status) = status {
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                status.to_string(),
+                Style::default().fg(Color::LightYellow),
+            )),
+            layout[2],
+        );
+    }
+
+    frame.render_widget(
+        Paragraph::new("↑/↓ choose  •  Enter confirm  •  Q quit")
+            .wrap(Wrap { trim: true })
+            .block(Block::default().borders(Borders::TOP)),
+        layout[3],
+    );
+}
+
+fn summary_lines(meta: &SaveMeta) -> Vec<Line<'static>> {
+    let fmt = Formatter::new(DisplaySettings::default());
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let age = format::duration_secs(now.saturating_sub(meta.saved_at) as f64);
+    vec![
+        Line::from(Span::styled(
+            format!("Day {}  •  {}", meta.day, fmt.credits(meta.credits)),
+            Style::default()
+                .fg(Color::LightCyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(format!(
+            "{} processor(s)  •  {} played",
+            meta.processors,
+            format::duration_ms(meta.play_time_ms)
+        )),
+        Line::from(Span::styled(
+            format!(
+                "Saved {age} ago by v{} (save format {})",
+                meta.game_version, meta.save_version
+            ),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ]
+}
@@ -1,17 +1,60 @@
 use crate::app::App;
-use crate::sim::game::Game;
+use crate::profiler::{Phase, Profiler};
+use crate::sim::game::{Game, MAX_PENDING_JOBS, ToastLevel};
 use crate::sim::processors::DaemonMode;
+use crate::sim::reputation::ReputationTier;
+use format::Formatter;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
+pub mod affinity_view;
+pub mod format;
+pub mod game_over_view;
+pub mod glossary;
+pub mod glossary_view;
+pub mod glyphs;
+pub mod heatmap_view;
+pub mod help_view;
 pub mod jobs_view;
+pub mod load_view;
+pub mod museum_view;
+pub mod new_game_view;
 pub mod processors_view;
+pub mod profile_view;
+pub mod profiler_view;
+pub mod quarantine_view;
+pub mod quick_pick_view;
+pub mod settings_view;
 pub mod storage_view;
 pub mod store_view;
+pub mod summary;
+pub mod toast_view;
+pub mod trade_in_view;
 
-pub fn render(frame: &mut Frame, app: &App, game: &Game) {
+pub const MIN_WIDTH: u16 = 60;
+pub const MIN_HEIGHT: u16 = 18;
+
+pub fn render(frame: &mut Frame, app: &App, game: &Game, profiler: &Profiler) {
     let size = frame.size();
+    if size.width < MIN_WIDTH || size.height < MIN_HEIGHT {
+        render_too_small(frame, size);
+        return;
+    }
+    if let Some(selected) = app.new_game {
+        new_game_view::render(
+            frame,
+            selected,
+            app.carry_over.as_ref(),
+            &app.mutators,
+            app.hardcore,
+        );
+        return;
+    }
+    if app.game_over {
+        game_over_view::render(frame, game);
+        return;
+    }
     let layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -21,7 +64,7 @@ pub fn render(frame: &mut Frame, app: &App, game: &Game) {
         ])
         .split(size);
 
-    render_header(frame, layout[0], app, game);
+    profiler.measure(Phase::Header, || render_header(frame, layout[0], app, game));
 
     let columns = Layout::default()
         .direction(Direction::Horizontal)
@@ -32,23 +75,78 @@ pub fn render(frame: &mut Frame, app: &App, game: &Game) {
         ])
         .split(layout[1]);
 
-    processors_view::render(frame, columns[0], app, game);
-    jobs_view::render(frame, columns[1], app, game);
-    storage_view::render(frame, columns[2], app, game);
+    profiler.measure(Phase::Processors, || {
+        processors_view::render(frame, columns[0], app, game)
+    });
+    profiler.measure(Phase::Jobs, || {
+        jobs_view::render(frame, columns[1], app, game)
+    });
+    profiler.measure(Phase::Systems, || {
+        storage_view::render(frame, columns[2], app, game)
+    });
+    profiler.measure(Phase::Footer, || render_footer(frame, layout[2], app));
+    profiler.measure(Phase::Overlays, || render_overlays(frame, app, game));
 
-    render_footer(frame, layout[2]);
+    if app.profiler_open {
+        profiler_view::render(frame, profiler);
+    }
+}
 
+fn render_overlays(frame: &mut Frame, app: &App, game: &Game) {
     if app.store_open {
         store_view::render(frame, app, game);
     }
+    if app.museum_open {
+        museum_view::render(frame, game);
+    }
+    if app.heatmap_open {
+        heatmap_view::render(frame, game);
+    }
+    if app.quick_pick.is_some() {
+        quick_pick_view::render(frame, app, game);
+    }
+    if let Some(editor) = app.affinity_editor {
+        affinity_view::render(frame, game, editor);
+    }
+    if let Some(picker) = app.trade_in {
+        trade_in_view::render(frame, game, picker);
+    }
+    if app.help_open {
+        help_view::render(frame);
+    }
+    if let Some(selected) = app.settings {
+        settings_view::render(frame, game, selected);
+    }
+    if let Some(glossary) = &app.glossary {
+        glossary_view::render(frame, glossary);
+    }
+    if app.quarantine_open {
+        quarantine_view::render(frame, game);
+    }
+    toast_view::render(frame, game);
+}
+
+fn render_too_small(frame: &mut Frame, area: Rect) {
+    let message = Paragraph::new(vec![
+        Line::from(Span::styled(
+            "Terminal too small",
+            Style::default()
+                .fg(Color::LightRed)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(format!(
+            "need {MIN_WIDTH}×{MIN_HEIGHT}, have {}×{}",
+            area.width, area.height
+        )),
+    ])
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true });
+    frame.render_widget(message, area);
 }
 
 fn render_header(frame: &mut Frame, area: Rect, app: &App, game: &Game) {
-    let pending = app
-        .pending_job
-        .as_ref()
-        .map(|job| job.name.as_str())
-        .unwrap_or("None");
+    let fmt = Formatter::of(game);
+    let pending = pending_queue(game);
     let automation_summary = if !game.state.daemon_unlocked {
         "Locked".to_string()
     } else {
@@ -66,6 +164,7 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App, game: &Game) {
             .count();
         format!("{auto} auto / {assist} assist")
     };
+    let reputation_tier = ReputationTier::for_reputation(game.state.reputation);
 
     let lines = vec![
         Line::from(vec![
@@ -75,19 +174,104 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App, game: &Game) {
                     .fg(Color::LightBlue)
                     .add_modifier(Modifier::BOLD),
             ),
+            Span::styled(
+                match game.state.profile_name.as_str() {
+                    "" => String::new(),
+                    name => format!(" [{name}]"),
+                },
+                Style::default().fg(Color::LightCyan),
+            ),
             Span::raw("  •  Credits: "),
             Span::styled(
-                format!("{}", game.state.credits),
+                fmt.number(game.state.credits),
                 Style::default().fg(Color::Yellow),
             ),
+            Span::styled(
+                match game.reserved_credits() {
+                    0 => String::new(),
+                    reserved => format!(" (reserved {})", fmt.credits(reserved)),
+                },
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::raw("  •  "),
+            Span::styled(
+                format!("Day {}", game.state.statistics.day()),
+                Style::default().fg(Color::White),
+            ),
+            Span::styled(
+                format!(
+                    " ({} played)",
+                    format::duration_ms(game.state.statistics.play_time_ms)
+                ),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::raw("  •  Rep: "),
+            Span::styled(
+                format!("{} ({})", reputation_tier.label(), game.state.reputation),
+                Style::default().fg(match reputation_tier {
+                    ReputationTier::Unknown => Color::Gray,
+                    ReputationTier::Trusted => Color::LightGreen,
+                    ReputationTier::Renowned => Color::LightYellow,
+                }),
+            ),
             Span::raw("  •  Pending: "),
-            Span::styled(pending.to_string(), Style::default().fg(Color::Cyan)),
+            Span::styled(pending, Style::default().fg(Color::Cyan)),
             Span::raw("  •  Automation: "),
             Span::styled(automation_summary, Style::default().fg(Color::Magenta)),
+            Span::styled(
+                game.state
+                    .mutators
+                    .iter()
+                    .map(|mutator| format!(" [{}]", mutator.badge()))
+                    .collect::<String>(),
+                Style::default().fg(Color::LightRed),
+            ),
+            if game.state.hardcore {
+                Span::styled(
+                    " HARDCORE ",
+                    Style::default()
+                        .fg(Color::White)
+                        .bg(Color::Magenta)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::raw("")
+            },
+            if game.tampered() {
+                Span::styled(
+                    " MODIFIED ",
+                    Style::default().fg(Color::Black).bg(Color::Red),
+                )
+            } else {
+                Span::raw("")
+            },
+            if app.demo {
+                Span::styled(
+                    "  DEMO — press any key to take over ",
+                    Style::default()
+                        .fg(Color::Black)
+                        .bg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::raw("")
+            },
         ]),
-        Line::from(vec![Span::raw(
-            "Use Tab to shift focus, Enter to interact with the highlighted panel.",
-        )]),
+        match game.ticker() {
+            Some(ticker) => Line::from(vec![
+                Span::styled("» ", Style::default().fg(Color::LightBlue)),
+                Span::styled(
+                    ticker.message.clone(),
+                    match ticker.level {
+                        ToastLevel::Info => Style::default().fg(Color::White),
+                        ToastLevel::Critical => Style::default().fg(Color::LightRed),
+                    },
+                ),
+            ]),
+            None => Line::from(vec![Span::raw(
+                "Use Tab to shift focus, Enter to interact with the highlighted panel.",
+            )]),
+        },
     ];
 
     let paragraph = Paragraph::new(lines)
@@ -96,7 +280,20 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App, game: &Game) {
     frame.render_widget(paragraph, area);
 }
 
-fn render_footer(frame: &mut Frame, area: Rect) {
+fn render_footer(frame: &mut Frame, area: Rect, app: &App) {
+    if let Some(flash) = &app.flash {
+        let rejection = Paragraph::new(Line::from(Span::styled(
+            flash.reason.clone(),
+            Style::default()
+                .fg(Color::White)
+                .bg(Color::Red)
+                .add_modifier(Modifier::BOLD),
+        )))
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::TOP));
+        frame.render_widget(rejection, area);
+        return;
+    }
     let instructions = Paragraph::new(Line::from(vec![
         Span::raw("Hotkeys: "),
         Span::styled("[J/K]", Style::default().fg(Color::Yellow)),
@@ -105,6 +302,8 @@ fn render_footer(frame: &mut Frame, area: Rect) {
         Span::raw(" switch focus  •  "),
         Span::styled("[Enter]", Style::default().fg(Color::Yellow)),
         Span::raw(" take/assign  •  "),
+        Span::styled("[P]", Style::default().fg(Color::Yellow)),
+        Span::raw(" pick job for unit  •  "),
         Span::styled("[Esc]", Style::default().fg(Color::Yellow)),
         Span::raw(" cancel pending  •  "),
         Span::styled("[S]", Style::default().fg(Color::Yellow)),
@@ -113,10 +312,30 @@ fn render_footer(frame: &mut Frame, area: Rect) {
         Span::raw(" cycle automation  •  "),
         Span::styled("[Shift+D]", Style::default().fg(Color::Yellow)),
         Span::raw(" cooling safety  •  "),
+        Span::styled("[C]", Style::default().fg(Color::Yellow)),
+        Span::raw(" cancel job  •  "),
+        Span::styled("[Shift+C]", Style::default().fg(Color::Yellow)),
+        Span::raw(" daemon preempt  •  "),
+        Span::styled("[U]", Style::default().fg(Color::Yellow)),
+        Span::raw(" utility work  •  "),
         Span::styled("[R]", Style::default().fg(Color::Yellow)),
         Span::raw(" replace unit  •  "),
         Span::styled("[Shift+R]", Style::default().fg(Color::Yellow)),
         Span::raw(" replace model  •  "),
+        Span::styled("[X]", Style::default().fg(Color::Yellow)),
+        Span::raw(" retire unit  •  "),
+        Span::styled("[M]", Style::default().fg(Color::Yellow)),
+        Span::raw(" museum  •  "),
+        Span::styled("[H]", Style::default().fg(Color::Yellow)),
+        Span::raw(" heat map  •  "),
+        Span::styled("[L]", Style::default().fg(Color::Yellow)),
+        Span::raw(" event log  •  "),
+        Span::styled("[F]", Style::default().fg(Color::Yellow)),
+        Span::raw(" credit reserve  •  "),
+        Span::styled("[B]", Style::default().fg(Color::Yellow)),
+        Span::raw(" power budget  •  "),
+        Span::styled("[?]", Style::default().fg(Color::Yellow)),
+        Span::raw(" help  •  "),
         Span::styled("[Q]", Style::default().fg(Color::Yellow)),
         Span::raw(" save & quit"),
     ]))
@@ -124,3 +343,161 @@ fn render_footer(frame: &mut Frame, area: Rect) {
     .block(Block::default().borders(Borders::TOP));
     frame.render_widget(instructions, area);
 }
+
+/// The pending queue front first, e.g. "Index Rebuild → SIMD Workload (2/3)".
+pub(crate) fn pending_queue(game: &Game) -> String {
+    let pending = &game.state.pending_jobs;
+    if pending.is_empty() {
+        return "None".to_string();
+    }
+    let names: Vec<&str> = pending.iter().map(|job| job.name.as_str()).collect();
+    format!(
+        "{} ({}/{MAX_PENDING_JOBS})",
+        names.join(" → "),
+        pending.len()
+    )
+}
+
+pub(crate) fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(area);
+
+    let vertical = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1]);
+
+    vertical[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    fn draw(width: u16, height: u16, app: &App, game: &Game) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).expect("terminal");
+        terminal
+            .draw(|frame| render(frame, app, game, &Profiler::default()))
+            .expect("draw succeeds");
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width.max(1) as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn tiny_terminal_shows_size_guard() {
+        let app = App::new();
+        let game = Game::fresh();
+        let screen = draw(45, 12, &app, &game);
+        assert!(screen.contains("Terminal too small"));
+        assert!(screen.contains("need 60×18, have 45×12"));
+    }
+
+    #[test]
+    fn degenerate_sizes_render_without_panicking() {
+        let mut app = App::new();
+        app.store_open = true;
+        app.museum_open = true;
+        app.heatmap_open = true;
+        app.help_open = true;
+        app.glossary = Some(crate::app::GlossaryState::default());
+        app.profiler_open = true;
+        let game = Game::fresh();
+        for (width, height) in [(1, 1), (10, 3), (59, 30), (120, 17), (60, 18)] {
+            draw(width, height, &app, &game);
+        }
+    }
+
+    #[test]
+    fn new_game_screen_lists_every_loadout() {
+        let mut app = App::new();
+        app.new_game = Some(0);
+        let game = Game::fresh();
+        let screen = draw(100, 30, &app, &game);
+        for loadout in crate::sim::loadout::Loadout::ALL {
+            assert!(screen.contains(loadout.label()), "{}", loadout.label());
+        }
+    }
+
+    #[test]
+    fn hardcore_runs_are_badged_and_end_on_the_game_over_screen() {
+        let mut app = App::new();
+        let game = Game::from_state(crate::sim::game::GameState {
+            hardcore: true,
+            ..Default::default()
+        });
+        assert!(draw(160, 30, &app, &game).contains("HARDCORE"));
+        app.game_over = true;
+        let screen = draw(100, 30, &app, &game);
+        assert!(screen.contains("Game over"));
+        assert!(screen.contains("save has been erased"));
+    }
+
+    #[test]
+    fn load_screen_summarises_the_save() {
+        let mut state = crate::sim::game::GameState::default();
+        state.statistics.days_elapsed = 41;
+        let meta = crate::persist::SaveMeta::of(&state);
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).expect("terminal");
+        terminal
+            .draw(|frame| load_view::render(frame, &meta, 1, Some("Sent 200 cr.")))
+            .expect("draw succeeds");
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(screen.contains("Day 42"));
+        assert!(screen.contains("> New Game"));
+        assert!(screen.contains("Receive Care Package"));
+        assert!(screen.contains("Sent 200 cr."));
+    }
+
+    #[test]
+    fn store_compares_a_catalog_model_with_the_selected_unit() {
+        let mut app = App::new();
+        let game = Game::fresh();
+        app.store_open = true;
+        app.selected_store_item = game
+            .store_items()
+            .iter()
+            .position(|item| {
+                item.action
+                    == crate::sim::game::StoreAction::BuyProcessor {
+                        model: "Model X1-Prototype",
+                    }
+            })
+            .expect("sold");
+        let screen = draw(160, 50, &app, &game);
+        assert!(screen.contains("Compare"));
+        assert!(screen.contains("X1-Prototype"));
+        assert!(screen.contains("Cooling cap"));
+    }
+
+    #[test]
+    fn minimum_size_renders_main_ui() {
+        let app = App::new();
+        let game = Game::fresh();
+        let screen = draw(MIN_WIDTH, MIN_HEIGHT, &app, &game);
+        assert!(!screen.contains("Terminal too small"));
+        assert!(screen.contains("Processors"));
+    }
+}
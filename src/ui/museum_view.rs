@@ -0,0 +1,73 @@
+use super::centered_rect;
+use crate::sim::game::Game;
+use crate::sim::museum::{MUSEUM_BONUS_MAX_ENTRIES, MUSEUM_MIN_COMPLETIONS};
+use crate::ui::format::{self, Formatter};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap};
+
+pub fn render(frame: &mut Frame, game: &Game) {
+    let area = centered_rect(60, 60, frame.size());
+    frame.render_widget(Clear, area);
+    let block = Block::default()
+        .title("Hall of Retired Silicon")
+        .borders(Borders::ALL);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(3)])
+        .split(inner);
+
+    let fmt = Formatter::of(game);
+
+    let mut items: Vec<ListItem> = game
+        .state
+        .museum
+        .iter()
+        .map(|entry| {
+            let header = Line::from(vec![Span::styled(
+                entry.name.clone(),
+                Style::default()
+                    .fg(Color::LightCyan)
+                    .add_modifier(Modifier::BOLD),
+            )]);
+            let highlights = Line::from(vec![Span::raw(format!(
+                "{} jobs • best quality {} • speed {:.2} • bias {:+} • cooling {} • hardening {} • wear {}",
+                fmt.number(entry.jobs_completed),
+                entry.best_quality,
+                entry.speed,
+                entry.quality_bias,
+                entry.cooling_level,
+                entry.hardening_level,
+                format::percent(entry.wear.min(1.0))
+            ))]);
+            let mut lines = vec![header, highlights];
+            if !entry.mutators.is_empty() {
+                let labels: Vec<&str> = entry.mutators.iter().map(|m| m.label()).collect();
+                lines.push(Line::from(Span::styled(
+                    format!("Survived: {}", labels.join(", ")),
+                    Style::default().fg(Color::Magenta),
+                )));
+            }
+            ListItem::new(lines)
+        })
+        .collect();
+    if items.is_empty() {
+        items.push(ListItem::new(format!(
+            "No exhibits yet. Retire a unit with {MUSEUM_MIN_COMPLETIONS}+ completed jobs to honor it."
+        )));
+    }
+    frame.render_widget(List::new(items), layout[0]);
+
+    let footer = Paragraph::new(vec![Line::from(vec![
+        Span::raw(format!(
+            "Fleet quality bonus: +{} quality (max {MUSEUM_BONUS_MAX_ENTRIES} exhibits)",
+            game.museum_quality_bonus()
+        )),
+        Span::raw("  •  Esc/M to close"),
+    ])])
+    .wrap(Wrap { trim: true });
+    frame.render_widget(footer, layout[1]);
+}
@@ -0,0 +1,165 @@
+use super::centered_rect;
+use crate::app::CarryOver;
+use crate::sim::carry_over::IMPORT_CREDIT_RATIO;
+use crate::sim::loadout::Loadout;
+use crate::sim::mutators::Mutator;
+use crate::ui::format;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+
+pub fn render(
+    frame: &mut Frame,
+    selected: usize,
+    carry_over: Option<&CarryOver>,
+    mutators: &[Mutator],
+    hardcore: bool,
+) {
+    let area = centered_rect(70, 60, frame.size());
+    let block = Block::default()
+        .title("New Game — choose a starting loadout")
+        .borders(Borders::ALL);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),
+            Constraint::Length(Mutator::ALL.len() as u16 + 1),
+            Constraint::Length(2),
+            Constraint::Length(if carry_over.is_some() { 4 } else { 0 }),
+            Constraint::Length(2),
+        ])
+        .split(inner);
+
+    let items: Vec<ListItem> = Loadout::ALL
+        .iter()
+        .map(|loadout| {
+            ListItem::new(vec![
+                Line::from(Span::styled(
+                    loadout.label(),
+                    Style::default()
+                        .fg(Color::LightCyan)
+                        .add_modifier(Modifier::BOLD),
+                )),
+                Line::from(format!("  {}", loadout.summary())),
+            ])
+        })
+        .collect();
+    let mut state = ListState::default();
+    state.select(Some(selected.min(Loadout::ALL.len() - 1)));
+    let list = List::new(items)
+        .highlight_style(Style::default().bg(Color::DarkGray))
+        .highlight_symbol("> ");
+    frame.render_stateful_widget(list, layout[0], &mut state);
+
+    frame.render_widget(
+        Paragraph::new(mutator_lines(mutators))
+            .block(Block::default().borders(Borders::TOP).title("Mutators")),
+        layout[1],
+    );
+
+    frame.render_widget(
+        Paragraph::new(hardcore_line(hardcore)).block(Block::default().borders(Borders::TOP)),
+        layout[2],
+    );
+
+    if let Some(carry) = carry_over {
+        frame.render_widget(
+            Paragraph::new(carry_over_lines(carry))
+                .wrap(Wrap { trim: true })
+                .block(Block::default().borders(Borders::TOP).title("Carry over")),
+            layout[3],
+        );
+    }
+
+    let footer = if carry_over.is_some() {
+        "↑/↓ choose  •  1-4 mutators  •  H hardcore  •  ←/→ carry over  •  Enter start  •  Q quit"
+    } else {
+        "↑/↓ choose  •  1-4 mutators  •  H hardcore  •  Enter start  •  Q quit"
+    };
+    let footer = Paragraph::new(footer)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::TOP));
+    frame.render_widget(footer, layout[4]);
+}
+
+fn hardcore_line(on: bool) -> Line<'static> {
+    let style = if on {
+        Style::default().fg(Color::LightRed)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+    Line::from(vec![
+        Span::styled(
+            format!("[{}] H Hardcore", if on { "x" } else { " " }),
+            style,
+        ),
+        Span::raw("  Saves only at day's end; losing the fleet erases the save."),
+    ])
+}
+
+fn mutator_lines(active: &[Mutator]) -> Vec<Line<'static>> {
+    Mutator::ALL
+        .iter()
+        .enumerate()
+        .map(|(index, mutator)| {
+            let on = active.contains(mutator);
+            let style = if on {
+                Style::default().fg(Color::LightRed)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!(
+                        "[{}] {} {}",
+                        if on { "x" } else { " " },
+                        index + 1,
+                        mutator.label()
+                    ),
+                    style,
+                ),
+                Span::raw(format!("  {}", mutator.summary())),
+            ])
+        })
+        .collect()
+}
+
+fn carry_over_lines(carry: &CarryOver) -> Vec<Line<'static>> {
+    let dim = Style::default().fg(Color::DarkGray);
+    if let Err(err) = &carry.processors {
+        return vec![Line::from(Span::styled(
+            format!("Unavailable: {err}."),
+            dim,
+        ))];
+    }
+    let Some(unit) = carry.chosen() else {
+        return vec![Line::from(Span::styled(
+            "Nothing — start clean with full credits.",
+            dim,
+        ))];
+    };
+    vec![
+        Line::from(vec![
+            Span::styled(
+                unit.name.clone(),
+                Style::default()
+                    .fg(Color::LightCyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(
+                "  speed {:.2} • bias {:+} • cooling {} • hardening {} • wear {}",
+                unit.speed,
+                unit.quality_bias,
+                unit.cooling_level,
+                unit.hardening_level,
+                format::percent(unit.wear.min(1.0))
+            )),
+        ]),
+        Line::from(format!(
+            "{} jobs • best quality {} • starting credits x{:.1}",
+            unit.jobs_completed, unit.best_quality, IMPORT_CREDIT_RATIO
+        )),
+    ]
+}
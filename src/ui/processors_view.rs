@@ -1,6 +1,12 @@
 use crate::app::{App, FocusTarget};
-use crate::sim::game::{AssistSuggestion, Game};
-use crate::sim::processors::{DaemonMode, ProcessorStatus};
+use crate::sim::economy;
+use crate::sim::game::{ASSIST_CHOICES, AssistSuggestion, DAY_DURATION, Game};
+use crate::sim::processors::{
+    ClockMode, Condition, DaemonMode, ProcessorState, ProcessorStatus, ProcessorWork,
+};
+use crate::sim::racks::{self, RACK_CAPACITY};
+use crate::sim::tuning::TUNING;
+use crate::ui::format::{self, Formatter};
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, List, ListItem, ListState};
 
@@ -12,9 +18,19 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, game: &Game) {
         Style::default()
     };
 
+    let fmt = Formatter::of(game);
+    let power = app.power_column.then(|| game.electricity_breakdown());
+    let top_consumer = power.as_ref().and_then(|bills| {
+        bills
+            .iter()
+            .filter(|(_, cost)| *cost > 0)
+            .max_by_key(|(_, cost)| *cost)
+            .map(|(index, _)| *index)
+    });
     let mut items: Vec<ListItem> = Vec::new();
     for (index, processor) in game.state.processors.iter().enumerate() {
-        let reliability_pct = processor.reliability_display() * 100.0;
+        let reliability = processor.reliability_display();
+        let reliability_pct = reliability * 100.0;
         let reliability_style = if reliability_pct >= 90.0 {
             Style::default().fg(Color::LightGreen)
         } else if reliability_pct >= 70.0 {
@@ -22,11 +38,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, game: &Game) {
         } else {
             Style::default().fg(Color::LightRed)
         };
-        let automation_label = match processor.daemon_mode {
-            DaemonMode::Off => "Off",
-            DaemonMode::Assist => "Assist",
-            DaemonMode::Auto => "Auto",
-        };
+        let automation_label = processor.daemon_mode.label();
         let header = Line::from(vec![
             Span::styled(
                 processor.name.clone(),
@@ -34,49 +46,146 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, game: &Game) {
                     .fg(Color::LightCyan)
                     .add_modifier(Modifier::BOLD),
             ),
+            match processor.clock {
+                ClockMode::Stock => Span::raw(""),
+                ClockMode::Eco => Span::styled(
+                    " ECO",
+                    Style::default()
+                        .fg(Color::LightGreen)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                ClockMode::Overclock => Span::styled(
+                    " OC",
+                    Style::default()
+                        .fg(Color::LightRed)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            },
+            if processor.maintenance_scheduled {
+                Span::styled(" MAINT", Style::default().fg(Color::LightBlue))
+            } else {
+                Span::raw("")
+            },
+            match processor.warranty_days_remaining {
+                0 => Span::raw(""),
+                days => Span::styled(
+                    format!(" WARRANTY {days}d"),
+                    Style::default().fg(Color::LightGreen),
+                ),
+            },
+            if processor.insured {
+                Span::styled(" INSURED", Style::default().fg(Color::LightBlue))
+            } else {
+                Span::raw("")
+            },
+            if processor.faraday_shroud {
+                Span::styled(" SHROUD", Style::default().fg(Color::LightBlue))
+            } else {
+                Span::raw("")
+            },
+            if processor.auto_replace {
+                Span::styled(" AUTO-REPLACE", Style::default().fg(Color::LightBlue))
+            } else {
+                Span::raw("")
+            },
             Span::raw(" "),
             Span::raw(format!("| speed {:.2}", processor.speed)),
             Span::raw(" "),
             Span::raw(format!("| bias {:+}", processor.quality_bias)),
             Span::raw(" "),
-            Span::raw(format!("| auto {}", automation_label)),
+            Span::raw(format!(
+                "| auto {}{}{}{}{}{}",
+                automation_label,
+                match processor.daemon_firmware_level {
+                    0 => String::new(),
+                    _ if processor.daemon_trial => String::new(),
+                    level => format!(" fw{level}"),
+                },
+                match processor.daemon_priority {
+                    0 => String::new(),
+                    priority => format!(" p{priority:+}"),
+                },
+                if processor.daemon_preempt { "+pre" } else { "" },
+                if processor.allow_utility { "+util" } else { "" },
+                if processor.daemon_trial {
+                    " (trial)"
+                } else {
+                    ""
+                }
+            )),
             Span::raw(" "),
-            Span::styled(format!("| rel {reliability_pct:.1}%"), reliability_style),
+            Span::styled(
+                format!("| rel {}", format::percent(reliability)),
+                reliability_style,
+            ),
+            lifespan_span(processor),
+            match &power {
+                Some(bills) => power_span(&fmt, bills[index].1, top_consumer == Some(index)),
+                None => Span::raw(""),
+            },
         ]);
 
-        let wear_pct = (processor.wear * 100.0).min(100.0);
+        let wear = format::percent(processor.wear.min(1.0));
         let power_draw = processor.last_power_draw();
+        let energy = processor.energy_today;
+        let (rack, rack_style) = rack_effect(processor.ambient_heat);
+        let temperature = temperature_span(processor.temperature);
+        let condition_style = match processor.condition() {
+            Condition::Good => Style::default().fg(Color::Green),
+            Condition::Worn => Style::default().fg(Color::Yellow),
+            Condition::Failing => Style::default().fg(Color::LightRed),
+        };
 
         let status_line = match &processor.status {
             ProcessorStatus::Idle => Line::from(vec![
-                Span::styled("Idle", Style::default().fg(Color::Green)),
+                if processor.paused {
+                    Span::styled(
+                        "Paused",
+                        Style::default()
+                            .fg(Color::LightBlue)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                } else if processor.in_standby() {
+                    Span::styled("Standby", Style::default().fg(Color::DarkGray))
+                } else {
+                    Span::styled("Idle", Style::default().fg(Color::Green))
+                },
                 Span::raw("  •  cooling "),
                 Span::raw(format!(
                     "{}/{}",
                     processor.cooling_level,
                     processor.cooling_cap()
                 )),
+                Span::raw("  •  "),
+                temperature.clone(),
                 Span::raw("  •  hardening "),
                 Span::raw(format!("{}", processor.hardening_level)),
                 Span::raw("  •  wear "),
-                Span::raw(format!("{wear_pct:.0}%")),
+                Span::raw(wear),
+                Span::raw(" "),
+                Span::styled(processor.condition().label(), condition_style),
                 Span::raw("  •  draw "),
                 Span::raw(format!("{power_draw:.1} kWh")),
+                Span::raw(format!(" (today {energy:.1})")),
+                Span::styled(rack.clone(), rack_style),
             ]),
             ProcessorStatus::Working(work) => {
                 let (remaining, total) = processor.remaining_and_total().unwrap_or((0, 1));
                 let elapsed = total.saturating_sub(remaining);
-                let remaining_secs = remaining as f64 / 1000.0;
-                let total_secs = total as f64 / 1000.0;
-                let elapsed_secs = elapsed as f64 / 1000.0;
                 let progress = if total > 0 {
                     (elapsed as f64 / total as f64).min(1.0)
                 } else {
                     0.0
                 };
-                let progress_pct = (progress * 100.0).round() as i32;
                 let heat = processor.heat_display();
-                let heat_span = if work.overheating {
+                let heat_span = if work.throttled() {
+                    Span::styled(
+                        format!("heat {heat:.2} THROTTLED"),
+                        Style::default()
+                            .fg(Color::LightRed)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                } else if work.overheating {
                     Span::styled(
                         format!("heat {heat:.2}"),
                         Style::default().fg(Color::LightRed),
@@ -84,23 +193,53 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, game: &Game) {
                 } else {
                     Span::raw(format!("heat {heat:.2}"))
                 };
-                Line::from(vec![
-                    Span::styled(
+                let (label, label_style) = if work.paused {
+                    (
+                        format!("Paused by breaker: {}", work.job.name),
+                        Style::default().fg(Color::LightRed),
+                    )
+                } else {
+                    (
                         format!("Working on {}", work.job.name),
                         Style::default().fg(Color::Yellow),
-                    ),
+                    )
+                };
+                Line::from(vec![
+                    Span::styled(label, label_style),
                     Span::raw(" "),
                     Span::raw(format!(
-                        "{elapsed_secs:.1}/{total_secs:.1}s ({progress_pct}%)"
+                        "{}/{} ({})",
+                        format::duration_ms(elapsed),
+                        format::duration_ms(total),
+                        format::percent(progress)
                     )),
                     Span::raw(" "),
-                    Span::raw(format!("remaining {remaining_secs:.1}s")),
+                    Span::raw(format!("remaining {}", format::duration_ms(remaining))),
                     Span::raw("  •  "),
                     heat_span,
+                    Span::raw("  •  "),
+                    temperature.clone(),
                     Span::raw("  •  draw "),
                     Span::raw(format!("{power_draw:.1} kWh")),
+                    Span::raw(format!(" (today {energy:.1})")),
+                    Span::styled(rack.clone(), rack_style),
                 ])
             }
+            ProcessorStatus::Servicing { remaining_ms } => Line::from(vec![
+                Span::styled(
+                    "Servicing",
+                    Style::default()
+                        .fg(Color::LightBlue)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!(" back in {}", format::duration_ms(*remaining_ms))),
+                Span::raw("  •  wear "),
+                Span::raw(wear),
+                Span::raw(" "),
+                Span::styled(processor.condition().label(), condition_style),
+                Span::raw("  •  "),
+                temperature.clone(),
+            ]),
             ProcessorStatus::BurntOut => Line::from(vec![Span::styled(
                 "Burnt Out — press [R] to replace",
                 Style::default().fg(Color::LightRed),
@@ -111,36 +250,132 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, game: &Game) {
             )]),
         };
 
-        let mut lines = vec![header, status_line];
+        let mut lines = Vec::new();
+        if let Some(rack) = processor.rack_id
+            && (index == 0 || game.state.processors[index - 1].rack_id != Some(rack))
+        {
+            lines.push(rack_header(game, rack));
+        }
+        lines.push(header);
+        lines.push(status_line);
+        for (core, work) in processor.extra_slots.iter().enumerate() {
+            lines.push(core_line(core + 2, work));
+        }
+        if highlight && Some(index) == app.processor_selection(game) {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "Lifetime: {} done  •  {} failed  •  {} earned  •  {} worked",
+                    fmt.number(processor.jobs_completed),
+                    fmt.number(processor.jobs_failed),
+                    fmt.credits(processor.credits_earned),
+                    format::duration_ms(processor.total_work_ms)
+                ),
+                Style::default().fg(Color::DarkGray),
+            )));
+            if !processor.readouts.is_empty() {
+                lines.push(trend_line(processor));
+            }
+            if processor.insured {
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "Insured: {}/day  •  claims pay {} of the replacement and {} of a lost job",
+                        fmt.credits(economy::insurance_premium(processor)),
+                        format::percent(economy::INSURANCE_REPLACEMENT_SHARE),
+                        format::percent(economy::INSURANCE_REWARD_SHARE)
+                    ),
+                    Style::default().fg(Color::LightBlue),
+                )));
+            }
+            lines.push(Line::from(Span::styled(
+                if processor.auto_replace {
+                    format!(
+                        "Auto-replace: on, keeps {} in the treasury  •  Ctrl+A to turn off",
+                        fmt.credits(game.state.auto_replace_floor)
+                    )
+                } else {
+                    "Auto-replace: off  •  Ctrl+A to turn on".to_string()
+                },
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        if !processor.daemon_blocked_tags.is_empty() {
+            lines.push(Line::from(vec![
+                Span::styled("Blocked", Style::default().fg(Color::LightRed)),
+                Span::raw(format!(
+                    " for automation: {}",
+                    processor.daemon_blocked_tags.join(", ")
+                )),
+            ]));
+        }
         if matches!(processor.daemon_mode, DaemonMode::Assist) {
-            if let Some(AssistSuggestion {
-                job_index,
-                eta_secs,
-                reliability,
-                heat,
-            }) = game.assist_suggestion(index)
-            {
-                if let Some(job) = game.state.jobs.get(job_index) {
-                    lines.push(Line::from(vec![
-                        Span::styled("Assist", Style::default().fg(Color::LightBlue)),
-                        Span::raw(format!(
-                            ": {} ({eta_secs:.1}s, rel {:.0}%, heat {:.2})",
-                            job.name,
-                            reliability * 100.0,
-                            heat
-                        )),
-                    ]));
-                }
+            // The focused unit lists every choice its number keys can take.
+            let choices = if highlight && Some(index) == app.processor_selection(game) {
+                ASSIST_CHOICES
+            } else {
+                1
+            };
+            for (rank, suggestion) in game.assist_suggestions(index, choices).iter().enumerate() {
+                let Some(job) = game.suggested_job(suggestion) else {
+                    continue;
+                };
+                let AssistSuggestion {
+                    eta_secs,
+                    reliability,
+                    heat,
+                    pending,
+                    score,
+                    ..
+                } = *suggestion;
+                let marker = if pending { " (pending)" } else { "" };
+                let tax = economy::daemon_tax_estimate(job, processor, None);
+                let label = if choices > 1 {
+                    format!("[{}] Assist", rank + 1)
+                } else {
+                    "Assist".to_string()
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(label, Style::default().fg(Color::LightBlue)),
+                    Span::raw(format!(
+                        ": {}{marker} ({}, rel {}, heat {:.2}, {:.1} cr/s)",
+                        job.name,
+                        format::duration_secs(eta_secs),
+                        format::percent(reliability),
+                        heat,
+                        score
+                    )),
+                    Span::styled(
+                        format!("  •  {}", fmt.daemon_tax(&tax)),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ]));
             }
         }
+        if matches!(processor.daemon_mode, DaemonMode::Auto)
+            && let ProcessorStatus::Working(work) = &processor.status
+            && work.daemon_penalty.is_some()
+        {
+            let tax =
+                economy::daemon_tax_estimate(&work.job, processor, work.daemon_penalty.as_ref());
+            lines.push(Line::from(vec![
+                Span::styled("Auto", Style::default().fg(Color::LightBlue)),
+                Span::raw(format!(": {}", fmt.daemon_tax(&tax))),
+            ]));
+        }
 
         items.push(ListItem::new(lines));
     }
 
+    let title = match &power {
+        Some(bills) => format!(
+            "Processors • power {}/day",
+            fmt.credits(bills.iter().map(|(_, cost)| cost).sum())
+        ),
+        None => "Processors".to_string(),
+    };
     let list = List::new(items)
         .block(
             Block::default()
-                .title("Processors")
+                .title(title)
                 .borders(Borders::ALL)
                 .border_style(border_style),
         )
@@ -148,9 +383,152 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, game: &Game) {
         .highlight_symbol("▶ ");
 
     let mut state = ListState::default();
-    if !game.state.processors.is_empty() {
-        let selection = app.selected_processor.min(game.state.processors.len() - 1);
-        state.select(Some(selection));
-    }
+    state.select(app.processor_selection(game));
     frame.render_stateful_widget(list, area, &mut state);
 }
+
+/// Sparklines of the unit's recent reliability and heat, oldest first.
+fn trend_line(processor: &ProcessorState) -> Line<'static> {
+    let readouts = &processor.readouts;
+    let peak_heat = readouts
+        .iter()
+        .map(|readout| readout.heat)
+        .fold(TUNING.overheat_threshold, f64::max);
+    let latest = readouts.iter().next_back().copied();
+    let reliability = readouts.iter().map(|readout| readout.reliability);
+    let heat = readouts.iter().map(|readout| readout.heat);
+    Line::from(vec![
+        Span::styled(
+            format!("Last {}s  rel ", readouts.len()),
+            Style::default().fg(Color::DarkGray),
+        ),
+        Span::styled(
+            format::sparkline(reliability, 1.0),
+            Style::default().fg(Color::LightGreen),
+        ),
+        Span::raw(format!(
+            " {}",
+            format::percent(latest.map_or(0.0, |readout| readout.reliability))
+        )),
+        Span::styled("  •  heat ", Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            format::sparkline(heat, peak_heat),
+            Style::default().fg(Color::LightRed),
+        ),
+        Span::raw(format!(
+            " {:.2}",
+            latest.map_or(0.0, |readout| readout.heat)
+        )),
+    ])
+}
+
+/// The unit's projected electricity bill; the fleet's biggest draw stands
+/// out.
+fn power_span(fmt: &Formatter, cost: u64, top: bool) -> Span<'static> {
+    let text = format!(" | power {}/day", fmt.credits(cost));
+    if top {
+        Span::styled(
+            text,
+            Style::default()
+                .fg(Color::LightRed)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Span::raw(text)
+    }
+}
+
+/// Progress of a job on a core past the first.
+fn core_line(core: usize, work: &ProcessorWork) -> Line<'static> {
+    let elapsed = work.total_ms.saturating_sub(work.remaining_ms);
+    let label_style = if work.overheating {
+        Style::default().fg(Color::LightRed)
+    } else {
+        Style::default().fg(Color::Yellow)
+    };
+    Line::from(vec![
+        Span::styled(format!("Core {core}: {}", work.job.name), label_style),
+        Span::styled(
+            if work.throttled() { " THROTTLED" } else { "" },
+            label_style,
+        ),
+        Span::raw(format!(
+            " {}/{} ({})",
+            format::duration_ms(elapsed),
+            format::duration_ms(work.total_ms),
+            format::percent(work.progress())
+        )),
+        Span::raw(format!(
+            " remaining {}",
+            format::duration_ms(work.remaining_ms)
+        )),
+    ])
+}
+
+/// Banner above the first member of each rack.
+fn rack_header(game: &Game, rack: u8) -> Line<'static> {
+    let cooling = game
+        .state
+        .racks
+        .get(rack as usize)
+        .map_or(0, |state| state.cooling_level);
+    let members = racks::members(&game.state.processors, rack).count();
+    Line::from(Span::styled(
+        format!(
+            "── Rack {} • cooling +{cooling} • {members}/{RACK_CAPACITY} units",
+            rack + 1
+        ),
+        Style::default().fg(Color::DarkGray),
+    ))
+}
+
+/// Wear and expected days left on finite-lifespan units, red near the end.
+fn lifespan_span(processor: &ProcessorState) -> Span<'static> {
+    let Some(left_ms) = processor.estimated_lifespan_ms() else {
+        return Span::raw("");
+    };
+    let days = left_ms as f64 / DAY_DURATION.as_millis() as f64;
+    let text = format!(
+        " | wear {} (~{days:.1} days)",
+        format::percent(processor.wear.min(1.0))
+    );
+    if processor.near_end_of_life() {
+        Span::styled(
+            text,
+            Style::default()
+                .fg(Color::LightRed)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Span::raw(text)
+    }
+}
+
+/// Soaked-in temperature, red once it passes the overheat line.
+fn temperature_span(temperature: f64) -> Span<'static> {
+    let text = format!("temp {temperature:.2}");
+    if temperature > TUNING.overheat_threshold {
+        Span::styled(text, Style::default().fg(Color::LightRed))
+    } else if temperature > TUNING.overheat_threshold / 2.0 {
+        Span::styled(text, Style::default().fg(Color::Yellow))
+    } else {
+        Span::raw(text)
+    }
+}
+
+/// Neighbor heat shown next to the draw, empty when the rack is neutral.
+fn rack_effect(ambient: f64) -> (String, Style) {
+    if ambient.abs() < 0.005 {
+        (String::new(), Style::default())
+    } else if ambient > 0.0 {
+        (
+            format!("  •  rack heat {ambient:+.2}"),
+            Style::default().fg(Color::LightRed),
+        )
+    } else {
+        (
+            format!("  •  rack heat {ambient:+.2}"),
+            Style::default().fg(Color::LightBlue),
+        )
+    }
+}
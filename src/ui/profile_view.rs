@@ -0,0 +1,68 @@
+use super::centered_rect;
+use crate::app::ProfilePicker;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+
+/// Launch-time profile picker; the default save is listed first.
+pub fn render(frame: &mut Frame, picker: &ProfilePicker) {
+    let area = centered_rect(60, 60, frame.size());
+    let block = Block::default()
+        .title("Who's playing?")
+        .borders(Borders::ALL);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(2),
+            Constraint::Length(3),
+            Constraint::Length(2),
+        ])
+        .split(inner);
+
+    let items: Vec<ListItem> = std::iter::once(ListItem::new(Span::styled(
+        "Default save",
+        Style::default().fg(Color::Gray),
+    )))
+    .chain(
+        picker
+            .profiles
+            .iter()
+            .map(|name| ListItem::new(name.clone())),
+    )
+    .collect();
+    let mut state = ListState::default();
+    state.select(Some(picker.selected.min(picker.profiles.len())));
+    let list = List::new(items)
+        .highlight_style(Style::default().bg(Color::DarkGray))
+        .highlight_symbol("> ");
+    frame.render_stateful_widget(list, layout[0], &mut state);
+
+    let mut naming = Vec::new();
+    if let Some(name) = &picker.naming {
+        naming.push(Line::from(vec![
+            Span::raw("New profile: "),
+            Span::styled(format!("{name}_"), Style::default().fg(Color::LightCyan)),
+        ]));
+    }
+    if let Some(error) = &picker.error {
+        naming.push(Line::from(Span::styled(
+            error.clone(),
+            Style::default().fg(Color::LightRed),
+        )));
+    }
+    frame.render_widget(Paragraph::new(naming).wrap(Wrap { trim: true }), layout[1]);
+
+    let footer = if picker.naming.is_some() {
+        "Type a name  •  Enter create  •  Esc cancel"
+    } else {
+        "↑/↓ choose  •  Enter play  •  N new profile  •  Q quit"
+    };
+    frame.render_widget(
+        Paragraph::new(footer)
+            .wrap(Wrap { trim: true })
+            .block(Block::default().borders(Borders::TOP)),
+        layout[2],
+    );
+}
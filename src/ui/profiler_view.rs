@@ -0,0 +1,66 @@
+use crate::TICK_RATE;
+use crate::profiler::{Phase, Profiler};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+const WIDTH: u16 = 34;
+
+/// Phases averaging above this share of the tick budget are flagged.
+const PHASE_WARN_SHARE: u32 = 4;
+
+pub fn render(frame: &mut Frame, profiler: &Profiler) {
+    let size = frame.size();
+    let height = Phase::ALL.len() as u16 + 3;
+    let area = Rect {
+        x: size.width.saturating_sub(WIDTH),
+        y: 0,
+        width: WIDTH.min(size.width),
+        height: height.min(size.height),
+    };
+    frame.render_widget(Clear, area);
+
+    let last_frame = profiler.last_frame();
+    let frame_style = if last_frame > TICK_RATE {
+        Style::default().fg(Color::White).bg(Color::Red)
+    } else {
+        Style::default().fg(Color::LightGreen)
+    };
+    let mut lines = vec![Line::from(Span::styled(
+        format!(
+            "frame {:>6.2} / {} ms",
+            millis(last_frame),
+            TICK_RATE.as_millis()
+        ),
+        frame_style,
+    ))];
+    for phase in Phase::ALL {
+        let line = match profiler.average(phase) {
+            Some(average) => {
+                let style = if average > TICK_RATE / PHASE_WARN_SHARE {
+                    Style::default().fg(Color::Yellow)
+                } else {
+                    Style::default()
+                };
+                Line::from(Span::styled(
+                    format!("{:<13}{:>8.2} ms", phase.label(), millis(average)),
+                    style,
+                ))
+            }
+            None => Line::from(Span::styled(
+                format!("{:<13}{:>11}", phase.label(), "—"),
+                Style::default().fg(Color::DarkGray),
+            )),
+        };
+        lines.push(line);
+    }
+    let overlay = Paragraph::new(lines).block(
+        Block::default()
+            .title("Profiler (avg)")
+            .borders(Borders::ALL),
+    );
+    frame.render_widget(overlay, area);
+}
+
+fn millis(duration: std::time::Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
@@ -0,0 +1,30 @@
+use super::centered_rect;
+use crate::sim::game::Game;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+pub fn render(frame: &mut Frame, game: &Game) {
+    let area = centered_rect(60, 50, frame.size());
+    frame.render_widget(Clear, area);
+    let mut lines = vec![
+        Line::from(Span::styled(
+            "This save holds content this version does not recognize.",
+            Style::default().fg(Color::Yellow),
+        )),
+        Line::from("It has been set aside and will be kept in future saves:"),
+        Line::from(""),
+    ];
+    lines.extend(
+        game.quarantine_notices()
+            .iter()
+            .map(|notice| Line::from(format!("• {notice}"))),
+    );
+    lines.push(Line::from(""));
+    lines.push(Line::from("Press Enter or Esc to continue."));
+    let popup = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title("Quarantined Content")
+            .borders(Borders::ALL),
+    );
+    frame.render_widget(popup, area);
+}
@@ -0,0 +1,82 @@
+use super::centered_rect;
+use crate::app::App;
+use crate::sim::economy;
+use crate::sim::game::Game;
+use crate::ui::format::{self, Formatter};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
+
+pub fn render(frame: &mut Frame, app: &App, game: &Game) {
+    let Some(pick) = app.quick_pick else {
+        return;
+    };
+    let Some(processor) = game.state.processors.get(pick.processor_index) else {
+        return;
+    };
+    let area = centered_rect(50, 50, frame.size());
+    frame.render_widget(Clear, area);
+    let block = Block::default()
+        .title(format!("Pick a job for {}", processor.name))
+        .borders(Borders::ALL);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(2)])
+        .split(inner);
+
+    let fmt = Formatter::of(game);
+    let candidates = game.job_candidates(pick.processor_index);
+    let items: Vec<ListItem> = candidates
+        .iter()
+        .filter_map(|candidate| {
+            let job = game.state.jobs.get(candidate.job_index)?;
+            let reliability_pct = candidate.reliability * 100.0;
+            let reliability_style = if reliability_pct >= 90.0 {
+                Style::default().fg(Color::LightGreen)
+            } else if reliability_pct >= 70.0 {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default().fg(Color::LightRed)
+            };
+            let mut spans = vec![
+                Span::styled(job.name.clone(), Style::default().fg(Color::Yellow)),
+                Span::raw(format!(
+                    " | {} | ETA {} | ",
+                    fmt.credits(job.base_reward),
+                    format::duration_secs(candidate.eta_secs)
+                )),
+                Span::styled(
+                    format!("rel {}", format::percent(candidate.reliability)),
+                    reliability_style,
+                ),
+                Span::raw(format!(" | heat {:.2}", candidate.heat)),
+            ];
+            if processor.daemon_unlocked() {
+                let tax = economy::daemon_tax_estimate(job, processor, None);
+                spans.push(Span::styled(
+                    format!(" | {}", fmt.daemon_tax(&tax)),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            Some(ListItem::new(Line::from(spans)))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .highlight_symbol("▶ ")
+        .highlight_style(Style::default().bg(Color::DarkGray).fg(Color::White));
+    let mut state = ListState::default();
+    if !candidates.is_empty() {
+        state.select(Some(pick.selected.min(candidates.len() - 1)));
+    }
+    frame.render_stateful_widget(list, layout[0], &mut state);
+
+    let footer = Paragraph::new(Line::from(vec![Span::raw(
+        "Enter to assign  •  J/K to move  •  Esc to cancel",
+    )]))
+    .wrap(Wrap { trim: true });
+    frame.render_widget(footer, layout[1]);
+}
@@ -0,0 +1,70 @@
+use super::centered_rect;
+use crate::sim::game::Game;
+use crate::sim::settings::{MessageCategory, MessageRoute};
+use crate::ui::format::Formatter;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+pub fn render(frame: &mut Frame, game: &Game, selected: usize) {
+    let area = centered_rect(50, 40, frame.size());
+    frame.render_widget(Clear, area);
+    let routing = &game.state.display.routing;
+    let mut lines: Vec<Line> = MessageCategory::ALL
+        .iter()
+        .enumerate()
+        .map(|(index, category)| {
+            let route = routing.route(*category);
+            let marker = if index == selected { "▶ " } else { "  " };
+            let style = if index == selected {
+                Style::default().bg(Color::DarkGray).fg(Color::White)
+            } else {
+                Style::default()
+            };
+            Line::from(vec![
+                Span::styled(format!("{marker}{:<18}", category.label()), style),
+                Span::styled(route.label(), route_style(route)),
+            ])
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::raw(format!("  {:<18}", "Compressed saves")),
+        Span::raw(if game.state.compress_saves {
+            "on"
+        } else {
+            "off"
+        }),
+    ]));
+    lines.push(Line::from(vec![
+        Span::raw(format!("  {:<18}", "Spare worn units")),
+        Span::raw(if game.state.spare_worn_units {
+            "on"
+        } else {
+            "off"
+        }),
+    ]));
+    lines.push(Line::from(vec![
+        Span::raw(format!("  {:<18}", "Auto-replace floor")),
+        Span::raw(Formatter::of(game).credits(game.state.auto_replace_floor)),
+    ]));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Enter change route  •  T send test notices  •  C compress saves  •  D spare worn units  •  +/- auto-replace floor  •  Esc close",
+        Style::default().fg(Color::DarkGray),
+    )));
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title("Message Routing")
+            .borders(Borders::ALL),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+fn route_style(route: MessageRoute) -> Style {
+    match route {
+        MessageRoute::Log => Style::default().fg(Color::DarkGray),
+        MessageRoute::Ticker => Style::default().fg(Color::LightBlue),
+        MessageRoute::Toast => Style::default().fg(Color::Yellow),
+        MessageRoute::ToastBell => Style::default().fg(Color::LightRed),
+    }
+}
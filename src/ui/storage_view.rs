@@ -1,23 +1,20 @@
 use crate::app::App;
 use crate::sim::economy;
 use crate::sim::game::{DAEMON_UNLOCK_CREDITS, Game};
+use crate::ui::format::{self, Formatter};
+use crate::ui::glyphs;
 use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App, game: &Game) {
-    let sections = Layout::vertical([Constraint::Length(9), Constraint::Min(3)]).split(area);
-
-    let stats_block = Block::default().title("Systems").borders(Borders::ALL);
-    let stats_area = stats_block.inner(sections[0]);
-    frame.render_widget(stats_block, sections[0]);
-
+    let fmt = Formatter::of(game);
     let storage = &game.state.storage;
     let passive_preview = economy::passive_income(storage.stored);
-    let spawn_pct = (game.job_spawn_progress() * 100.0).min(100.0);
-    let day_pct = (game.day_progress() * 100.0).min(100.0);
+    let spawn_pct = format::percent(game.job_spawn_progress().min(1.0));
+    let day_pct = format::percent(game.day_progress().min(1.0));
     let daemon_status = if !game.state.daemon_unlocked {
-        format!("Locked ({} cr needed)", DAEMON_UNLOCK_CREDITS)
+        format!("Locked ({} needed)", fmt.credits(DAEMON_UNLOCK_CREDITS))
     } else {
         let auto = game
             .state
@@ -31,38 +28,84 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, game: &Game) {
             .iter()
             .filter(|p| matches!(p.daemon_mode, crate::sim::processors::DaemonMode::Assist))
             .count();
-        format!("{auto} auto / {assist} assist")
+        let stats = &game.state.statistics;
+        let running_tax: u64 = game
+            .state
+            .processors
+            .iter()
+            .flat_map(|processor| {
+                processor
+                    .slots()
+                    .filter(|work| work.daemon_penalty.is_some())
+                    .map(|work| {
+                        economy::daemon_tax_estimate(
+                            &work.job,
+                            processor,
+                            work.daemon_penalty.as_ref(),
+                        )
+                        .credits
+                    })
+            })
+            .sum();
+        format!(
+            "{auto} auto / {assist} assist • {} jobs, {} preempted • tax in flight ≈ {}",
+            fmt.number(stats.daemon_assignments),
+            fmt.number(stats.daemon_preemptions),
+            fmt.credits(running_tax)
+        )
     };
-    let pending_job = app
-        .pending_job
-        .as_ref()
-        .map(|job| job.name.as_str())
-        .unwrap_or("None");
 
-    let stats_lines = vec![
+    let mut stats_lines = vec![
         Line::from(vec![
             Span::styled("Credits", Style::default().fg(Color::Yellow)),
-            Span::raw(format!(": {}", game.state.credits)),
+            Span::raw(format!(": {}", fmt.number(game.state.credits))),
             Span::raw("    Upkeep/day: "),
-            Span::raw(format!("{}", game.total_upkeep())),
+            Span::raw(fmt.credits(game.total_upkeep())),
+            Span::raw(match game.insurance_upkeep() {
+                0 => String::new(),
+                premiums => format!(" (insurance {})", fmt.credits(premiums)),
+            }),
         ]),
         Line::from(vec![
             Span::styled("Storage", Style::default().fg(Color::LightGreen)),
             Span::raw(format!(
                 ": {}/{} (free {} units)",
-                storage.stored,
-                storage.capacity,
-                storage.free_capacity()
+                fmt.number(storage.stored),
+                fmt.number(storage.capacity),
+                fmt.number(storage.free_capacity())
             )),
+            Span::styled(
+                match storage.reserved {
+                    0 => String::new(),
+                    reserved => format!(" • {} held by running jobs", fmt.number(reserved)),
+                },
+                Style::default().fg(Color::DarkGray),
+            ),
         ]),
         Line::from(vec![
             Span::raw("Passive income each cycle: "),
-            Span::raw(format!("{} credits", passive_preview)),
+            Span::raw(fmt.credits(passive_preview)),
+            Span::styled(
+                if game.state.dividend_boost > 0.0 {
+                    format!(" (surveillance x{:.2})", 1.0 + game.state.dividend_boost)
+                } else {
+                    String::new()
+                },
+                Style::default().fg(Color::LightMagenta),
+            ),
         ]),
         Line::from(vec![
             Span::raw("Instruction tags: "),
             Span::styled(
-                game.state.unlocked_tags.join(", "),
+                game.state
+                    .unlocked_tags
+                    .iter()
+                    .map(|tag| match game.state.statistics.tag_rank(tag) {
+                        Some(rank) => format!("{tag} ({})", rank.label()),
+                        None => tag.clone(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", "),
                 Style::default().fg(Color::White),
             ),
         ]),
@@ -74,8 +117,31 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, game: &Game) {
             Span::raw("Power draw: "),
             Span::raw(format!("{:.1} kWh", game.total_power_draw())),
             Span::raw("  •  Electricity/day: "),
-            Span::raw(format!("{} cr", game.total_electricity_cost())),
+            Span::raw(fmt.credits(game.total_electricity_cost())),
+        ]),
+        Line::from(vec![
+            Span::raw("Escrow: "),
+            Span::styled(
+                format!("{} locked", fmt.credits(game.escrow_locked())),
+                Style::default().fg(Color::LightMagenta),
+            ),
+            Span::raw(format!(
+                "  •  {} forfeited this session",
+                fmt.credits(game.escrow_forfeited())
+            )),
+        ]),
+        fleet_lifetime_line(game),
+        power_budget_line(game),
+        versatility_line(game),
+        Line::from(vec![
+            Span::raw("Market: "),
+            Span::raw(format!(
+                "{:.2} cr/kWh  •  demand {:.2}x",
+                game.electricity_rate(),
+                game.state.market_today.demand
+            )),
         ]),
+        weather_line(game),
         Line::from(vec![
             Span::raw("Thermal paste: "),
             Span::styled(
@@ -89,29 +155,258 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, game: &Game) {
         ]),
         Line::from(vec![
             Span::raw("Job spawn timer: "),
-            Span::raw(format!("{spawn_pct:.0}%")),
+            Span::raw(spawn_pct),
             Span::raw("    Day progress: "),
-            Span::raw(format!("{day_pct:.0}%")),
+            Span::raw(day_pct),
+        ]),
+        Line::from(vec![
+            Span::raw("Pending jobs: "),
+            Span::styled(super::pending_queue(game), Style::default().fg(Color::Cyan)),
         ]),
         Line::from(vec![
-            Span::raw("Pending job: "),
-            Span::styled(pending_job.to_string(), Style::default().fg(Color::Cyan)),
+            Span::raw("Loadout: "),
+            Span::raw(game.state.loadout.label()),
         ]),
     ];
+    for contract in &game.state.contracts {
+        stats_lines.push(Line::from(vec![
+            Span::styled(
+                format!("Contract #{}: ", contract.id),
+                Style::default().fg(Color::LightMagenta),
+            ),
+            Span::raw(format!(
+                "stage {}/{} [{}]  •  bonus {}",
+                contract.stage,
+                contract.stages,
+                contract.tag,
+                fmt.credits(contract.bonus)
+            )),
+        ]));
+    }
+    for (offset, outlook) in game.visible_forecast().enumerate() {
+        let label = if offset == 0 {
+            "Tomorrow".to_string()
+        } else {
+            format!("In {} days", offset + 1)
+        };
+        stats_lines.push(Line::from(vec![
+            Span::styled(
+                format!("Forecast {label}: "),
+                Style::default().fg(Color::LightBlue),
+            ),
+            Span::raw(format!(
+                "{:.2} cr/kWh  •  demand {:.2}x",
+                outlook.electricity_rate * game.tuning().electricity_multiplier,
+                outlook.demand
+            )),
+        ]));
+    }
+
+    let sections = Layout::vertical([
+        Constraint::Length(stats_lines.len() as u16 + 2),
+        Constraint::Min(3),
+    ])
+    .split(area);
+
+    let stats_block = Block::default().title("Systems").borders(Borders::ALL);
+    let stats_area = stats_block.inner(sections[0]);
+    frame.render_widget(stats_block, sections[0]);
 
     let paragraph = Paragraph::new(stats_lines).wrap(Wrap { trim: true });
     frame.render_widget(paragraph, stats_area);
 
-    let log_block = Block::default().title("Event Log").borders(Borders::ALL);
-    let log_area = log_block.inner(sections[1]);
-    frame.render_widget(log_block, sections[1]);
+    render_log(frame, sections[1], app, game);
+}
+
+/// Tail of the event log, or the whole log with a highlighted entry and
+/// search matches while the log has focus.
+fn render_log(frame: &mut Frame, area: Rect, app: &App, game: &Game) {
+    let log = game.log();
+    let focus = app.log_focus.as_ref();
+    let title = match focus {
+        Some(focus) if focus.searching || !focus.search.query().is_empty() => format!(
+            "Event Log — /{}{} ({} matches)",
+            focus.search.query(),
+            if focus.searching { "_" } else { "" },
+            focus.search.matches().len()
+        ),
+        Some(_) => "Event Log — / search, Enter jump, L close".to_string(),
+        None => "Event Log".to_string(),
+    };
+    let border_style = if focus.is_some() {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default()
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(border_style);
 
-    let mut items: Vec<ListItem> = game
-        .messages()
-        .map(|msg| ListItem::new(msg.clone()))
+    if log.is_empty() {
+        let list = List::new(vec![ListItem::new("No events yet. Stay vigilant.")]).block(block);
+        frame.render_widget(list, area);
+        return;
+    }
+    let Some(focus) = focus else {
+        let visible = area.height.saturating_sub(2) as usize;
+        let items: Vec<ListItem> = log
+            .iter()
+            .skip(log.len().saturating_sub(visible))
+            .map(|entry| ListItem::new(entry.text.clone()))
+            .collect();
+        frame.render_widget(List::new(items).block(block), area);
+        return;
+    };
+
+    let query = focus.search.query();
+    let items: Vec<ListItem> = log
+        .iter()
+        .map(|entry| {
+            if focus.search.is_match(entry.seq) {
+                ListItem::new(highlight_matches(&entry.text, query))
+            } else {
+                ListItem::new(entry.text.clone())
+            }
+        })
         .collect();
-    if items.is_empty() {
-        items.push(ListItem::new("No events yet. Stay vigilant."));
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().bg(Color::DarkGray).fg(Color::White))
+        .highlight_symbol("▶ ");
+    let mut state = ListState::default();
+    state.select(focus.selected.and_then(|seq| log.position(seq)));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+/// Styles every ASCII case-insensitive occurrence of `query` in `text`.
+fn highlight_matches(text: &str, query: &str) -> Line<'static> {
+    let style = Style::default().fg(Color::Black).bg(Color::Yellow);
+    let needle = query.to_ascii_lowercase();
+    let haystack = text.to_ascii_lowercase();
+    let mut spans = Vec::new();
+    let mut cursor = 0;
+    if !needle.is_empty() {
+        while let Some(found) = haystack[cursor..].find(&needle) {
+            let start = cursor + found;
+            let end = start + needle.len();
+            spans.push(Span::raw(text[cursor..start].to_string()));
+            spans.push(Span::styled(text[start..end].to_string(), style));
+            cursor = end;
+        }
+    }
+    spans.push(Span::raw(text[cursor..].to_string()));
+    Line::from(spans)
+}
+
+const GAUGE_WIDTH: usize = 20;
+
+fn power_budget_line(game: &Game) -> Line<'static> {
+    let budget = &game.state.power_budget;
+    if !budget.enabled {
+        return Line::from(vec![
+            Span::raw("Power budget: "),
+            Span::styled("off", Style::default().fg(Color::DarkGray)),
+            Span::raw(format!(" ({:.1} kWh panel)", budget.capacity)),
+        ]);
+    }
+    let glyphs = glyphs::current();
+    let draw = game.total_power_draw();
+    let ratio = if budget.capacity > 0.0 {
+        (draw / budget.capacity).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+    let filled = (ratio * GAUGE_WIDTH as f64).round() as usize;
+    let color = if ratio >= 0.9 {
+        Color::LightRed
+    } else if ratio >= 0.7 {
+        Color::Yellow
+    } else {
+        Color::LightGreen
+    };
+    Line::from(vec![
+        Span::raw("Power budget: "),
+        Span::styled(glyphs.working.repeat(filled), Style::default().fg(color)),
+        Span::raw(glyphs.idle.repeat(GAUGE_WIDTH - filled)),
+        Span::raw(format!(
+            " {draw:.1}/{:.1} kWh  •  {} trips",
+            budget.capacity, budget.trips
+        )),
+    ])
+}
+
+fn fleet_lifetime_line(game: &Game) -> Line<'static> {
+    let fmt = Formatter::of(game);
+    let processors = &game.state.processors;
+    let completed: u64 = processors.iter().map(|p| p.jobs_completed).sum();
+    let failed: u64 = processors.iter().map(|p| p.jobs_failed).sum();
+    let earned: u64 = processors.iter().map(|p| p.credits_earned).sum();
+    let worked: u64 = processors.iter().map(|p| p.total_work_ms).sum();
+    Line::from(vec![
+        Span::raw("Fleet lifetime: "),
+        Span::raw(format!(
+            "{} done  •  {} failed  •  {} earned  •  {} worked",
+            fmt.number(completed),
+            fmt.number(failed),
+            fmt.credits(earned),
+            format::duration_ms(worked)
+        )),
+    ])
+}
+
+fn weather_line(game: &Game) -> Line<'static> {
+    let ambient = game.ambient_temperature();
+    let style = if ambient > 0.0 {
+        Style::default().fg(Color::LightRed)
+    } else if ambient < 0.0 {
+        Style::default().fg(Color::LightBlue)
+    } else {
+        Style::default()
+    };
+    let mut spans = vec![
+        Span::raw("Ambient: "),
+        Span::styled(format!("{ambient:+.2} heat"), style),
+    ];
+    let days = game.state.heat_wave_days;
+    if days > 0 {
+        spans.push(Span::styled(
+            format!(
+                "  •  heat wave, {days} day{} left",
+                if days == 1 { "" } else { "s" }
+            ),
+            Style::default()
+                .fg(Color::LightRed)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    Line::from(spans)
+}
+
+fn versatility_line(game: &Game) -> Line<'static> {
+    let (done, required) = game.versatility_progress();
+    let mut spans = vec![
+        Span::raw("Versatility: "),
+        Span::styled(
+            format!("{done}/{required} tags today"),
+            if done >= required {
+                Style::default().fg(Color::LightGreen)
+            } else {
+                Style::default()
+            },
+        ),
+    ];
+    if game.state.unlocked_tags.len() < required {
+        spans.push(Span::styled(
+            format!("  •  unlock {required} tags to qualify"),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    if game.state.statistics.versatility_active {
+        spans.push(Span::styled(
+            "  •  dividend boost active",
+            Style::default().fg(Color::LightGreen),
+        ));
     }
-    frame.render_widget(List::new(items), log_area);
+    Line::from(spans)
 }
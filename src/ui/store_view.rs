@@ -1,8 +1,16 @@
+use super::centered_rect;
 use crate::app::App;
-use crate::sim::game::{Game, StoreAction};
-use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use crate::sim::economy;
+use crate::sim::game::{
+    ANGEL_UNLOCK_CREDITS, Game, ModelComparison, REPAIR_WEAR_STEP, StoreAction,
+};
+use crate::sim::jobs;
+use crate::sim::processors::{MAX_DAEMON_FIRMWARE_LEVEL, MAX_HARDENING_CAP, ProcessorState};
+use crate::ui::format::{self, Formatter};
+use ratatui::layout::{Constraint, Direction, Layout};
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
+use std::cmp::Ordering;
 
 pub fn render(frame: &mut Frame, app: &App, game: &Game) {
     let area = centered_rect(60, 70, frame.size());
@@ -15,14 +23,33 @@ pub fn render(frame: &mut Frame, app: &App, game: &Game) {
 
     let layout = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Min(5), Constraint::Length(3)])
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(5),
+            Constraint::Length(3),
+        ])
         .split(inner);
 
-    let processor_index = if game.state.processors.is_empty() {
-        None
-    } else {
-        Some(app.selected_processor.min(game.state.processors.len() - 1))
+    let fmt = Formatter::of(game);
+    let banner = match game.store_recommendation() {
+        Some(pick) => Line::from(vec![
+            Span::styled("Recommended: ", Style::default().fg(Color::LightGreen)),
+            Span::raw(format!(
+                "{} for {} (unit {}) (payback ~{:.0} days)  [G] go",
+                pick.item_name,
+                game.state.processors[pick.processor_index].name,
+                pick.processor_index + 1,
+                pick.payback_days.ceil()
+            )),
+        ]),
+        None => Line::from(Span::styled(
+            "No upgrade recommendation yet — complete a few jobs first.",
+            Style::default().fg(Color::DarkGray),
+        )),
     };
+    frame.render_widget(Paragraph::new(banner), layout[0]);
+
+    let processor_index = app.processor_selection(game);
 
     let mut items: Vec<ListItem> = Vec::new();
     for (idx, item) in game.store_items().iter().enumerate() {
@@ -34,6 +61,10 @@ pub fn render(frame: &mut Frame, app: &App, game: &Game) {
                     status_note = Some("Cooling maxed".to_string());
                     None
                 }
+                Some(proc) if proc.cooling_worn > 0 => {
+                    status_note = Some(format!("Recharge: {} worn level(s)", proc.cooling_worn));
+                    game.item_cost(idx, processor_index)
+                }
                 Some(_) => game.item_cost(idx, processor_index),
                 None => {
                     status_note = Some("Select a processor".to_string());
@@ -42,11 +73,14 @@ pub fn render(frame: &mut Frame, app: &App, game: &Game) {
             },
             StoreAction::UpgradeHardening => match processor {
                 Some(proc) => {
-                    if proc.hardening_level >= 3 {
+                    if proc.hardening_level >= proc.hardening_cap {
                         status_note = Some("Hardening maxed".to_string());
                         None
                     } else {
-                        status_note = Some(format!("Hardening level {}", proc.hardening_level));
+                        status_note = Some(format!(
+                            "Hardening level {}/{}",
+                            proc.hardening_level, proc.hardening_cap
+                        ));
                         game.item_cost(idx, processor_index)
                     }
                 }
@@ -55,9 +89,32 @@ pub fn render(frame: &mut Frame, app: &App, game: &Game) {
                     None
                 }
             },
-            StoreAction::InstallDaemonFirmware => match processor {
-                Some(proc) if proc.daemon_unlocked => {
-                    status_note = Some("Firmware installed".to_string());
+            StoreAction::InstallInstruction { tag } => match processor {
+                Some(_) if !game.is_instruction_unlocked(tag) => {
+                    status_note = Some(if tag == jobs::ANGEL_TAG {
+                        format!("Opens at {} cr earned", fmt.number(ANGEL_UNLOCK_CREDITS))
+                    } else {
+                        format!("License {tag} first")
+                    });
+                    None
+                }
+                Some(proc) if proc.supports(tag) => {
+                    status_note = Some("Installed".to_string());
+                    None
+                }
+                Some(_) => game.item_cost(idx, processor_index),
+                None => {
+                    status_note = Some("Select a processor".to_string());
+                    None
+                }
+            },
+            StoreAction::ReinforceChassis => match processor {
+                Some(proc) if proc.hardening_cap >= MAX_HARDENING_CAP => {
+                    status_note = Some("Chassis maxed".to_string());
+                    None
+                }
+                Some(proc) if proc.hardening_level < proc.hardening_cap => {
+                    status_note = Some(format!("Harden to {} first", proc.hardening_cap));
                     None
                 }
                 Some(_) => game.item_cost(idx, processor_index),
@@ -66,7 +123,78 @@ pub fn render(frame: &mut Frame, app: &App, game: &Game) {
                     None
                 }
             },
+            StoreAction::InstallDaemonFirmware => match processor {
+                Some(proc)
+                    if proc.daemon_firmware_level >= MAX_DAEMON_FIRMWARE_LEVEL
+                        && !proc.daemon_trial =>
+                {
+                    status_note = Some("Firmware maxed".to_string());
+                    None
+                }
+                Some(proc) => {
+                    if proc.daemon_unlocked() && !proc.daemon_trial {
+                        status_note = Some(format!(
+                            "Firmware level {}/{}",
+                            proc.daemon_firmware_level, MAX_DAEMON_FIRMWARE_LEVEL
+                        ));
+                    }
+                    game.item_cost(idx, processor_index)
+                }
+                None => {
+                    status_note = Some("Select a processor".to_string());
+                    None
+                }
+            },
+            StoreAction::ServiceProcessor => match processor {
+                Some(proc) if proc.wear <= 0.0 => {
+                    status_note = Some("No wear".to_string());
+                    None
+                }
+                Some(proc) if !proc.is_functional() => {
+                    status_note = Some("Unit is offline".to_string());
+                    None
+                }
+                Some(proc) => {
+                    status_note = Some(format!(
+                        "{} ({} wear)",
+                        proc.condition().label(),
+                        format::percent(proc.wear.min(1.0))
+                    ));
+                    game.item_cost(idx, processor_index)
+                }
+                None => {
+                    status_note = Some("Select a processor".to_string());
+                    None
+                }
+            },
+            StoreAction::RepairProcessor => match processor {
+                Some(proc) if !proc.is_functional() => {
+                    status_note = Some("Unit is offline".to_string());
+                    None
+                }
+                Some(proc) => {
+                    let cost = game.item_cost(idx, processor_index);
+                    status_note = Some(if cost.is_some() {
+                        format!(
+                            "{} → {} wear",
+                            format::percent(proc.wear.min(1.0)),
+                            format::percent((proc.wear - REPAIR_WEAR_STEP).clamp(0.0, 1.0))
+                        )
+                    } else {
+                        "No wear".to_string()
+                    });
+                    cost
+                }
+                None => {
+                    status_note = Some("Select a processor".to_string());
+                    None
+                }
+            },
             StoreAction::ReplaceProcessor => match processor {
+                Some(proc) if proc.under_warranty() => {
+                    status_note = Some("Covered under warranty".to_string());
+                    game.item_cost(idx, processor_index)
+                }
                 Some(proc) if !proc.is_functional() => game.item_cost(idx, processor_index),
                 Some(_) => {
                     status_note = Some("Unit is operational".to_string());
@@ -77,6 +205,41 @@ pub fn render(frame: &mut Frame, app: &App, game: &Game) {
                     None
                 }
             },
+            StoreAction::InstallFaradayShroud => match processor {
+                Some(proc) if proc.faraday_shroud => {
+                    status_note = Some("Fitted".to_string());
+                    None
+                }
+                Some(_) => game.item_cost(idx, processor_index),
+                None => {
+                    status_note = Some("Select a processor".to_string());
+                    None
+                }
+            },
+            StoreAction::InsureProcessor => match processor {
+                Some(proc) if proc.insured => {
+                    status_note = Some(format!(
+                        "Insured ({}/day)",
+                        fmt.credits(economy::insurance_premium(proc))
+                    ));
+                    None
+                }
+                Some(proc) if !proc.is_functional() => {
+                    status_note = Some("Unit is offline".to_string());
+                    None
+                }
+                Some(proc) => {
+                    status_note = Some(format!(
+                        "Premium {}/day",
+                        fmt.credits(economy::insurance_quote(proc))
+                    ));
+                    game.item_cost(idx, processor_index)
+                }
+                None => {
+                    status_note = Some("Select a processor".to_string());
+                    None
+                }
+            },
             StoreAction::ReplaceModel => match processor {
                 Some(proc) => {
                     let offline = game
@@ -98,12 +261,56 @@ pub fn render(frame: &mut Frame, app: &App, game: &Game) {
                     None
                 }
             },
+            StoreAction::UpgradeRackCooling => match processor {
+                Some(proc) => match proc
+                    .rack_id
+                    .and_then(|rack| game.state.racks.get(rack as usize).map(|r| (rack, r)))
+                {
+                    Some((rack, state)) => {
+                        status_note =
+                            Some(format!("Rack {} at +{}", rack + 1, state.cooling_level));
+                        game.item_cost(idx, processor_index)
+                    }
+                    None => {
+                        status_note = Some("Unit not racked".to_string());
+                        None
+                    }
+                },
+                None => {
+                    status_note = Some("Select a processor".to_string());
+                    None
+                }
+            },
             StoreAction::ApplyThermalPaste => {
                 if game.thermal_paste_active() {
                     status_note = Some("Active this cycle".to_string());
                 }
                 game.item_cost(idx, processor_index)
             }
+            StoreAction::BuyProcessor { model } => {
+                status_note = ProcessorState::of_model(model).map(|unit| {
+                    let cores = if unit.cores > 1 {
+                        format!(" • {} cores", unit.cores)
+                    } else {
+                        String::new()
+                    };
+                    let lifespan = if unit.finite_lifespan {
+                        format!(
+                            " • wears out after ~{} of work",
+                            format::duration_ms(unit.mttf_ticks)
+                        )
+                    } else {
+                        String::new()
+                    };
+                    format!(
+                        "speed {:.2} • upkeep {}/day • {}{cores}{lifespan}",
+                        unit.speed,
+                        fmt.credits(unit.upkeep_cost),
+                        unit.instruction_set.join(", ")
+                    )
+                });
+                game.item_cost(idx, processor_index)
+            }
             _ => game.item_cost(idx, processor_index),
         };
         let purchased = game.store_purchases(idx).unwrap_or(0);
@@ -122,7 +329,7 @@ pub fn render(frame: &mut Frame, app: &App, game: &Game) {
             .add_modifier(Modifier::BOLD);
         line.push(Span::styled(item.name, name_style));
         match cost_opt {
-            Some(cost) => line.push(Span::raw(format!("  [{} cr]", cost))),
+            Some(cost) => line.push(Span::raw(format!("  [{}]", fmt.credits(cost)))),
             None => {
                 let label = status_note.as_deref().unwrap_or("Unavailable");
                 line.push(Span::styled(
@@ -141,21 +348,28 @@ pub fn render(frame: &mut Frame, app: &App, game: &Game) {
             line.push(Span::raw(format!("  (limit {max})")));
         }
         let mut detail_spans = vec![Span::raw(item.description)];
-        if let Some(proc) = processor {
-            if matches!(
+        if let Some(proc) = processor
+            && matches!(
                 item.action,
                 StoreAction::UpgradeCooling
                     | StoreAction::UpgradeHardening
+                    | StoreAction::ReinforceChassis
+                    | StoreAction::InstallInstruction { .. }
                     | StoreAction::InstallDaemonFirmware
+                    | StoreAction::ServiceProcessor
+                    | StoreAction::RepairProcessor
+                    | StoreAction::UpgradeRackCooling
                     | StoreAction::ReplaceProcessor
                     | StoreAction::ReplaceModel
-            ) {
-                detail_spans.push(Span::raw(" • Target: "));
-                detail_spans.push(Span::styled(
-                    proc.name.clone(),
-                    Style::default().fg(Color::LightCyan),
-                ));
-            }
+                    | StoreAction::InsureProcessor
+                    | StoreAction::InstallFaradayShroud
+            )
+        {
+            detail_spans.push(Span::raw(" • Target: "));
+            detail_spans.push(Span::styled(
+                proc.name.clone(),
+                Style::default().fg(Color::LightCyan),
+            ));
         }
         if let Some(note) = status_note {
             detail_spans.push(Span::raw(" • "));
@@ -166,43 +380,175 @@ pub fn render(frame: &mut Frame, app: &App, game: &Game) {
         items.push(list_item);
     }
 
+    let selection = app
+        .selected_store_item
+        .min(game.store_items().len().saturating_sub(1));
+    let comparison = match game.store_items().get(selection).map(|item| item.action) {
+        Some(StoreAction::BuyProcessor { model }) => game.compare_models(model, processor_index),
+        _ => None,
+    };
+    let list_area = match &comparison {
+        Some(comparison) => {
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+                .split(layout[1]);
+            frame.render_widget(comparison_pane(comparison, &fmt), columns[1]);
+            columns[0]
+        }
+        None => layout[1],
+    };
+
     let list = List::new(items)
         .block(Block::default().borders(Borders::ALL).title("Upgrades"))
         .highlight_symbol("▶ ")
         .highlight_style(Style::default().bg(Color::DarkGray).fg(Color::White));
     let mut state = ListState::default();
     if !game.store_items().is_empty() {
-        let selection = app.selected_store_item.min(game.store_items().len() - 1);
         state.select(Some(selection));
     }
-    frame.render_stateful_widget(list, layout[0], &mut state);
+    frame.render_stateful_widget(list, list_area, &mut state);
 
     let footer = Paragraph::new(vec![Line::from(vec![
-        Span::raw(format!("Credits: {}", game.state.credits)),
-        Span::raw("  •  Enter to purchase  •  Esc/S to close"),
+        Span::raw(format!("Credits: {}", fmt.number(game.state.credits))),
+        Span::raw("  •  Enter to purchase  •  T trade in the unit  •  Esc/S to close"),
     ])])
     .wrap(Wrap { trim: true });
-    frame.render_widget(footer, layout[1]);
+    frame.render_widget(footer, layout[2]);
 }
 
-fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(area);
-
-    let vertical = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1]);
+/// The highlighted model's stats beside the selected unit's, green where
+/// buying would be an improvement and red where it would be a step back.
+fn comparison_pane<'a>(comparison: &ModelComparison, fmt: &Formatter) -> Paragraph<'a> {
+    let ModelComparison {
+        candidate, current, ..
+    } = comparison;
+    let mut lines = vec![Line::from(vec![
+        Span::styled(format!("{:<10}", ""), Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            format!("{:<12}", candidate.name.trim_start_matches("Model ")),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            current
+                .as_ref()
+                .map_or("no unit selected".to_string(), |unit| {
+                    format!("now: {}", unit.name.trim_start_matches("Model "))
+                }),
+            Style::default().fg(Color::LightCyan),
+        ),
+    ])];
+    let now = current.as_ref();
+    lines.push(compare_row(
+        "Speed",
+        format!("{:.2}", candidate.speed),
+        now.map(|unit| {
+            (
+                format!("{:.2}", unit.speed),
+                candidate.speed.total_cmp(&unit.speed),
+            )
+        }),
+    ));
+    lines.push(compare_row(
+        "Upkeep",
+        format!("{}/day", fmt.credits(candidate.upkeep)),
+        now.map(|unit| {
+            (
+                format!("{}/day", fmt.credits(unit.upkeep)),
+                unit.upkeep.cmp(&candidate.upkeep),
+            )
+        }),
+    ));
+    lines.push(compare_row(
+        "Power",
+        format!("{:.1}", candidate.power_draw),
+        now.map(|unit| {
+            (
+                format!("{:.1}", unit.power_draw),
+                unit.power_draw.total_cmp(&candidate.power_draw),
+            )
+        }),
+    ));
+    lines.push(compare_row(
+        "Cooling cap",
+        candidate.cooling_cap.to_string(),
+        now.map(|unit| {
+            (
+                unit.cooling_cap.to_string(),
+                candidate.cooling_cap.cmp(&unit.cooling_cap),
+            )
+        }),
+    ));
+    if let Some(job) = &comparison.pending_job {
+        let eta = |eta: Option<u64>| eta.map_or("can't run".to_string(), format::duration_ms);
+        lines.push(compare_row(
+            "Pending",
+            eta(candidate.pending_eta_ms),
+            now.map(|unit| {
+                // A unit that can't run the job at all loses to one that can.
+                let order = match (candidate.pending_eta_ms, unit.pending_eta_ms) {
+                    (Some(new), Some(old)) => old.cmp(&new),
+                    (new, old) => new.is_some().cmp(&old.is_some()),
+                };
+                (eta(unit.pending_eta_ms), order)
+            }),
+        ));
+        lines.push(Line::from(Span::styled(
+            format!("{:<10}{job}", ""),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    lines.push(Line::from(""));
+    let mut tags = vec![Span::styled(
+        format!("{:<10}", "Tags"),
+        Style::default().fg(Color::DarkGray),
+    )];
+    for tag in &candidate.instruction_set {
+        let style = match now {
+            Some(unit) if !unit.instruction_set.contains(tag) => {
+                Style::default().fg(Color::LightGreen)
+            }
+            _ => Style::default(),
+        };
+        tags.push(Span::styled(format!("{tag} "), style));
+    }
+    if let Some(unit) = now {
+        for tag in unit
+            .instruction_set
+            .iter()
+            .filter(|tag| !candidate.instruction_set.contains(tag))
+        {
+            tags.push(Span::styled(
+                format!("-{tag} "),
+                Style::default().fg(Color::LightRed),
+            ));
+        }
+    }
+    lines.push(Line::from(tags));
+    Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title("Compare"))
+        .wrap(Wrap { trim: true })
+}
 
-    vertical[1]
+/// One stat: the candidate's value, colored by how it orders against the
+/// current unit's (`Greater` means the candidate is better), then the
+/// current value.
+fn compare_row<'a>(
+    label: &str,
+    candidate: String,
+    current: Option<(String, Ordering)>,
+) -> Line<'a> {
+    let style = match current.as_ref().map(|(_, order)| order) {
+        Some(Ordering::Greater) => Style::default().fg(Color::LightGreen),
+        Some(Ordering::Less) => Style::default().fg(Color::LightRed),
+        _ => Style::default(),
+    };
+    let mut spans = vec![
+        Span::styled(format!("{label:<10}"), Style::default().fg(Color::DarkGray)),
+        Span::styled(format!("{candidate:<12}"), style),
+    ];
+    if let Some((current, _)) = current {
+        spans.push(Span::raw(current));
+    }
+    Line::from(spans)
 }
@@ -0,0 +1,182 @@
+use crate::sim::game::GameState;
+use crate::sim::processors::{ProcessorState, ProcessorStatus};
+use crate::ui::format::{self, Formatter};
+use std::fmt::Write;
+
+/// Events included at the bottom of a snapshot.
+pub const SUMMARY_EVENTS: usize = 5;
+
+/// Plain-text run snapshot wrapped in a code fence so chat and forum
+/// renderers keep the table columns aligned.
+pub fn render(state: &GameState, recent_events: &[String]) -> String {
+    let fmt = Formatter::new(state.display);
+    let mut out = String::new();
+    out.push_str("```\n");
+    let _ = writeln!(out, "Array of Babel — Day {}", state.statistics.day());
+    let _ = writeln!(
+        out,
+        "Credits: {}   Loadout: {}",
+        fmt.credits(state.credits),
+        state.loadout.label()
+    );
+    if !state.mutators.is_empty() {
+        let labels: Vec<&str> = state.mutators.iter().map(|m| m.label()).collect();
+        let _ = writeln!(out, "Mutators: {}", labels.join(", "));
+    }
+    let _ = writeln!(
+        out,
+        "Storage: {}/{} units   Tags: {}",
+        fmt.number(state.storage.stored),
+        fmt.number(state.storage.capacity),
+        tag_list(state)
+    );
+
+    out.push_str("\nFleet\n");
+    let _ = writeln!(
+        out,
+        "{:>3}  {:<18} {:<9} {:>5} {:>5} {:>5} {:>4} {:>5} {:>6}",
+        "#", "Model", "Status", "Speed", "Rel", "Cool", "Hard", "Wear", "Jobs"
+    );
+    for (index, processor) in state.processors.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "{:>3}  {:<18} {:<9} {:>5.2} {:>5} {:>5} {:>4} {:>5} {:>6}",
+            index + 1,
+            truncate(&processor.name, 18),
+            status_label(processor),
+            processor.speed,
+            format::percent(processor.reliability_base),
+            format!("{}/{}", processor.cooling_level, processor.cooling_cap()),
+            processor.hardening_level,
+            format::percent(processor.wear.min(1.0)),
+            fmt.number(processor.jobs_completed),
+        );
+    }
+
+    out.push_str("\nActive contracts\n");
+    let mut contracts = 0;
+    for (index, processor) in state.processors.iter().enumerate() {
+        for work in processor.slots().filter(|work| work.job.escrow > 0) {
+            contracts += 1;
+            let _ = writeln!(
+                out,
+                "  {} on unit {} — {} escrowed, pays {}",
+                work.job.name,
+                index + 1,
+                fmt.credits(work.job.escrow),
+                fmt.credits(work.job.base_reward)
+            );
+        }
+    }
+    if contracts == 0 {
+        out.push_str("  none\n");
+    }
+
+    out.push_str("\nRecent events\n");
+    let skip = recent_events.len().saturating_sub(SUMMARY_EVENTS);
+    if recent_events.is_empty() {
+        out.push_str("  none recorded\n");
+    }
+    for event in &recent_events[skip..] {
+        let _ = writeln!(out, "  - {event}");
+    }
+    out.push_str("```\n");
+    out
+}
+
+fn tag_list(state: &GameState) -> String {
+    state
+        .unlocked_tags
+        .iter()
+        .map(|tag| match state.statistics.tag_rank(tag) {
+            Some(rank) => format!("{tag} ({})", rank.label()),
+            None => tag.clone(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn status_label(processor: &ProcessorState) -> &'static str {
+    match &processor.status {
+        ProcessorStatus::Idle if processor.paused => "Paused",
+        ProcessorStatus::Idle => "Idle",
+        ProcessorStatus::Working(work) if work.paused => "Paused",
+        ProcessorStatus::Working(_) => "Working",
+        ProcessorStatus::Servicing { .. } => "Servicing",
+        ProcessorStatus::BurntOut => "Burnt out",
+        ProcessorStatus::Destroyed => "Destroyed",
+    }
+}
+
+fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        text.to_string()
+    } else {
+        let mut cut: String = text.chars().take(width - 1).collect();
+        cut.push('…');
+        cut
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::jobs::Job;
+
+    const GOLDEN: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/summary.golden");
+
+    fn snapshot_state() -> GameState {
+        let mut state = GameState {
+            credits: 12_500,
+            ..GameState::default()
+        };
+        state.statistics.days_elapsed = 6;
+        state
+            .statistics
+            .completions_by_tag
+            .insert("GENERAL".to_string(), 12);
+        state.storage.stored = 48;
+        let mut second = ProcessorState::starter();
+        second.name = "Model F12-Rugged-Extended".to_string();
+        second.hardening_level = 2;
+        second.wear = 0.35;
+        second.jobs_completed = 1_204;
+        second.assign(
+            Job {
+                id: 9,
+                name: "SIMD Workload #9".to_string(),
+                base_reward: 220,
+                escrow: 44,
+                ..Job::default()
+            },
+            8_000,
+            None,
+        );
+        state.processors.push(second);
+        state.processors.push({
+            let mut burnt = ProcessorState::starter();
+            burnt.status = ProcessorStatus::BurntOut;
+            burnt
+        });
+        state
+    }
+
+    #[test]
+    fn summary_matches_golden_file() {
+        let events: Vec<String> = (1..=7).map(|n| format!("event {n}")).collect();
+        let rendered = render(&snapshot_state(), &events);
+        if std::env::var_os("BLESS").is_some() {
+            std::fs::write(GOLDEN, &rendered).expect("golden file written");
+        }
+        let expected = std::fs::read_to_string(GOLDEN).expect("golden file present");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn summary_without_events_or_contracts_says_so() {
+        let rendered = render(&GameState::default(), &[]);
+        assert!(rendered.contains("Active contracts\n  none\n"));
+        assert!(rendered.contains("Recent events\n  none recorded\n"));
+        assert!(!rendered.contains('\x1b'));
+    }
+}
@@ -0,0 +1,32 @@
+use crate::sim::game::{Game, ToastLevel};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+const TOAST_WIDTH: u16 = 44;
+
+pub fn render(frame: &mut Frame, game: &Game) {
+    let size = frame.size();
+    let width = TOAST_WIDTH.min(size.width);
+    let mut y = size.y + 3;
+    for toast in game.toasts() {
+        if y + 3 > size.bottom() {
+            break;
+        }
+        let area = Rect::new(size.right().saturating_sub(width), y, width, 3);
+        frame.render_widget(Clear, area);
+        let (text, border) = match toast.level {
+            ToastLevel::Info => (Color::LightYellow, Color::Yellow),
+            ToastLevel::Critical => (Color::LightRed, Color::Red),
+        };
+        let paragraph = Paragraph::new(toast.message.clone())
+            .wrap(Wrap { trim: true })
+            .style(Style::default().fg(text))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(border)),
+            );
+        frame.render_widget(paragraph, area);
+        y += 3;
+    }
+}
@@ -0,0 +1,72 @@
+use super::centered_rect;
+use crate::app::TradeInPicker;
+use crate::sim::game::Game;
+use crate::sim::processors::ProcessorState;
+use crate::ui::format::Formatter;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
+
+pub fn render(frame: &mut Frame, game: &Game, picker: TradeInPicker) {
+    let Some(processor) = game.state.processors.get(picker.processor_index) else {
+        return;
+    };
+    let area = centered_rect(50, 45, frame.size());
+    frame.render_widget(Clear, area);
+    let block = Block::default()
+        .title(format!(
+            "Trade in unit {} — {}",
+            picker.processor_index + 1,
+            processor.name
+        ))
+        .borders(Borders::ALL);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(3)])
+        .split(inner);
+
+    let fmt = Formatter::of(game);
+    let items: Vec<ListItem> = Game::catalog_models()
+        .into_iter()
+        .map(|model| {
+            let speed = ProcessorState::of_model(model).map_or(0.0, |unit| unit.speed);
+            let price = match game.trade_in_cost(picker.processor_index, model) {
+                Ok(cost) => Span::styled(
+                    fmt.credits(cost),
+                    if game.state.credits >= cost {
+                        Style::default().fg(Color::Yellow)
+                    } else {
+                        Style::default().fg(Color::DarkGray)
+                    },
+                ),
+                Err(err) => Span::styled(err.to_string(), Style::default().fg(Color::DarkGray)),
+            };
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{model:<20} speed {speed:.2}  ")),
+                price,
+            ]))
+        })
+        .collect();
+    let mut state = ListState::default();
+    if !items.is_empty() {
+        state.select(Some(picker.selected.min(items.len() - 1)));
+    }
+    let list = List::new(items)
+        .highlight_style(Style::default().bg(Color::DarkGray))
+        .highlight_symbol("> ");
+    frame.render_stateful_widget(list, layout[0], &mut state);
+
+    let credit = game.resale_value(picker.processor_index).unwrap_or(0);
+    frame.render_widget(
+        Paragraph::new(format!(
+            "Old unit credited {}; the slot keeps its rack, daemon setup and record.  ↑/↓ model  •  Enter trade  •  Esc close",
+            fmt.credits(credit)
+        ))
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::TOP)),
+        layout[1],
+    );
+}
@@ -0,0 +1,48 @@
+//! Balance regression: a fixed seed played by the conservative policy must
+//! keep landing on the committed metrics. When a balance change is
+//! intentional, regenerate the goldens with
+//!
+//! ```sh
+//! BLESS=1 cargo test --test golden
+//! ```
+
+use array_of_babel::sim::harness::{self, RunReport};
+use array_of_babel::sim::policy::Policy;
+
+const GOLDEN: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/golden_run.ron");
+const DAYS: u64 = 50;
+const SEED: u64 = 1234;
+/// Allowed drift in final credits, as a share of the golden value.
+const CREDIT_TOLERANCE: f64 = 0.02;
+/// Allowed drift in completed jobs.
+const COMPLETION_TOLERANCE: u64 = 1;
+
+#[test]
+fn conservative_run_matches_golden_metrics() {
+    let game = harness::simulate(DAYS, SEED, Some(Policy::Conservative));
+    let report = RunReport::of(&game);
+    if std::env::var_os("BLESS").is_some() {
+        let text = ron::ser::to_string_pretty(&report, ron::ser::PrettyConfig::new())
+            .expect("report serializes");
+        std::fs::write(GOLDEN, text + "\n").expect("golden file written");
+    }
+    let golden: RunReport =
+        ron::from_str(&std::fs::read_to_string(GOLDEN).expect("golden file present"))
+            .expect("golden file parses");
+
+    assert_eq!(report.days, golden.days);
+    let credit_slack = (golden.credits as f64 * CREDIT_TOLERANCE).ceil() as u64;
+    assert!(
+        report.credits.abs_diff(golden.credits) <= credit_slack,
+        "credits {} drifted from golden {}",
+        report.credits,
+        golden.credits
+    );
+    assert!(
+        report.completions.abs_diff(golden.completions) <= COMPLETION_TOLERANCE,
+        "completions {} drifted from golden {}",
+        report.completions,
+        golden.completions
+    );
+    assert_eq!(report.burnouts, golden.burnouts, "burnouts drifted");
+}
@@ -0,0 +1,386 @@
+use array_of_babel::persist::{load_game_from, meta_path_for, read_meta, save_game_to};
+use array_of_babel::sim::game::{Game, GameState, MAX_FRAME_DELTA};
+use std::time::Duration;
+
+#[test]
+fn save_and_load_round_trip() {
+    let mut game = Game::fresh();
+    game.state.seed_starter_board(3);
+    game.queue_pending(0).expect("queues a starter job");
+    game.update(Duration::from_secs(5));
+    game.add_message("Unit 1 burnt out just before quitting.");
+
+    let path = std::env::temp_dir().join(format!("array-of-babel-{}.ron", std::process::id()));
+    let path = path.to_str().expect("utf-8 temp path");
+    save_game_to(&game.state, path).expect("saves");
+    let loaded: GameState = load_game_from(path).expect("reads").expect("save exists");
+    let meta = read_meta(path).expect("summary written beside the save");
+    std::fs::remove_file(path).ok();
+    std::fs::remove_file(meta_path_for(path.as_ref())).ok();
+    assert_eq!(meta.credits, game.state.credits);
+    assert_eq!(meta.day, 1);
+    assert_eq!(meta.processors, game.state.processors.len());
+
+    assert_eq!(
+        ron::to_string(&loaded).expect("serializes"),
+        ron::to_string(&game.state).expect("serializes")
+    );
+    let restored = Game::from_state(loaded);
+    assert!(restored.quarantine_notices().is_empty());
+    assert!(restored.messages().eq(game.messages()));
+    assert_eq!(
+        restored.state.pending_jobs.first().map(|job| job.id),
+        game.state.pending_jobs.first().map(|job| job.id)
+    );
+}
+
+#[test]
+fn missing_save_loads_as_none() {
+    let path = std::env::temp_dir().join("array-of-babel-missing.ron");
+    let loaded = load_game_from(path.to_str().expect("utf-8 temp path")).expect("reads");
+    assert!(loaded.is_none());
+}
+
+#[test]
+fn care_package_moves_between_saves_once() {
+    use array_of_babel::persist::{export_package, import_package};
+
+    let path = std::env::temp_dir().join(format!("array-of-babel-pkg-{}.ron", std::process::id()));
+    let save = path.with_extension("sender.ron");
+    let mut sender = GameState {
+        credits: 400,
+        ..GameState::default()
+    };
+    export_package(&mut sender, &save, 120, 0, &path).expect("exports");
+    assert_eq!(sender.credits, 280);
+    let saved = load_game_from(&save).expect("reads").expect("debit saved");
+    std::fs::remove_file(&save).ok();
+    std::fs::remove_file(meta_path_for(&save)).ok();
+    assert_eq!(saved.credits, 280);
+    let path = path.as_path();
+
+    let mut receiver = GameState::default();
+    let delivery = import_package(&mut receiver, path).expect("imports");
+    assert_eq!(delivery.credits, 60);
+    assert!(import_package(&mut receiver, path).is_err());
+
+    let tampered = std::fs::read_to_string(path)
+        .expect("reads")
+        .replace("credits: 120", "credits: 200");
+    std::fs::write(path, tampered).expect("writes");
+    let mut other = GameState::default();
+    let err = import_package(&mut other, path).expect_err("signature rejects edits");
+    std::fs::remove_file(path).ok();
+    assert!(err.to_string().contains("signature"));
+}
+
+#[test]
+fn care_package_is_not_published_when_the_debit_cannot_be_saved() {
+    use array_of_babel::persist::export_package;
+
+    let dir = std::env::temp_dir();
+    let blocker = dir.join(format!("array-of-babel-blocker-{}", std::process::id()));
+    std::fs::write(&blocker, "not a directory").expect("writes");
+    let path = dir.join(format!("array-of-babel-unsent-{}.ron", std::process::id()));
+    let mut sender = GameState {
+        credits: 400,
+        ..GameState::default()
+    };
+
+    let result = export_package(&mut sender, &blocker.join("save.ron"), 120, 0, &path);
+    std::fs::remove_file(&blocker).ok();
+    assert!(result.is_err());
+    assert!(!path.exists());
+}
+
+#[test]
+fn waking_from_sleep_matches_a_save_and_load_of_the_same_gap() {
+    let mut state = GameState::default();
+    state.seed_starter_board(7);
+    let mut slept = Game::seeded(state.clone(), 7);
+    let mut saved = Game::seeded(state, 7);
+    for game in [&mut slept, &mut saved] {
+        game.queue_pending(0).expect("queues a starter job");
+        game.assign_pending(0).expect("assigns");
+    }
+
+    let gap = Duration::from_secs(3 * 60 * 60);
+    slept.update(MAX_FRAME_DELTA + gap);
+    saved.update(MAX_FRAME_DELTA);
+
+    let path =
+        std::env::temp_dir().join(format!("array-of-babel-sleep-{}.ron", std::process::id()));
+    let path = path.to_str().expect("utf-8 temp path");
+    save_game_to(&saved.state, path).expect("saves");
+    let loaded: GameState = load_game_from(path).expect("reads").expect("save exists");
+    std::fs::remove_file(path).ok();
+    std::fs::remove_file(meta_path_for(path.as_ref())).ok();
+    let resumed = Game::from_state(loaded);
+
+    // Only the logs differ: one run says it slept.
+    let without_log = |state: &GameState| GameState {
+        recent_messages: Vec::new(),
+        ..state.clone()
+    };
+    assert_eq!(
+        ron::to_string(&without_log(&slept.state)).expect("serializes"),
+        ron::to_string(&without_log(&resumed.state)).expect("serializes")
+    );
+    assert!(
+        slept
+            .messages()
+            .any(|message| message.starts_with("System slept for 3h 0m")
+                && message.ends_with("that time was skipped."))
+    );
+}
+
+#[test]
+fn old_save_versions_migrate_to_the_current_schema() {
+    use array_of_babel::sim::game::SAVE_VERSION;
+    use array_of_babel::sim::processors::{ClockMode, DaemonMode};
+
+    let v0 = load_game_from("testdata/save_v0.ron")
+        .expect("reads v0")
+        .expect("fixture exists");
+    assert_eq!(v0.version, SAVE_VERSION);
+    assert!(!v0.daemon_enabled);
+    assert!(
+        v0.processors
+            .iter()
+            .all(|p| p.daemon_mode == DaemonMode::Auto && p.daemon_unlocked())
+    );
+
+    let v1 = load_game_from("testdata/save_v1.ron")
+        .expect("reads v1")
+        .expect("fixture exists");
+    assert_eq!(v1.version, SAVE_VERSION);
+    assert!(
+        v1.processors
+            .iter()
+            .all(|p| p.daemon_mode == DaemonMode::Off && p.daemon_unlocked())
+    );
+
+    let v2 = load_game_from("testdata/save_v2.ron")
+        .expect("reads v2")
+        .expect("fixture exists");
+    assert_eq!(v2.version, SAVE_VERSION);
+    assert_eq!(v2.processors[0].clock, ClockMode::Overclock);
+    assert!(!v2.processors[0].overclocked);
+
+    let v3 = load_game_from("testdata/save_v3.ron")
+        .expect("reads v3")
+        .expect("fixture exists");
+    assert_eq!(v3.version, SAVE_VERSION);
+    assert_eq!(v3.processors[0].daemon_firmware_level, 1);
+    assert!(!v3.processors[0].daemon_unlocked);
+
+    let v4 = load_game_from("testdata/save_v4.ron")
+        .expect("reads v4")
+        .expect("fixture exists");
+    assert_eq!(v4.version, SAVE_VERSION);
+    assert!(v4.pending_job.is_none());
+    assert_eq!(
+        v4.pending_jobs.iter().map(|job| job.id).collect::<Vec<_>>(),
+        vec![4]
+    );
+}
+
+#[test]
+fn saves_from_a_newer_build_are_refused_clearly() {
+    use array_of_babel::persist::MigrationError;
+
+    let path =
+        std::env::temp_dir().join(format!("array-of-babel-future-{}.ron", std::process::id()));
+    let path = path.to_str().expect("utf-8 temp path");
+    let future = std::fs::read_to_string("testdata/save_v1.ron")
+        .expect("reads fixture")
+        .replace("version: 1,", "version: 99,\n    hologram_bay: Some(3),");
+    std::fs::write(path, future).expect("writes");
+    let err = load_game_from(path).expect_err("newer saves are rejected");
+    std::fs::remove_file(path).ok();
+    assert!(matches!(
+        err.downcast_ref::<MigrationError>(),
+        Some(MigrationError::NewerVersion { found: 99, .. })
+    ));
+}
+
+#[test]
+fn corrupt_saves_are_reported_and_set_aside() {
+    use array_of_babel::persist::{SaveSlot, read_save, set_aside_corrupt};
+
+    let full = std::fs::read_to_string("testdata/save_v1.ron").expect("reads fixture");
+    let payloads = [
+        ("truncated", full[..full.len() / 2].to_string()),
+        ("invalid", full.replace("credits: 120,", "credits: [120,")),
+    ];
+    for (name, payload) in payloads {
+        let path =
+            std::env::temp_dir().join(format!("array-of-babel-{name}-{}.ron", std::process::id()));
+        std::fs::write(&path, payload).expect("writes");
+        let slot = read_save(&path).expect("corruption is not an I/O error");
+        assert!(
+            matches!(slot, SaveSlot::Corrupt(_)),
+            "{name} save: {slot:?}"
+        );
+        assert!(load_game_from(&path).is_err());
+
+        let aside = set_aside_corrupt(&path).expect("renames");
+        assert!(!path.exists());
+        assert!(
+            aside
+                .to_str()
+                .is_some_and(|aside| aside.contains(".ron.corrupt-"))
+        );
+        assert!(matches!(read_save(&path), Ok(SaveSlot::Missing)));
+        std::fs::remove_file(aside).ok();
+    }
+}
+
+#[test]
+fn hand_edited_saves_load_but_are_flagged() {
+    use array_of_babel::persist::{SaveSlot, read_save};
+
+    let tampered = |path: &std::path::Path| match read_save(path).expect("reads") {
+        SaveSlot::Loaded { tampered, .. } => tampered,
+        other => panic!("expected a loaded save, got {other:?}"),
+    };
+    assert!(!tampered("testdata/save_v1.ron".as_ref()));
+
+    let path =
+        std::env::temp_dir().join(format!("array-of-babel-edited-{}.ron", std::process::id()));
+    let state = GameState {
+        credits: 120,
+        ..GameState::default()
+    };
+    save_game_to(&state, &path).expect("saves");
+    assert!(!tampered(&path));
+
+    let edited = std::fs::read_to_string(&path)
+        .expect("reads")
+        .replace("credits: 120", "credits: 99999");
+    std::fs::write(&path, edited).expect("writes");
+    assert!(tampered(&path));
+    let loaded = load_game_from(&path).expect("still loads").expect("exists");
+    std::fs::remove_file(&path).ok();
+    std::fs::remove_file(meta_path_for(&path)).ok();
+    assert_eq!(loaded.credits, 99999);
+
+    let mut game = Game::from_state(loaded);
+    game.mark_tampered();
+    assert!(game.tampered());
+}
+
+#[test]
+fn compressed_saves_round_trip_and_garbage_after_them_is_corrupt() {
+    use array_of_babel::persist::{SaveSlot, gz_path_for, read_save};
+
+    let path = std::env::temp_dir().join(format!("array-of-babel-gz-{}.ron", std::process::id()));
+    let gz = gz_path_for(&path);
+    let mut state = GameState {
+        credits: 777,
+        ..GameState::default()
+    };
+    state.seed_starter_board(5);
+
+    save_game_to(&state, &path).expect("saves plain");
+    assert!(path.is_file() && !gz.exists());
+
+    state.compress_saves = true;
+    save_game_to(&state, &path).expect("saves compressed");
+    assert!(gz.is_file() && !path.exists(), "the plain copy is replaced");
+    let bytes = std::fs::read(&gz).expect("reads");
+    assert_eq!(bytes[..2], [0x1f, 0x8b]);
+    let loaded = load_game_from(&path).expect("reads").expect("exists");
+    assert_eq!(
+        ron::to_string(&loaded).expect("serializes"),
+        ron::to_string(&state).expect("serializes")
+    );
+
+    let mut damaged = bytes;
+    damaged.extend_from_slice(b"trailing garbage");
+    std::fs::write(&gz, damaged).expect("writes");
+    let slot = read_save(&path).expect("corruption is not an I/O error");
+    std::fs::remove_file(&gz).ok();
+    std::fs::remove_file(meta_path_for(&path)).ok();
+    assert!(matches!(slot, SaveSlot::Corrupt(_)), "{slot:?}");
+}
+
+#[test]
+fn crash_saves_sit_beside_the_save_without_replacing_it() {
+    use array_of_babel::persist::{crash_save_for, write_crash_save};
+
+    let path =
+        std::env::temp_dir().join(format!("array-of-babel-crash-{}.ron", std::process::id()));
+    let good = GameState {
+        credits: 500,
+        ..GameState::default()
+    };
+    save_game_to(&good, &path).expect("saves");
+    let broken = GameState {
+        credits: 1,
+        ..GameState::default()
+    };
+    let crash = write_crash_save(&broken, &path).expect("dumps");
+    assert_eq!(crash, crash_save_for(&path));
+
+    let kept = load_game_from(&path).expect("reads").expect("exists");
+    let dumped = load_game_from(&crash).expect("reads").expect("exists");
+    for file in [&path, &crash, &meta_path_for(&path)] {
+        std::fs::remove_file(file).ok();
+    }
+    assert_eq!(kept.credits, 500);
+    assert_eq!(dumped.credits, 1);
+}
+
+#[test]
+fn lost_hardcore_runs_leave_no_save_behind() {
+    use array_of_babel::persist::{delete_save, gz_path_for};
+
+    let path = std::env::temp_dir().join(format!(
+        "array-of-babel-hardcore-{}.ron",
+        std::process::id()
+    ));
+    let mut state = GameState {
+        hardcore: true,
+        ..GameState::default()
+    };
+    save_game_to(&state, &path).expect("saves");
+    let loaded = load_game_from(&path).expect("reads").expect("exists");
+    assert!(loaded.hardcore);
+
+    state.compress_saves = true;
+    save_game_to(&state, &path).expect("saves compressed");
+    delete_save(&path).expect("erases");
+    assert!(!path.exists() && !gz_path_for(&path).exists());
+    assert!(!meta_path_for(&path).exists());
+    assert!(load_game_from(&path).expect("reads").is_none());
+    delete_save(&path).expect("nothing left to erase is fine");
+}
+
+#[test]
+fn saves_taken_mid_maintenance_resume_servicing() {
+    use array_of_babel::sim::processors::ProcessorStatus;
+
+    let path =
+        std::env::temp_dir().join(format!("array-of-babel-maint-{}.ron", std::process::id()));
+    let mut state = GameState::default();
+    state.processors[0].status = ProcessorStatus::Servicing {
+        remaining_ms: 2_500,
+    };
+    state.processors[0].wear = 0.4;
+    save_game_to(&state, &path).expect("saves");
+    let loaded = load_game_from(&path).expect("reads").expect("exists");
+    std::fs::remove_file(&path).ok();
+    std::fs::remove_file(meta_path_for(&path)).ok();
+    assert!(matches!(
+        loaded.processors[0].status,
+        ProcessorStatus::Servicing {
+            remaining_ms: 2_500
+        }
+    ));
+
+    let mut game = Game::from_state(loaded);
+    game.update(Duration::from_secs(3));
+    assert!(game.state.processors[0].is_idle());
+    assert!(game.state.processors[0].wear < 0.4);
+}
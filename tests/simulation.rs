@@ -0,0 +1,113 @@
+use array_of_babel::TICK_RATE;
+use array_of_babel::sim::game::{DAY_DURATION, Game};
+use array_of_babel::sim::processors::DaemonMode;
+
+#[test]
+fn fresh_game_runs_ten_days_without_violations() {
+    let mut game = Game::fresh();
+    game.state.seed_starter_board(11);
+    let processor = &mut game.state.processors[0];
+    processor.daemon_firmware_level = 1;
+    processor.daemon_mode = DaemonMode::Auto;
+
+    let ticks = 10 * DAY_DURATION.as_millis() / TICK_RATE.as_millis();
+    for _ in 0..ticks {
+        game.update(TICK_RATE);
+    }
+
+    assert_eq!(game.state.statistics.days_elapsed, 10);
+    assert!(game.state.job_counter > 3, "the board kept spawning work");
+    assert_eq!(game.audit(), 0);
+}
+
+#[test]
+fn experimental_units_wear_out_for_good_and_are_bulk_replaced() {
+    use array_of_babel::persist::{SaveSlot, meta_path_for, read_save, save_game_to};
+    use array_of_babel::sim::game::StoreAction;
+    use array_of_babel::sim::processors::ProcessorStatus;
+
+    let mut game = Game::fresh();
+    game.state.seed_starter_board(7);
+    game.state.credits = 1_000_000;
+    game.state.processors.clear();
+    let model = "Model X1-Prototype";
+    let buy = Game::store_index_for(StoreAction::BuyProcessor { model }).expect("sold");
+    for _ in 0..4 {
+        game.purchase_item(buy, None).expect("buys");
+    }
+    for unit in &mut game.state.processors {
+        unit.cooling_level = unit.cooling_cap();
+        unit.daemon_firmware_level = 1;
+        unit.daemon_mode = DaemonMode::Auto;
+    }
+    let replace_model = Game::store_index_for(StoreAction::ReplaceModel).expect("sold");
+
+    let ticks = 40 * DAY_DURATION.as_millis() / TICK_RATE.as_millis();
+    let mut destroyed = None;
+    for _ in 0..ticks {
+        game.update(TICK_RATE);
+        destroyed = game
+            .state
+            .processors
+            .iter()
+            .position(|unit| matches!(unit.status, ProcessorStatus::Destroyed));
+        if destroyed.is_some() {
+            break;
+        }
+        // Burnt units go back in service so wear keeps building elsewhere.
+        if let Some(offline) = game
+            .state
+            .processors
+            .iter()
+            .position(|unit| !unit.is_functional())
+        {
+            game.purchase_item(replace_model, Some(offline))
+                .expect("replaces");
+        }
+    }
+
+    let destroyed = destroyed.expect("a unit wore out within 40 days");
+    assert!(game.state.processors[destroyed].wear >= 1.0);
+    assert!(game.item_cost(replace_model, Some(destroyed)).is_some());
+    game.purchase_item(replace_model, Some(destroyed))
+        .expect("replaces");
+    assert!(
+        game.state
+            .processors
+            .iter()
+            .all(|unit| unit.is_functional())
+    );
+
+    // Both experimental models come back as themselves after a restart.
+    let vector = StoreAction::BuyProcessor {
+        model: "Model X7-Vector",
+    };
+    game.purchase_item(Game::store_index_for(vector).expect("sold"), None)
+        .expect("buys");
+    let path = std::env::temp_dir().join(format!(
+        "array-of-babel-experimental-{}.ron",
+        std::process::id()
+    ));
+    save_game_to(&game.state, &path).expect("saves");
+    let slot = read_save(&path).expect("reads");
+    std::fs::remove_file(&path).ok();
+    std::fs::remove_file(meta_path_for(&path)).ok();
+    let SaveSlot::Loaded { state, .. } = slot else {
+        panic!("save exists");
+    };
+    let reloaded = Game::from_state(*state);
+    assert!(reloaded.quarantine_notices().is_empty());
+    let models = |game: &Game| -> Vec<(String, bool, u64)> {
+        game.state
+            .processors
+            .iter()
+            .map(|unit| (unit.name.clone(), unit.finite_lifespan, unit.mttf_ticks))
+            .collect()
+    };
+    assert_eq!(models(&reloaded), models(&game));
+    assert_eq!(
+        models(&reloaded).last().map(|(name, ..)| name.as_str()),
+        Some("Model X7-Vector")
+    );
+    assert!(models(&reloaded).iter().all(|(_, finite, _)| *finite));
+}
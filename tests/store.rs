@@ -0,0 +1,89 @@
+use array_of_babel::persist::{load_game_from, meta_path_for, save_game_to};
+use array_of_babel::sim::game::{Game, PurchaseError, StoreAction};
+use array_of_babel::sim::jobs::SIMD_TAG;
+
+fn item(game: &Game, name: &str) -> usize {
+    game.store_items()
+        .iter()
+        .position(|item| item.name == name)
+        .expect("catalog item")
+}
+
+#[test]
+fn purchase_sequence_scales_costs_and_applies_effects() {
+    let mut game = Game::fresh();
+    game.state.credits = 2_000;
+    let tuning = item(&game, "Clock Tuning");
+    let microcode = item(&game, "Instruction Microcode");
+    let install = item(&game, "SIMD Microcode Install");
+    let speed = game.state.processors[0].speed;
+
+    let first = game.item_cost(tuning, None).expect("priced");
+    game.purchase_item(tuning, None).expect("buys tuning");
+    let second = game.item_cost(tuning, None).expect("priced");
+    game.purchase_item(tuning, None).expect("buys tuning again");
+    assert!(second > first);
+    assert!((game.state.processors[0].speed - speed - 0.1).abs() < 1e-9);
+
+    assert!(matches!(
+        game.purchase_item(install, Some(0)),
+        Err(PurchaseError::InstructionLocked { .. })
+    ));
+    game.purchase_item(microcode, None).expect("buys microcode");
+    assert!(!game.state.processors[0].supports(SIMD_TAG));
+    assert!(matches!(
+        game.purchase_item(microcode, None),
+        Err(PurchaseError::MaxedOut { .. })
+    ));
+    game.purchase_item(install, Some(0))
+        .expect("installs on one unit");
+    assert!(game.state.processors[0].supports(SIMD_TAG));
+    assert!(matches!(
+        game.purchase_item(install, Some(0)),
+        Err(PurchaseError::InstructionAlreadyInstalled { .. })
+    ));
+
+    let spent = first + second + 260 + 90;
+    assert_eq!(game.state.credits, 2_000 - spent);
+    assert_eq!(game.store_purchases(tuning), Some(2));
+}
+
+#[test]
+fn every_store_model_survives_a_save_and_reload() {
+    let mut game = Game::fresh();
+    game.state.credits = 10_000;
+    let models: Vec<&str> = game
+        .store_items()
+        .iter()
+        .filter_map(|item| match item.action {
+            StoreAction::BuyProcessor { model } => Some(model),
+            _ => None,
+        })
+        .collect();
+    for model in &models {
+        let index = game
+            .store_items()
+            .iter()
+            .position(|item| item.action == StoreAction::BuyProcessor { model })
+            .expect("sold");
+        game.purchase_item(index, None).expect("buys the model");
+    }
+
+    let path =
+        std::env::temp_dir().join(format!("array-of-babel-models-{}.ron", std::process::id()));
+    save_game_to(&game.state, &path).expect("saves");
+    let loaded = load_game_from(&path).expect("reads").expect("save exists");
+    std::fs::remove_file(&path).ok();
+    std::fs::remove_file(meta_path_for(&path)).ok();
+    let reloaded = Game::from_state(loaded);
+
+    assert!(reloaded.quarantine_notices().is_empty());
+    let racked: Vec<&str> = reloaded
+        .state
+        .processors
+        .iter()
+        .skip(1)
+        .map(|processor| processor.name.as_str())
+        .collect();
+    assert_eq!(racked, models);
+}